@@ -2,6 +2,31 @@ use std::env;
 use std::fmt;
 use tracing::info;
 
+/// `TODO_RETENTION_DAYS` 가 없을 때의 휴지통 보존 일수.
+const DEFAULT_TODO_RETENTION_DAYS: i64 = 30;
+
+/// `ATTACHMENT_DIR` 가 없을 때 첨부 파일을 저장하는 디렉터리.
+const DEFAULT_ATTACHMENT_DIR: &str = "./storage/attachments";
+
+/// `ATTACHMENT_MAX_BYTES` 가 없을 때의 첨부 파일 최대 크기 (10 MiB).
+const DEFAULT_ATTACHMENT_MAX_BYTES: u64 = 10 * 1024 * 1024;
+
+/// `ATTACHMENT_CONTENT_TYPES` 가 없을 때 허용하는 첨부 파일 형식.
+const DEFAULT_ATTACHMENT_CONTENT_TYPES: &str =
+    "image/png,image/jpeg,image/gif,application/pdf,text/plain";
+
+/// `REMINDER_POLL_SECONDS` 가 없을 때 보낼 알림을 찾는 주기(초).
+const DEFAULT_REMINDER_POLL_SECONDS: u64 = 30;
+
+/// `REMINDER_BATCH_SIZE` 가 없을 때 한 주기에 보내는 최대 알림 수.
+const DEFAULT_REMINDER_BATCH_SIZE: i64 = 100;
+
+/// `IDEMPOTENCY_TTL_SECONDS` 가 없을 때 멱등 키와 응답을 남겨 두는 시간(초, 24시간).
+const DEFAULT_IDEMPOTENCY_TTL_SECONDS: i64 = 24 * 60 * 60;
+
+/// `IDEMPOTENCY_SWEEP_SECONDS` 가 없을 때 만료된 멱등 키를 지우는 주기(초, 10분).
+const DEFAULT_IDEMPOTENCY_SWEEP_SECONDS: u64 = 10 * 60;
+
 // ---------------------------------------------------------------------------
 // ConfigError
 // ---------------------------------------------------------------------------
@@ -39,6 +64,28 @@ pub struct ApplicationConfig {
     pub allowed_origin: String,
     pub jwt_duration: i64,
     pub jwt_max_age: i64,
+    /// 휴지통의 todo 를 영구 삭제하기 전까지 보존하는 일수.
+    pub todo_retention_days: i64,
+    /// 하위 작업을 모두 끝내면 상위 todo 도 완료(`done`)로 바꿀지 여부.
+    pub todo_rollup_completion: bool,
+    /// 로컬 파일 시스템 저장소에서 첨부 파일을 저장하는 디렉터리.
+    pub attachment_dir: String,
+    /// 첨부 파일 하나의 최대 크기(바이트).
+    pub attachment_max_bytes: u64,
+    /// 업로드를 허용하는 첨부 파일의 content type 목록. 소문자이다.
+    pub attachment_content_types: Vec<String>,
+    /// 보낼 알림을 찾는 주기(초). 0 이면 알림 작업을 띄우지 않는다.
+    pub reminder_poll_seconds: u64,
+    /// 한 주기에 보내는 최대 알림 수.
+    pub reminder_batch_size: i64,
+    /// 알림을 POST 할 웹훅 URL. 없으면 로그로만 남긴다.
+    pub reminder_webhook_url: Option<String>,
+    /// 상태 목록을 관리할 수 있는 관리자 사용자 이름(이메일) 목록.
+    pub admin_usernames: Vec<String>,
+    /// `Idempotency-Key` 로 받은 키와 처음 응답을 남겨 두는 시간(초). 지나면 같은 키를 다시 쓸 수 있다.
+    pub idempotency_ttl_seconds: i64,
+    /// 만료된 멱등 키를 지우는 주기(초). 0 이면 정리 작업을 띄우지 않는다.
+    pub idempotency_sweep_seconds: u64,
 }
 
 impl ApplicationConfig {
@@ -63,6 +110,91 @@ impl ApplicationConfig {
             .parse::<i64>()
             .map_err(|e| ConfigError::ParseError("JWT_MAX_AGE", e.to_string()))?;
 
+        let todo_retention_days = match env::var("TODO_RETENTION_DAYS") {
+            Ok(days) => days
+                .parse::<i64>()
+                .ok()
+                .filter(|days| *days >= 0)
+                .ok_or_else(|| {
+                    ConfigError::ParseError("TODO_RETENTION_DAYS", format!("invalid days: {days}"))
+                })?,
+            Err(_) => DEFAULT_TODO_RETENTION_DAYS,
+        };
+
+        let todo_rollup_completion = match env::var("TODO_ROLLUP_COMPLETION") {
+            Ok(value) => value
+                .parse::<bool>()
+                .map_err(|e| ConfigError::ParseError("TODO_ROLLUP_COMPLETION", e.to_string()))?,
+            Err(_) => false,
+        };
+
+        let attachment_dir =
+            env::var("ATTACHMENT_DIR").unwrap_or_else(|_| DEFAULT_ATTACHMENT_DIR.to_string());
+
+        let attachment_max_bytes = match env::var("ATTACHMENT_MAX_BYTES") {
+            Ok(bytes) => bytes
+                .parse::<u64>()
+                .ok()
+                .filter(|bytes| *bytes > 0)
+                .ok_or_else(|| {
+                    ConfigError::ParseError(
+                        "ATTACHMENT_MAX_BYTES",
+                        format!("invalid size: {bytes}"),
+                    )
+                })?,
+            Err(_) => DEFAULT_ATTACHMENT_MAX_BYTES,
+        };
+
+        let attachment_content_types = parse_content_types(
+            &env::var("ATTACHMENT_CONTENT_TYPES")
+                .unwrap_or_else(|_| DEFAULT_ATTACHMENT_CONTENT_TYPES.to_string()),
+        );
+
+        let reminder_poll_seconds = match env::var("REMINDER_POLL_SECONDS") {
+            Ok(seconds) => seconds
+                .parse::<u64>()
+                .map_err(|e| ConfigError::ParseError("REMINDER_POLL_SECONDS", e.to_string()))?,
+            Err(_) => DEFAULT_REMINDER_POLL_SECONDS,
+        };
+
+        let reminder_batch_size = match env::var("REMINDER_BATCH_SIZE") {
+            Ok(size) => size
+                .parse::<i64>()
+                .ok()
+                .filter(|size| *size > 0)
+                .ok_or_else(|| {
+                    ConfigError::ParseError("REMINDER_BATCH_SIZE", format!("invalid size: {size}"))
+                })?,
+            Err(_) => DEFAULT_REMINDER_BATCH_SIZE,
+        };
+
+        let reminder_webhook_url = env::var("REMINDER_WEBHOOK_URL")
+            .ok()
+            .filter(|url| !url.trim().is_empty());
+
+        let admin_usernames = parse_list(&env::var("ADMIN_USERNAMES").unwrap_or_default());
+
+        let idempotency_ttl_seconds = match env::var("IDEMPOTENCY_TTL_SECONDS") {
+            Ok(seconds) => seconds
+                .parse::<i64>()
+                .ok()
+                .filter(|seconds| *seconds > 0)
+                .ok_or_else(|| {
+                    ConfigError::ParseError(
+                        "IDEMPOTENCY_TTL_SECONDS",
+                        format!("invalid seconds: {seconds}"),
+                    )
+                })?,
+            Err(_) => DEFAULT_IDEMPOTENCY_TTL_SECONDS,
+        };
+
+        let idempotency_sweep_seconds = match env::var("IDEMPOTENCY_SWEEP_SECONDS") {
+            Ok(seconds) => seconds
+                .parse::<u64>()
+                .map_err(|e| ConfigError::ParseError("IDEMPOTENCY_SWEEP_SECONDS", e.to_string()))?,
+            Err(_) => DEFAULT_IDEMPOTENCY_SWEEP_SECONDS,
+        };
+
         Ok(ApplicationConfig {
             debug,
             database_url,
@@ -70,10 +202,39 @@ impl ApplicationConfig {
             allowed_origin,
             jwt_duration,
             jwt_max_age,
+            todo_retention_days,
+            todo_rollup_completion,
+            attachment_dir,
+            attachment_max_bytes,
+            attachment_content_types,
+            reminder_poll_seconds,
+            reminder_batch_size,
+            reminder_webhook_url,
+            admin_usernames,
+            idempotency_ttl_seconds,
+            idempotency_sweep_seconds,
         })
     }
 }
 
+/// 쉼표로 구분한 content type 목록을 소문자로 정리한다. 빈 항목은 버린다.
+fn parse_content_types(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|t| t.trim().to_ascii_lowercase())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+/// 쉼표로 구분한 목록을 정리한다. 빈 항목은 버린다.
+fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
 fn require_env(name: &'static str) -> Result<String, ConfigError> {
     env::var(name).map_err(|_| ConfigError::MissingEnvVar(name))
 }
@@ -119,6 +280,82 @@ mod tests {
         assert_eq!(config.jwt_max_age, 7200i64);
     }
 
+    #[test]
+    fn application_config_todo_retention_days_defaults_when_unset() {
+        set_env_vars("false");
+        env::remove_var("TODO_RETENTION_DAYS");
+        let config = ApplicationConfig::try_init().expect("설정 파싱 성공해야 함");
+        assert_eq!(config.todo_retention_days, DEFAULT_TODO_RETENTION_DAYS);
+    }
+
+    #[test]
+    fn application_config_todo_rollup_completion_defaults_to_false() {
+        set_env_vars("false");
+        env::remove_var("TODO_ROLLUP_COMPLETION");
+        let config = ApplicationConfig::try_init().expect("설정 파싱 성공해야 함");
+        assert!(!config.todo_rollup_completion);
+    }
+
+    #[test]
+    fn application_config_attachment_settings_default_when_unset() {
+        set_env_vars("false");
+        env::remove_var("ATTACHMENT_MAX_BYTES");
+        env::remove_var("ATTACHMENT_CONTENT_TYPES");
+        let config = ApplicationConfig::try_init().expect("설정 파싱 성공해야 함");
+        assert_eq!(config.attachment_max_bytes, DEFAULT_ATTACHMENT_MAX_BYTES);
+        assert!(config
+            .attachment_content_types
+            .contains(&"application/pdf".to_string()));
+    }
+
+    #[test]
+    fn application_config_reminder_settings_default_when_unset() {
+        set_env_vars("false");
+        env::remove_var("REMINDER_POLL_SECONDS");
+        env::remove_var("REMINDER_BATCH_SIZE");
+        env::remove_var("REMINDER_WEBHOOK_URL");
+        let config = ApplicationConfig::try_init().expect("설정 파싱 성공해야 함");
+        assert_eq!(config.reminder_poll_seconds, DEFAULT_REMINDER_POLL_SECONDS);
+        assert_eq!(config.reminder_batch_size, DEFAULT_REMINDER_BATCH_SIZE);
+        assert!(config.reminder_webhook_url.is_none());
+    }
+
+    #[test]
+    fn application_config_idempotency_ttl_defaults_when_unset() {
+        set_env_vars("false");
+        env::remove_var("IDEMPOTENCY_TTL_SECONDS");
+        env::remove_var("IDEMPOTENCY_SWEEP_SECONDS");
+        let config = ApplicationConfig::try_init().expect("설정 파싱 성공해야 함");
+        assert_eq!(
+            config.idempotency_ttl_seconds,
+            DEFAULT_IDEMPOTENCY_TTL_SECONDS
+        );
+        assert_eq!(
+            config.idempotency_sweep_seconds,
+            DEFAULT_IDEMPOTENCY_SWEEP_SECONDS
+        );
+    }
+
+    #[test]
+    fn parse_content_types_trims_and_lowercases() {
+        assert_eq!(
+            parse_content_types(" Image/PNG, ,text/plain"),
+            vec!["image/png".to_string(), "text/plain".to_string()]
+        );
+    }
+
+    #[test]
+    fn parse_list_trims_and_drops_empty_entries() {
+        assert_eq!(
+            parse_list(" admin@example.com, ,ops@example.com "),
+            vec![
+                "admin@example.com".to_string(),
+                "ops@example.com".to_string()
+            ]
+        );
+        assert!(parse_list("").is_empty());
+    }
+
     #[test]
     fn application_config_returns_error_when_env_var_missing() {
         set_env_vars("false");
@@ -3,6 +3,8 @@ use controller::module::usecase_module::{AppModule, AppState};
 use controller::startup::startup;
 use dotenvy::dotenv;
 use infra::db::{create_pool, Db, DbParameters};
+use infra::notifier::{INotifier, WebhookNotifier};
+use infra::storage::{LocalBlobStorage, LocalBlobStorageParameters};
 use std::sync::Arc;
 use tracing::info;
 use tracing_subscriber::EnvFilter;
@@ -17,11 +19,17 @@ async fn main() -> anyhow::Result<()> {
     info!(debug = %config.debug, allowed_origin = %config.allowed_origin, "server starting");
 
     let pool = create_pool(&config).await?;
-    let module = Arc::new(
-        AppModule::builder()
-            .with_component_parameters::<Db>(DbParameters { pool })
-            .build(),
-    );
+    let mut builder = AppModule::builder()
+        .with_component_parameters::<Db>(DbParameters { pool })
+        .with_component_parameters::<LocalBlobStorage>(LocalBlobStorageParameters {
+            root: config.attachment_dir.clone().into(),
+        });
+    // 웹훅 URL 이 있으면 로그 대신 웹훅으로 알림을 보낸다.
+    if let Some(url) = config.reminder_webhook_url.clone() {
+        builder =
+            builder.with_component_override::<dyn INotifier>(Box::new(WebhookNotifier::new(url)));
+    }
+    let module = Arc::new(builder.build());
     let app_state = AppState::new(module, config);
     startup(Arc::new(app_state)).await?;
     Ok(())
@@ -1,19 +1,55 @@
+use crate::model::attachment::AttachmentUpload;
+use crate::model::batch::{JsonTodoBatch, JsonTodoBatchOperation};
+use crate::model::comment::{CommentQuery, JsonCreateComment, JsonUpdateComment};
+use crate::model::history::TodoHistoryQuery;
+use crate::model::position::JsonMoveTodo;
+use crate::model::project::{JsonCreateProject, JsonUpdateProject, ProjectQuery};
+use crate::model::recurrence::TodoOccurrencesQuery;
+use crate::model::reminder::JsonSnoozeTodo;
+use crate::model::share::JsonShareTodo;
+use crate::model::stats::{
+    JsonTodoStats, JsonTodoStatsBucket, JsonTodoStatusCount, TodoStatsQuery,
+};
+use crate::model::status::{
+    JsonCreateTodoStatus, JsonRenameTodoStatus, JsonTodoStatus, JsonTodoStatusEntry,
+};
+use crate::model::tag::{JsonCreateTag, JsonUpdateTag};
 use crate::model::todo::{
-    JsonCreateTodo, JsonUpdateTodoContents, JsonUpsertTodoContents, TodoQuery,
+    DeleteTodoQuery, GetTodoQuery, JsonCreateTodo, JsonUpdateTodoContents, JsonUpsertTodoContents,
+    TodoQuery,
 };
-use crate::model::user::{JsonCreateUser, UserQuery};
-use crate::routes::{todo, user};
+use crate::model::transfer::{TransferFormat, TransferQuery};
+use crate::model::user::{JsonCreateUser, JsonUpdateUserLocale, UserQuery};
+use crate::routes::{attachment, comment, project, share, status, tag, todo, transfer, user};
 use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
 use utoipa::{Modify, OpenApi};
 #[derive(OpenApi)]
 #[openapi(
     paths(
-        todo::get_todo, todo::find_todo, todo::create_todo, todo::update_todo, todo::upsert_todo, todo::delete_todo,
-        user::create_user, user::get_user, user::get_user_by_username, user::login_user
+        todo::get_todo, todo::find_todo, todo::find_todo_children, todo::find_todo_history, todo::find_todo_occurrences, todo::stop_todo_recurrence, todo::snooze_todo, todo::move_todo, todo::create_todo, todo::run_todo_batch, todo::update_todo, todo::upsert_todo, todo::delete_todo,
+        todo::restore_todo, todo::purge_trash, todo::get_status_transitions, todo::get_todo_stats,
+        transfer::export_todos, transfer::import_todos,
+        attachment::find_attachments, attachment::upload_attachment, attachment::download_attachment, attachment::delete_attachment,
+        comment::find_comments, comment::create_comment, comment::update_comment, comment::delete_comment,
+        share::find_todo_shares, share::share_todo, share::unshare_todo,
+        status::find_todo_statuses, status::create_todo_status, status::rename_todo_status, status::disable_todo_status, status::enable_todo_status,
+        tag::find_tags, tag::get_tag, tag::create_tag, tag::update_tag, tag::delete_tag,
+        project::find_projects, project::get_project, project::find_project_todos, project::create_project, project::update_project, project::delete_project,
+        project::archive_project, project::unarchive_project,
+        user::create_user, user::get_user, user::get_user_by_username, user::update_user_locale, user::login_user
     ),
     components(schemas(
-        JsonCreateTodo, TodoQuery, JsonUpdateTodoContents, JsonUpsertTodoContents,
-        JsonCreateUser, UserQuery
+        JsonCreateTodo, TodoQuery, GetTodoQuery, DeleteTodoQuery, JsonUpdateTodoContents, JsonUpsertTodoContents, TodoHistoryQuery, TodoOccurrencesQuery, JsonSnoozeTodo, JsonMoveTodo,
+        JsonTodoBatch, JsonTodoBatchOperation,
+        TodoStatsQuery, JsonTodoStats, JsonTodoStatusCount, JsonTodoStatsBucket,
+        TransferFormat, TransferQuery,
+        AttachmentUpload,
+        CommentQuery, JsonCreateComment, JsonUpdateComment,
+        JsonShareTodo,
+        JsonTodoStatus, JsonTodoStatusEntry, JsonCreateTodoStatus, JsonRenameTodoStatus,
+        JsonCreateTag, JsonUpdateTag,
+        ProjectQuery, JsonCreateProject, JsonUpdateProject,
+        JsonCreateUser, JsonUpdateUserLocale, UserQuery
         )
     ),
     modifiers(&SecurityAddon),
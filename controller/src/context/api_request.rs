@@ -1,9 +1,13 @@
 use crate::context::errors::AppError;
 use crate::context::validate::ValidatedRequest;
+use crate::model::patch::{JsonTodoPatch, JSON_PATCH_CONTENT_TYPE, MERGE_PATCH_CONTENT_TYPE};
+use crate::model::todo::JsonUpdateTodoContents;
 use axum::extract::rejection::JsonRejection;
 use axum::extract::{FromRequest, Request};
+use axum::http::header::CONTENT_TYPE;
 use axum::{async_trait, Json};
 use serde::de::DeserializeOwned;
+use serde_json::Value;
 use validator::Validate;
 
 #[async_trait]
@@ -21,3 +25,38 @@ where
         Ok(ValidatedRequest(value))
     }
 }
+
+#[async_trait]
+impl<S> FromRequest<S> for JsonTodoPatch
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let content_type = req
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.split(';').next())
+            .map(|v| v.trim().to_ascii_lowercase())
+            .unwrap_or_default();
+        match content_type.as_str() {
+            MERGE_PATCH_CONTENT_TYPE => match Json::<Value>::from_request(req, state).await? {
+                Json(Value::Object(patch)) => Ok(JsonTodoPatch::Merge(patch)),
+                Json(_) => Err(AppError::Error(
+                    "merge patch must be a JSON object.".to_string(),
+                )),
+            },
+            JSON_PATCH_CONTENT_TYPE => {
+                let Json(operations) = Json::from_request(req, state).await?;
+                Ok(JsonTodoPatch::Operations(operations))
+            }
+            _ => {
+                let ValidatedRequest(contents) =
+                    ValidatedRequest::<JsonUpdateTodoContents>::from_request(req, state).await?;
+                Ok(JsonTodoPatch::Contents(Box::new(contents)))
+            }
+        }
+    }
+}
@@ -1,15 +1,21 @@
 use crate::context::errors::AppError;
+use crate::context::locale::Locale;
+use crate::context::message::Message;
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use axum::Json;
 use serde::{Deserialize, Serialize};
 use tracing::error;
+use usecase::error::UseCaseError;
 use utoipa::ToSchema;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ApiResponse<Data> {
     pub result: bool,
+    /// 실패 응답의 메시지 코드. 언어와 무관하므로 클라이언트는 `message` 대신 이 값으로 구분한다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
     pub message: String,
     pub data: Option<Data>,
 }
@@ -18,68 +24,166 @@ impl<Data> ApiResponse<Data> {
     pub fn success(message: impl Into<String>, data: Data) -> Self {
         Self {
             result: true,
+            code: None,
             message: message.into(),
             data: Some(data),
         }
     }
 }
 
+/// 오류 응답의 메시지 코드. 응답 확장에 넣어 두어 미들웨어가 본문을 읽지 않고도 어떤 오류인지 알 수 있다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ErrorCode(pub &'static str);
+
 pub(crate) fn internal_error(err: impl std::fmt::Debug) -> AppError {
     error!(error = ?err, "internal error");
-    AppError::Error("서버 오류가 발생했습니다".to_string())
+    Message::InternalError.into()
 }
 
-impl IntoResponse for AppError {
-    fn into_response(self) -> Response {
-        let (status_code, error_message) = match self {
+/// 유스케이스 오류를 응답 오류로 변환한다.
+/// `UseCaseError` 가 아니면 내부 오류로 처리한다.
+pub(crate) fn usecase_error(err: anyhow::Error) -> AppError {
+    match err.downcast_ref::<UseCaseError>() {
+        Some(UseCaseError::NotFound(_)) => {
+            error!(error = %err, "not found");
+            Message::NotFound.into()
+        }
+        Some(UseCaseError::InvalidParameter(name)) => {
+            error!(error = %err, "invalid parameter");
+            Message::InvalidParameter(name.clone()).into()
+        }
+        Some(UseCaseError::Conflict(reason)) => {
+            error!(error = %err, "conflict");
+            AppError::Conflict(reason.clone().into())
+        }
+        Some(UseCaseError::Forbidden(reason)) => {
+            error!(error = %err, "forbidden");
+            AppError::Forbidden(reason.clone().into())
+        }
+        Some(UseCaseError::PreconditionFailed(reason)) => {
+            error!(error = %err, "precondition failed");
+            AppError::PreconditionFailed(reason.clone().into())
+        }
+        None => internal_error(err),
+    }
+}
+
+/// 검증 오류를 카탈로그 메시지로 모은다.
+pub(crate) fn validation_messages(errors: &validator::ValidationErrors) -> Vec<Message> {
+    errors
+        .field_errors()
+        .into_iter()
+        .flat_map(|(field, v)| {
+            v.iter()
+                .map(|e| Message::from_validation(field, e))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// 메시지들을 언어에 맞게 한 문장으로 잇는다.
+fn join_messages(messages: &[Message], locale: Locale) -> String {
+    messages
+        .iter()
+        .map(|m| m.text(locale))
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
+impl AppError {
+    /// 응답 상태 코드, 메시지 코드, 사용자에게 보여줄 메시지를 정한다.
+    pub(crate) fn status_and_message(self, locale: Locale) -> (StatusCode, &'static str, String) {
+        let localized = |message: Message| message.text(locale);
+        match self {
             AppError::InvalidJwt(_) => {
                 error!("invalid or missing JWT");
-                (StatusCode::UNAUTHORIZED, "인증이 필요합니다".to_string())
+                (
+                    StatusCode::UNAUTHORIZED,
+                    Message::Unauthorized.code(),
+                    localized(Message::Unauthorized),
+                )
             }
-            AppError::Forbidden(_) => {
-                error!("access forbidden");
-                (StatusCode::FORBIDDEN, "접근이 거부되었습니다".to_string())
+            AppError::Forbidden(message) => {
+                error!(code = message.code(), "access forbidden");
+                (StatusCode::FORBIDDEN, message.code(), localized(message))
             }
             AppError::Validation(validation_errors) => {
-                let messages: Vec<String> = validation_errors
-                    .field_errors()
-                    .into_values()
-                    .flat_map(|v| {
-                        v.iter()
-                            .filter_map(|e| e.message.as_deref().map(str::to_string))
-                            .collect::<Vec<_>>()
-                    })
-                    .collect();
-                error!(messages = %messages.join(", "), "validation failed");
-                (StatusCode::BAD_REQUEST, messages.join(" or "))
+                let messages = validation_messages(&validation_errors);
+                let text = join_messages(&messages, locale);
+                error!(messages = %text, "validation failed");
+                (StatusCode::BAD_REQUEST, "validation_failed", text)
             }
             AppError::JsonRejection(rejection) => {
                 error!(error = %rejection, "JSON rejection");
-                (StatusCode::BAD_REQUEST, rejection.to_string())
+                let message = Message::InvalidBody(rejection.body_text());
+                (StatusCode::BAD_REQUEST, message.code(), localized(message))
             }
             AppError::ApiPathRejection(rejection) => {
                 error!(error = %rejection, "path rejection");
-                (StatusCode::BAD_REQUEST, rejection.to_string())
+                let message = Message::InvalidPath(rejection.body_text());
+                (StatusCode::BAD_REQUEST, message.code(), localized(message))
+            }
+            AppError::Conflict(message) => {
+                error!(code = message.code(), "conflict");
+                (StatusCode::CONFLICT, message.code(), localized(message))
+            }
+            AppError::PreconditionFailed(message) => {
+                error!(code = message.code(), "precondition failed");
+                (
+                    StatusCode::PRECONDITION_FAILED,
+                    message.code(),
+                    localized(message),
+                )
             }
             AppError::UnknownApiVerRejection(version) => {
                 error!(version = %version, "unknown API version");
+                let message = Message::UnknownApiVersion(version);
+                (StatusCode::BAD_REQUEST, message.code(), localized(message))
+            }
+            AppError::IdempotencyKeyReused => {
+                error!("idempotency key reused with a different request");
+                let message = Message::IdempotencyKeyReused;
                 (
-                    StatusCode::BAD_REQUEST,
-                    format!("Unknown api version({version})."),
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    message.code(),
+                    localized(message),
                 )
             }
+            AppError::IdempotencyKeyInProgress => {
+                error!("idempotency key in progress");
+                let message = Message::IdempotencyKeyInProgress;
+                (StatusCode::CONFLICT, message.code(), localized(message))
+            }
+            AppError::Localized(messages) => {
+                let text = join_messages(&messages, locale);
+                error!(messages = %text, "application error");
+                let code = match messages.as_slice() {
+                    [message] => message.code(),
+                    _ => "validation_failed",
+                };
+                (StatusCode::OK, code, text)
+            }
             AppError::Error(error) => {
                 error!(error = %error, "application error");
-                (StatusCode::OK, format!("error({error})."))
+                (StatusCode::OK, "error", format!("error({error})."))
             }
-        };
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let (status_code, code, error_message) = self.status_and_message(Locale::current());
         let response: ApiResponse<String> = ApiResponse::<String> {
             result: false,
+            code: Some(code.to_string()),
             message: error_message,
             data: None,
         };
 
-        (status_code, Json(response)).into_response()
+        let mut response = (status_code, Json(response)).into_response();
+        response.extensions_mut().insert(ErrorCode(code));
+        response
     }
 }
 
@@ -89,6 +193,7 @@ mod tests {
     use crate::context::errors::AppError;
     use axum::http::StatusCode;
     use axum::response::IntoResponse;
+    use usecase::error::ErrorReason;
 
     #[test]
     fn app_error_invalid_jwt_returns_unauthorized() {
@@ -99,11 +204,52 @@ mod tests {
 
     #[test]
     fn app_error_forbidden_returns_forbidden_status() {
-        let err = AppError::Forbidden("forbidden".to_string());
+        let err = AppError::Forbidden(Message::Forbidden);
         let response = err.into_response();
         assert_eq!(response.status(), StatusCode::FORBIDDEN);
     }
 
+    #[test]
+    fn app_error_conflict_returns_conflict_status() {
+        let err = AppError::Conflict(Message::Conflict);
+        let response = err.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn app_error_precondition_failed_returns_precondition_failed_status() {
+        let err = AppError::PreconditionFailed(Message::TodoChanged);
+        let response = err.into_response();
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+    }
+
+    #[test]
+    fn usecase_error_conflict_maps_to_app_error_conflict() {
+        let err = anyhow::Error::from(UseCaseError::Conflict(ErrorReason::NotInTrash));
+        assert!(matches!(usecase_error(err), AppError::Conflict(_)));
+    }
+
+    #[test]
+    fn usecase_error_forbidden_maps_to_app_error_forbidden() {
+        let err = anyhow::Error::from(UseCaseError::Forbidden(ErrorReason::OwnerOnly));
+        assert!(matches!(usecase_error(err), AppError::Forbidden(_)));
+    }
+
+    #[test]
+    fn usecase_error_precondition_failed_maps_to_app_error_precondition_failed() {
+        let err = anyhow::Error::from(UseCaseError::PreconditionFailed(ErrorReason::TodoChanged));
+        assert!(matches!(
+            usecase_error(err),
+            AppError::PreconditionFailed(_)
+        ));
+    }
+
+    #[test]
+    fn app_error_idempotency_key_reused_returns_unprocessable_entity() {
+        let response = AppError::IdempotencyKeyReused.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
     #[test]
     fn app_error_unknown_ver_rejection_returns_bad_request() {
         let err = AppError::UnknownApiVerRejection("v99".to_string());
@@ -122,6 +268,7 @@ mod tests {
     fn api_response_fields_set_correctly() {
         let resp: ApiResponse<String> = ApiResponse {
             result: true,
+            code: None,
             message: "success".to_string(),
             data: Some("payload".to_string()),
         };
@@ -134,6 +281,7 @@ mod tests {
     fn api_response_with_none_data_has_no_payload() {
         let resp: ApiResponse<String> = ApiResponse {
             result: false,
+            code: Some("error".to_string()),
             message: "error".to_string(),
             data: None,
         };
@@ -145,6 +293,7 @@ mod tests {
     fn api_response_serializes_to_json() {
         let resp: ApiResponse<String> = ApiResponse {
             result: true,
+            code: None,
             message: "ok".to_string(),
             data: Some("value".to_string()),
         };
@@ -158,6 +307,7 @@ mod tests {
     fn api_response_null_data_serializes_as_null() {
         let resp: ApiResponse<String> = ApiResponse {
             result: false,
+            code: None,
             message: "err".to_string(),
             data: None,
         };
@@ -1,5 +1,7 @@
 use crate::context::errors::AppError;
 use crate::context::errors::AppError::InvalidJwt;
+use crate::context::locale::Locale;
+use crate::context::message::Message;
 use crate::model::user::TokenClaims;
 use crate::module::usecase_module::AppState;
 use axum::extract::{Request, State};
@@ -27,8 +29,13 @@ pub async fn auth(
             error!(error = %err, "authorization failed");
             InvalidJwt(err.to_string())
         })?;
+    // 사용자가 고른 언어가 있으면 `Accept-Language` 보다 앞선다.
+    let preferred = current_user.locale.as_deref().and_then(Locale::parse);
     req.extensions_mut().insert(current_user);
-    Ok(next.run(req).await)
+    Ok(match preferred {
+        Some(locale) => locale.scope(next.run(req)).await,
+        None => next.run(req).await,
+    })
 }
 
 async fn authorize_current_user(
@@ -57,3 +64,19 @@ async fn authorize_current_user(
         })?
         .ok_or_else(|| InvalidJwt("user not found".to_string()))
 }
+
+/// `auth` 뒤에 두어 설정의 관리자(`ADMIN_USERNAMES`)만 통과시킨다.
+pub async fn require_admin(
+    State(state): State<Arc<AppState>>,
+    req: Request,
+    next: Next,
+) -> Result<impl IntoResponse, AppError> {
+    let is_admin = req
+        .extensions()
+        .get::<UserView>()
+        .is_some_and(|user| state.config.admin_usernames.contains(&user.username));
+    if !is_admin {
+        return Err(AppError::Forbidden(Message::Forbidden));
+    }
+    Ok(next.run(req).await)
+}
@@ -1,19 +1,42 @@
+use crate::context::message::Message;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum AppError {
     #[error("{0}")]
     InvalidJwt(String),
-    #[error("{0}")]
-    Forbidden(String),
+    /// 권한이 없다. `403 Forbidden` 으로 응답한다.
+    #[error("{0:?}")]
+    Forbidden(Message),
     #[error(transparent)]
     Validation(#[from] validator::ValidationErrors),
     #[error(transparent)]
     JsonRejection(#[from] axum::extract::rejection::JsonRejection),
     #[error(transparent)]
     ApiPathRejection(#[from] axum::extract::rejection::PathRejection),
+    /// 현재 상태와 충돌한다. `409 Conflict` 로 응답한다.
+    #[error("{0:?}")]
+    Conflict(Message),
+    /// 기대한 버전과 현재 버전이 다르다. `412 Precondition Failed` 로 응답한다.
+    #[error("{0:?}")]
+    PreconditionFailed(Message),
     #[error("{0}")]
     UnknownApiVerRejection(String),
+    /// 같은 멱등 키를 다른 요청에 썼다. `422 Unprocessable Entity` 로 응답한다.
+    #[error("idempotency key reused")]
+    IdempotencyKeyReused,
+    /// 같은 멱등 키로 보낸 처음 요청을 아직 처리하고 있다. `409 Conflict` 로 응답한다.
+    #[error("idempotency key in progress")]
+    IdempotencyKeyInProgress,
+    /// 카탈로그 메시지로 알리는 오류. `Error` 와 같이 `200 OK` 에 `result: false` 로 응답한다.
+    #[error("{0:?}")]
+    Localized(Vec<Message>),
     #[error("{0}")]
     Error(String),
 }
+
+impl From<Message> for AppError {
+    fn from(message: Message) -> Self {
+        AppError::Localized(vec![message])
+    }
+}
@@ -0,0 +1,206 @@
+use crate::context::api_response::{usecase_error, ErrorCode};
+use crate::context::errors::AppError;
+use crate::context::message::Message;
+use crate::module::usecase_module::AppState;
+use axum::body::{to_bytes, Body, Bytes};
+use axum::extract::{OriginalUri, Request, State};
+use axum::http::{HeaderName, HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::Response;
+use sha2::{Digest, Sha256};
+use shaku::HasComponent;
+use std::sync::Arc;
+use tracing::error;
+use usecase::model::idempotency::{IdempotencyBegin, IdempotentResponseView};
+use usecase::model::user::UserView;
+use usecase::usecase::idempotency::IIdempotencyUseCase;
+
+/// 멱등 키를 받는 요청 헤더.
+pub const IDEMPOTENCY_KEY: HeaderName = HeaderName::from_static("idempotency-key");
+
+/// 오류 메시지에 쓰는 헤더 이름.
+const IDEMPOTENCY_KEY_FIELD: &str = "Idempotency-Key";
+
+/// 저장해 둔 처음 응답을 돌려주었음을 알리는 응답 헤더.
+pub const IDEMPOTENT_REPLAYED: HeaderName = HeaderName::from_static("idempotent-replayed");
+
+/// 요청이 같은지 비교하려고 읽는 본문의 최대 크기. axum 의 기본 본문 한도와 같다.
+const MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// `auth` 뒤에 두어 `Idempotency-Key` 헤더가 있는 POST 요청을 한 번만 처리한다.
+///
+/// 같은 사용자가 같은 키로 같은 요청을 다시 보내면 핸들러를 부르지 않고 처음 응답을
+/// `Idempotent-Replayed: true` 와 함께 돌려준다. 같은 키로 메서드, 경로, 본문이 다른 요청을 보내면 `422`,
+/// 처음 요청을 아직 처리하고 있으면 `409` 이다. 서버 오류(5xx)나 `200` 에 담긴 내부 오류(`internal_error`)로
+/// 끝난 응답은 남기지 않으므로 같은 키로 다시 시도할 수 있다. 헤더가 없는 요청은 그대로 처리한다.
+pub(crate) async fn idempotency(
+    State(state): State<Arc<AppState>>,
+    req: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    if req.method() != Method::POST {
+        return Ok(next.run(req).await);
+    }
+    let Some(key) = req.headers().get(IDEMPOTENCY_KEY) else {
+        return Ok(next.run(req).await);
+    };
+    let key = key
+        .to_str()
+        .map_err(|_| Message::InvalidParameter(IDEMPOTENCY_KEY_FIELD.to_string()))?
+        .to_string();
+    let user_id = req
+        .extensions()
+        .get::<UserView>()
+        .map(|user| user.id.clone())
+        .ok_or_else(|| AppError::InvalidJwt("user not found".to_string()))?;
+
+    let (parts, body) = req.into_parts();
+    let body = to_bytes(body, MAX_BODY_BYTES)
+        .await
+        .map_err(|err| Message::InvalidBody(err.to_string()))?;
+    let uri = parts
+        .extensions
+        .get::<OriginalUri>()
+        .map_or(&parts.uri, |original| &original.0);
+    let hash = request_hash(&parts.method, uri.to_string().as_str(), &body);
+
+    let uc: Arc<dyn IIdempotencyUseCase> = state.module.resolve();
+    let ttl_seconds = state.config.idempotency_ttl_seconds;
+    match uc
+        .begin(user_id.clone(), key.clone(), hash, ttl_seconds)
+        .await
+        .map_err(usecase_error)?
+    {
+        IdempotencyBegin::Proceed => {}
+        IdempotencyBegin::Replay(stored) => return Ok(replay(stored)),
+        IdempotencyBegin::InProgress => return Err(AppError::IdempotencyKeyInProgress),
+        IdempotencyBegin::Mismatch => return Err(AppError::IdempotencyKeyReused),
+    }
+
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+    if !is_storable(&response) {
+        if let Err(err) = uc.release(user_id, key).await {
+            error!(error = %err, "idempotency key release failed");
+        }
+        return Ok(response);
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = match to_bytes(body, usize::MAX).await {
+        Ok(body) => body,
+        Err(err) => {
+            error!(error = %err, "response body could not be read");
+            if let Err(err) = uc.release(user_id, key).await {
+                error!(error = %err, "idempotency key release failed");
+            }
+            return Err(Message::InternalError.into());
+        }
+    };
+    let stored = IdempotentResponseView {
+        status: parts.status.as_u16(),
+        headers: parts
+            .headers
+            .iter()
+            .filter_map(|(name, value)| {
+                Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+            })
+            .collect(),
+        body: body.to_vec(),
+    };
+    // 남기지 못한 키는 처리 중으로 남았다가 잠시 뒤 다시 쓸 수 있게 되므로 응답은 그대로 보낸다.
+    if let Err(err) = uc.complete(user_id, key, stored).await {
+        error!(error = %err, "idempotency key completion failed");
+    }
+    Ok(Response::from_parts(parts, Body::from(body)))
+}
+
+/// 다시 보낸 요청에 그대로 돌려줄 응답인지 여부. 일시적일 수 있는 서버 오류와 내부 오류는 남기지 않는다.
+fn is_storable(response: &Response) -> bool {
+    let internal_error = response
+        .extensions()
+        .get::<ErrorCode>()
+        .is_some_and(|code| code.0 == Message::InternalError.code());
+    !response.status().is_server_error() && !internal_error
+}
+
+/// 메서드, 경로와 쿼리, 본문으로 요청의 SHA-256 해시(16진수)를 만든다.
+fn request_hash(method: &Method, uri: &str, body: &Bytes) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(method.as_str());
+    hasher.update(b"\n");
+    hasher.update(uri);
+    hasher.update(b"\n");
+    hasher.update(body);
+    format!("{:x}", hasher.finalize())
+}
+
+/// 저장해 둔 응답을 다시 만든다.
+fn replay(stored: IdempotentResponseView) -> Response {
+    let mut response = Response::new(Body::from(stored.body));
+    *response.status_mut() = StatusCode::from_u16(stored.status).unwrap_or(StatusCode::OK);
+    for (name, value) in stored.headers {
+        if let (Ok(name), Ok(value)) = (HeaderName::try_from(name), HeaderValue::try_from(value)) {
+            response.headers_mut().append(name, value);
+        }
+    }
+    response
+        .headers_mut()
+        .insert(IDEMPOTENT_REPLAYED, HeaderValue::from_static("true"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::todo::batch_failure;
+    use axum::response::IntoResponse;
+
+    #[test]
+    fn request_hash_differs_by_method_path_and_body() {
+        let body = Bytes::from_static(b"{\"title\":\"a\"}");
+        let hash = request_hash(&Method::POST, "/v1/todo", &body);
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash, request_hash(&Method::POST, "/v1/todo", &body));
+        assert_ne!(hash, request_hash(&Method::POST, "/v1/todo/batch", &body));
+        assert_ne!(hash, request_hash(&Method::PUT, "/v1/todo", &body));
+        assert_ne!(
+            hash,
+            request_hash(&Method::POST, "/v1/todo", &Bytes::from_static(b"{}"))
+        );
+    }
+
+    #[test]
+    fn is_storable_skips_server_and_internal_errors() {
+        assert!(is_storable(&StatusCode::CREATED.into_response()));
+        assert!(is_storable(
+            &AppError::from(Message::NotFound).into_response()
+        ));
+        assert!(!is_storable(
+            &StatusCode::SERVICE_UNAVAILABLE.into_response()
+        ));
+        let internal = AppError::from(Message::InternalError).into_response();
+        assert_eq!(internal.status(), StatusCode::OK);
+        assert!(!is_storable(&internal));
+    }
+
+    #[test]
+    fn is_storable_skips_batch_internal_errors() {
+        let conflict = batch_failure(1, AppError::Conflict(Message::Conflict));
+        assert!(is_storable(&conflict));
+        let internal = batch_failure(1, AppError::from(Message::InternalError));
+        assert_eq!(internal.status(), StatusCode::OK);
+        assert!(!is_storable(&internal));
+    }
+
+    #[test]
+    fn replay_restores_status_headers_and_marks_replayed() {
+        let response = replay(IdempotentResponseView {
+            status: 201,
+            headers: vec![("etag".to_string(), "\"1\"".to_string())],
+            body: b"{}".to_vec(),
+        });
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.headers()["etag"], "\"1\"");
+        assert_eq!(response.headers()[IDEMPOTENT_REPLAYED], "true");
+    }
+}
@@ -0,0 +1,60 @@
+use crate::context::errors::AppError;
+use crate::context::message::Message;
+use axum::async_trait;
+use axum::extract::FromRequestParts;
+use axum::http::header::IF_MATCH;
+use axum::http::request::Parts;
+
+/// `If-Match` 헤더로 받은 todo 버전.
+///
+/// 헤더가 없거나 `*` 이면 `None` 이다. ETag 는 [`etag`] 형식의 강한 검증자 하나만 받으며,
+/// 형식이 다르면 어떤 버전과도 맞지 않으므로 `412 Precondition Failed` 로 거절한다.
+#[derive(Debug)]
+pub struct IfMatch(pub Option<i64>);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for IfMatch
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Some(value) = parts.headers.get(IF_MATCH) else {
+            return Ok(IfMatch(None));
+        };
+        let value = value.to_str().unwrap_or_default().trim();
+        if value == "*" {
+            return Ok(IfMatch(None));
+        }
+        parse_etag(value)
+            .map(|v| IfMatch(Some(v)))
+            .ok_or_else(|| AppError::PreconditionFailed(Message::IfMatchMismatch))
+    }
+}
+
+/// todo 버전을 `ETag` 헤더 값으로 만든다.
+pub fn etag(version: i64) -> String {
+    format!("\"{version}\"")
+}
+
+fn parse_etag(value: &str) -> Option<i64> {
+    value.strip_prefix('"')?.strip_suffix('"')?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_etag_reads_quoted_version() {
+        assert_eq!(parse_etag(&etag(7)), Some(7));
+    }
+
+    #[test]
+    fn parse_etag_rejects_weak_or_unquoted_tags() {
+        assert_eq!(parse_etag("W/\"7\""), None);
+        assert_eq!(parse_etag("7"), None);
+        assert_eq!(parse_etag("\"seven\""), None);
+    }
+}
@@ -0,0 +1,117 @@
+use axum::extract::Request;
+use axum::http::header::{ACCEPT_LANGUAGE, VARY};
+use axum::http::HeaderValue;
+use axum::middleware::Next;
+use axum::response::Response;
+use std::future::Future;
+
+tokio::task_local! {
+    static LOCALE: Locale;
+}
+
+/// 응답 메시지와 상태 이름에 쓰는 언어.
+///
+/// 요청마다 [`resolve_locale`] 이 `Accept-Language` 로 정하고, 로그인한 사용자가 언어를 골라 두었으면
+/// `auth` 가 그 값으로 바꾼다. 요청 밖에서는 기본값인 한국어이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    Ko,
+    En,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::Ko, Locale::En];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Locale::Ko => "ko",
+            Locale::En => "en",
+        }
+    }
+
+    /// `ko`, `en-US`, `EN_gb` 처럼 언어 태그의 첫 부분으로 언어를 고른다. 모르는 언어이면 `None`.
+    pub fn parse(tag: &str) -> Option<Locale> {
+        let language = tag.trim().split(['-', '_']).next()?;
+        Locale::ALL
+            .into_iter()
+            .find(|locale| language.eq_ignore_ascii_case(locale.as_str()))
+    }
+
+    /// `Accept-Language` 값에서 품질값(`q`)이 가장 높은, 지원하는 언어를 고른다.
+    /// 품질값이 같으면 먼저 적힌 언어를 쓴다.
+    pub fn from_accept_language(value: &str) -> Option<Locale> {
+        let mut candidates: Vec<(Locale, f32)> = value
+            .split(',')
+            .filter_map(|item| {
+                let mut parts = item.split(';');
+                let locale = Locale::parse(parts.next()?)?;
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+                (quality > 0.0).then_some((locale, quality))
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+        candidates.first().map(|(locale, _)| *locale)
+    }
+
+    /// 지금 처리 중인 요청의 언어.
+    pub fn current() -> Locale {
+        LOCALE.try_with(|locale| *locale).unwrap_or_default()
+    }
+
+    /// `future` 를 이 언어로 실행한다.
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        LOCALE.scope(self, future).await
+    }
+}
+
+/// `Accept-Language` 로 요청의 언어를 정한다. 응답은 언어에 따라 다르므로 `Vary` 에 남긴다.
+pub async fn resolve_locale(req: Request, next: Next) -> Response {
+    let locale = req
+        .headers()
+        .get(ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(Locale::from_accept_language)
+        .unwrap_or_default();
+    let mut response = locale.scope(next.run(req)).await;
+    response
+        .headers_mut()
+        .append(VARY, HeaderValue::from_static("accept-language"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reads_primary_language_subtag() {
+        assert_eq!(Locale::parse("en-US"), Some(Locale::En));
+        assert_eq!(Locale::parse(" KO_kr"), Some(Locale::Ko));
+        assert_eq!(Locale::parse("fr"), None);
+        assert_eq!(Locale::parse("*"), None);
+    }
+
+    #[test]
+    fn from_accept_language_prefers_highest_quality_supported_language() {
+        assert_eq!(
+            Locale::from_accept_language("fr-CH, fr;q=0.9, en;q=0.8, ko;q=0.7"),
+            Some(Locale::En)
+        );
+        assert_eq!(
+            Locale::from_accept_language("ko;q=0.5, en;q=0.9"),
+            Some(Locale::En)
+        );
+        assert_eq!(Locale::from_accept_language("en;q=0, ko"), Some(Locale::Ko));
+        assert_eq!(Locale::from_accept_language("de, *;q=0.1"), None);
+    }
+
+    #[tokio::test]
+    async fn current_follows_scope_and_defaults_to_korean() {
+        assert_eq!(Locale::current(), Locale::Ko);
+        let inside = Locale::En.scope(async { Locale::current() }).await;
+        assert_eq!(inside, Locale::En);
+    }
+}
@@ -0,0 +1,423 @@
+use crate::context::locale::Locale;
+use usecase::error::ErrorReason;
+use validator::ValidationError;
+
+/// 응답 메시지 카탈로그.
+///
+/// 각 메시지는 언어와 무관한 코드([`Message::code`])를 가지며, 응답의 `code` 로 내려간다.
+/// 클라이언트는 문구 대신 코드로 오류를 구분한다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    Unauthorized,
+    Forbidden,
+    InternalError,
+    NotFound,
+    Timeout,
+    UnknownRoute,
+    UnknownApiVersion(String),
+    InvalidParameter(String),
+    InvalidBody(String),
+    InvalidPath(String),
+    Conflict,
+    PreconditionFailed,
+    IdempotencyKeyReused,
+    IdempotencyKeyInProgress,
+    Required(String),
+    Empty(String),
+    NotNull(String),
+    NotString(String),
+    NotStringList(String),
+    Length { field: String, min: u64, max: u64 },
+    Email(String),
+    Password,
+    NotPatchable(String),
+    ImportNotUtf8,
+    ImportNotJsonArray(String),
+    ImportCsvUnterminatedQuote,
+    ImportCsvNoTitle,
+    ImportNotCalendar,
+    ImportTooManyRows(usize),
+    ImportInvalidRow(String),
+    ProjectNameTaken(String),
+    TagNameTaken(String),
+    StatusCodeTaken(String),
+    BuiltInStatus(String),
+    StatusTransition { from: String, to: String },
+    NotInTrash,
+    MoveIntoSubtree,
+    OwnerOnly,
+    ShareOwnerOnly,
+    SharedTodoFields,
+    ReadOnlyTodo,
+    ReadOnlyComment,
+    ReadOnlyAttachment,
+    CommentAuthorOnly,
+    AttachmentUploaderOnly,
+    TodoChanged,
+    PatchTestFailed(String),
+    IfMatchMismatch,
+}
+
+impl Message {
+    /// 바뀌지 않는 메시지 코드.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Message::Unauthorized => "unauthorized",
+            Message::Forbidden => "forbidden",
+            Message::InternalError => "internal_error",
+            Message::NotFound => "not_found",
+            Message::Timeout => "timeout",
+            Message::UnknownRoute => "unknown_route",
+            Message::UnknownApiVersion(_) => "unknown_api_version",
+            Message::InvalidParameter(_) => "invalid_parameter",
+            Message::InvalidBody(_) => "invalid_body",
+            Message::InvalidPath(_) => "invalid_path",
+            Message::Conflict => "conflict",
+            Message::PreconditionFailed => "precondition_failed",
+            Message::IdempotencyKeyReused => "idempotency_key_reused",
+            Message::IdempotencyKeyInProgress => "idempotency_key_in_progress",
+            Message::Required(_) => "required",
+            Message::Empty(_) => "empty",
+            Message::NotNull(_) => "not_null",
+            Message::NotString(_) => "not_string",
+            Message::NotStringList(_) => "not_string_list",
+            Message::Length { .. } => "length",
+            Message::Email(_) => "email",
+            Message::Password => "password",
+            Message::NotPatchable(_) => "not_patchable",
+            Message::ImportNotUtf8 => "import_not_utf8",
+            Message::ImportNotJsonArray(_) => "import_not_json_array",
+            Message::ImportCsvUnterminatedQuote => "import_csv_unterminated_quote",
+            Message::ImportCsvNoTitle => "import_csv_no_title",
+            Message::ImportNotCalendar => "import_not_calendar",
+            Message::ImportTooManyRows(_) => "import_too_many_rows",
+            Message::ImportInvalidRow(_) => "import_invalid_row",
+            Message::ProjectNameTaken(_) => "project_name_taken",
+            Message::TagNameTaken(_) => "tag_name_taken",
+            Message::StatusCodeTaken(_) => "status_code_taken",
+            Message::BuiltInStatus(_) => "built_in_status",
+            Message::StatusTransition { .. } => "status_transition",
+            Message::NotInTrash => "not_in_trash",
+            Message::MoveIntoSubtree => "move_into_subtree",
+            Message::OwnerOnly => "owner_only",
+            Message::ShareOwnerOnly => "share_owner_only",
+            Message::SharedTodoFields => "shared_todo_fields",
+            Message::ReadOnlyTodo => "read_only_todo",
+            Message::ReadOnlyComment => "read_only_comment",
+            Message::ReadOnlyAttachment => "read_only_attachment",
+            Message::CommentAuthorOnly => "comment_author_only",
+            Message::AttachmentUploaderOnly => "attachment_uploader_only",
+            Message::TodoChanged => "todo_changed",
+            Message::PatchTestFailed(_) => "patch_test_failed",
+            Message::IfMatchMismatch => "if_match_mismatch",
+        }
+    }
+
+    /// 언어에 맞는 문구.
+    pub fn text(&self, locale: Locale) -> String {
+        match locale {
+            Locale::Ko => self.text_ko(),
+            Locale::En => self.text_en(),
+        }
+    }
+
+    fn text_ko(&self) -> String {
+        match self {
+            Message::Unauthorized => "인증이 필요합니다".to_string(),
+            Message::Forbidden => "접근이 거부되었습니다".to_string(),
+            Message::InternalError => "서버 오류가 발생했습니다".to_string(),
+            Message::NotFound => "데이터를 찾을 수 없습니다".to_string(),
+            Message::Timeout => "요청 시간이 초과되었습니다".to_string(),
+            Message::UnknownRoute => "없는 경로입니다".to_string(),
+            Message::UnknownApiVersion(v) => format!("알 수 없는 API 버전입니다({v})"),
+            Message::InvalidParameter(f) => format!("`{f}` 값이 올바르지 않습니다"),
+            Message::InvalidBody(detail) => format!("요청을 읽을 수 없습니다: {detail}"),
+            Message::InvalidPath(detail) => format!("요청 경로를 읽을 수 없습니다: {detail}"),
+            Message::Conflict => "현재 상태와 충돌하여 처리할 수 없습니다".to_string(),
+            Message::PreconditionFailed => "다른 요청이 먼저 바꾸었습니다".to_string(),
+            Message::IdempotencyKeyReused => {
+                "같은 Idempotency-Key 로 다른 요청을 보냈습니다".to_string()
+            }
+            Message::IdempotencyKeyInProgress => {
+                "같은 Idempotency-Key 로 보낸 요청을 아직 처리하고 있습니다".to_string()
+            }
+            Message::Required(f) => format!("`{f}` 값이 필요합니다"),
+            Message::Empty(f) => format!("`{f}` 값이 비어 있습니다"),
+            Message::NotNull(f) => format!("`{f}` 값은 null 일 수 없습니다"),
+            Message::NotString(f) => format!("`{f}` 값은 문자열이어야 합니다"),
+            Message::NotStringList(f) => format!("`{f}` 값은 문자열 목록이어야 합니다"),
+            Message::Length { field, min, max } => {
+                format!("`{field}` 값은 {min}~{max}자여야 합니다")
+            }
+            Message::Email(f) => format!("`{f}` 값이 이메일 형식이 아닙니다"),
+            Message::Password => {
+                "비밀번호는 8자 이상이고 숫자와 특수 문자를 하나 이상 넣어야 합니다".to_string()
+            }
+            Message::NotPatchable(f) => format!("`{f}` 는 바꿀 수 없습니다"),
+            Message::ImportNotUtf8 => "가져올 파일은 UTF-8 텍스트여야 합니다".to_string(),
+            Message::ImportNotJsonArray(detail) => {
+                format!("가져올 파일은 todo 의 JSON 배열이어야 합니다: {detail}")
+            }
+            Message::ImportCsvUnterminatedQuote => {
+                "CSV 에 닫히지 않은 따옴표가 있습니다".to_string()
+            }
+            Message::ImportCsvNoTitle => {
+                "CSV 는 `title` 열이 있는 머리 행으로 시작해야 합니다".to_string()
+            }
+            Message::ImportNotCalendar => {
+                "가져올 파일은 iCalendar(BEGIN:VCALENDAR) 문서여야 합니다".to_string()
+            }
+            Message::ImportTooManyRows(max) => {
+                format!("한 번에 {max}행까지 가져올 수 있습니다")
+            }
+            Message::ImportInvalidRow(detail) => format!("행을 읽을 수 없습니다: {detail}"),
+            Message::ProjectNameTaken(name) => format!("`{name}` 프로젝트가 이미 있습니다"),
+            Message::TagNameTaken(name) => format!("`{name}` 태그가 이미 있습니다"),
+            Message::StatusCodeTaken(code) => format!("`{code}` 상태가 이미 있습니다"),
+            Message::BuiltInStatus(code) => format!("기본 상태 `{code}` 는 끌 수 없습니다"),
+            Message::StatusTransition { from, to } => {
+                format!("`{from}` 상태에서 `{to}` 상태로 바꿀 수 없습니다")
+            }
+            Message::NotInTrash => "휴지통에 있는 todo 가 아닙니다".to_string(),
+            Message::MoveIntoSubtree => {
+                "자기 자신이나 하위 작업 아래로 옮길 수 없습니다".to_string()
+            }
+            Message::OwnerOnly => "소유자만 할 수 있는 작업입니다".to_string(),
+            Message::ShareOwnerOnly => "소유자만 공유를 관리할 수 있습니다".to_string(),
+            Message::SharedTodoFields => {
+                "공유받은 사용자는 태그·상위 todo·프로젝트를 바꿀 수 없습니다".to_string()
+            }
+            Message::ReadOnlyTodo => "읽기 권한으로 공유받은 todo 는 고칠 수 없습니다".to_string(),
+            Message::ReadOnlyComment => {
+                "읽기 권한으로 공유받은 todo 에는 댓글을 달 수 없습니다".to_string()
+            }
+            Message::ReadOnlyAttachment => {
+                "읽기 권한으로 공유받은 todo 의 첨부 파일은 바꿀 수 없습니다".to_string()
+            }
+            Message::CommentAuthorOnly => "작성자만 댓글을 고치거나 지울 수 있습니다".to_string(),
+            Message::AttachmentUploaderOnly => {
+                "올린 사용자만 첨부 파일을 지울 수 있습니다".to_string()
+            }
+            Message::TodoChanged => "todo 가 다른 요청으로 먼저 바뀌었습니다".to_string(),
+            Message::PatchTestFailed(path) => format!("`{path}` 의 test 값이 현재 값과 다릅니다"),
+            Message::IfMatchMismatch => "If-Match 값이 현재 버전과 맞지 않습니다".to_string(),
+        }
+    }
+
+    fn text_en(&self) -> String {
+        match self {
+            Message::Unauthorized => "Authentication is required.".to_string(),
+            Message::Forbidden => "Access denied.".to_string(),
+            Message::InternalError => "An internal server error occurred.".to_string(),
+            Message::NotFound => "Data not found.".to_string(),
+            Message::Timeout => "The request timed out.".to_string(),
+            Message::UnknownRoute => "Unknown route.".to_string(),
+            Message::UnknownApiVersion(v) => format!("Unknown api version({v})."),
+            Message::InvalidParameter(f) => format!("`{f}` is invalid."),
+            Message::InvalidBody(detail) => format!("The request could not be read: {detail}"),
+            Message::InvalidPath(detail) => format!("The request path could not be read: {detail}"),
+            Message::Conflict => "The request conflicts with the current state.".to_string(),
+            Message::PreconditionFailed => "The data was changed by another request.".to_string(),
+            Message::IdempotencyKeyReused => {
+                "The Idempotency-Key was already used for a different request.".to_string()
+            }
+            Message::IdempotencyKeyInProgress => {
+                "A request with the same Idempotency-Key is still being processed.".to_string()
+            }
+            Message::Required(f) => format!("`{f}` is required."),
+            Message::Empty(f) => format!("`{f}` is empty."),
+            Message::NotNull(f) => format!("`{f}` cannot be null."),
+            Message::NotString(f) => format!("`{f}` must be a string."),
+            Message::NotStringList(f) => format!("`{f}` must be a list of strings."),
+            Message::Length { field, min, max } => {
+                format!("`{field}` must be {min} to {max} characters.")
+            }
+            Message::Email(f) => format!("`{f}` is not a valid email."),
+            Message::Password => "password must contain one digit, one special character and must be at least 8 characters long".to_string(),
+            Message::NotPatchable(f) => format!("`{f}` cannot be patched."),
+            Message::ImportNotUtf8 => "The file must be UTF-8 text.".to_string(),
+            Message::ImportNotJsonArray(detail) => {
+                format!("The file must be a JSON array of todos: {detail}")
+            }
+            Message::ImportCsvUnterminatedQuote => {
+                "The CSV has an unterminated quoted field.".to_string()
+            }
+            Message::ImportCsvNoTitle => {
+                "The CSV must start with a header row that has a `title` column.".to_string()
+            }
+            Message::ImportNotCalendar => {
+                "The file must be an iCalendar (BEGIN:VCALENDAR) document.".to_string()
+            }
+            Message::ImportTooManyRows(max) => {
+                format!("At most {max} rows can be imported at once.")
+            }
+            Message::ImportInvalidRow(detail) => format!("The row could not be read: {detail}"),
+            Message::ProjectNameTaken(name) => format!("A project named `{name}` already exists."),
+            Message::TagNameTaken(name) => format!("A tag named `{name}` already exists."),
+            Message::StatusCodeTaken(code) => format!("The status `{code}` already exists."),
+            Message::BuiltInStatus(code) => {
+                format!("The built-in status `{code}` cannot be disabled.")
+            }
+            Message::StatusTransition { from, to } => {
+                format!("The status cannot change from `{from}` to `{to}`.")
+            }
+            Message::NotInTrash => "The todo is not in the trash.".to_string(),
+            Message::MoveIntoSubtree => {
+                "A todo cannot be moved under itself or its subtasks.".to_string()
+            }
+            Message::OwnerOnly => "Only the owner can do this.".to_string(),
+            Message::ShareOwnerOnly => "Only the owner can manage shares.".to_string(),
+            Message::SharedTodoFields => {
+                "Shared users cannot change tags, the parent todo or the project.".to_string()
+            }
+            Message::ReadOnlyTodo => "A todo shared read-only cannot be edited.".to_string(),
+            Message::ReadOnlyComment => {
+                "A todo shared read-only cannot be commented on.".to_string()
+            }
+            Message::ReadOnlyAttachment => {
+                "Attachments of a todo shared read-only cannot be changed.".to_string()
+            }
+            Message::CommentAuthorOnly => {
+                "Only the author can edit or delete the comment.".to_string()
+            }
+            Message::AttachmentUploaderOnly => {
+                "Only the uploader can delete the attachment.".to_string()
+            }
+            Message::TodoChanged => "The todo was changed by another request.".to_string(),
+            Message::PatchTestFailed(path) => {
+                format!("The test value of `{path}` does not match the current value.")
+            }
+            Message::IfMatchMismatch => {
+                "If-Match does not match the current version.".to_string()
+            }
+        }
+    }
+
+    /// validator 의 검증 오류를 카탈로그 메시지로 바꾼다. 필드 이름은 요청 JSON 처럼 camelCase 로 쓴다.
+    pub fn from_validation(field: &str, error: &ValidationError) -> Message {
+        let field = camel_case(field);
+        let param = |name: &str| error.params.get(name).and_then(|v| v.as_u64());
+        match error.code.as_ref() {
+            "required" => Message::Required(field),
+            "email" => Message::Email(field),
+            "password" => Message::Password,
+            "length" => match (param("min"), param("max")) {
+                (Some(min), Some(max)) => Message::Length { field, min, max },
+                _ => Message::Empty(field),
+            },
+            _ => Message::InvalidParameter(field),
+        }
+    }
+}
+
+impl From<ErrorReason> for Message {
+    fn from(reason: ErrorReason) -> Self {
+        match reason {
+            ErrorReason::ProjectNameTaken(name) => Message::ProjectNameTaken(name),
+            ErrorReason::TagNameTaken(name) => Message::TagNameTaken(name),
+            ErrorReason::StatusCodeTaken(code) => Message::StatusCodeTaken(code),
+            ErrorReason::BuiltInStatus(code) => Message::BuiltInStatus(code),
+            ErrorReason::StatusTransition { from, to } => Message::StatusTransition { from, to },
+            ErrorReason::NotInTrash => Message::NotInTrash,
+            ErrorReason::MoveIntoSubtree => Message::MoveIntoSubtree,
+            ErrorReason::OwnerOnly => Message::OwnerOnly,
+            ErrorReason::ShareOwnerOnly => Message::ShareOwnerOnly,
+            ErrorReason::SharedTodoFields => Message::SharedTodoFields,
+            ErrorReason::ReadOnlyTodo => Message::ReadOnlyTodo,
+            ErrorReason::ReadOnlyComment => Message::ReadOnlyComment,
+            ErrorReason::ReadOnlyAttachment => Message::ReadOnlyAttachment,
+            ErrorReason::CommentAuthorOnly => Message::CommentAuthorOnly,
+            ErrorReason::AttachmentUploaderOnly => Message::AttachmentUploaderOnly,
+            ErrorReason::TodoChanged => Message::TodoChanged,
+        }
+    }
+}
+
+/// 기본 상태의 언어별 이름. 한국어는 저장된(관리자가 고친) 이름을 그대로 쓰므로 `None` 이다.
+/// 사용자 정의 상태도 `None` 이다.
+pub fn status_name(locale: Locale, code: &str) -> Option<&'static str> {
+    match locale {
+        Locale::Ko => None,
+        Locale::En => match code {
+            "new" => Some("New"),
+            "working" => Some("In progress"),
+            "waiting" => Some("Waiting"),
+            "done" => Some("Done"),
+            "discontinued" => Some("Discontinued"),
+            "pending" => Some("Pending"),
+            "deleted" => Some("Deleted"),
+            _ => None,
+        },
+    }
+}
+
+fn camel_case(field: &str) -> String {
+    let mut parts = field.split('_');
+    let mut result = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            result.extend(first.to_uppercase());
+            result.push_str(chars.as_str());
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn message_text_depends_on_locale_but_code_does_not() {
+        let message = Message::Required("title".to_string());
+        assert_eq!(message.code(), "required");
+        assert_eq!(message.text(Locale::En), "`title` is required.");
+        assert_eq!(message.text(Locale::Ko), "`title` 값이 필요합니다");
+    }
+
+    #[test]
+    fn from_validation_maps_length_and_required_with_camel_case_field() {
+        let mut error = ValidationError::new("length");
+        error.add_param(Cow::from("min"), &1);
+        error.add_param(Cow::from("max"), &32);
+        assert_eq!(
+            Message::from_validation("status_code", &error),
+            Message::Length {
+                field: "statusCode".to_string(),
+                min: 1,
+                max: 32
+            }
+        );
+
+        let mut error = ValidationError::new("length");
+        error.add_param(Cow::from("min"), &1);
+        assert_eq!(
+            Message::from_validation("title", &error),
+            Message::Empty("title".to_string())
+        );
+        assert_eq!(
+            Message::from_validation("name", &ValidationError::new("required")),
+            Message::Required("name".to_string())
+        );
+    }
+
+    #[test]
+    fn error_reason_keeps_its_parameters_in_both_locales() {
+        let message = Message::from(ErrorReason::TagNameTaken("work".to_string()));
+        assert_eq!(message.code(), "tag_name_taken");
+        assert_eq!(message.text(Locale::Ko), "`work` 태그가 이미 있습니다");
+        assert_eq!(
+            message.text(Locale::En),
+            "A tag named `work` already exists."
+        );
+    }
+
+    #[test]
+    fn status_name_translates_built_in_statuses_only() {
+        assert_eq!(status_name(Locale::En, "working"), Some("In progress"));
+        assert_eq!(status_name(Locale::En, "review"), None);
+        assert_eq!(status_name(Locale::Ko, "working"), None);
+    }
+}
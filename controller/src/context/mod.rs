@@ -4,4 +4,9 @@ pub mod api_response;
 pub mod api_version;
 pub(crate) mod auth_resolver;
 pub mod errors;
+pub(crate) mod idempotency;
+pub mod if_match;
+pub mod locale;
+pub mod message;
+pub(crate) mod timeout;
 pub mod validate;
@@ -0,0 +1,55 @@
+use crate::context::errors::AppError;
+use crate::context::message::Message;
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::time::Duration;
+
+/// `limit` 안에 응답하지 못한 요청을 끊고 시간 초과 응답을 돌려준다.
+/// 끊긴 핸들러의 future 는 그 자리에서 버려진다.
+pub(crate) async fn request_timeout(
+    State(limit): State<Duration>,
+    request: Request,
+    next: Next,
+) -> Response {
+    match tokio::time::timeout(limit, next.run(request)).await {
+        Ok(response) => response,
+        Err(_) => AppError::from(Message::Timeout).into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::routing::get;
+    use axum::{middleware, Router};
+    use tower::ServiceExt;
+
+    fn app(limit: Duration) -> Router {
+        Router::new()
+            .route(
+                "/",
+                get(|| async {
+                    tokio::time::sleep(Duration::from_millis(200)).await;
+                    "done"
+                }),
+            )
+            .layer(middleware::from_fn_with_state(limit, request_timeout))
+    }
+
+    async fn body_of(app: Router) -> String {
+        let request = Request::builder().uri("/").body(Body::empty()).unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn request_timeout_cuts_slow_requests_only() {
+        assert!(body_of(app(Duration::from_millis(20)))
+            .await
+            .contains("\"code\":\"timeout\""));
+        assert_eq!(body_of(app(Duration::from_secs(5))).await, "done");
+    }
+}
@@ -3,3 +3,4 @@ pub mod model;
 pub mod module;
 pub mod routes;
 pub mod startup;
+pub mod worker;
@@ -0,0 +1,78 @@
+use serde::Serialize;
+use usecase::model::attachment::AttachmentView;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonAttachment {
+    pub id: String,
+    pub todo_id: String,
+    pub uploader_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub created_at: String,
+}
+
+impl From<AttachmentView> for JsonAttachment {
+    fn from(av: AttachmentView) -> Self {
+        Self {
+            id: av.id,
+            todo_id: av.todo_id,
+            uploader_id: av.uploader_id,
+            file_name: av.file_name,
+            content_type: av.content_type,
+            size_bytes: av.size_bytes,
+            created_at: av.created_at.to_string(),
+        }
+    }
+}
+
+/// 첨부 파일 업로드 multipart 본문. OpenAPI 문서용이다.
+#[allow(dead_code)]
+#[derive(ToSchema)]
+pub struct AttachmentUpload {
+    /// 올릴 파일. 파일 이름과 content type 은 이 part 의 헤더에서 읽는다.
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+/// `Content-Disposition` 헤더 값을 만든다.
+/// ASCII 가 아닌 이름도 내려받을 수 있도록 RFC 6266 의 `filename*` 을 함께 넣는다.
+pub fn content_disposition(file_name: &str) -> String {
+    let fallback: String = file_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_graphic() && c != '"' && c != '\\' || c == ' ' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let encoded: String = file_name
+        .bytes()
+        .map(|b| {
+            if b.is_ascii_alphanumeric() || b"-._~".contains(&b) {
+                (b as char).to_string()
+            } else {
+                format!("%{b:02X}")
+            }
+        })
+        .collect();
+    format!("attachment; filename=\"{fallback}\"; filename*=UTF-8''{encoded}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_disposition_escapes_non_ascii_and_quotes() {
+        assert_eq!(
+            content_disposition("보고서 \"v2\".pdf"),
+            "attachment; filename=\"___ _v2_.pdf\"; \
+             filename*=UTF-8''%EB%B3%B4%EA%B3%A0%EC%84%9C%20%22v2%22.pdf"
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use crate::context::errors::AppError;
+use crate::model::todo::{JsonCreateTodo, JsonUpdateTodoContents, JsonUpsertTodoContents};
+use serde::Deserialize;
+use usecase::model::todo::batch::TodoBatchOperation;
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// 일괄 처리 요청. `operations` 를 순서대로 한 트랜잭션에서 실행하며 하나라도 실패하면 모두 되돌린다.
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTodoBatch {
+    /// 실행할 작업 목록 (1 ~ 100개)
+    pub operations: Vec<JsonTodoBatchOperation>,
+}
+
+/// 일괄 처리 작업. `op` 로 종류를 고르며 나머지 필드는 단건 API 의 요청 본문과 같다.
+/// `version` 은 단건 API 의 `If-Match` 처럼 기대하는 todo 버전이며, 다르면 실패한다.
+#[derive(Deserialize, Debug, ToSchema)]
+#[serde(tag = "op", rename_all = "camelCase")]
+pub enum JsonTodoBatchOperation {
+    Create(JsonCreateTodo),
+    Update {
+        id: String,
+        version: Option<i64>,
+        #[serde(flatten)]
+        contents: JsonUpdateTodoContents,
+    },
+    Upsert {
+        id: String,
+        version: Option<i64>,
+        #[serde(flatten)]
+        contents: JsonUpsertTodoContents,
+    },
+    /// 휴지통으로 옮긴다.
+    Delete {
+        id: String,
+        version: Option<i64>,
+    },
+}
+
+impl JsonTodoBatchOperation {
+    /// 입력을 검증하여 유스케이스 작업으로 바꾼다.
+    pub fn try_into_operation(
+        self,
+        roll_up_completion: bool,
+    ) -> Result<TodoBatchOperation, AppError> {
+        match self {
+            Self::Create(source) => {
+                source.validate()?;
+                Ok(TodoBatchOperation::Create(source.try_into()?))
+            }
+            Self::Update {
+                id,
+                version,
+                contents,
+            } => {
+                let mut view = contents.validate(id).map_err(AppError::Localized)?;
+                view.roll_up_completion = roll_up_completion;
+                view.expected_version = version;
+                Ok(TodoBatchOperation::Update(view))
+            }
+            Self::Upsert {
+                id,
+                version,
+                contents,
+            } => {
+                Validate::validate(&contents)?;
+                let mut view = contents.try_to_view(id)?;
+                view.expected_version = version;
+                Ok(TodoBatchOperation::Upsert(view))
+            }
+            Self::Delete { id, version } => Ok(TodoBatchOperation::Delete {
+                id,
+                expected_version: version,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn json_todo_batch_deserializes_each_operation_kind() {
+        let batch: JsonTodoBatch = serde_json::from_value(json!({
+            "operations": [
+                { "op": "create", "title": "New", "description": "desc" },
+                { "op": "update", "id": "a", "version": 2, "title": "Renamed" },
+                { "op": "upsert", "id": "b", "title": "T", "description": "d", "statusCode": "new" },
+                { "op": "delete", "id": "c" }
+            ]
+        }))
+        .unwrap();
+
+        let operations: Vec<TodoBatchOperation> = batch
+            .operations
+            .into_iter()
+            .map(|op| op.try_into_operation(false).unwrap())
+            .collect();
+        assert!(matches!(&operations[0], TodoBatchOperation::Create(c) if c.title == "New"));
+        assert!(matches!(
+            &operations[1],
+            TodoBatchOperation::Update(u) if u.title.as_deref() == Some("Renamed") && u.expected_version == Some(2)
+        ));
+        assert!(matches!(&operations[2], TodoBatchOperation::Upsert(u) if u.status_code == "new"));
+        assert!(matches!(
+            &operations[3],
+            TodoBatchOperation::Delete { id, expected_version: None } if id == "c"
+        ));
+    }
+
+    #[test]
+    fn try_into_operation_rejects_invalid_contents() {
+        let op: JsonTodoBatchOperation =
+            serde_json::from_value(json!({ "op": "create", "title": "", "description": "d" }))
+                .unwrap();
+        assert!(matches!(
+            op.try_into_operation(false),
+            Err(AppError::Validation(_))
+        ));
+
+        let op: JsonTodoBatchOperation =
+            serde_json::from_value(json!({ "op": "update", "id": "a", "statusCode": "" })).unwrap();
+        assert!(matches!(
+            op.try_into_operation(false),
+            Err(AppError::Localized(_))
+        ));
+    }
+}
@@ -0,0 +1,130 @@
+use crate::context::errors::AppError;
+use crate::context::message::Message;
+use serde::{Deserialize, Serialize};
+use usecase::model::comment::{
+    CommentPageView, CommentView, CreateComment, SearchCommentCondition, UpdateCommentView,
+};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonComment {
+    pub id: String,
+    pub todo_id: String,
+    pub author_id: String,
+    pub body: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<CommentView> for JsonComment {
+    fn from(cv: CommentView) -> Self {
+        Self {
+            id: cv.id,
+            todo_id: cv.todo_id,
+            author_id: cv.author_id,
+            body: cv.body,
+            created_at: cv.created_at.to_string(),
+            updated_at: cv.updated_at.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonCommentList {
+    pub comments: Vec<JsonComment>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+impl From<CommentPageView> for JsonCommentList {
+    fn from(page: CommentPageView) -> Self {
+        Self {
+            comments: page.comments.into_iter().map(Into::into).collect(),
+            next_cursor: page.next_cursor,
+            has_more: page.has_more,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonCreateComment {
+    /// 댓글 본문
+    #[validate(length(min = 1, max = 4000), required)]
+    pub body: Option<String>,
+}
+
+impl TryFrom<JsonCreateComment> for CreateComment {
+    type Error = AppError;
+
+    fn try_from(jc: JsonCreateComment) -> Result<Self, Self::Error> {
+        Ok(CreateComment::new(jc.body.ok_or_else(|| {
+            AppError::from(Message::Required("body".to_string()))
+        })?))
+    }
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonUpdateComment {
+    /// 바꿀 댓글 본문
+    #[validate(length(min = 1, max = 4000), required)]
+    pub body: Option<String>,
+}
+
+impl JsonUpdateComment {
+    pub fn try_to_view(self, id: String) -> Result<UpdateCommentView, AppError> {
+        Ok(UpdateCommentView::new(
+            id,
+            self.body
+                .ok_or_else(|| AppError::from(Message::Required("body".to_string())))?,
+        ))
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentQuery {
+    /// 페이지 크기 (기본 20, 최대 100)
+    #[param(minimum = 1, maximum = 100)]
+    pub limit: Option<i64>,
+    /// 이전 응답의 `nextCursor`. 없으면 가장 오래된 댓글부터 조회한다.
+    pub cursor: Option<String>,
+}
+
+impl From<CommentQuery> for SearchCommentCondition {
+    fn from(cq: CommentQuery) -> Self {
+        Self {
+            cursor: cq.cursor,
+            limit: cq.limit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_update_comment_try_to_view_keeps_id_and_body() {
+        let json = JsonUpdateComment {
+            body: Some("수정했습니다".to_string()),
+        };
+        let view = json
+            .try_to_view("01JRWBKE4KE4P9MQNHCX4F0000".to_string())
+            .unwrap();
+        assert_eq!(view.id, "01JRWBKE4KE4P9MQNHCX4F0000");
+        assert_eq!(view.body, "수정했습니다");
+    }
+
+    #[test]
+    fn json_create_comment_with_empty_body_fails_validation() {
+        let json = JsonCreateComment {
+            body: Some(String::new()),
+        };
+        assert!(json.validate().is_err());
+    }
+}
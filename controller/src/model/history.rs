@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use usecase::model::todo::history::{
+    SearchTodoHistoryCondition, TodoEventView, TodoFieldChangeView, TodoHistoryPageView,
+};
+use utoipa::{IntoParams, ToSchema};
+
+/// 필드 하나의 변경 내용. 값이 없던(없어진) 경우 `before`(`after`) 는 `null` 이다.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTodoFieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl From<TodoFieldChangeView> for JsonTodoFieldChange {
+    fn from(cv: TodoFieldChangeView) -> Self {
+        Self {
+            field: cv.field,
+            before: cv.before,
+            after: cv.after,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTodoEvent {
+    pub id: String,
+    pub todo_id: String,
+    pub actor_id: String,
+    pub changes: Vec<JsonTodoFieldChange>,
+    pub created_at: String,
+}
+
+impl From<TodoEventView> for JsonTodoEvent {
+    fn from(ev: TodoEventView) -> Self {
+        Self {
+            id: ev.id,
+            todo_id: ev.todo_id,
+            actor_id: ev.actor_id,
+            changes: ev.changes.into_iter().map(Into::into).collect(),
+            created_at: ev.created_at.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTodoHistory {
+    pub events: Vec<JsonTodoEvent>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+impl From<TodoHistoryPageView> for JsonTodoHistory {
+    fn from(page: TodoHistoryPageView) -> Self {
+        Self {
+            events: page.events.into_iter().map(Into::into).collect(),
+            next_cursor: page.next_cursor,
+            has_more: page.has_more,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct TodoHistoryQuery {
+    /// 페이지 크기 (기본 20, 최대 100)
+    #[param(minimum = 1, maximum = 100)]
+    pub limit: Option<i64>,
+    /// 이전 응답의 `nextCursor`. 없으면 가장 최근 이력부터 조회한다.
+    pub cursor: Option<String>,
+}
+
+impl From<TodoHistoryQuery> for SearchTodoHistoryCondition {
+    fn from(hq: TodoHistoryQuery) -> Self {
+        Self {
+            cursor: hq.cursor,
+            limit: hq.limit,
+        }
+    }
+}
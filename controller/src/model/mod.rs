@@ -1,3 +1,16 @@
+pub mod attachment;
+pub mod batch;
+pub mod comment;
+pub mod history;
+pub mod patch;
+pub mod position;
+pub mod project;
+pub mod recurrence;
+pub mod reminder;
+pub mod share;
+pub mod stats;
 pub mod status;
+pub mod tag;
 pub mod todo;
+pub mod transfer;
 pub mod user;
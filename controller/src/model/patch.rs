@@ -0,0 +1,325 @@
+use crate::context::errors::AppError;
+use crate::context::message::Message;
+use crate::model::todo::JsonUpdateTodoContents;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use usecase::model::todo::{TodoView, UpdateTodoView};
+use usecase::model::FieldUpdate;
+use utoipa::ToSchema;
+
+/// RFC 7396 JSON Merge Patch 본문의 Content-Type.
+pub const MERGE_PATCH_CONTENT_TYPE: &str = "application/merge-patch+json";
+/// RFC 6902 JSON Patch 본문의 Content-Type.
+pub const JSON_PATCH_CONTENT_TYPE: &str = "application/json-patch+json";
+
+/// `PATCH /v1/todo/{id}` 본문. Content-Type 으로 해석 방식을 고른다.
+#[derive(Debug)]
+pub enum JsonTodoPatch {
+    /// `application/json`. 없는 필드와 `null` 은 모두 바꾸지 않는다.
+    Contents(Box<JsonUpdateTodoContents>),
+    /// `application/merge-patch+json`. 없는 필드는 그대로 두고 `null` 이면 지운다.
+    Merge(Map<String, Value>),
+    /// `application/json-patch+json`. 작업을 순서대로 현재 todo 에 적용한다.
+    Operations(Vec<JsonPatchOperation>),
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum JsonPatchOp {
+    Test,
+    Add,
+    Replace,
+    Remove,
+}
+
+/// JSON Patch 작업 하나. `path` 는 `/title` 처럼 필드 하나를 가리키는 JSON Pointer 다.
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct JsonPatchOperation {
+    pub op: JsonPatchOp,
+    pub path: String,
+    /// `test`, `add`, `replace` 에 쓰는 값. 없으면 `null` 로 본다.
+    #[serde(default)]
+    pub value: Value,
+}
+
+/// Merge Patch 를 부분 수정 요청으로 바꾼다.
+///
+/// `description` 을 지우면 빈 문자열, `parentId` 를 지우면 최상위로 옮기고 `projectId` 를 지우면 프로젝트에서 뺀다.
+/// `title`, `statusCode`, `priority` 는 지울 수 없다.
+pub fn merge_patch_to_view(
+    id: String,
+    patch: Map<String, Value>,
+) -> Result<UpdateTodoView, Vec<Message>> {
+    let mut view = UpdateTodoView::new(id, None, None, None);
+    let mut errors: Vec<Message> = vec![];
+
+    for (field, value) in patch {
+        let result =
+            match field.as_str() {
+                "title" => required_text(&field, value).map(|v| view.title = Some(v)),
+                "description" => nullable_text(&field, value)
+                    .map(|v| view.description = Some(v.unwrap_or_default())),
+                "statusCode" => required_text(&field, value).map(|v| view.status_code = Some(v)),
+                "dueAt" => nullable_text(&field, value)
+                    .map(|v| view.due_at = v.map_or(FieldUpdate::Clear, FieldUpdate::Set)),
+                "priority" => required_text(&field, value).map(|v| view.priority = Some(v)),
+                "parentId" => nullable_text(&field, value)
+                    .map(|v| view.parent_id = Some(v.unwrap_or_default())),
+                "projectId" => nullable_text(&field, value)
+                    .map(|v| view.project_id = Some(v.unwrap_or_default())),
+                "recurrence" => nullable_text(&field, value)
+                    .map(|v| view.recurrence = v.map_or(FieldUpdate::Clear, FieldUpdate::Set)),
+                "remindAt" => nullable_text(&field, value)
+                    .map(|v| view.remind_at = v.map_or(FieldUpdate::Clear, FieldUpdate::Set)),
+                "addTagIds" => text_list(&field, value).map(|v| view.add_tag_ids = v),
+                "removeTagIds" => text_list(&field, value).map(|v| view.remove_tag_ids = v),
+                _ => Err(Message::NotPatchable(field)),
+            };
+        if let Err(err) = result {
+            errors.push(err);
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok(view)
+}
+
+/// JSON Patch 를 현재 todo 에 적용하고 바뀐 필드만 Merge Patch 로 돌려준다.
+///
+/// 다룰 수 있는 경로는 `/title`, `/description`, `/statusCode`, `/dueAt`, `/priority`,
+/// `/parentId`, `/projectId`, `/recurrence`, `/remindAt` 이다. `test` 가 맞지 않으면 아무것도 바꾸지 않고 `409 Conflict` 로 끝낸다.
+pub fn json_patch_to_merge_patch(
+    current: &TodoView,
+    operations: Vec<JsonPatchOperation>,
+) -> Result<Map<String, Value>, AppError> {
+    let original = patch_document(current);
+    let mut document = original.clone();
+
+    for operation in operations {
+        let field = operation
+            .path
+            .strip_prefix('/')
+            .filter(|field| original.contains_key(*field))
+            .ok_or_else(|| AppError::from(Message::NotPatchable(operation.path.clone())))?;
+        match operation.op {
+            JsonPatchOp::Test => {
+                if document[field] != operation.value {
+                    return Err(AppError::Conflict(Message::PatchTestFailed(
+                        operation.path.clone(),
+                    )));
+                }
+            }
+            JsonPatchOp::Add | JsonPatchOp::Replace => {
+                document.insert(field.to_string(), operation.value);
+            }
+            JsonPatchOp::Remove => {
+                document.insert(field.to_string(), Value::Null);
+            }
+        }
+    }
+
+    Ok(document
+        .into_iter()
+        .filter(|(field, value)| original.get(field) != Some(value))
+        .collect())
+}
+
+/// JSON Patch 를 적용할 todo 문서. 값이 없는 필드는 `null` 이다.
+fn patch_document(todo: &TodoView) -> Map<String, Value> {
+    let mut document = Map::new();
+    document.insert("title".to_string(), todo.title.clone().into());
+    document.insert("description".to_string(), todo.description.clone().into());
+    document.insert("statusCode".to_string(), todo.status.code.clone().into());
+    document.insert(
+        "dueAt".to_string(),
+        todo.due_at.as_ref().map(|v| v.to_string()).into(),
+    );
+    document.insert("priority".to_string(), todo.priority.clone().into());
+    document.insert("parentId".to_string(), todo.parent_id.clone().into());
+    document.insert("projectId".to_string(), todo.project_id.clone().into());
+    document.insert("recurrence".to_string(), todo.recurrence.clone().into());
+    document.insert(
+        "remindAt".to_string(),
+        todo.remind_at.as_ref().map(|v| v.to_string()).into(),
+    );
+    document
+}
+
+fn required_text(field: &str, value: Value) -> Result<String, Message> {
+    match nullable_text(field, value)? {
+        Some(text) if text.is_empty() => Err(Message::Empty(field.to_string())),
+        Some(text) => Ok(text),
+        None => Err(Message::NotNull(field.to_string())),
+    }
+}
+
+fn nullable_text(field: &str, value: Value) -> Result<Option<String>, Message> {
+    match value {
+        Value::Null => Ok(None),
+        Value::String(text) => Ok(Some(text)),
+        _ => Err(Message::NotString(field.to_string())),
+    }
+}
+
+fn text_list(field: &str, value: Value) -> Result<Vec<String>, Message> {
+    match value {
+        Value::Null => Ok(vec![]),
+        Value::Array(items) => items
+            .into_iter()
+            .map(|item| match item {
+                Value::String(text) => Ok(text),
+                _ => Err(Message::NotStringList(field.to_string())),
+            })
+            .collect(),
+        _ => Err(Message::NotStringList(field.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use usecase::model::todo::status::TodoStatusView;
+    use usecase::model::DateTimeRfc3339;
+
+    fn object(value: Value) -> Map<String, Value> {
+        match value {
+            Value::Object(map) => map,
+            _ => panic!("not an object"),
+        }
+    }
+
+    fn make_todo_view() -> TodoView {
+        TodoView {
+            id: "01HZXK0000000000000000000A".to_string(),
+            owner_id: "01HZXK0000000000000000000B".to_string(),
+            parent_id: None,
+            title: "Plan".to_string(),
+            description: "desc".to_string(),
+            status: TodoStatusView {
+                id: "01HZXK0000000000000000000C".to_string(),
+                code: "new".to_string(),
+                name: "신규".to_string(),
+            },
+            due_at: Some(DateTimeRfc3339("2030-05-01T09:00:00+00:00".to_string())),
+            priority: "medium".to_string(),
+            tags: vec![],
+            created_at: DateTimeRfc3339("2026-10-18T00:00:00+00:00".to_string()),
+            updated_at: DateTimeRfc3339("2026-10-18T00:00:00+00:00".to_string()),
+            deleted_at: None,
+            version: 3,
+            recurrence: None,
+            remind_at: None,
+            project_id: None,
+            archived_at: None,
+            position: "i".to_string(),
+            rank: None,
+            snippet: None,
+        }
+    }
+
+    fn operation(op: JsonPatchOp, path: &str, value: Value) -> JsonPatchOperation {
+        JsonPatchOperation {
+            op,
+            path: path.to_string(),
+            value,
+        }
+    }
+
+    #[test]
+    fn merge_patch_distinguishes_absent_and_null() {
+        let view = merge_patch_to_view(
+            "id".to_string(),
+            object(json!({
+                "dueAt": null, "description": null, "parentId": null, "projectId": null
+            })),
+        )
+        .unwrap();
+        assert_eq!(view.due_at, FieldUpdate::Clear);
+        assert_eq!(view.description.as_deref(), Some(""));
+        assert_eq!(view.parent_id.as_deref(), Some(""));
+        assert_eq!(view.project_id.as_deref(), Some(""));
+        assert!(view.title.is_none());
+        assert!(view.priority.is_none());
+
+        let view =
+            merge_patch_to_view("id".to_string(), object(json!({ "title": "New" }))).unwrap();
+        assert_eq!(view.title.as_deref(), Some("New"));
+        assert_eq!(view.due_at, FieldUpdate::Keep);
+    }
+
+    #[test]
+    fn merge_patch_sets_due_at_and_tags() {
+        let view = merge_patch_to_view(
+            "id".to_string(),
+            object(json!({ "dueAt": "2030-05-01T09:00:00Z", "addTagIds": ["t1"] })),
+        )
+        .unwrap();
+        assert_eq!(
+            view.due_at,
+            FieldUpdate::Set("2030-05-01T09:00:00Z".to_string())
+        );
+        assert_eq!(view.add_tag_ids, vec!["t1".to_string()]);
+    }
+
+    #[test]
+    fn merge_patch_rejects_null_required_and_unknown_fields() {
+        let Err(errors) = merge_patch_to_view(
+            "id".to_string(),
+            object(json!({ "title": null, "priority": 1, "owner": "x" })),
+        ) else {
+            panic!("merge patch with invalid fields must fail");
+        };
+        assert_eq!(
+            errors,
+            vec![
+                Message::NotPatchable("owner".to_string()),
+                Message::NotString("priority".to_string()),
+                Message::NotNull("title".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn json_patch_returns_only_changed_fields() {
+        let patch = json_patch_to_merge_patch(
+            &make_todo_view(),
+            vec![
+                operation(JsonPatchOp::Test, "/title", json!("Plan")),
+                operation(JsonPatchOp::Replace, "/title", json!("Ship")),
+                operation(JsonPatchOp::Remove, "/dueAt", Value::Null),
+                operation(JsonPatchOp::Replace, "/priority", json!("medium")),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            Value::Object(patch),
+            json!({ "title": "Ship", "dueAt": null })
+        );
+    }
+
+    #[test]
+    fn json_patch_failed_test_is_conflict() {
+        let err = json_patch_to_merge_patch(
+            &make_todo_view(),
+            vec![operation(JsonPatchOp::Test, "/statusCode", json!("done"))],
+        )
+        .unwrap_err();
+        assert!(matches!(err, AppError::Conflict(_)));
+    }
+
+    #[test]
+    fn json_patch_rejects_unknown_path() {
+        let err = json_patch_to_merge_patch(
+            &make_todo_view(),
+            vec![operation(JsonPatchOp::Replace, "/ownerId", json!("x"))],
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            AppError::Localized(messages) if messages == [Message::NotPatchable("/ownerId".to_string())]
+        ));
+    }
+}
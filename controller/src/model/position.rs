@@ -0,0 +1,13 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// 직접 정한 순서에서 옮길 위치. 둘 중 하나 이상을 지정하며, 둘 다 지정하면 그 사이로 옮긴다.
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonMoveTodo {
+    /// 이 todo 바로 앞으로 옮긴다.
+    pub before: Option<String>,
+    /// 이 todo 바로 뒤로 옮긴다.
+    pub after: Option<String>,
+}
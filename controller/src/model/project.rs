@@ -0,0 +1,100 @@
+use crate::context::errors::AppError;
+use crate::context::message::Message;
+use serde::{Deserialize, Serialize};
+use usecase::model::project::{CreateProject, ProjectView, UpdateProjectView};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonProject {
+    pub id: String,
+    pub name: String,
+    /// `#rrggbb` 형식의 색
+    pub color: String,
+    pub archived: bool,
+}
+
+impl From<ProjectView> for JsonProject {
+    fn from(pv: ProjectView) -> Self {
+        Self {
+            id: pv.id,
+            name: pv.name,
+            color: pv.color,
+            archived: pv.archived,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonCreateProject {
+    /// 프로젝트 이름. 사용자 안에서 유일해야 한다.
+    #[validate(length(min = 1, max = 64), required)]
+    pub name: Option<String>,
+    /// `#rrggbb` 형식의 색. 없으면 기본 색이다.
+    pub color: Option<String>,
+}
+
+impl TryFrom<JsonCreateProject> for CreateProject {
+    type Error = AppError;
+
+    fn try_from(jc: JsonCreateProject) -> Result<Self, Self::Error> {
+        Ok(CreateProject::new(
+            jc.name
+                .ok_or_else(|| AppError::from(Message::Required("name".to_string())))?,
+            jc.color,
+        ))
+    }
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonUpdateProject {
+    /// 바꿀 프로젝트 이름. 사용자 안에서 유일해야 한다.
+    #[validate(length(min = 1, max = 64))]
+    pub name: Option<String>,
+    /// 바꿀 `#rrggbb` 형식의 색
+    pub color: Option<String>,
+}
+
+impl JsonUpdateProject {
+    pub fn into_view(self, id: String) -> UpdateProjectView {
+        UpdateProjectView::new(id, self.name, self.color)
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectQuery {
+    /// `true` 이면 보관한 프로젝트도 포함한다.
+    pub include_archived: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_project_from_project_view_maps_all_fields() {
+        let view = ProjectView {
+            id: "01JRWBKE4KE4P9MQNHCX4F0000".to_string(),
+            name: "work".to_string(),
+            color: "#ff8800".to_string(),
+            archived: true,
+        };
+        let json: JsonProject = view.into();
+        assert_eq!(json.id, "01JRWBKE4KE4P9MQNHCX4F0000");
+        assert_eq!(json.color, "#ff8800");
+        assert!(json.archived);
+    }
+
+    #[test]
+    fn json_create_project_validate_with_too_long_name_returns_error() {
+        let jc = JsonCreateProject {
+            name: Some("a".repeat(65)),
+            color: None,
+        };
+        assert!(jc.validate().is_err());
+    }
+}
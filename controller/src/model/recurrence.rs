@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use usecase::model::todo::recurrence::TodoOccurrencesView;
+use utoipa::{IntoParams, ToSchema};
+
+/// 반복 todo 의 다음 회차 미리보기. 반복하지 않는 todo 면 `recurrence` 는 `null`, `occurrences` 는 빈 목록이다.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTodoOccurrences {
+    pub recurrence: Option<String>,
+    pub occurrences: Vec<String>,
+}
+
+impl From<TodoOccurrencesView> for JsonTodoOccurrences {
+    fn from(view: TodoOccurrencesView) -> Self {
+        Self {
+            recurrence: view.recurrence,
+            occurrences: view.occurrences.iter().map(|at| at.to_string()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct TodoOccurrencesQuery {
+    /// 미리 볼 회차 수 (기본 5, 최대 50)
+    #[param(minimum = 1, maximum = 50)]
+    pub limit: Option<i64>,
+}
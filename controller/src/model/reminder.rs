@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSnoozeTodo {
+    /// 지금부터 알림을 미룰 시간(분). 1 ~ 10080(7일)
+    #[validate(required)]
+    #[schema(minimum = 1, maximum = 10080)]
+    pub minutes: Option<i64>,
+}
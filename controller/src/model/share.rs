@@ -0,0 +1,53 @@
+use crate::context::errors::AppError;
+use crate::context::message::Message;
+use serde::{Deserialize, Serialize};
+use usecase::model::todo::share::{ShareTodo, TodoShareView};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTodoShare {
+    pub todo_id: String,
+    pub user_id: String,
+    pub username: String,
+    pub permission: String,
+    pub created_at: String,
+}
+
+impl From<TodoShareView> for JsonTodoShare {
+    fn from(sv: TodoShareView) -> Self {
+        Self {
+            todo_id: sv.todo_id,
+            user_id: sv.user_id,
+            username: sv.username,
+            permission: sv.permission,
+            created_at: sv.created_at.to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonShareTodo {
+    /// 공유할 사용자 이름
+    #[validate(length(min = 1), required)]
+    pub username: Option<String>,
+    /// 공유 권한 (`read`, `write`)
+    #[validate(required)]
+    pub permission: Option<String>,
+}
+
+impl TryFrom<JsonShareTodo> for ShareTodo {
+    type Error = AppError;
+
+    fn try_from(js: JsonShareTodo) -> Result<Self, Self::Error> {
+        let username = js
+            .username
+            .ok_or_else(|| AppError::from(Message::Required("username".to_string())))?;
+        let permission = js
+            .permission
+            .ok_or_else(|| AppError::from(Message::Required("permission".to_string())))?;
+        Ok(ShareTodo::new(username, permission))
+    }
+}
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use usecase::model::todo::stats::{
+    TodoStatsBucketView, TodoStatsCondition, TodoStatsView, TodoStatusCountView,
+};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTodoStatusCount {
+    pub code: String,
+    pub count: i64,
+}
+
+impl From<TodoStatusCountView> for JsonTodoStatusCount {
+    fn from(cv: TodoStatusCountView) -> Self {
+        Self {
+            code: cv.code,
+            count: cv.count,
+        }
+    }
+}
+
+/// 기간 한 칸의 생성·완료 건수. `start` 는 칸이 시작하는 날짜이며, 주 단위이면 월요일이다.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTodoStatsBucket {
+    #[schema(example = "2026-10-12")]
+    pub start: String,
+    pub created: i64,
+    pub completed: i64,
+}
+
+impl From<TodoStatsBucketView> for JsonTodoStatsBucket {
+    fn from(bv: TodoStatsBucketView) -> Self {
+        Self {
+            start: bv.start,
+            created: bv.created,
+            completed: bv.completed,
+        }
+    }
+}
+
+/// todo 통계. `statusCounts` 는 모든 상태를 0 건까지 담고, `buckets` 는 빈 칸 없이 기간을 채운다.
+/// `averageCompletionSeconds` 는 기간 안에 완료한 todo 가 생성부터 완료까지 걸린 평균 초이며,
+/// 완료한 todo 가 없으면 `null` 이다. `overdue` 는 마감일이 지났지만 끝나지 않은 todo 건수이다.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTodoStats {
+    #[schema(example = "2026-09-19")]
+    pub from: String,
+    #[schema(example = "2026-10-18")]
+    pub to: String,
+    #[schema(example = "day")]
+    pub interval: String,
+    pub status_counts: Vec<JsonTodoStatusCount>,
+    pub buckets: Vec<JsonTodoStatsBucket>,
+    pub average_completion_seconds: Option<f64>,
+    pub overdue: i64,
+}
+
+impl From<TodoStatsView> for JsonTodoStats {
+    fn from(sv: TodoStatsView) -> Self {
+        Self {
+            from: sv.from,
+            to: sv.to,
+            interval: sv.interval,
+            status_counts: sv.status_counts.into_iter().map(Into::into).collect(),
+            buckets: sv.buckets.into_iter().map(Into::into).collect(),
+            average_completion_seconds: sv.average_completion_seconds,
+            overdue: sv.overdue,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct TodoStatsQuery {
+    /// 기간 시작일 (UTC, `YYYY-MM-DD`). 없으면 `to` 까지 30일이다.
+    #[param(example = "2026-09-19")]
+    pub from: Option<String>,
+    /// 기간 마지막 날 (UTC, `YYYY-MM-DD`, 포함). 없으면 오늘이다. 기간은 366일까지이다.
+    #[param(example = "2026-10-18")]
+    pub to: Option<String>,
+    /// 추이를 나눌 단위: `day`(기본) 또는 `week`
+    #[param(example = "week")]
+    pub interval: Option<String>,
+}
+
+impl From<TodoStatsQuery> for TodoStatsCondition {
+    fn from(sq: TodoStatsQuery) -> Self {
+        Self {
+            from: sq.from,
+            to: sq.to,
+            interval: sq.interval,
+        }
+    }
+}
@@ -1,18 +1,108 @@
-use serde::Serialize;
-use usecase::model::todo::status::TodoStatusView;
+use crate::context::errors::AppError;
+use crate::context::locale::Locale;
+use crate::context::message::{status_name, Message};
+use serde::{Deserialize, Serialize};
+use usecase::model::todo::status::{
+    CreateTodoStatus, TodoStatusEntryView, TodoStatusTransitionView, TodoStatusView,
+};
+use utoipa::ToSchema;
+use validator::Validate;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct JsonTodoStatus {
     pub code: String,
     pub name: String,
 }
 
+/// 기본 상태의 이름은 요청 언어로 바꾸고, 한국어이거나 사용자 정의 상태이면 저장된 이름을 쓴다.
 impl From<TodoStatusView> for JsonTodoStatus {
     fn from(sv: TodoStatusView) -> Self {
+        let name = status_name(Locale::current(), &sv.code).map_or(sv.name, str::to_string);
         Self {
             code: sv.code,
-            name: sv.name,
+            name,
+        }
+    }
+}
+
+/// 상태 목록의 한 항목. 기본 상태인지와 새로 고를 수 있는지를 함께 보여 준다.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTodoStatusEntry {
+    #[serde(flatten)]
+    pub status: JsonTodoStatus,
+    /// 기본 상태이면 `true`. 기본 상태는 끌 수 없다.
+    pub builtin: bool,
+    /// 끈 상태는 새로 고를 수 없지만 이미 그 상태인 todo 는 그대로 둔다.
+    pub enabled: bool,
+}
+
+impl From<TodoStatusEntryView> for JsonTodoStatusEntry {
+    fn from(ev: TodoStatusEntryView) -> Self {
+        Self {
+            status: ev.status.into(),
+            builtin: ev.builtin,
+            enabled: ev.enabled,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonCreateTodoStatus {
+    /// 상태 코드. 영소문자로 시작하고 영소문자·숫자·`_` 로 이루어진다.
+    #[validate(length(min = 2, max = 32), required)]
+    pub code: Option<String>,
+    /// 화면에 보여 줄 이름.
+    #[validate(length(min = 1, max = 32), required)]
+    pub name: Option<String>,
+}
+
+impl TryFrom<JsonCreateTodoStatus> for CreateTodoStatus {
+    type Error = AppError;
+
+    fn try_from(jc: JsonCreateTodoStatus) -> Result<Self, Self::Error> {
+        Ok(CreateTodoStatus::new(
+            jc.code
+                .ok_or_else(|| AppError::from(Message::Required("code".to_string())))?,
+            jc.name
+                .ok_or_else(|| AppError::from(Message::Required("name".to_string())))?,
+        ))
+    }
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonRenameTodoStatus {
+    /// 바꿀 이름.
+    #[validate(length(min = 1, max = 32), required)]
+    pub name: Option<String>,
+}
+
+impl JsonRenameTodoStatus {
+    pub fn try_into_name(self) -> Result<String, AppError> {
+        self.name
+            .ok_or_else(|| AppError::from(Message::Required("name".to_string())))
+    }
+}
+
+/// 상태와 그 상태에서 바꿀 수 있는 다음 상태 목록.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTodoStatusTransition {
+    pub code: String,
+    pub name: String,
+    pub next: Vec<JsonTodoStatus>,
+}
+
+impl From<TodoStatusTransitionView> for JsonTodoStatusTransition {
+    fn from(tv: TodoStatusTransitionView) -> Self {
+        let status: JsonTodoStatus = tv.status.into();
+        Self {
+            code: status.code,
+            name: status.name,
+            next: tv.next.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -33,4 +123,58 @@ mod tests {
         assert_eq!(json.code, "IN_PROGRESS");
         assert_eq!(json.name, "In Progress");
     }
+
+    #[test]
+    fn json_todo_status_transition_flattens_status_and_maps_next() {
+        let view = |code: &str, name: &str| TodoStatusView {
+            id: "01JRWBKE4KE4P9MQNHCX4F0000".to_string(),
+            code: code.to_string(),
+            name: name.to_string(),
+        };
+        let transition = TodoStatusTransitionView {
+            status: view("done", "완료"),
+            next: vec![view("working", "착수"), view("deleted", "삭제")],
+        };
+        let json: JsonTodoStatusTransition = transition.into();
+        assert_eq!(json.code, "done");
+        assert_eq!(json.name, "완료");
+        let next: Vec<&str> = json.next.iter().map(|s| s.code.as_str()).collect();
+        assert_eq!(next, ["working", "deleted"]);
+    }
+
+    #[tokio::test]
+    async fn json_todo_status_uses_catalog_name_for_built_in_status_in_english() {
+        let view = |code: &str, name: &str| TodoStatusView {
+            id: "01JRWBKE4KE4P9MQNHCX4F0000".to_string(),
+            code: code.to_string(),
+            name: name.to_string(),
+        };
+        let (done, review) = Locale::En
+            .scope(async {
+                let done: JsonTodoStatus = view("done", "완료").into();
+                let review: JsonTodoStatus = view("review", "검토").into();
+                (done, review)
+            })
+            .await;
+        assert_eq!(done.name, "Done");
+        assert_eq!(review.name, "검토");
+    }
+
+    #[test]
+    fn json_todo_status_entry_serializes_flat() {
+        let entry = TodoStatusEntryView {
+            status: TodoStatusView {
+                id: "01JRWBKE4KE4P9MQNHCX4F0000".to_string(),
+                code: "review".to_string(),
+                name: "검토".to_string(),
+            },
+            builtin: false,
+            enabled: true,
+        };
+        let json = serde_json::to_value(JsonTodoStatusEntry::from(entry)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"code": "review", "name": "검토", "builtin": false, "enabled": true})
+        );
+    }
 }
@@ -0,0 +1,95 @@
+use crate::context::errors::AppError;
+use crate::context::message::Message;
+use serde::{Deserialize, Serialize};
+use usecase::model::tag::{CreateTag, TagView, UpdateTagView};
+use utoipa::ToSchema;
+use validator::Validate;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTag {
+    pub id: String,
+    pub name: String,
+}
+
+impl From<TagView> for JsonTag {
+    fn from(tv: TagView) -> Self {
+        Self {
+            id: tv.id,
+            name: tv.name,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonCreateTag {
+    /// 태그 이름. 사용자 안에서 유일해야 한다.
+    #[validate(length(min = 1, max = 32), required)]
+    pub name: Option<String>,
+}
+
+impl TryFrom<JsonCreateTag> for CreateTag {
+    type Error = AppError;
+
+    fn try_from(jc: JsonCreateTag) -> Result<Self, Self::Error> {
+        Ok(CreateTag::new(jc.name.ok_or_else(|| {
+            AppError::from(Message::Required("name".to_string()))
+        })?))
+    }
+}
+
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonUpdateTag {
+    /// 바꿀 태그 이름. 사용자 안에서 유일해야 한다.
+    #[validate(length(min = 1, max = 32), required)]
+    pub name: Option<String>,
+}
+
+impl JsonUpdateTag {
+    pub fn try_to_view(self, id: String) -> Result<UpdateTagView, AppError> {
+        Ok(UpdateTagView::new(
+            id,
+            self.name
+                .ok_or_else(|| AppError::from(Message::Required("name".to_string())))?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_tag_from_tag_view_maps_all_fields() {
+        let view = TagView {
+            id: "01JRWBKE4KE4P9MQNHCX4F0000".to_string(),
+            name: "work".to_string(),
+        };
+        let json: JsonTag = view.into();
+        assert_eq!(json.id, "01JRWBKE4KE4P9MQNHCX4F0000");
+        assert_eq!(json.name, "work");
+    }
+
+    #[test]
+    fn json_create_tag_validate_with_too_long_name_returns_error() {
+        let jc = JsonCreateTag {
+            name: Some("a".repeat(33)),
+        };
+        assert!(jc.validate().is_err());
+    }
+
+    #[test]
+    fn json_update_tag_try_to_view_returns_err_when_name_is_none() {
+        let ju = JsonUpdateTag { name: None };
+        let err = ju
+            .try_to_view("id1".to_string())
+            .err()
+            .expect("expected Err");
+        assert!(
+            err.to_string().contains("name"),
+            "expected name error, got: {err}"
+        );
+    }
+}
@@ -1,8 +1,11 @@
 use crate::context::errors::AppError;
+use crate::context::message::Message;
 use crate::model::status::JsonTodoStatus;
+use crate::model::tag::JsonTag;
 use serde::{Deserialize, Serialize};
+use usecase::model::todo::tree::TodoTreeView;
 use usecase::model::todo::{
-    CreateTodo, SearchTodoCondition, TodoView, UpdateTodoView, UpsertTodoView,
+    CreateTodo, SearchTodoCondition, TodoPageView, TodoView, UpdateTodoView, UpsertTodoView,
 };
 use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
@@ -11,22 +14,89 @@ use validator::Validate;
 #[serde(rename_all = "camelCase")]
 pub struct JsonTodo {
     pub id: String,
+    pub owner_id: String,
+    /// 상위 todo id. 하위 작업에만 포함된다.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
     pub title: String,
     pub description: String,
     pub status: JsonTodoStatus,
+    /// 마감일. 지정한 todo 에만 포함된다.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_at: Option<String>,
+    /// 우선순위. `low`, `medium`, `high`, `urgent` 중 하나다.
+    pub priority: String,
+    pub tags: Vec<JsonTag>,
     pub created_at: String,
     pub updated_at: String,
+    /// 휴지통으로 옮긴 시각. 휴지통에 있는 todo 에만 포함된다.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
+    /// 내용이 바뀔 때마다 오르는 버전. `ETag` 헤더와 같은 값이다.
+    pub version: i64,
+    /// 반복 규칙 (RRULE). 반복 todo 에만 포함된다.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<String>,
+    /// 알림 시각. 지정한 todo 에만 포함된다.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remind_at: Option<String>,
+    /// 속한 프로젝트 id. 프로젝트에 속한 todo 에만 포함된다.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<String>,
+    /// 프로젝트와 함께 보관한 시각. 보관한 todo 에만 포함된다.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<String>,
+    /// 직접 정한 순서의 순위. `sort=position` 으로 조회하면 이 값의 문자열 순서이다.
+    pub position: String,
+    /// 검색 관련도. 검색 결과에만 포함된다.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rank: Option<f32>,
+    /// 검색어를 `<mark>` 로 강조한 발췌. 원문은 HTML 이스케이프되어 있다. 검색 결과에만 포함된다.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
 }
 
 impl From<TodoView> for JsonTodo {
     fn from(tv: TodoView) -> Self {
         Self {
             id: tv.id,
+            owner_id: tv.owner_id,
+            parent_id: tv.parent_id,
             title: tv.title,
             description: tv.description,
             status: tv.status.into(),
+            due_at: tv.due_at.map(|d| d.to_string()),
+            priority: tv.priority,
+            tags: tv.tags.into_iter().map(Into::into).collect(),
             created_at: tv.created_at.to_string(),
             updated_at: tv.updated_at.to_string(),
+            deleted_at: tv.deleted_at.map(|d| d.to_string()),
+            version: tv.version,
+            recurrence: tv.recurrence,
+            remind_at: tv.remind_at.map(|d| d.to_string()),
+            project_id: tv.project_id,
+            archived_at: tv.archived_at.map(|d| d.to_string()),
+            position: tv.position,
+            rank: tv.rank,
+            snippet: tv.snippet,
+        }
+    }
+}
+
+/// 하위 작업을 `children` 으로 포함한 todo.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTodoTree {
+    #[serde(flatten)]
+    pub todo: JsonTodo,
+    pub children: Vec<JsonTodoTree>,
+}
+
+impl From<TodoTreeView> for JsonTodoTree {
+    fn from(tree: TodoTreeView) -> Self {
+        Self {
+            todo: tree.todo.into(),
+            children: tree.children.into_iter().map(Into::into).collect(),
         }
     }
 }
@@ -35,24 +105,51 @@ impl From<TodoView> for JsonTodo {
 #[serde(rename_all = "camelCase")]
 pub struct JsonTodoList {
     pub todos: Vec<JsonTodo>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
 }
 
 impl JsonTodoList {
-    pub fn new(todos: Vec<JsonTodo>) -> Self {
-        Self { todos }
+    pub fn new(todos: Vec<JsonTodo>, next_cursor: Option<String>, has_more: bool) -> Self {
+        Self {
+            todos,
+            next_cursor,
+            has_more,
+        }
+    }
+}
+
+impl From<TodoPageView> for JsonTodoList {
+    fn from(page: TodoPageView) -> Self {
+        Self::new(
+            page.todos.into_iter().map(Into::into).collect(),
+            page.next_cursor,
+            page.has_more,
+        )
     }
 }
 
 #[derive(Deserialize, Debug, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct JsonCreateTodo {
-    #[validate(
-        length(min = 1, message = "`title` is empty."),
-        required(message = "`title` is null.")
-    )]
+    #[validate(length(min = 1), required)]
     pub title: Option<String>,
-    #[validate(required(message = "`description` is null."))]
+    #[validate(required)]
     pub description: Option<String>,
+    /// 마감일 (RFC 3339)
+    pub due_at: Option<String>,
+    /// 우선순위. `low`, `medium`, `high`, `urgent` 중 하나이며 기본 `medium` 이다.
+    pub priority: Option<String>,
+    /// 붙일 태그 id 목록
+    pub tag_ids: Option<Vec<String>>,
+    /// 상위 todo id. 지정하면 그 todo 의 하위 작업으로 만든다.
+    pub parent_id: Option<String>,
+    /// 반복 규칙 (RRULE, 예: `FREQ=WEEKLY;BYDAY=MO,WE`). 완료하면 다음 회차를 만든다.
+    pub recurrence: Option<String>,
+    /// 알림 시각 (RFC 3339)
+    pub remind_at: Option<String>,
+    /// 프로젝트 id. 지정하면 그 프로젝트에 만든다.
+    pub project_id: Option<String>,
 }
 
 impl TryFrom<JsonCreateTodo> for CreateTodo {
@@ -62,10 +159,17 @@ impl TryFrom<JsonCreateTodo> for CreateTodo {
         Ok(CreateTodo {
             title: jc
                 .title
-                .ok_or_else(|| AppError::Error("`title` is required".to_string()))?,
+                .ok_or_else(|| AppError::from(Message::Required("title".to_string())))?,
             description: jc
                 .description
-                .ok_or_else(|| AppError::Error("`description` is required".to_string()))?,
+                .ok_or_else(|| AppError::from(Message::Required("description".to_string())))?,
+            due_at: jc.due_at,
+            priority: jc.priority,
+            tag_ids: jc.tag_ids.unwrap_or_default(),
+            parent_id: jc.parent_id,
+            recurrence: jc.recurrence,
+            remind_at: jc.remind_at,
+            project_id: jc.project_id,
         })
     }
 }
@@ -76,21 +180,37 @@ pub struct JsonUpdateTodoContents {
     pub title: Option<String>,
     pub description: Option<String>,
     pub status_code: Option<String>,
+    /// 마감일 (RFC 3339)
+    pub due_at: Option<String>,
+    /// 우선순위. `low`, `medium`, `high`, `urgent` 중 하나다.
+    pub priority: Option<String>,
+    /// 붙일 태그 id 목록
+    pub add_tag_ids: Option<Vec<String>>,
+    /// 뗄 태그 id 목록
+    pub remove_tag_ids: Option<Vec<String>>,
+    /// 옮길 상위 todo id. 빈 문자열이면 최상위로 옮긴다.
+    pub parent_id: Option<String>,
+    /// 반복 규칙 (RRULE)
+    pub recurrence: Option<String>,
+    /// 알림 시각 (RFC 3339). 바꾸면 알림을 다시 보낸다.
+    pub remind_at: Option<String>,
+    /// 옮길 프로젝트 id. 빈 문자열이면 프로젝트에서 뺀다.
+    pub project_id: Option<String>,
 }
 
 impl JsonUpdateTodoContents {
-    pub fn validate(self, id: String) -> Result<UpdateTodoView, Vec<String>> {
-        let mut errors: Vec<String> = vec![];
+    pub fn validate(self, id: String) -> Result<UpdateTodoView, Vec<Message>> {
+        let mut errors: Vec<Message> = vec![];
 
         if let Some(title) = &self.title {
             if title.is_empty() {
-                errors.push("`title` is empty.".to_string());
+                errors.push(Message::Empty("title".to_string()));
             }
         }
 
         if let Some(status_code) = &self.status_code {
             if status_code.is_empty() {
-                errors.push("`statusCode` is empty.".to_string());
+                errors.push(Message::Empty("statusCode".to_string()));
             }
         }
 
@@ -98,60 +218,128 @@ impl JsonUpdateTodoContents {
             return Err(errors);
         }
 
-        Ok(UpdateTodoView::new(
-            id,
-            self.title,
-            self.description,
-            self.status_code,
-        ))
+        let mut view = UpdateTodoView::new(id, self.title, self.description, self.status_code);
+        view.due_at = self.due_at.into();
+        view.priority = self.priority;
+        view.recurrence = self.recurrence.into();
+        view.remind_at = self.remind_at.into();
+        view.add_tag_ids = self.add_tag_ids.unwrap_or_default();
+        view.remove_tag_ids = self.remove_tag_ids.unwrap_or_default();
+        view.parent_id = self.parent_id;
+        view.project_id = self.project_id;
+        Ok(view)
     }
 }
 
 #[derive(Deserialize, Debug, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct JsonUpsertTodoContents {
-    #[validate(
-        length(min = 1, message = "`title` is empty."),
-        required(message = "`title` is null.")
-    )]
+    #[validate(length(min = 1), required)]
     pub title: Option<String>,
-    #[validate(required(message = "`description` is null."))]
+    #[validate(required)]
     pub description: Option<String>,
-    #[validate(
-        length(min = 1, message = "`statusCode` is empty."),
-        required(message = "`statusCode` is null.")
-    )]
+    #[validate(length(min = 1), required)]
     pub status_code: Option<String>,
+    /// 마감일 (RFC 3339). 없으면 마감일을 지운다.
+    pub due_at: Option<String>,
+    /// 우선순위. 없으면 `medium` 이다.
+    pub priority: Option<String>,
 }
 
 impl JsonUpsertTodoContents {
     pub fn try_to_view(self, id: String) -> Result<UpsertTodoView, AppError> {
-        Ok(UpsertTodoView::new(
+        let mut view = UpsertTodoView::new(
             id,
             self.title
-                .ok_or_else(|| AppError::Error("`title` is required".to_string()))?,
+                .ok_or_else(|| AppError::from(Message::Required("title".to_string())))?,
             self.description
-                .ok_or_else(|| AppError::Error("`description` is required".to_string()))?,
+                .ok_or_else(|| AppError::from(Message::Required("description".to_string())))?,
             self.status_code
-                .ok_or_else(|| AppError::Error("`statusCode` is required".to_string()))?,
-        ))
+                .ok_or_else(|| AppError::from(Message::Required("statusCode".to_string())))?,
+        );
+        view.due_at = self.due_at;
+        view.priority = self.priority;
+        Ok(view)
     }
 }
 
 #[derive(Debug, Deserialize, ToSchema, IntoParams)]
 #[serde(rename_all = "camelCase")]
 pub struct TodoQuery {
+    /// 검색어. 제목과 설명을 전문 검색하며 결과는 관련도 순으로 정렬된다.
+    pub q: Option<String>,
+    /// 상태 코드. 쉼표로 구분하여 여러 개를 지정할 수 있다. (예: `new,working`)
+    /// 지정하지 않으면 휴지통의 todo 는 제외되며, 휴지통은 `deleted` 로 조회한다.
     pub status: Option<String>,
+    /// 이 시각 이후 생성된 todo (RFC 3339)
+    pub created_after: Option<String>,
+    /// 이 시각 이전 생성된 todo (RFC 3339)
+    pub created_before: Option<String>,
+    /// 이 시각 이후 수정된 todo (RFC 3339)
+    pub updated_since: Option<String>,
+    /// 이 시각 이전이 마감인 todo (RFC 3339)
+    pub due_before: Option<String>,
+    /// `true` 이면 마감일이 지났는데 완료(`done`)나 중단(`discontinued`)되지 않은 todo 만 조회한다.
+    pub overdue: Option<bool>,
+    /// 태그 이름. 쉼표로 구분하여 여러 개를 지정하면 모두 붙어 있는 todo 만 조회한다. (예: `work,urgent`)
+    pub tag: Option<String>,
+    /// 정렬 기준. `createdAt`, `updatedAt`, `title`, `priority`, `position` 이며 `-` 접두사는 역순이다. (기본 `createdAt`)
+    /// `priority` 는 우선순위가 높은 순, 같으면 마감일이 빠른 순이다. `position` 은 직접 정한 순서이다.
+    pub sort: Option<String>,
+    /// 페이지 크기 (기본 20, 최대 100)
+    #[param(minimum = 1, maximum = 100)]
+    pub limit: Option<i64>,
+    /// 이전 응답의 `nextCursor`. 없으면 첫 페이지를 조회한다.
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTodoQuery {
+    /// `true` 이면 하위 작업을 `children` 으로 포함한 트리를 조회한다.
+    pub tree: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteTodoQuery {
+    /// `true` 이면 휴지통에 있는 todo 를 영구 삭제한다. 휴지통에 없는 todo 는 지우지 않는다.
+    pub hard: Option<bool>,
 }
 
 impl From<TodoQuery> for SearchTodoCondition {
     fn from(tq: TodoQuery) -> Self {
         Self {
-            status_code: tq.status,
+            q: tq.q,
+            status_codes: split_comma(tq.status.as_deref()),
+            created_after: tq.created_after,
+            created_before: tq.created_before,
+            updated_since: tq.updated_since,
+            due_before: tq.due_before,
+            overdue: tq.overdue.unwrap_or(false),
+            tag_names: split_comma(tq.tag.as_deref()),
+            parent_id: None,
+            project_id: None,
+            sort: tq.sort,
+            cursor: tq.cursor,
+            limit: tq.limit,
         }
     }
 }
 
+/// 쉼표로 구분한 값을 나눈다. 빈 값은 버린다.
+fn split_comma(value: Option<&str>) -> Vec<String> {
+    value
+        .map(|s| {
+            s.split(',')
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -162,6 +350,14 @@ mod tests {
             title: Some("New Title".to_string()),
             description: Some("New Desc".to_string()),
             status_code: Some("DONE".to_string()),
+            due_at: None,
+            priority: None,
+            add_tag_ids: None,
+            remove_tag_ids: None,
+            parent_id: None,
+            recurrence: None,
+            remind_at: None,
+            project_id: None,
         };
         let result = contents.validate("abc123".to_string());
         assert!(result.is_ok());
@@ -177,11 +373,19 @@ mod tests {
             title: Some("".to_string()),
             description: None,
             status_code: None,
+            due_at: None,
+            priority: None,
+            add_tag_ids: None,
+            remove_tag_ids: None,
+            parent_id: None,
+            recurrence: None,
+            remind_at: None,
+            project_id: None,
         };
         let result = contents.validate("id1".to_string());
         assert!(result.is_err());
         let errs = result.err().unwrap();
-        assert!(errs.iter().any(|e| e.contains("title")));
+        assert_eq!(errs, vec![Message::Empty("title".to_string())]);
     }
 
     #[test]
@@ -190,11 +394,19 @@ mod tests {
             title: None,
             description: None,
             status_code: Some("".to_string()),
+            due_at: None,
+            priority: None,
+            add_tag_ids: None,
+            remove_tag_ids: None,
+            parent_id: None,
+            recurrence: None,
+            remind_at: None,
+            project_id: None,
         };
         let result = contents.validate("id2".to_string());
         assert!(result.is_err());
         let errs = result.err().unwrap();
-        assert!(errs.iter().any(|e| e.contains("statusCode")));
+        assert_eq!(errs, vec![Message::Empty("statusCode".to_string())]);
     }
 
     #[test]
@@ -203,6 +415,14 @@ mod tests {
             title: Some("".to_string()),
             description: None,
             status_code: Some("".to_string()),
+            due_at: None,
+            priority: None,
+            add_tag_ids: None,
+            remove_tag_ids: None,
+            parent_id: None,
+            recurrence: None,
+            remind_at: None,
+            project_id: None,
         };
         let result = contents.validate("id3".to_string());
         assert!(result.is_err());
@@ -215,6 +435,14 @@ mod tests {
             title: None,
             description: None,
             status_code: None,
+            due_at: None,
+            priority: None,
+            add_tag_ids: None,
+            remove_tag_ids: None,
+            parent_id: None,
+            recurrence: None,
+            remind_at: None,
+            project_id: None,
         };
         let result = contents.validate("id4".to_string());
         assert!(result.is_ok());
@@ -223,23 +451,116 @@ mod tests {
     #[test]
     fn todo_query_from_search_condition_with_status_maps_correctly() {
         let query = TodoQuery {
+            q: Some("milk".to_string()),
             status: Some("OPEN".to_string()),
+            created_after: Some("2024-01-01T00:00:00Z".to_string()),
+            created_before: None,
+            updated_since: None,
+            due_before: None,
+            overdue: None,
+            tag: None,
+            sort: Some("-updatedAt".to_string()),
+            limit: Some(10),
+            cursor: Some("abc".to_string()),
+        };
+        let condition: SearchTodoCondition = query.into();
+        assert_eq!(condition.q, Some("milk".to_string()));
+        assert_eq!(condition.status_codes, vec!["OPEN".to_string()]);
+        assert_eq!(
+            condition.created_after,
+            Some("2024-01-01T00:00:00Z".to_string())
+        );
+        assert_eq!(condition.sort, Some("-updatedAt".to_string()));
+        assert_eq!(condition.limit, Some(10));
+        assert_eq!(condition.cursor, Some("abc".to_string()));
+    }
+
+    #[test]
+    fn todo_query_from_search_condition_splits_comma_separated_statuses() {
+        let query = TodoQuery {
+            q: None,
+            status: Some("new, working,,done".to_string()),
+            created_after: None,
+            created_before: None,
+            updated_since: None,
+            due_before: None,
+            overdue: None,
+            tag: None,
+            sort: None,
+            limit: None,
+            cursor: None,
+        };
+        let condition: SearchTodoCondition = query.into();
+        assert_eq!(condition.status_codes, vec!["new", "working", "done"]);
+    }
+
+    #[test]
+    fn todo_query_from_search_condition_maps_due_conditions() {
+        let query = TodoQuery {
+            q: None,
+            status: None,
+            created_after: None,
+            created_before: None,
+            updated_since: None,
+            due_before: Some("2026-11-01T00:00:00Z".to_string()),
+            overdue: Some(true),
+            tag: None,
+            sort: Some("priority".to_string()),
+            limit: None,
+            cursor: None,
+        };
+        let condition: SearchTodoCondition = query.into();
+        assert_eq!(
+            condition.due_before,
+            Some("2026-11-01T00:00:00Z".to_string())
+        );
+        assert!(condition.overdue);
+        assert_eq!(condition.sort, Some("priority".to_string()));
+    }
+
+    #[test]
+    fn todo_query_from_search_condition_splits_comma_separated_tags() {
+        let query = TodoQuery {
+            q: None,
+            status: None,
+            created_after: None,
+            created_before: None,
+            updated_since: None,
+            due_before: None,
+            overdue: None,
+            tag: Some("work, home,".to_string()),
+            sort: None,
+            limit: None,
+            cursor: None,
         };
         let condition: SearchTodoCondition = query.into();
-        assert_eq!(condition.status_code, Some("OPEN".to_string()));
+        assert_eq!(condition.tag_names, vec!["work", "home"]);
     }
 
     #[test]
     fn todo_query_from_search_condition_without_status_maps_none() {
-        let query = TodoQuery { status: None };
+        let query = TodoQuery {
+            q: None,
+            status: None,
+            created_after: None,
+            created_before: None,
+            updated_since: None,
+            due_before: None,
+            overdue: None,
+            tag: None,
+            sort: None,
+            limit: None,
+            cursor: None,
+        };
         let condition: SearchTodoCondition = query.into();
-        assert!(condition.status_code.is_none());
+        assert!(condition.status_codes.is_empty());
     }
 
     #[test]
     fn json_todo_list_new_with_empty_vec_stores_empty_todos() {
-        let list = JsonTodoList::new(vec![]);
+        let list = JsonTodoList::new(vec![], None, false);
         assert!(list.todos.is_empty());
+        assert!(!list.has_more);
     }
 
     #[test]
@@ -247,6 +568,13 @@ mod tests {
         let jc = JsonCreateTodo {
             title: Some("My Todo".to_string()),
             description: Some("Some description".to_string()),
+            due_at: None,
+            priority: None,
+            tag_ids: None,
+            parent_id: None,
+            recurrence: None,
+            remind_at: None,
+            project_id: None,
         };
         let result = CreateTodo::try_from(jc);
         assert!(result.is_ok());
@@ -260,6 +588,13 @@ mod tests {
         let jc = JsonCreateTodo {
             title: None,
             description: Some("desc".to_string()),
+            due_at: None,
+            priority: None,
+            tag_ids: None,
+            parent_id: None,
+            recurrence: None,
+            remind_at: None,
+            project_id: None,
         };
         let err = CreateTodo::try_from(jc).err().expect("expected Err");
         assert!(
@@ -273,6 +608,13 @@ mod tests {
         let jc = JsonCreateTodo {
             title: Some("title".to_string()),
             description: None,
+            due_at: None,
+            priority: None,
+            tag_ids: None,
+            parent_id: None,
+            recurrence: None,
+            remind_at: None,
+            project_id: None,
         };
         let err = CreateTodo::try_from(jc).err().expect("expected Err");
         assert!(
@@ -287,6 +629,8 @@ mod tests {
             title: None,
             description: Some("desc".to_string()),
             status_code: Some("NEW".to_string()),
+            due_at: None,
+            priority: None,
         };
         let err = jc
             .try_to_view("id1".to_string())
@@ -304,6 +648,8 @@ mod tests {
             title: Some("title".to_string()),
             description: None,
             status_code: Some("NEW".to_string()),
+            due_at: None,
+            priority: None,
         };
         let err = jc
             .try_to_view("id1".to_string())
@@ -321,6 +667,8 @@ mod tests {
             title: Some("title".to_string()),
             description: Some("desc".to_string()),
             status_code: None,
+            due_at: None,
+            priority: None,
         };
         let err = jc
             .try_to_view("id1".to_string())
@@ -0,0 +1,134 @@
+//! RFC 4180 CSV 읽기·쓰기.
+
+use crate::context::message::Message;
+use serde_json::{Map, Value};
+
+/// 빈 칸이어도 빈 문자열로 읽는 열. 나머지 열은 빈 칸이면 값이 없는 것으로 본다.
+const TEXT_COLUMNS: [&str; 2] = ["title", "description"];
+
+/// 레코드 하나를 CRLF 로 끝나는 한 줄로 쓴다. 쉼표·따옴표·줄바꿈이 있는 필드는 따옴표로 감싼다.
+pub fn write_record(fields: &[&str]) -> String {
+    let mut line = fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\r', '\n']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push_str("\r\n");
+    line
+}
+
+/// 본문을 레코드 목록으로 읽는다. 줄 끝은 CRLF 와 LF 를 모두 받으며 빈 줄은 건너뛴다.
+pub fn read_records(body: &str) -> Result<Vec<Vec<String>>, Message> {
+    let body = body.strip_prefix('\u{feff}').unwrap_or(body);
+    let mut records = vec![];
+    let mut record: Vec<String> = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = body.chars().peekable();
+    while let Some(c) = chars.next() {
+        if quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => quoted = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => quoted = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|f| !f.is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                }
+                record.clear();
+            }
+            _ => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(Message::ImportCsvUnterminatedQuote);
+    }
+    record.push(field);
+    if record.iter().any(|f| !f.is_empty()) {
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// 첫 레코드를 머리 행으로 보고 나머지 레코드를 열 이름을 키로 하는 객체로 읽는다.
+/// `description` 열이 없으면 빈 설명으로 본다.
+pub fn read_rows(body: &str) -> Result<Vec<Value>, Message> {
+    let mut records = read_records(body)?.into_iter();
+    let header = records.next().ok_or(Message::ImportCsvNoTitle)?;
+    if !header.iter().any(|h| h == "title") {
+        return Err(Message::ImportCsvNoTitle);
+    }
+    Ok(records
+        .map(|record| {
+            let mut row = Map::new();
+            row.insert("description".to_string(), Value::from(""));
+            for (name, value) in header.iter().zip(record) {
+                if value.is_empty() && !TEXT_COLUMNS.contains(&name.as_str()) {
+                    continue;
+                }
+                row.insert(name.clone(), Value::from(value));
+            }
+            Value::Object(row)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_record_quotes_only_when_needed() {
+        let line = write_record(&["a", "b,c", "say \"hi\"", "x\ny"]);
+        assert_eq!(line, "a,\"b,c\",\"say \"\"hi\"\"\",\"x\ny\"\r\n");
+    }
+
+    #[test]
+    fn read_records_round_trips_written_records() {
+        let fields = ["plain", "b,c", "say \"hi\"", "multi\r\nline", ""];
+        let body = format!("{}{}", write_record(&fields), write_record(&["z"]));
+        let records = read_records(&body).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0], fields.map(str::to_string).to_vec());
+        assert_eq!(records[1], vec!["z".to_string()]);
+    }
+
+    #[test]
+    fn read_records_rejects_unterminated_quote() {
+        assert_eq!(
+            read_records("\"open,field\n").unwrap_err(),
+            Message::ImportCsvUnterminatedQuote
+        );
+    }
+
+    #[test]
+    fn read_rows_maps_header_and_drops_empty_optional_cells() {
+        let rows = read_rows("\u{feff}title,dueAt,priority\nBuy milk,,high\n\n").unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0]["title"], "Buy milk");
+        assert_eq!(rows[0]["description"], "");
+        assert_eq!(rows[0]["priority"], "high");
+        assert!(rows[0].get("dueAt").is_none());
+        assert_eq!(
+            read_rows("name\nBuy milk\n").unwrap_err(),
+            Message::ImportCsvNoTitle
+        );
+    }
+}
@@ -0,0 +1,337 @@
+//! iCalendar(RFC 5545) VTODO 읽기·쓰기.
+//!
+//! 상태는 표준 `STATUS` 와 함께 원래 상태 코드를 `X-TODO-STATUS` 로 남겨, 다시 가져올 때 그대로 되살린다.
+//! 알림은 절대 시각 `TRIGGER` 를 가진 VALARM 으로 쓰며, 가져올 때 상대 시간 TRIGGER 는 무시한다.
+//! 시간대(`TZID`)가 붙은 시각은 UTC 로 본다.
+
+use super::JsonTodoExport;
+use crate::context::message::Message;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+use serde_json::{Map, Value};
+
+/// 한 줄 최대 길이(octet). 넘으면 접어서 이어 쓴다.
+const MAX_LINE_OCTETS: usize = 75;
+
+pub fn calendar_header() -> String {
+    [
+        "BEGIN:VCALENDAR",
+        "VERSION:2.0",
+        "PRODID:-//axum-todo//todo export//EN",
+    ]
+    .iter()
+    .map(|line| fold(line))
+    .collect()
+}
+
+pub fn calendar_footer() -> String {
+    fold("END:VCALENDAR")
+}
+
+/// todo 하나를 VTODO 로 쓴다.
+pub fn write_vtodo(todo: &JsonTodoExport) -> String {
+    let mut lines = vec![
+        "BEGIN:VTODO".to_string(),
+        format!("UID:{}", todo.id),
+        format!("DTSTAMP:{}", basic_time(&todo.updated_at)),
+        format!("CREATED:{}", basic_time(&todo.created_at)),
+        format!("LAST-MODIFIED:{}", basic_time(&todo.updated_at)),
+        format!("SUMMARY:{}", escape_text(&todo.title)),
+    ];
+    if !todo.description.is_empty() {
+        lines.push(format!("DESCRIPTION:{}", escape_text(&todo.description)));
+    }
+    lines.push(format!("STATUS:{}", standard_status(&todo.status_code)));
+    lines.push(format!("X-TODO-STATUS:{}", todo.status_code));
+    lines.push(format!("PRIORITY:{}", standard_priority(&todo.priority)));
+    if let Some(due_at) = &todo.due_at {
+        lines.push(format!("DUE:{}", basic_time(due_at)));
+    }
+    if let Some(recurrence) = &todo.recurrence {
+        lines.push(format!("RRULE:{recurrence}"));
+    }
+    if let Some(remind_at) = &todo.remind_at {
+        lines.push("BEGIN:VALARM".to_string());
+        lines.push("ACTION:DISPLAY".to_string());
+        lines.push(format!("DESCRIPTION:{}", escape_text(&todo.title)));
+        lines.push(format!("TRIGGER;VALUE=DATE-TIME:{}", basic_time(remind_at)));
+        lines.push("END:VALARM".to_string());
+    }
+    lines.push("END:VTODO".to_string());
+    lines.iter().map(|line| fold(line)).collect()
+}
+
+/// 본문의 VTODO 를 하나씩 `JsonImportTodo` 필드 이름의 객체로 읽는다.
+/// 알 수 없는 형식의 값은 그대로 넘겨 검증에서 걸러지게 한다.
+pub fn read_vtodos(body: &str) -> Result<Vec<Value>, Message> {
+    let lines = unfold(body);
+    if lines.first().map(|l| l.trim().to_ascii_uppercase()) != Some("BEGIN:VCALENDAR".to_string()) {
+        return Err(Message::ImportNotCalendar);
+    }
+    let mut rows = vec![];
+    let mut current: Option<Map<String, Value>> = None;
+    let mut standard_status_code: Option<String> = None;
+    let mut in_alarm = false;
+    for line in lines {
+        let Some((name, params, value)) = parse_line(&line) else {
+            continue;
+        };
+        match (name.as_str(), current.as_mut()) {
+            ("BEGIN", None) if value.eq_ignore_ascii_case("VTODO") => {
+                let mut row = Map::new();
+                row.insert("description".to_string(), Value::from(""));
+                current = Some(row);
+                standard_status_code = None;
+            }
+            ("BEGIN", Some(_)) if value.eq_ignore_ascii_case("VALARM") => in_alarm = true,
+            ("END", Some(_)) if value.eq_ignore_ascii_case("VALARM") => in_alarm = false,
+            ("END", Some(row)) if value.eq_ignore_ascii_case("VTODO") => {
+                if let (false, Some(code)) = (row.contains_key("statusCode"), &standard_status_code)
+                {
+                    row.insert("statusCode".to_string(), Value::from(code.clone()));
+                }
+                rows.extend(current.take().map(Value::Object));
+            }
+            ("TRIGGER", Some(row))
+                if in_alarm
+                    && !row.contains_key("remindAt")
+                    && params
+                        .iter()
+                        .any(|p| p.eq_ignore_ascii_case("VALUE=DATE-TIME")) =>
+            {
+                row.insert("remindAt".to_string(), Value::from(rfc3339_time(&value)));
+            }
+            (_, Some(_)) if in_alarm => {}
+            ("SUMMARY", Some(row)) => {
+                row.insert("title".to_string(), Value::from(unescape_text(&value)));
+            }
+            ("DESCRIPTION", Some(row)) => {
+                row.insert(
+                    "description".to_string(),
+                    Value::from(unescape_text(&value)),
+                );
+            }
+            ("X-TODO-STATUS", Some(row)) => {
+                row.insert("statusCode".to_string(), Value::from(value));
+            }
+            ("STATUS", Some(_)) => standard_status_code = Some(status_code_of(&value)),
+            ("PRIORITY", Some(row)) => {
+                if let Some(priority) = priority_of(&value) {
+                    row.insert("priority".to_string(), Value::from(priority));
+                }
+            }
+            ("DUE", Some(row)) => {
+                row.insert("dueAt".to_string(), Value::from(rfc3339_time(&value)));
+            }
+            ("RRULE", Some(row)) => {
+                row.insert("recurrence".to_string(), Value::from(value));
+            }
+            _ => {}
+        }
+    }
+    Ok(rows)
+}
+
+/// 상태 코드를 RFC 5545 `STATUS` 값으로 바꾼다.
+fn standard_status(code: &str) -> &'static str {
+    match code {
+        "working" => "IN-PROCESS",
+        "done" => "COMPLETED",
+        "discontinued" => "CANCELLED",
+        _ => "NEEDS-ACTION",
+    }
+}
+
+/// RFC 5545 `STATUS` 값을 상태 코드로 바꾼다. 모르는 값은 신규로 본다.
+fn status_code_of(status: &str) -> String {
+    match status.to_ascii_uppercase().as_str() {
+        "IN-PROCESS" => "working",
+        "COMPLETED" => "done",
+        "CANCELLED" => "discontinued",
+        _ => "new",
+    }
+    .to_string()
+}
+
+/// 우선순위를 RFC 5545 `PRIORITY`(1 이 가장 높고 9 가 가장 낮음)로 바꾼다.
+fn standard_priority(priority: &str) -> u8 {
+    match priority {
+        "urgent" => 1,
+        "high" => 3,
+        "low" => 9,
+        _ => 5,
+    }
+}
+
+/// RFC 5545 `PRIORITY` 를 우선순위로 바꾼다. 0 은 정하지 않은 것이고, 숫자가 아니면 그대로 넘긴다.
+fn priority_of(value: &str) -> Option<String> {
+    let priority = match value.trim().parse::<u8>() {
+        Ok(0) => return None,
+        Ok(1) => "urgent",
+        Ok(2..=4) => "high",
+        Ok(5) => "medium",
+        Ok(6..=9) => "low",
+        _ => return Some(value.to_string()),
+    };
+    Some(priority.to_string())
+}
+
+/// RFC 3339 시각을 `20300102T030405Z` 형식으로 바꾼다.
+fn basic_time(rfc3339: &str) -> String {
+    DateTime::parse_from_rfc3339(rfc3339)
+        .map(|t| t.with_timezone(&Utc).format("%Y%m%dT%H%M%SZ").to_string())
+        .unwrap_or_else(|_| rfc3339.to_string())
+}
+
+/// `20300102T030405Z`, `20300102T030405`, `20300102` 형식의 시각을 RFC 3339 로 바꾼다.
+/// 읽을 수 없으면 그대로 둔다.
+fn rfc3339_time(value: &str) -> String {
+    let value = value.trim();
+    let naive = NaiveDateTime::parse_from_str(value.trim_end_matches('Z'), "%Y%m%dT%H%M%S")
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y%m%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+        });
+    match naive {
+        Some(naive) => naive.and_utc().to_rfc3339_opts(SecondsFormat::Secs, true),
+        None => value.to_string(),
+    }
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+fn unescape_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// 한 줄을 75 octet 단위로 접어 CRLF 로 끝낸다. 이어지는 줄은 공백 하나로 시작한다.
+fn fold(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + 2);
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += len;
+    }
+    out.push_str("\r\n");
+    out
+}
+
+/// 접힌 줄을 펴서 논리적인 줄 목록으로 만든다.
+fn unfold(body: &str) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+    for raw in body.lines() {
+        match (raw.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ if raw.is_empty() => {}
+            _ => lines.push(raw.to_string()),
+        }
+    }
+    lines
+}
+
+/// `NAME;PARAM=..:VALUE` 를 (대문자 이름, 파라미터, 값) 으로 나눈다. 따옴표 안의 `:` 는 구분자가 아니다.
+fn parse_line(line: &str) -> Option<(String, Vec<String>, String)> {
+    let mut quoted = false;
+    let split = line.char_indices().find_map(|(i, c)| match c {
+        '"' => {
+            quoted = !quoted;
+            None
+        }
+        ':' if !quoted => Some(i),
+        _ => None,
+    })?;
+    let (head, value) = (&line[..split], &line[split + 1..]);
+    let mut parts = head.split(';');
+    let name = parts.next()?.trim().to_ascii_uppercase();
+    let params = parts.map(str::to_string).collect();
+    Some((name, params, value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn export(title: &str) -> JsonTodoExport {
+        JsonTodoExport {
+            id: "01HZX3Q8W0000000000000000".to_string(),
+            title: title.to_string(),
+            description: "line1\nline2, with; marks".to_string(),
+            status_code: "waiting".to_string(),
+            priority: "high".to_string(),
+            due_at: Some("2030-01-02T03:04:05+00:00".to_string()),
+            remind_at: Some("2030-01-01T09:00:00+00:00".to_string()),
+            recurrence: Some("FREQ=WEEKLY;BYDAY=MO".to_string()),
+            created_at: "2026-10-18T00:00:00+00:00".to_string(),
+            updated_at: "2026-10-18T01:00:00+00:00".to_string(),
+        }
+    }
+
+    #[test]
+    fn vtodo_round_trips_through_read() {
+        let title = "긴 제목 ".repeat(20);
+        let body = format!(
+            "{}{}{}",
+            calendar_header(),
+            write_vtodo(&export(&title)),
+            calendar_footer()
+        );
+        assert!(body.lines().all(|l| l.len() <= MAX_LINE_OCTETS));
+
+        let rows = read_vtodos(&body).unwrap();
+        assert_eq!(rows.len(), 1);
+        let row = &rows[0];
+        assert_eq!(row["title"], title.as_str());
+        assert_eq!(row["description"], "line1\nline2, with; marks");
+        assert_eq!(row["statusCode"], "waiting");
+        assert_eq!(row["priority"], "high");
+        assert_eq!(row["dueAt"], "2030-01-02T03:04:05Z");
+        assert_eq!(row["remindAt"], "2030-01-01T09:00:00Z");
+        assert_eq!(row["recurrence"], "FREQ=WEEKLY;BYDAY=MO");
+    }
+
+    #[test]
+    fn read_vtodos_maps_standard_properties_of_other_tools() {
+        let body = "BEGIN:VCALENDAR\nVERSION:2.0\nBEGIN:VTODO\nUID:x\nSUMMARY:Pay rent\n\
+                    STATUS:COMPLETED\nPRIORITY:0\nDUE;VALUE=DATE:20300102\n\
+                    BEGIN:VALARM\nTRIGGER:-PT15M\nEND:VALARM\nEND:VTODO\nEND:VCALENDAR\n";
+        let rows = read_vtodos(body).unwrap();
+        assert_eq!(rows.len(), 1);
+        let row = &rows[0];
+        assert_eq!(row["title"], "Pay rent");
+        assert_eq!(row["description"], "");
+        assert_eq!(row["statusCode"], "done");
+        assert_eq!(row["dueAt"], "2030-01-02T00:00:00Z");
+        assert!(row.get("priority").is_none());
+        assert!(row.get("remindAt").is_none());
+    }
+
+    #[test]
+    fn read_vtodos_rejects_non_calendar_body() {
+        assert!(read_vtodos("title,description\n").is_err());
+    }
+}
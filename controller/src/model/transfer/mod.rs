@@ -0,0 +1,289 @@
+//! Todo 내보내기·가져오기 파일 형식.
+//!
+//! JSON 과 CSV 는 `JsonTodoExport` 의 필드를 같은 이름으로 쓰며, iCalendar 는 VTODO 속성으로 옮긴다.
+//! 가져올 때는 어느 형식이든 행마다 JSON 객체로 맞춘 뒤 `JsonImportTodo` 로 읽어 `JsonCreateTodo` 규칙으로 검증한다.
+
+pub mod csv;
+pub mod ics;
+
+use crate::context::api_response::{usecase_error, validation_messages};
+use crate::context::errors::AppError;
+use crate::context::locale::Locale;
+use crate::context::message::Message;
+use crate::model::todo::{JsonCreateTodo, JsonTodo};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use usecase::model::todo::transfer::{ImportTodoRow, TodoImportErrorView, TodoImportView};
+use usecase::model::todo::TodoView;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+/// 내보내기·가져오기 파일 형식.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TransferFormat {
+    #[default]
+    Json,
+    Csv,
+    Ics,
+}
+
+impl TransferFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Ics => "text/calendar; charset=utf-8",
+        }
+    }
+
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Self::Json => "todos.json",
+            Self::Csv => "todos.csv",
+            Self::Ics => "todos.ics",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, IntoParams, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferQuery {
+    /// 파일 형식 (`json`, `csv`, `ics`). 기본 `json` 이다.
+    pub format: Option<TransferFormat>,
+}
+
+/// CSV 머리 행. `JsonTodoExport` 의 JSON 필드 이름과 같은 순서이다.
+pub const EXPORT_COLUMNS: [&str; 10] = [
+    "id",
+    "title",
+    "description",
+    "statusCode",
+    "priority",
+    "dueAt",
+    "remindAt",
+    "recurrence",
+    "createdAt",
+    "updatedAt",
+];
+
+/// 내보내는 todo 한 건.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTodoExport {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub status_code: String,
+    pub priority: String,
+    pub due_at: Option<String>,
+    pub remind_at: Option<String>,
+    pub recurrence: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<TodoView> for JsonTodoExport {
+    fn from(tv: TodoView) -> Self {
+        Self {
+            id: tv.id,
+            title: tv.title,
+            description: tv.description,
+            status_code: tv.status.code,
+            priority: tv.priority,
+            due_at: tv.due_at.map(|v| v.to_string()),
+            remind_at: tv.remind_at.map(|v| v.to_string()),
+            recurrence: tv.recurrence,
+            created_at: tv.created_at.to_string(),
+            updated_at: tv.updated_at.to_string(),
+        }
+    }
+}
+
+impl JsonTodoExport {
+    /// `EXPORT_COLUMNS` 순서의 CSV 필드. 값이 없으면 빈 문자열이다.
+    fn csv_fields(&self) -> [&str; 10] {
+        [
+            &self.id,
+            &self.title,
+            &self.description,
+            &self.status_code,
+            &self.priority,
+            self.due_at.as_deref().unwrap_or_default(),
+            self.remind_at.as_deref().unwrap_or_default(),
+            self.recurrence.as_deref().unwrap_or_default(),
+            &self.created_at,
+            &self.updated_at,
+        ]
+    }
+}
+
+/// 내보낼 파일을 조각으로 만든다. `header`, 페이지마다의 `page`, `footer` 를 차례로 이어 붙이면 한 파일이 된다.
+pub struct TodoExportEncoder {
+    format: TransferFormat,
+    first: bool,
+}
+
+impl TodoExportEncoder {
+    pub fn new(format: TransferFormat) -> Self {
+        Self {
+            format,
+            first: true,
+        }
+    }
+
+    pub fn header(&self) -> String {
+        match self.format {
+            TransferFormat::Json => "[".to_string(),
+            TransferFormat::Csv => csv::write_record(&EXPORT_COLUMNS),
+            TransferFormat::Ics => ics::calendar_header(),
+        }
+    }
+
+    pub fn page(&mut self, todos: Vec<TodoView>) -> Result<String, serde_json::Error> {
+        let mut chunk = String::new();
+        for todo in todos {
+            let row: JsonTodoExport = todo.into();
+            match self.format {
+                TransferFormat::Json => {
+                    if !self.first {
+                        chunk.push(',');
+                    }
+                    chunk.push_str(&serde_json::to_string(&row)?);
+                }
+                TransferFormat::Csv => chunk.push_str(&csv::write_record(&row.csv_fields())),
+                TransferFormat::Ics => chunk.push_str(&ics::write_vtodo(&row)),
+            }
+            self.first = false;
+        }
+        Ok(chunk)
+    }
+
+    pub fn footer(&self) -> String {
+        match self.format {
+            TransferFormat::Json => "]".to_string(),
+            TransferFormat::Csv => String::new(),
+            TransferFormat::Ics => ics::calendar_footer(),
+        }
+    }
+}
+
+/// 가져올 파일을 행 목록으로 읽는다. 파일 자체를 읽을 수 없으면 그 이유를 반환한다.
+pub fn read_import_rows(format: TransferFormat, body: &str) -> Result<Vec<Value>, Message> {
+    match format {
+        TransferFormat::Json => serde_json::from_str::<Vec<Value>>(body)
+            .map_err(|e| Message::ImportNotJsonArray(e.to_string())),
+        TransferFormat::Csv => csv::read_rows(body),
+        TransferFormat::Ics => ics::read_vtodos(body),
+    }
+}
+
+/// 가져올 todo 한 건. `JsonCreateTodo` 에 처음 상태를 더한 것이다.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonImportTodo {
+    #[serde(flatten)]
+    pub todo: JsonCreateTodo,
+    /// 처음 상태. 없으면 `new` 이다.
+    pub status_code: Option<String>,
+}
+
+/// 파일의 `row` 번째 행(1부터)을 `JsonCreateTodo` 규칙으로 검증한다. 틀리면 이유를 모두 반환한다.
+pub fn to_import_row(row: usize, value: Value) -> Result<ImportTodoRow, Vec<String>> {
+    let locale = Locale::current();
+    let source: JsonImportTodo = serde_json::from_value(value)
+        .map_err(|e| vec![Message::ImportInvalidRow(e.to_string()).text(locale)])?;
+    source.todo.validate().map_err(|e| {
+        validation_messages(&e)
+            .iter()
+            .map(|m| m.text(locale))
+            .collect::<Vec<_>>()
+    })?;
+    let create = source
+        .todo
+        .try_into()
+        .map_err(|e: AppError| vec![e.status_and_message(locale).2])?;
+    Ok(ImportTodoRow::new(row, create, source.status_code))
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTodoImportError {
+    pub row: usize,
+    pub messages: Vec<String>,
+}
+
+impl JsonTodoImportError {
+    /// 유스케이스가 거절한 행. 이유는 다른 오류 응답과 같은 문구를 언어에 맞게 쓴다.
+    fn from_view(ev: TodoImportErrorView, locale: Locale) -> Self {
+        let (_, _, message) = usecase_error(ev.error.into()).status_and_message(locale);
+        Self {
+            row: ev.row,
+            messages: vec![message],
+        }
+    }
+}
+
+/// 가져오기 결과. `errors` 는 행 순서이다.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonTodoImport {
+    pub imported: usize,
+    pub todos: Vec<JsonTodo>,
+    pub errors: Vec<JsonTodoImportError>,
+}
+
+impl JsonTodoImport {
+    /// 유스케이스 결과에 파일을 읽으며 걸러 낸 행의 오류를 더한다.
+    pub fn new(view: TodoImportView, rejected: Vec<JsonTodoImportError>) -> Self {
+        let locale = Locale::current();
+        let mut errors: Vec<JsonTodoImportError> = rejected
+            .into_iter()
+            .chain(
+                view.errors
+                    .into_iter()
+                    .map(|ev| JsonTodoImportError::from_view(ev, locale)),
+            )
+            .collect();
+        errors.sort_by_key(|e| e.row);
+        Self {
+            imported: view.imported.len(),
+            todos: view.imported.into_iter().map(Into::into).collect(),
+            errors,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn to_import_row_applies_create_todo_rules() {
+        let row = to_import_row(
+            1,
+            json!({ "id": "ignored", "title": "a", "description": "", "statusCode": "done" }),
+        )
+        .unwrap();
+        assert_eq!(row.source.title, "a");
+        assert_eq!(row.status_code.as_deref(), Some("done"));
+
+        let messages = to_import_row(2, json!({ "title": "", "description": "" }))
+            .err()
+            .unwrap();
+        assert_eq!(messages, vec!["`title` 값이 비어 있습니다".to_string()]);
+        let messages = to_import_row(3, json!({ "title": "a" })).err().unwrap();
+        assert_eq!(messages, vec!["`description` 값이 필요합니다".to_string()]);
+        assert!(to_import_row(4, json!("not an object")).is_err());
+    }
+
+    #[test]
+    fn json_export_is_a_single_array_across_pages() {
+        let mut encoder = TodoExportEncoder::new(TransferFormat::Json);
+        let mut body = encoder.header();
+        body.push_str(&encoder.page(vec![]).unwrap());
+        body.push_str(&encoder.footer());
+        assert_eq!(serde_json::from_str::<Vec<Value>>(&body).unwrap().len(), 0);
+    }
+}
@@ -1,4 +1,6 @@
 use crate::context::errors::AppError;
+use crate::context::locale::Locale;
+use crate::context::message::Message;
 use fancy_regex::Regex;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
@@ -20,28 +22,18 @@ fn validate_password(value: &str) -> Result<(), ValidationError> {
     {
         Ok(())
     } else {
-        Err(ValidationError::new(""))
+        Err(ValidationError::new("password"))
     }
 }
 
 #[derive(Deserialize, Debug, Validate, ToSchema, IntoParams)]
 #[serde(rename_all = "camelCase")]
 pub struct JsonCreateUser {
-    #[validate(email(message = "invalid email"))]
+    #[validate(email)]
     pub username: Option<String>,
-    #[validate(custom(
-        function = "validate_password",
-        message = "password must contain one digit, one special character and must be at least 8 characters long"
-    ))]
+    #[validate(custom(function = "validate_password"))]
     pub password: Option<String>,
-    #[validate(
-        length(
-            min = 2,
-            max = 30,
-            message = "fullname must be between 3 and 30 characters"
-        ),
-        required(message = "fullname is null")
-    )]
+    #[validate(length(min = 2, max = 30), required)]
     pub fullname: Option<String>,
 }
 
@@ -52,13 +44,13 @@ impl TryFrom<JsonCreateUser> for CreateUser {
         Ok(CreateUser {
             username: jcu
                 .username
-                .ok_or_else(|| AppError::Error("`username` is required".to_string()))?,
+                .ok_or_else(|| AppError::from(Message::Required("username".to_string())))?,
             password: jcu
                 .password
-                .ok_or_else(|| AppError::Error("`password` is required".to_string()))?,
+                .ok_or_else(|| AppError::from(Message::Required("password".to_string())))?,
             fullname: jcu
                 .fullname
-                .ok_or_else(|| AppError::Error("`fullname` is required".to_string()))?,
+                .ok_or_else(|| AppError::from(Message::Required("fullname".to_string())))?,
         })
     }
 }
@@ -70,6 +62,8 @@ pub struct JsonUser {
     pub username: String,
     pub email: String,
     pub fullname: String,
+    /// 고른 응답 언어. 없으면 `Accept-Language` 를 따른다.
+    pub locale: Option<String>,
 }
 
 impl From<UserView> for JsonUser {
@@ -79,10 +73,31 @@ impl From<UserView> for JsonUser {
             username: uv.username,
             email: uv.email,
             fullname: uv.fullname,
+            locale: uv.locale,
         }
     }
 }
 
+#[derive(Deserialize, Debug, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonUpdateUserLocale {
+    /// 응답 언어 (`ko`, `en`). `null` 이면 지우고 `Accept-Language` 를 따른다.
+    pub locale: Option<String>,
+}
+
+impl JsonUpdateUserLocale {
+    /// 지원하는 언어인지 확인하고 `ko`, `en` 처럼 정리한다.
+    pub fn try_into_locale(self) -> Result<Option<String>, AppError> {
+        self.locale
+            .map(|tag| {
+                Locale::parse(&tag)
+                    .map(|locale| locale.as_str().to_string())
+                    .ok_or_else(|| AppError::from(Message::InvalidParameter("locale".to_string())))
+            })
+            .transpose()
+    }
+}
+
 #[derive(Debug, Deserialize, ToSchema, IntoParams)]
 #[serde(rename_all = "camelCase")]
 pub struct UserQuery {
@@ -108,12 +123,9 @@ pub struct TokenClaims {
 #[derive(Deserialize, Debug, Validate, ToSchema, IntoParams)]
 #[serde(rename_all = "camelCase")]
 pub struct JsonLoginUser {
-    #[validate(email(message = "invalid email"))]
+    #[validate(email)]
     pub username: Option<String>,
-    #[validate(custom(
-        function = "validate_password",
-        message = "password must contain one digit, one special character and must be at least 8 characters long"
-    ))]
+    #[validate(custom(function = "validate_password"))]
     pub password: Option<String>,
 }
 
@@ -124,10 +136,10 @@ impl TryFrom<JsonLoginUser> for LoginUser {
         Ok(LoginUser {
             username: jcu
                 .username
-                .ok_or_else(|| AppError::Error("`username` is required".to_string()))?,
+                .ok_or_else(|| AppError::from(Message::Required("username".to_string())))?,
             password: jcu
                 .password
-                .ok_or_else(|| AppError::Error("`password` is required".to_string()))?,
+                .ok_or_else(|| AppError::from(Message::Required("password".to_string())))?,
         })
     }
 }
@@ -137,6 +149,20 @@ mod tests {
     use super::*;
     use usecase::model::user::UserView;
 
+    #[test]
+    fn json_update_user_locale_normalizes_supported_tags() {
+        let json = JsonUpdateUserLocale {
+            locale: Some("en-US".to_string()),
+        };
+        assert_eq!(json.try_into_locale().unwrap().as_deref(), Some("en"));
+        let json = JsonUpdateUserLocale { locale: None };
+        assert_eq!(json.try_into_locale().unwrap(), None);
+        let json = JsonUpdateUserLocale {
+            locale: Some("fr".to_string()),
+        };
+        assert!(json.try_into_locale().is_err());
+    }
+
     #[test]
     fn validate_password_with_valid_password_returns_ok() {
         assert!(validate_password("Secret1!").is_ok());
@@ -175,11 +201,13 @@ mod tests {
             username: "alice@example.com".to_string(),
             email: "alice@example.com".to_string(),
             fullname: "Alice".to_string(),
+            locale: Some("en".to_string()),
         };
         let json = JsonUser::from(view);
         assert_eq!(json.id, "user-id-01");
         assert_eq!(json.username, "alice@example.com");
         assert_eq!(json.fullname, "Alice");
+        assert_eq!(json.locale.as_deref(), Some("en"));
     }
 
     #[test]
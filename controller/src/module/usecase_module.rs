@@ -2,16 +2,50 @@
 #[allow(unused_imports)]
 use infra::db::Db;
 #[allow(unused_imports)]
+use infra::notifier::LogNotifier;
+#[allow(unused_imports)]
+use infra::repository::attachment::AttachmentRepository;
+#[allow(unused_imports)]
+use infra::repository::comment::CommentRepository;
+#[allow(unused_imports)]
 use infra::repository::health_check::HealthCheckRepository;
 #[allow(unused_imports)]
+use infra::repository::idempotency::IdempotencyRepository;
+#[allow(unused_imports)]
+use infra::repository::project::ProjectRepository;
+#[allow(unused_imports)]
+use infra::repository::tag::TagRepository;
+#[allow(unused_imports)]
+use infra::repository::todo::event::TodoEventRepository;
+#[allow(unused_imports)]
+use infra::repository::todo::reminder::TodoReminderRepository;
+use infra::repository::todo::share::TodoShareRepository;
+#[allow(unused_imports)]
 use infra::repository::todo::status::TodoStatusRepository;
 #[allow(unused_imports)]
 use infra::repository::todo::TodoRepository;
 #[allow(unused_imports)]
 use infra::repository::user::UserRepository;
 #[allow(unused_imports)]
+use infra::storage::LocalBlobStorage;
+#[allow(unused_imports)]
+use usecase::usecase::attachment::AttachmentUseCase;
+#[allow(unused_imports)]
+use usecase::usecase::comment::CommentUseCase;
+#[allow(unused_imports)]
 use usecase::usecase::health_check::HealthCheckUseCase;
 #[allow(unused_imports)]
+use usecase::usecase::idempotency::IdempotencyUseCase;
+#[allow(unused_imports)]
+use usecase::usecase::project::ProjectUseCase;
+#[allow(unused_imports)]
+use usecase::usecase::reminder::ReminderUseCase;
+use usecase::usecase::share::TodoShareUseCase;
+#[allow(unused_imports)]
+use usecase::usecase::status::TodoStatusUseCase;
+#[allow(unused_imports)]
+use usecase::usecase::tag::TagUseCase;
+#[allow(unused_imports)]
 use usecase::usecase::todo::TodoUseCase;
 #[allow(unused_imports)]
 use usecase::usecase::user::UserUseCase;
@@ -30,11 +64,29 @@ module! {
             Db,
             TodoRepository,
             TodoStatusRepository,
+            TodoEventRepository,
+            TodoReminderRepository,
+            TodoShareRepository,
+            TagRepository,
+            ProjectRepository,
+            CommentRepository,
+            AttachmentRepository,
+            LocalBlobStorage,
+            LogNotifier,
             UserRepository,
             HealthCheckRepository,
+            IdempotencyRepository,
             TodoUseCase,
+            TagUseCase,
+            ProjectUseCase,
+            CommentUseCase,
+            AttachmentUseCase,
+            ReminderUseCase,
+            TodoShareUseCase,
+            TodoStatusUseCase,
             UserUseCase,
             HealthCheckUseCase,
+            IdempotencyUseCase,
         ],
         providers = []
     }
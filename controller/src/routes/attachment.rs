@@ -0,0 +1,177 @@
+use crate::context::api_response::{usecase_error, ApiResponse};
+use crate::context::api_version::ApiVersion;
+use crate::context::errors::AppError;
+use crate::context::message::Message;
+use crate::model::attachment::{content_disposition, AttachmentUpload, JsonAttachment};
+use crate::module::usecase_module::AppState;
+use axum::body::Body;
+use axum::extract::{Multipart, Path, State};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::{Extension, Json};
+use futures_util::TryStreamExt;
+use serde_json::{json, Value};
+use shaku::HasComponent;
+use std::io;
+use std::sync::Arc;
+use tokio_util::io::{ReaderStream, StreamReader};
+use tracing::info;
+use usecase::model::attachment::{AttachmentLimits, UploadAttachment};
+use usecase::model::user::UserView;
+use usecase::usecase::attachment::IAttachmentUseCase;
+
+/// multipart 에서 파일을 담는 part 이름.
+const FILE_FIELD: &str = "file";
+
+#[utoipa::path(
+    get,
+    path = "/v1/todo/{id}/attachments",
+    operation_id = stringify!(find_attachments),
+    responses(
+        (status = OK, description = "find attachments of a todo successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "attachment",
+)]
+pub async fn find_attachments(
+    _: ApiVersion,
+    Path((_v, todo_id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(todo_id = %todo_id, current_user_id = %current_user.id, "find_attachments");
+    let uc: Arc<dyn IAttachmentUseCase> = state.module.resolve();
+    let attachments = uc
+        .find_attachments(current_user.id, todo_id)
+        .await
+        .map_err(usecase_error)?;
+    let json: Vec<JsonAttachment> = attachments.into_iter().map(Into::into).collect();
+    let response = ApiResponse::success("success", json!({ "attachments": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/todo/{id}/attachments",
+    request_body(
+        content = AttachmentUpload,
+        content_type = "multipart/form-data"
+    ),
+    operation_id = stringify!(upload_attachment),
+    responses(
+        (status = OK, description = "attachment uploaded successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "attachment",
+)]
+pub async fn upload_attachment(
+    _: ApiVersion,
+    Path((_v, todo_id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    let uc: Arc<dyn IAttachmentUseCase> = state.module.resolve();
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::Error(e.body_text()))?
+    {
+        if field.name() != Some(FILE_FIELD) {
+            continue;
+        }
+        let source = UploadAttachment::new(
+            field.file_name().unwrap_or_default().to_string(),
+            field
+                .content_type()
+                .unwrap_or("application/octet-stream")
+                .to_string(),
+        );
+        let limits = AttachmentLimits::new(
+            state.config.attachment_max_bytes,
+            state.config.attachment_content_types.clone(),
+        );
+        let body = StreamReader::new(field.map_err(io::Error::other));
+        let av = uc
+            .upload_attachment(current_user.id, todo_id, source, limits, Box::pin(body))
+            .await
+            .map_err(usecase_error)?;
+        info!(attachment_id = %av.id, size_bytes = av.size_bytes, "upload_attachment: succeeded");
+        let json: JsonAttachment = av.into();
+        let response = ApiResponse::success("success", json!({ "attachmentView": json }));
+        return Ok((StatusCode::OK, Json(response)));
+    }
+    Err(Message::Required(FILE_FIELD.to_string()).into())
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/todo/{id}/attachments/{attachment_id}",
+    operation_id = stringify!(download_attachment),
+    responses(
+        (status = OK, description = "attachment content", content_type = "application/octet-stream", body = Vec<u8>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "attachment",
+)]
+pub async fn download_attachment(
+    _: ApiVersion,
+    Path((_v, todo_id, id)): Path<(ApiVersion, String, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<Response, AppError> {
+    info!(todo_id = %todo_id, attachment_id = %id, current_user_id = %current_user.id, "download_attachment");
+    let uc: Arc<dyn IAttachmentUseCase> = state.module.resolve();
+    let download = uc
+        .download_attachment(current_user.id, todo_id, id)
+        .await
+        .map_err(usecase_error)?;
+    let attachment = download.attachment;
+    let headers = [
+        (header::CONTENT_TYPE, attachment.content_type),
+        (header::CONTENT_LENGTH, attachment.size_bytes.to_string()),
+        (
+            header::CONTENT_DISPOSITION,
+            content_disposition(&attachment.file_name),
+        ),
+    ];
+    let body = Body::from_stream(ReaderStream::new(download.body));
+    Ok((StatusCode::OK, headers, body).into_response())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/todo/{id}/attachments/{attachment_id}",
+    operation_id = stringify!(delete_attachment),
+    responses(
+        (status = OK, description = "attachment deleted successfully", body = ApiResponse<Value>),
+        (status = FORBIDDEN, description = "attachment uploaded by another user", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "attachment",
+)]
+pub async fn delete_attachment(
+    _: ApiVersion,
+    Path((_v, todo_id, id)): Path<(ApiVersion, String, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(todo_id = %todo_id, attachment_id = %id, current_user_id = %current_user.id, "delete_attachment");
+    let uc: Arc<dyn IAttachmentUseCase> = state.module.resolve();
+    let av = uc
+        .delete_attachment(current_user.id, todo_id, id)
+        .await
+        .map_err(usecase_error)?;
+    info!(attachment_id = %av.id, "delete_attachment: succeeded");
+    let json: JsonAttachment = av.into();
+    let response = ApiResponse::success("success", json!({ "attachmentView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
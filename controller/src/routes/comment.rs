@@ -0,0 +1,148 @@
+use crate::context::api_response::{usecase_error, ApiResponse};
+use crate::context::api_version::ApiVersion;
+use crate::context::errors::AppError;
+use crate::context::validate::ValidatedRequest;
+use crate::model::comment::{
+    CommentQuery, JsonComment, JsonCommentList, JsonCreateComment, JsonUpdateComment,
+};
+use crate::module::usecase_module::AppState;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use serde_json::{json, Value};
+use shaku::HasComponent;
+use std::sync::Arc;
+use tracing::info;
+use usecase::model::user::UserView;
+use usecase::usecase::comment::ICommentUseCase;
+
+#[utoipa::path(
+    get,
+    path = "/v1/todo/{id}/comments",
+    params(CommentQuery),
+    operation_id = stringify!(find_comments),
+    responses(
+        (status = OK, description = "find comments of a todo successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "comment",
+)]
+pub async fn find_comments(
+    _: ApiVersion,
+    Path((_v, todo_id)): Path<(ApiVersion, String)>,
+    Query(query): Query<CommentQuery>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(todo_id = %todo_id, limit = ?query.limit, current_user_id = %current_user.id, "find_comments");
+    let uc: Arc<dyn ICommentUseCase> = state.module.resolve();
+    let page = uc
+        .find_comments(current_user.id, todo_id, query.into())
+        .await
+        .map_err(usecase_error)?;
+    let json: JsonCommentList = page.into();
+    let response = ApiResponse::success("success", json!({ "commentView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/todo/{id}/comments",
+    request_body(
+        content = JsonCreateComment,
+        content_type = "application/json"
+    ),
+    operation_id = stringify!(create_comment),
+    responses(
+        (status = OK, description = "comment created successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "comment",
+)]
+pub async fn create_comment(
+    _: ApiVersion,
+    Path((_v, todo_id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    ValidatedRequest(source): ValidatedRequest<JsonCreateComment>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    let uc: Arc<dyn ICommentUseCase> = state.module.resolve();
+    let cv = uc
+        .create_comment(current_user.id, todo_id, source.try_into()?)
+        .await
+        .map_err(usecase_error)?;
+    info!(comment_id = %cv.id, "create_comment: succeeded");
+    let json: JsonComment = cv.into();
+    let response = ApiResponse::success("success", json!({ "commentView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/v1/todo/{id}/comments/{comment_id}",
+    request_body(
+        content = JsonUpdateComment,
+        content_type = "application/json"
+    ),
+    operation_id = stringify!(update_comment),
+    responses(
+        (status = OK, description = "comment updated successfully", body = ApiResponse<Value>),
+        (status = FORBIDDEN, description = "comment written by another user", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "comment",
+)]
+pub async fn update_comment(
+    _: ApiVersion,
+    Path((_v, todo_id, id)): Path<(ApiVersion, String, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    ValidatedRequest(source): ValidatedRequest<JsonUpdateComment>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    let uc: Arc<dyn ICommentUseCase> = state.module.resolve();
+    let cv = uc
+        .update_comment(current_user.id, todo_id, source.try_to_view(id)?)
+        .await
+        .map_err(usecase_error)?;
+    info!(comment_id = %cv.id, "update_comment: succeeded");
+    let json: JsonComment = cv.into();
+    let response = ApiResponse::success("success", json!({ "commentView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/todo/{id}/comments/{comment_id}",
+    operation_id = stringify!(delete_comment),
+    responses(
+        (status = OK, description = "comment deleted successfully", body = ApiResponse<Value>),
+        (status = FORBIDDEN, description = "comment written by another user", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "comment",
+)]
+pub async fn delete_comment(
+    _: ApiVersion,
+    Path((_v, todo_id, id)): Path<(ApiVersion, String, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(todo_id = %todo_id, comment_id = %id, current_user_id = %current_user.id, "delete_comment");
+    let uc: Arc<dyn ICommentUseCase> = state.module.resolve();
+    let cv = uc
+        .delete_comment(current_user.id, todo_id, id)
+        .await
+        .map_err(usecase_error)?;
+    info!(comment_id = %cv.id, "delete_comment: succeeded");
+    let json: JsonComment = cv.into();
+    let response = ApiResponse::success("success", json!({ "commentView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
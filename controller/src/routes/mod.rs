@@ -1,3 +1,10 @@
+pub mod attachment;
+pub mod comment;
 pub mod health_check;
+pub mod project;
+pub mod share;
+pub mod status;
+pub mod tag;
 pub mod todo;
+pub mod transfer;
 pub mod user;
@@ -0,0 +1,281 @@
+use crate::context::api_response::{internal_error, usecase_error, ApiResponse};
+use crate::context::api_version::ApiVersion;
+use crate::context::errors::AppError;
+use crate::context::message::Message;
+use crate::context::validate::ValidatedRequest;
+use crate::model::project::{JsonCreateProject, JsonProject, JsonUpdateProject, ProjectQuery};
+use crate::model::todo::{JsonTodoList, TodoQuery};
+use crate::module::usecase_module::AppState;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use serde_json::{json, Value};
+use shaku::HasComponent;
+use std::sync::Arc;
+use tracing::{error, info};
+use usecase::model::user::UserView;
+use usecase::usecase::project::IProjectUseCase;
+use usecase::usecase::todo::ITodoUseCase;
+
+#[utoipa::path(
+    get,
+    path = "/v1/project",
+    params(ProjectQuery),
+    operation_id = stringify!(find_projects),
+    responses(
+        (status = OK, description = "find all projects successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "project",
+)]
+pub async fn find_projects(
+    _: ApiVersion,
+    Query(query): Query<ProjectQuery>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(include_archived = ?query.include_archived, current_user_id = %current_user.id, "find_projects");
+    let uc: Arc<dyn IProjectUseCase> = state.module.resolve();
+    let projects = uc
+        .find_projects(current_user.id, query.include_archived.unwrap_or(false))
+        .await
+        .map_err(internal_error)?;
+    let json: Vec<JsonProject> = projects.into_iter().map(Into::into).collect();
+    let response = ApiResponse::success("success", json!({ "projects": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/project/{id}",
+    operation_id = stringify!(get_project),
+    responses(
+        (status = OK, description = "Get one project successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "project",
+)]
+pub async fn get_project(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(project_id = %id, current_user_id = %current_user.id, "get_project");
+    let uc: Arc<dyn IProjectUseCase> = state.module.resolve();
+    let pv = uc
+        .get_project(current_user.id, id)
+        .await
+        .map_err(internal_error)?
+        .ok_or_else(|| {
+            error!("get_project: project not found");
+            AppError::from(Message::NotFound)
+        })?;
+    let json: JsonProject = pv.into();
+    let response = ApiResponse::success("success", json!({ "projectView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/project/{id}/todos",
+    params(TodoQuery),
+    operation_id = stringify!(find_project_todos),
+    responses(
+        (status = OK, description = "find todos of a project, including archived ones, successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "project",
+)]
+pub async fn find_project_todos(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    Query(query): Query<TodoQuery>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(project_id = %id, status = ?query.status, sort = ?query.sort, limit = ?query.limit, current_user_id = %current_user.id, "find_project_todos");
+    let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
+    let page = uc
+        .find_project_todos(current_user.id, id, query.into())
+        .await
+        .map_err(usecase_error)?;
+
+    let message = if page.todos.is_empty() {
+        "todo not found.".to_string()
+    } else {
+        "success".to_string()
+    };
+    let json: JsonTodoList = page.into();
+    let response = ApiResponse::success(message, json!({ "todoView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/project",
+    request_body(
+        content = JsonCreateProject,
+        content_type = "application/json"
+    ),
+    operation_id = stringify!(create_project),
+    responses(
+        (status = OK, description = "project created successfully", body = ApiResponse<Value>),
+        (status = CONFLICT, description = "project name already in use", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "project",
+)]
+pub async fn create_project(
+    _: ApiVersion,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    ValidatedRequest(source): ValidatedRequest<JsonCreateProject>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    let uc: Arc<dyn IProjectUseCase> = state.module.resolve();
+    let pv = uc
+        .create_project(current_user.id, source.try_into()?)
+        .await
+        .map_err(usecase_error)?;
+    info!(project_id = %pv.id, "create_project: succeeded");
+    let json: JsonProject = pv.into();
+    let response = ApiResponse::success("success", json!({ "projectView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/v1/project/{id}",
+    request_body(
+        content = JsonUpdateProject,
+        content_type = "application/json"
+    ),
+    operation_id = stringify!(update_project),
+    responses(
+        (status = OK, description = "project renamed or recoloured successfully", body = ApiResponse<Value>),
+        (status = CONFLICT, description = "project name already in use", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "project",
+)]
+pub async fn update_project(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    ValidatedRequest(source): ValidatedRequest<JsonUpdateProject>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    let uc: Arc<dyn IProjectUseCase> = state.module.resolve();
+    let pv = uc
+        .update_project(current_user.id, source.into_view(id))
+        .await
+        .map_err(usecase_error)?;
+    info!(project_id = %pv.id, "update_project: succeeded");
+    let json: JsonProject = pv.into();
+    let response = ApiResponse::success("success", json!({ "projectView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/project/{id}",
+    operation_id = stringify!(delete_project),
+    responses(
+        (status = OK, description = "project deleted and its todos left without a project successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "project",
+)]
+pub async fn delete_project(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(project_id = %id, current_user_id = %current_user.id, "delete_project");
+    let uc: Arc<dyn IProjectUseCase> = state.module.resolve();
+    let pv = uc
+        .delete_project(current_user.id, id)
+        .await
+        .map_err(internal_error)?
+        .ok_or_else(|| {
+            error!("delete_project: project not found");
+            AppError::from(Message::NotFound)
+        })?;
+    info!(project_id = %pv.id, "delete_project: succeeded");
+    let json: JsonProject = pv.into();
+    let response = ApiResponse::success("success", json!({ "projectView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/project/{id}/archive",
+    operation_id = stringify!(archive_project),
+    responses(
+        (status = OK, description = "project archived together with its todos successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "project",
+)]
+pub async fn archive_project(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(project_id = %id, current_user_id = %current_user.id, "archive_project");
+    let uc: Arc<dyn IProjectUseCase> = state.module.resolve();
+    let pv = uc
+        .archive_project(current_user.id, id)
+        .await
+        .map_err(usecase_error)?;
+    info!(project_id = %pv.id, "archive_project: succeeded");
+    let json: JsonProject = pv.into();
+    let response = ApiResponse::success("success", json!({ "projectView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/project/{id}/unarchive",
+    operation_id = stringify!(unarchive_project),
+    responses(
+        (status = OK, description = "project and its todos unarchived successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "project",
+)]
+pub async fn unarchive_project(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(project_id = %id, current_user_id = %current_user.id, "unarchive_project");
+    let uc: Arc<dyn IProjectUseCase> = state.module.resolve();
+    let pv = uc
+        .unarchive_project(current_user.id, id)
+        .await
+        .map_err(usecase_error)?;
+    info!(project_id = %pv.id, "unarchive_project: succeeded");
+    let json: JsonProject = pv.into();
+    let response = ApiResponse::success("success", json!({ "projectView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
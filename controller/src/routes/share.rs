@@ -0,0 +1,111 @@
+use crate::context::api_response::{usecase_error, ApiResponse};
+use crate::context::api_version::ApiVersion;
+use crate::context::errors::AppError;
+use crate::context::validate::ValidatedRequest;
+use crate::model::share::{JsonShareTodo, JsonTodoShare};
+use crate::module::usecase_module::AppState;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use serde_json::{json, Value};
+use shaku::HasComponent;
+use std::sync::Arc;
+use tracing::info;
+use usecase::model::user::UserView;
+use usecase::usecase::share::ITodoShareUseCase;
+
+#[utoipa::path(
+    get,
+    path = "/v1/todo/{id}/shares",
+    operation_id = stringify!(find_todo_shares),
+    responses(
+        (status = OK, description = "find users the todo is shared with successfully", body = ApiResponse<Value>),
+        (status = FORBIDDEN, description = "todo owned by another user", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "share",
+)]
+pub async fn find_todo_shares(
+    _: ApiVersion,
+    Path((_v, todo_id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(todo_id = %todo_id, current_user_id = %current_user.id, "find_todo_shares");
+    let uc: Arc<dyn ITodoShareUseCase> = state.module.resolve();
+    let shares = uc
+        .find_shares(current_user.id, todo_id)
+        .await
+        .map_err(usecase_error)?;
+    let json: Vec<JsonTodoShare> = shares.into_iter().map(Into::into).collect();
+    let response = ApiResponse::success("success", json!({ "shareViews": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/todo/{id}/shares",
+    request_body(
+        content = JsonShareTodo,
+        content_type = "application/json"
+    ),
+    operation_id = stringify!(share_todo),
+    responses(
+        (status = OK, description = "todo shared successfully", body = ApiResponse<Value>),
+        (status = FORBIDDEN, description = "todo owned by another user", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "share",
+)]
+pub async fn share_todo(
+    _: ApiVersion,
+    Path((_v, todo_id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    ValidatedRequest(source): ValidatedRequest<JsonShareTodo>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    let uc: Arc<dyn ITodoShareUseCase> = state.module.resolve();
+    let sv = uc
+        .share_todo(current_user.id, todo_id, source.try_into()?)
+        .await
+        .map_err(usecase_error)?;
+    info!(todo_id = %sv.todo_id, user_id = %sv.user_id, permission = %sv.permission, "share_todo: succeeded");
+    let json: JsonTodoShare = sv.into();
+    let response = ApiResponse::success("success", json!({ "shareView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/todo/{id}/shares/{username}",
+    operation_id = stringify!(unshare_todo),
+    responses(
+        (status = OK, description = "todo unshared successfully", body = ApiResponse<Value>),
+        (status = FORBIDDEN, description = "todo owned by another user", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "share",
+)]
+pub async fn unshare_todo(
+    _: ApiVersion,
+    Path((_v, todo_id, username)): Path<(ApiVersion, String, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(todo_id = %todo_id, username = %username, current_user_id = %current_user.id, "unshare_todo");
+    let uc: Arc<dyn ITodoShareUseCase> = state.module.resolve();
+    let sv = uc
+        .unshare_todo(current_user.id, todo_id, username)
+        .await
+        .map_err(usecase_error)?;
+    info!(todo_id = %sv.todo_id, user_id = %sv.user_id, "unshare_todo: succeeded");
+    let json: JsonTodoShare = sv.into();
+    let response = ApiResponse::success("success", json!({ "shareView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
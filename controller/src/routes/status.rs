@@ -0,0 +1,165 @@
+use crate::context::api_response::{internal_error, usecase_error, ApiResponse};
+use crate::context::api_version::ApiVersion;
+use crate::context::errors::AppError;
+use crate::context::validate::ValidatedRequest;
+use crate::model::status::{JsonCreateTodoStatus, JsonRenameTodoStatus, JsonTodoStatusEntry};
+use crate::module::usecase_module::AppState;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use serde_json::{json, Value};
+use shaku::HasComponent;
+use std::sync::Arc;
+use tracing::info;
+use usecase::model::user::UserView;
+use usecase::usecase::status::ITodoStatusUseCase;
+
+#[utoipa::path(
+    get,
+    path = "/v1/todo-status",
+    operation_id = stringify!(find_todo_statuses),
+    responses(
+        (status = OK, description = "find all todo statuses including disabled ones successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo-status",
+)]
+pub async fn find_todo_statuses(
+    _: ApiVersion,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(current_user_id = %current_user.id, "find_todo_statuses");
+    let uc: Arc<dyn ITodoStatusUseCase> = state.module.resolve();
+    let statuses = uc.find_statuses().await.map_err(internal_error)?;
+    let json: Vec<JsonTodoStatusEntry> = statuses.into_iter().map(Into::into).collect();
+    let response = ApiResponse::success("success", json!({ "statuses": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/todo-status",
+    request_body(
+        content = JsonCreateTodoStatus,
+        content_type = "application/json"
+    ),
+    operation_id = stringify!(create_todo_status),
+    responses(
+        (status = OK, description = "custom todo status added successfully", body = ApiResponse<Value>),
+        (status = FORBIDDEN, description = "current user is not an admin", body = ApiResponse<Value>),
+        (status = CONFLICT, description = "status code already in use", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo-status",
+)]
+pub async fn create_todo_status(
+    _: ApiVersion,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    ValidatedRequest(source): ValidatedRequest<JsonCreateTodoStatus>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    let uc: Arc<dyn ITodoStatusUseCase> = state.module.resolve();
+    let ev = uc
+        .create_status(source.try_into()?)
+        .await
+        .map_err(usecase_error)?;
+    info!(code = %ev.status.code, current_user_id = %current_user.id, "create_todo_status: succeeded");
+    let json: JsonTodoStatusEntry = ev.into();
+    let response = ApiResponse::success("success", json!({ "statusView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/v1/todo-status/{code}",
+    request_body(
+        content = JsonRenameTodoStatus,
+        content_type = "application/json"
+    ),
+    operation_id = stringify!(rename_todo_status),
+    responses(
+        (status = OK, description = "todo status renamed successfully", body = ApiResponse<Value>),
+        (status = FORBIDDEN, description = "current user is not an admin", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo-status",
+)]
+pub async fn rename_todo_status(
+    _: ApiVersion,
+    Path((_v, code)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    ValidatedRequest(source): ValidatedRequest<JsonRenameTodoStatus>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    let uc: Arc<dyn ITodoStatusUseCase> = state.module.resolve();
+    let ev = uc
+        .rename_status(code, source.try_into_name()?)
+        .await
+        .map_err(usecase_error)?;
+    info!(code = %ev.status.code, current_user_id = %current_user.id, "rename_todo_status: succeeded");
+    let json: JsonTodoStatusEntry = ev.into();
+    let response = ApiResponse::success("success", json!({ "statusView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/todo-status/{code}/disable",
+    operation_id = stringify!(disable_todo_status),
+    responses(
+        (status = OK, description = "custom todo status can no longer be assigned", body = ApiResponse<Value>),
+        (status = FORBIDDEN, description = "current user is not an admin", body = ApiResponse<Value>),
+        (status = CONFLICT, description = "built-in statuses cannot be disabled", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo-status",
+)]
+pub async fn disable_todo_status(
+    _: ApiVersion,
+    Path((_v, code)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(code = %code, current_user_id = %current_user.id, "disable_todo_status");
+    let uc: Arc<dyn ITodoStatusUseCase> = state.module.resolve();
+    let ev = uc.disable_status(code).await.map_err(usecase_error)?;
+    let json: JsonTodoStatusEntry = ev.into();
+    let response = ApiResponse::success("success", json!({ "statusView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/todo-status/{code}/enable",
+    operation_id = stringify!(enable_todo_status),
+    responses(
+        (status = OK, description = "todo status can be assigned again", body = ApiResponse<Value>),
+        (status = FORBIDDEN, description = "current user is not an admin", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo-status",
+)]
+pub async fn enable_todo_status(
+    _: ApiVersion,
+    Path((_v, code)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(code = %code, current_user_id = %current_user.id, "enable_todo_status");
+    let uc: Arc<dyn ITodoStatusUseCase> = state.module.resolve();
+    let ev = uc.enable_status(code).await.map_err(usecase_error)?;
+    let json: JsonTodoStatusEntry = ev.into();
+    let response = ApiResponse::success("success", json!({ "statusView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
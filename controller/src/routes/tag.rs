@@ -0,0 +1,180 @@
+use crate::context::api_response::{internal_error, usecase_error, ApiResponse};
+use crate::context::api_version::ApiVersion;
+use crate::context::errors::AppError;
+use crate::context::message::Message;
+use crate::context::validate::ValidatedRequest;
+use crate::model::tag::{JsonCreateTag, JsonTag, JsonUpdateTag};
+use crate::module::usecase_module::AppState;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use serde_json::{json, Value};
+use shaku::HasComponent;
+use std::sync::Arc;
+use tracing::{error, info};
+use usecase::model::user::UserView;
+use usecase::usecase::tag::ITagUseCase;
+
+#[utoipa::path(
+    get,
+    path = "/v1/tag",
+    operation_id = stringify!(find_tags),
+    responses(
+        (status = OK, description = "find all tags successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "tag",
+)]
+pub async fn find_tags(
+    _: ApiVersion,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(current_user_id = %current_user.id, "find_tags");
+    let uc: Arc<dyn ITagUseCase> = state.module.resolve();
+    let tags = uc
+        .find_tags(current_user.id)
+        .await
+        .map_err(internal_error)?;
+    let json: Vec<JsonTag> = tags.into_iter().map(Into::into).collect();
+    let response = ApiResponse::success("success", json!({ "tags": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/tag/{id}",
+    operation_id = stringify!(get_tag),
+    responses(
+        (status = OK, description = "Get one tag successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "tag",
+)]
+pub async fn get_tag(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(tag_id = %id, current_user_id = %current_user.id, "get_tag");
+    let uc: Arc<dyn ITagUseCase> = state.module.resolve();
+    let tv = uc
+        .get_tag(current_user.id, id)
+        .await
+        .map_err(internal_error)?
+        .ok_or_else(|| {
+            error!("get_tag: tag not found");
+            AppError::from(Message::NotFound)
+        })?;
+    let json: JsonTag = tv.into();
+    let response = ApiResponse::success("success", json!({ "tagView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/tag",
+    request_body(
+        content = JsonCreateTag,
+        content_type = "application/json"
+    ),
+    operation_id = stringify!(create_tag),
+    responses(
+        (status = OK, description = "tag created successfully", body = ApiResponse<Value>),
+        (status = CONFLICT, description = "tag name already in use", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "tag",
+)]
+pub async fn create_tag(
+    _: ApiVersion,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    ValidatedRequest(source): ValidatedRequest<JsonCreateTag>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    let uc: Arc<dyn ITagUseCase> = state.module.resolve();
+    let tv = uc
+        .create_tag(current_user.id, source.try_into()?)
+        .await
+        .map_err(usecase_error)?;
+    info!(tag_id = %tv.id, "create_tag: succeeded");
+    let json: JsonTag = tv.into();
+    let response = ApiResponse::success("success", json!({ "tagView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/v1/tag/{id}",
+    request_body(
+        content = JsonUpdateTag,
+        content_type = "application/json"
+    ),
+    operation_id = stringify!(update_tag),
+    responses(
+        (status = OK, description = "tag renamed successfully", body = ApiResponse<Value>),
+        (status = CONFLICT, description = "tag name already in use", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "tag",
+)]
+pub async fn update_tag(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    ValidatedRequest(source): ValidatedRequest<JsonUpdateTag>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    let uc: Arc<dyn ITagUseCase> = state.module.resolve();
+    let tv = uc
+        .update_tag(current_user.id, source.try_to_view(id)?)
+        .await
+        .map_err(usecase_error)?;
+    info!(tag_id = %tv.id, "update_tag: succeeded");
+    let json: JsonTag = tv.into();
+    let response = ApiResponse::success("success", json!({ "tagView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/tag/{id}",
+    operation_id = stringify!(delete_tag),
+    responses(
+        (status = OK, description = "tag deleted and detached from todos successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "tag",
+)]
+pub async fn delete_tag(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(tag_id = %id, current_user_id = %current_user.id, "delete_tag");
+    let uc: Arc<dyn ITagUseCase> = state.module.resolve();
+    let tv = uc
+        .delete_tag(current_user.id, id)
+        .await
+        .map_err(internal_error)?
+        .ok_or_else(|| {
+            error!("delete_tag: tag not found");
+            AppError::from(Message::NotFound)
+        })?;
+    info!(tag_id = %tv.id, "delete_tag: succeeded");
+    let json: JsonTag = tv.into();
+    let response = ApiResponse::success("success", json!({ "tagView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
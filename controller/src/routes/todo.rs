@@ -1,27 +1,58 @@
-use crate::context::api_response::{internal_error, ApiResponse};
+use crate::context::api_response::{internal_error, usecase_error, ApiResponse, ErrorCode};
 use crate::context::api_version::ApiVersion;
 use crate::context::errors::AppError;
+use crate::context::if_match::{etag, IfMatch};
+use crate::context::locale::Locale;
+use crate::context::message::Message;
 use crate::context::validate::ValidatedRequest;
+use crate::model::batch::JsonTodoBatch;
+use crate::model::history::{JsonTodoHistory, TodoHistoryQuery};
+use crate::model::patch::{
+    json_patch_to_merge_patch, merge_patch_to_view, JsonPatchOperation, JsonTodoPatch,
+};
+use crate::model::position::JsonMoveTodo;
+use crate::model::recurrence::{JsonTodoOccurrences, TodoOccurrencesQuery};
+use crate::model::reminder::JsonSnoozeTodo;
+use crate::model::stats::{JsonTodoStats, TodoStatsQuery};
+use crate::model::status::JsonTodoStatusTransition;
 use crate::model::todo::{
-    JsonCreateTodo, JsonTodo, JsonTodoList, JsonUpdateTodoContents, JsonUpsertTodoContents,
-    TodoQuery,
+    DeleteTodoQuery, GetTodoQuery, JsonCreateTodo, JsonTodo, JsonTodoList, JsonTodoTree,
+    JsonUpdateTodoContents, JsonUpsertTodoContents, TodoQuery,
 };
 use crate::module::usecase_module::AppState;
 use axum::extract::{Path, Query, State};
+use axum::http::header::{HeaderName, CACHE_CONTROL, ETAG};
 use axum::http::StatusCode;
-use axum::Json;
+use axum::response::{IntoResponse, Response};
+use axum::{Extension, Json};
 use serde_json::{json, Value};
 use shaku::HasComponent;
 use std::sync::Arc;
 use tracing::{error, info};
+use usecase::error::TodoBatchError;
+use usecase::model::todo::{TodoView, UpdateTodoView};
+use usecase::model::user::UserView;
+use usecase::model::FieldUpdate;
 use usecase::usecase::todo::ITodoUseCase;
 
+/// 통계 응답의 `Cache-Control` 값.
+const STATS_CACHE_CONTROL: &str = "private, max-age=60";
+
+/// todo 한 건 응답. 버전을 `ETag` 헤더로 함께 보낸다.
+type TodoResponse = (
+    StatusCode,
+    [(HeaderName, String); 1],
+    Json<ApiResponse<Value>>,
+);
+
 #[utoipa::path(
     get,
     path = "/v1/todo/{id}",
+    params(GetTodoQuery),
     operation_id = stringify!(get_todo),
     responses(
-        (status = OK, description = "Get one todo successfully", body = ApiResponse<Value>)
+        (status = OK, description = "Get one todo successfully", body = ApiResponse<Value>,
+            headers(("ETag" = String, description = "todo version")))
     ),
     security(
         ("Authorization" = [])
@@ -31,22 +62,37 @@ use usecase::usecase::todo::ITodoUseCase;
 pub async fn get_todo(
     _: ApiVersion,
     Path((_v, id)): Path<(ApiVersion, String)>,
+    Query(query): Query<GetTodoQuery>,
     State(state): State<Arc<AppState>>,
-) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
-    info!(todo_id = %id, "get_todo");
+    Extension(current_user): Extension<UserView>,
+) -> Result<TodoResponse, AppError> {
+    let tree = query.tree.unwrap_or(false);
+    info!(todo_id = %id, tree, current_user_id = %current_user.id, "get_todo");
     let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
-    let resp = uc.get_todo(id).await;
+    if tree {
+        let tree = uc
+            .get_todo_tree(current_user.id, id)
+            .await
+            .map_err(internal_error)?
+            .ok_or_else(|| {
+                error!("get_todo: todo not found");
+                AppError::from(Message::NotFound)
+            })?;
+        let headers = [(ETAG, etag(tree.todo.version))];
+        let json: JsonTodoTree = tree.into();
+        let response = ApiResponse::success("success", json!({ "todoView": json }));
+        return Ok((StatusCode::OK, headers, Json(response)));
+    }
+    let resp = uc.get_todo(current_user.id, id).await;
     match resp {
         Ok(tv) => tv
             .map(|tv| {
                 info!(todo_id = %tv.id, "get_todo: found");
-                let json: JsonTodo = tv.into();
-                let response = ApiResponse::success("success", json!({ "todoView": json }));
-                (StatusCode::OK, Json(response))
+                todo_response(tv)
             })
             .ok_or_else(|| {
                 error!("get_todo: todo not found");
-                AppError::Error("data not found".to_string())
+                AppError::from(Message::NotFound)
             }),
         Err(err) => Err(internal_error(err)),
     }
@@ -69,24 +115,237 @@ pub async fn find_todo(
     _: ApiVersion,
     Query(query): Query<TodoQuery>,
     State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
 ) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
-    info!(status = ?query.status, "find_todo");
-    if query.status.is_none() {
-        return Err(AppError::Error("status is none".to_string()));
-    }
+    info!(q = ?query.q, status = ?query.status, sort = ?query.sort, limit = ?query.limit, current_user_id = %current_user.id, "find_todo");
     let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
-    let todos = uc.find_todo(query.into()).await.map_err(internal_error)?;
+    let page = uc
+        .find_todo(current_user.id, query.into())
+        .await
+        .map_err(usecase_error)?;
 
-    let message = if todos.is_empty() {
+    let message = if page.todos.is_empty() {
         "todo not found.".to_string()
     } else {
         "success".to_string()
     };
-    let json = JsonTodoList::new(todos.into_iter().map(|t| t.into()).collect());
+    let json: JsonTodoList = page.into();
     let response = ApiResponse::success(message, json!({ "todoView": json }));
     Ok((StatusCode::OK, Json(response)))
 }
 
+#[utoipa::path(
+    get,
+    path = "/v1/todo/{id}/children",
+    params(TodoQuery),
+    operation_id = stringify!(find_todo_children),
+    responses(
+        (status = OK, description = "find subtasks of a todo successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo",
+)]
+pub async fn find_todo_children(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    Query(query): Query<TodoQuery>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(todo_id = %id, status = ?query.status, sort = ?query.sort, limit = ?query.limit, current_user_id = %current_user.id, "find_todo_children");
+    let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
+    let page = uc
+        .find_children(current_user.id, id, query.into())
+        .await
+        .map_err(usecase_error)?;
+
+    let message = if page.todos.is_empty() {
+        "todo not found.".to_string()
+    } else {
+        "success".to_string()
+    };
+    let json: JsonTodoList = page.into();
+    let response = ApiResponse::success(message, json!({ "todoView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/todo/{id}/history",
+    params(TodoHistoryQuery),
+    operation_id = stringify!(find_todo_history),
+    responses(
+        (status = OK, description = "find change history of a todo successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo",
+)]
+pub async fn find_todo_history(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    Query(query): Query<TodoHistoryQuery>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(todo_id = %id, limit = ?query.limit, current_user_id = %current_user.id, "find_todo_history");
+    let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
+    let page = uc
+        .find_history(current_user.id, id, query.into())
+        .await
+        .map_err(usecase_error)?;
+    let json: JsonTodoHistory = page.into();
+    let response = ApiResponse::success("success", json!({ "historyView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/todo/{id}/occurrences",
+    params(TodoOccurrencesQuery),
+    operation_id = stringify!(find_todo_occurrences),
+    responses(
+        (status = OK, description = "preview upcoming occurrences of a recurring todo successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo",
+)]
+pub async fn find_todo_occurrences(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    Query(query): Query<TodoOccurrencesQuery>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(todo_id = %id, limit = ?query.limit, current_user_id = %current_user.id, "find_todo_occurrences");
+    let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
+    let view = uc
+        .get_occurrences(current_user.id, id, query.limit)
+        .await
+        .map_err(usecase_error)?;
+    let json: JsonTodoOccurrences = view.into();
+    let response = ApiResponse::success("success", json!({ "occurrencesView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/todo/{id}/recurrence",
+    operation_id = stringify!(stop_todo_recurrence),
+    params(
+        ("If-Match" = Option<String>, Header, description = "ETag of the version being updated")
+    ),
+    responses(
+        (status = OK, description = "Todo item no longer recurs", body = ApiResponse<Value>,
+            headers(("ETag" = String, description = "todo version"))),
+        (status = PRECONDITION_FAILED, description = "Todo item was changed by another request", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo",
+)]
+pub async fn stop_todo_recurrence(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    IfMatch(expected_version): IfMatch,
+) -> Result<TodoResponse, AppError> {
+    info!(todo_id = %id, current_user_id = %current_user.id, "stop_todo_recurrence");
+    let mut todo = UpdateTodoView::new(id, None, None, None);
+    todo.recurrence = FieldUpdate::Clear;
+    todo.expected_version = expected_version;
+    let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
+    let tv = uc
+        .update_todo(current_user.id, todo)
+        .await
+        .map_err(usecase_error)?;
+    info!(todo_id = %tv.id, "stop_todo_recurrence: succeeded");
+    Ok(todo_response(tv))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/todo/{id}/snooze",
+    request_body(
+        content = JsonSnoozeTodo,
+        content_type = "application/json"
+    ),
+    operation_id = stringify!(snooze_todo),
+    params(
+        ("If-Match" = Option<String>, Header, description = "ETag of the version being updated")
+    ),
+    responses(
+        (status = OK, description = "Todo reminder postponed", body = ApiResponse<Value>,
+            headers(("ETag" = String, description = "todo version"))),
+        (status = PRECONDITION_FAILED, description = "Todo item was changed by another request", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo",
+)]
+pub async fn snooze_todo(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    IfMatch(expected_version): IfMatch,
+    ValidatedRequest(source): ValidatedRequest<JsonSnoozeTodo>,
+) -> Result<TodoResponse, AppError> {
+    info!(todo_id = %id, current_user_id = %current_user.id, "snooze_todo");
+    let minutes = source
+        .minutes
+        .ok_or_else(|| AppError::from(Message::Required("minutes".to_string())))?;
+    let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
+    let tv = uc
+        .snooze_todo(current_user.id, id, minutes, expected_version)
+        .await
+        .map_err(usecase_error)?;
+    info!(todo_id = %tv.id, "snooze_todo: succeeded");
+    Ok(todo_response(tv))
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/todo/{id}/move",
+    request_body(
+        content = JsonMoveTodo,
+        content_type = "application/json"
+    ),
+    operation_id = stringify!(move_todo),
+    responses(
+        (status = OK, description = "Todo moved in the manual order", body = ApiResponse<Value>,
+            headers(("ETag" = String, description = "todo version")))
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo",
+)]
+pub async fn move_todo(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    ValidatedRequest(source): ValidatedRequest<JsonMoveTodo>,
+) -> Result<TodoResponse, AppError> {
+    info!(todo_id = %id, before = ?source.before, after = ?source.after, current_user_id = %current_user.id, "move_todo");
+    let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
+    let tv = uc
+        .move_todo(current_user.id, id, source.before, source.after)
+        .await
+        .map_err(usecase_error)?;
+    info!(todo_id = %tv.id, position = %tv.position, "move_todo: succeeded");
+    Ok(todo_response(tv))
+}
+
 #[utoipa::path(
     post,
     path = "/v1/todo",
@@ -95,8 +354,13 @@ pub async fn find_todo(
         content_type = "application/json"
     ),
     operation_id = stringify!(create_todo),
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Retries with the same key and body replay the first response")
+    ),
     responses(
-        (status = OK, description = "todo created successfully", body = ApiResponse<Value>)
+        (status = OK, description = "todo created successfully", body = ApiResponse<Value>),
+        (status = CONFLICT, description = "A request with the same Idempotency-Key is still being processed", body = ApiResponse<Value>),
+        (status = UNPROCESSABLE_ENTITY, description = "The Idempotency-Key was already used for a different request", body = ApiResponse<Value>)
     ),
     security(
         ("Authorization" = [])
@@ -106,29 +370,90 @@ pub async fn find_todo(
 pub async fn create_todo(
     _: ApiVersion,
     State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
     ValidatedRequest(source): ValidatedRequest<JsonCreateTodo>,
-) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+) -> Result<TodoResponse, AppError> {
     let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
-    let resp = uc.create_todo(source.try_into()?).await;
+    let resp = uc.create_todo(current_user.id, source.try_into()?).await;
     resp.map(|tv| {
         info!(todo_id = %tv.id, "create_todo: succeeded");
-        let json: JsonTodo = tv.into();
-        let response = ApiResponse::success("success", json!({ "todoView": json }));
-        (StatusCode::OK, Json(response))
+        todo_response(tv)
     })
-    .map_err(internal_error)
+    .map_err(usecase_error)
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/todo/batch",
+    request_body(
+        content = JsonTodoBatch,
+        content_type = "application/json"
+    ),
+    operation_id = stringify!(run_todo_batch),
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Retries with the same key and body replay the first response")
+    ),
+    responses(
+        (status = OK, description = "All operations applied in one transaction; `results` are in request order", body = ApiResponse<Value>),
+        (status = CONFLICT, description = "An operation failed and every operation was rolled back; `failedIndex` points at it", body = ApiResponse<Value>),
+        (status = PRECONDITION_FAILED, description = "An operation's version was stale and every operation was rolled back", body = ApiResponse<Value>),
+        (status = UNPROCESSABLE_ENTITY, description = "The Idempotency-Key was already used for a different request", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo",
+)]
+pub async fn run_todo_batch(
+    _: ApiVersion,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    ValidatedRequest(source): ValidatedRequest<JsonTodoBatch>,
+) -> Result<Response, AppError> {
+    info!(operations = source.operations.len(), current_user_id = %current_user.id, "run_todo_batch");
+    let roll_up_completion = state.config.todo_rollup_completion;
+    let mut operations = Vec::with_capacity(source.operations.len());
+    for (index, operation) in source.operations.into_iter().enumerate() {
+        match operation.try_into_operation(roll_up_completion) {
+            Ok(operation) => operations.push(operation),
+            Err(err) => return Ok(batch_failure(index, err)),
+        }
+    }
+    let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
+    match uc.run_batch(current_user.id, operations).await {
+        Ok(todos) => {
+            info!(applied = todos.len(), "run_todo_batch: succeeded");
+            let json: Vec<JsonTodo> = todos.into_iter().map(Into::into).collect();
+            let response = ApiResponse::success("success", json!({ "results": json }));
+            Ok((StatusCode::OK, Json(response)).into_response())
+        }
+        Err(err) => match err.downcast::<TodoBatchError>() {
+            Ok(failure) => Ok(batch_failure(failure.index, usecase_error(failure.source))),
+            Err(err) => Err(usecase_error(err)),
+        },
+    }
 }
 
 #[utoipa::path(
     patch,
     path = "/v1/todo/{id}",
     request_body(
-        content = JsonUpdateTodoContents,
-        content_type = "application/json"
+        description = "`application/json` ignores null fields, `application/merge-patch+json` (RFC 7396) clears them, `application/json-patch+json` (RFC 6902) applies test/add/replace/remove operations",
+        content(
+            (JsonUpdateTodoContents = "application/json"),
+            (Value = "application/merge-patch+json"),
+            (Vec<JsonPatchOperation> = "application/json-patch+json")
+        )
     ),
     operation_id = stringify!(update_todo),
+    params(
+        ("If-Match" = Option<String>, Header, description = "ETag of the version being updated")
+    ),
     responses(
-        (status = OK, description = "Todo item updated successfully", body = ApiResponse<Value>)
+        (status = OK, description = "Todo item updated successfully", body = ApiResponse<Value>,
+            headers(("ETag" = String, description = "todo version"))),
+        (status = CONFLICT, description = "JSON Patch test operation failed", body = ApiResponse<Value>),
+        (status = PRECONDITION_FAILED, description = "Todo item was changed by another request", body = ApiResponse<Value>)
     ),
     security(
         ("Authorization" = [])
@@ -139,28 +464,37 @@ pub async fn update_todo(
     _: ApiVersion,
     Path((_v, id)): Path<(ApiVersion, String)>,
     State(state): State<Arc<AppState>>,
-    ValidatedRequest(source): ValidatedRequest<JsonUpdateTodoContents>,
-) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
-    match source.validate(id) {
-        Ok(todo) => {
-            let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
-            let resp = uc.update_todo(todo).await;
-            resp.map(|tv| {
-                info!(todo_id = %tv.id, "update_todo: succeeded");
-                let json: JsonTodo = tv.into();
-                let response = ApiResponse::success("success", json!({ "todoView": json }));
-                (StatusCode::OK, Json(response))
-            })
-            .map_err(internal_error)
+    Extension(current_user): Extension<UserView>,
+    IfMatch(mut expected_version): IfMatch,
+    patch: JsonTodoPatch,
+) -> Result<TodoResponse, AppError> {
+    let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
+    let todo = match patch {
+        JsonTodoPatch::Contents(source) => (*source).validate(id),
+        JsonTodoPatch::Merge(patch) => merge_patch_to_view(id, patch),
+        JsonTodoPatch::Operations(operations) => {
+            let current = uc
+                .get_todo(current_user.id.clone(), id.clone())
+                .await
+                .map_err(internal_error)?
+                .ok_or_else(|| {
+                    error!("update_todo: todo not found");
+                    AppError::from(Message::NotFound)
+                })?;
+            // 패치를 적용한 버전에만 반영해야 그 사이 다른 수정을 덮어쓰지 않는다.
+            expected_version = expected_version.or(Some(current.version));
+            merge_patch_to_view(id, json_patch_to_merge_patch(&current, operations)?)
         }
-        Err(errors) => Err(AppError::Error(
-            errors
-                .iter()
-                .map(|s| s.to_string())
-                .collect::<Vec<String>>()
-                .join(" or "),
-        )),
-    }
+    };
+    let mut todo = todo.map_err(AppError::Localized)?;
+    todo.roll_up_completion = state.config.todo_rollup_completion;
+    todo.expected_version = expected_version;
+    let resp = uc.update_todo(current_user.id, todo).await;
+    resp.map(|tv| {
+        info!(todo_id = %tv.id, "update_todo: succeeded");
+        todo_response(tv)
+    })
+    .map_err(usecase_error)
 }
 
 #[utoipa::path(
@@ -171,8 +505,13 @@ pub async fn update_todo(
         content_type = "application/json"
     ),
     operation_id = stringify!(upsert_todo),
+    params(
+        ("If-Match" = Option<String>, Header, description = "ETag of the version being replaced")
+    ),
     responses(
-        (status = OK, description = "Todo item upserted successfully", body = ApiResponse<Value>)
+        (status = OK, description = "Todo item upserted successfully", body = ApiResponse<Value>,
+            headers(("ETag" = String, description = "todo version"))),
+        (status = PRECONDITION_FAILED, description = "Todo item was changed by another request", body = ApiResponse<Value>)
     ),
     security(
         ("Authorization" = [])
@@ -183,25 +522,34 @@ pub async fn upsert_todo(
     _: ApiVersion,
     Path((_v, id)): Path<(ApiVersion, String)>,
     State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    IfMatch(expected_version): IfMatch,
     ValidatedRequest(source): ValidatedRequest<JsonUpsertTodoContents>,
-) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+) -> Result<TodoResponse, AppError> {
+    let mut todo = source.try_to_view(id)?;
+    todo.expected_version = expected_version;
     let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
-    let resp = uc.upsert_todo(source.try_to_view(id)?).await;
+    let resp = uc.upsert_todo(current_user.id, todo).await;
     resp.map(|tv| {
         info!(todo_id = %tv.id, "upsert_todo: succeeded");
-        let json: JsonTodo = tv.into();
-        let response = ApiResponse::success("success", json!({ "todoView": json }));
-        (StatusCode::OK, Json(response))
+        todo_response(tv)
     })
-    .map_err(internal_error)
+    .map_err(usecase_error)
 }
 
 #[utoipa::path(
     delete,
     path = "/v1/todo/{id}",
+    params(
+        DeleteTodoQuery,
+        ("If-Match" = Option<String>, Header, description = "ETag of the version being deleted")
+    ),
     operation_id = stringify!(delete_todo),
     responses(
-        (status = OK, description = "Todo item created successfully", body = ApiResponse<Value>)
+        (status = OK, description = "Todo item moved to trash or permanently deleted successfully", body = ApiResponse<Value>,
+            headers(("ETag" = String, description = "todo version"))),
+        (status = CONFLICT, description = "`hard=true` was given for a todo that is not in the trash", body = ApiResponse<Value>),
+        (status = PRECONDITION_FAILED, description = "Todo item was changed by another request", body = ApiResponse<Value>)
     ),
     security(
         ("Authorization" = [])
@@ -211,23 +559,170 @@ pub async fn upsert_todo(
 pub async fn delete_todo(
     _: ApiVersion,
     Path((_v, id)): Path<(ApiVersion, String)>,
+    Query(query): Query<DeleteTodoQuery>,
     State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    IfMatch(expected_version): IfMatch,
+) -> Result<TodoResponse, AppError> {
+    let hard = query.hard.unwrap_or(false);
+    info!(todo_id = %id, hard, expected_version, current_user_id = %current_user.id, "delete_todo");
+    let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
+    let resp = if hard {
+        uc.purge_todo(current_user.id, id, expected_version).await
+    } else {
+        uc.delete_todo(current_user.id, id, expected_version).await
+    };
+    resp.map_err(usecase_error)?
+        .map(|tv| {
+            info!(todo_id = %tv.id, "delete_todo: succeeded");
+            todo_response(tv)
+        })
+        .ok_or_else(|| {
+            error!("delete_todo: todo not found");
+            AppError::from(Message::NotFound)
+        })
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/todo/{id}/restore",
+    operation_id = stringify!(restore_todo),
+    responses(
+        (status = OK, description = "Todo item restored from trash successfully", body = ApiResponse<Value>),
+        (status = CONFLICT, description = "Todo item is not in trash", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo",
+)]
+pub async fn restore_todo(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<TodoResponse, AppError> {
+    info!(todo_id = %id, current_user_id = %current_user.id, "restore_todo");
+    let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
+    let tv = uc
+        .restore_todo(current_user.id, id)
+        .await
+        .map_err(usecase_error)?;
+    info!(todo_id = %tv.id, "restore_todo: succeeded");
+    Ok(todo_response(tv))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/v1/todo/trash",
+    operation_id = stringify!(purge_trash),
+    responses(
+        (status = OK, description = "Todo items past the retention period permanently deleted", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo",
+)]
+pub async fn purge_trash(
+    _: ApiVersion,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
 ) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
-    info!(todo_id = %id, "delete_todo");
+    let retention_days = state.config.todo_retention_days;
+    info!(retention_days, current_user_id = %current_user.id, "purge_trash");
     let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
-    let resp = uc.delete_todo(id).await;
-    match resp {
-        Ok(tv) => tv
-            .map(|tv| {
-                info!(todo_id = %tv.id, "delete_todo: succeeded");
-                let json: JsonTodo = tv.into();
-                let response = ApiResponse::success("success", json!({ "todoView": json }));
-                (StatusCode::OK, Json(response))
-            })
-            .ok_or_else(|| {
-                error!("delete_todo: todo not found");
-                AppError::Error("data not found".to_string())
-            }),
-        Err(err) => Err(internal_error(err)),
-    }
+    let purged = uc
+        .purge_deleted_todos(current_user.id, retention_days)
+        .await
+        .map_err(internal_error)?;
+    info!(purged, "purge_trash: succeeded");
+    let response = ApiResponse::success("success", json!({ "purgedCount": purged }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/todo/statuses",
+    operation_id = stringify!(get_status_transitions),
+    responses(
+        (status = OK, description = "Get todo statuses and their allowed next statuses", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo",
+)]
+pub async fn get_status_transitions(
+    _: ApiVersion,
+    State(state): State<Arc<AppState>>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
+    let transitions = uc.get_status_transitions().await.map_err(internal_error)?;
+    let json: Vec<JsonTodoStatusTransition> = transitions.into_iter().map(Into::into).collect();
+    let response = ApiResponse::success("success", json!({ "statuses": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/todo/stats",
+    params(TodoStatsQuery),
+    operation_id = stringify!(get_todo_stats),
+    responses(
+        (status = OK, description = "Get todo statistics as `statsView` (see `JsonTodoStats`). Cacheable for 60 seconds per user.", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo",
+)]
+pub async fn get_todo_stats(
+    _: ApiVersion,
+    Query(query): Query<TodoStatsQuery>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<
+    (
+        StatusCode,
+        [(HeaderName, &'static str); 1],
+        Json<ApiResponse<Value>>,
+    ),
+    AppError,
+> {
+    info!(query = ?query, current_user_id = %current_user.id, "get_todo_stats");
+    let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
+    let stats = uc
+        .get_stats(current_user.id, query.into())
+        .await
+        .map_err(usecase_error)?;
+    // 사용자마다 다른 집계이므로 공유 캐시에는 남기지 않는다.
+    let headers = [(CACHE_CONTROL, STATS_CACHE_CONTROL)];
+    let json: JsonTodoStats = stats.into();
+    let response = ApiResponse::success("success", json!({ "statsView": json }));
+    Ok((StatusCode::OK, headers, Json(response)))
+}
+
+/// todo 를 `todoView` 로 담고 `ETag` 헤더를 붙인다.
+fn todo_response(tv: TodoView) -> TodoResponse {
+    let headers = [(ETAG, etag(tv.version))];
+    let json: JsonTodo = tv.into();
+    let response = ApiResponse::success("success", json!({ "todoView": json }));
+    (StatusCode::OK, headers, Json(response))
+}
+
+/// 일괄 처리 실패 응답. 실패한 작업의 오류로 상태 코드를 정하고 그 위치를 `failedIndex` 로 알린다.
+/// `AppError` 응답과 같이 메시지 코드를 응답 확장에 넣어 멱등 미들웨어가 내부 오류를 알아보게 한다.
+pub(crate) fn batch_failure(index: usize, err: AppError) -> Response {
+    error!(index, "run_todo_batch: rolled back");
+    let (status_code, code, message) = err.status_and_message(Locale::current());
+    let response = ApiResponse {
+        result: false,
+        code: Some(code.to_string()),
+        message: format!("operations[{index}]: {message}"),
+        data: Some(json!({ "failedIndex": index })),
+    };
+    let mut response = (status_code, Json(response)).into_response();
+    response.extensions_mut().insert(ErrorCode(code));
+    response
 }
@@ -0,0 +1,175 @@
+use crate::context::api_response::{internal_error, usecase_error, ApiResponse};
+use crate::context::api_version::ApiVersion;
+use crate::context::errors::AppError;
+use crate::context::message::Message;
+use crate::model::transfer::{
+    read_import_rows, to_import_row, JsonTodoImport, JsonTodoImportError, TodoExportEncoder,
+    TransferQuery,
+};
+use crate::module::usecase_module::AppState;
+use axum::body::{Body, Bytes};
+use axum::extract::{Query, State};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::{Extension, Json};
+use futures_util::stream::{self, StreamExt};
+use serde_json::{json, Value};
+use shaku::HasComponent;
+use std::sync::Arc;
+use tracing::{error, info};
+use usecase::model::todo::transfer::MAX_IMPORT_ROWS;
+use usecase::model::user::UserView;
+use usecase::usecase::todo::ITodoUseCase;
+
+/// 첫 페이지 다음부터 내보낼 todo 를 한 페이지씩 읽어 오는 상태.
+struct ExportPages {
+    uc: Arc<dyn ITodoUseCase>,
+    user_id: String,
+    encoder: TodoExportEncoder,
+    cursor: Option<String>,
+    done: bool,
+}
+
+impl ExportPages {
+    /// 다음 조각. 남은 페이지가 없으면 파일 꼬리를 내보내고 끝난다.
+    async fn next_chunk(mut self) -> Option<(anyhow::Result<String>, Self)> {
+        if self.done {
+            return None;
+        }
+        let Some(cursor) = self.cursor.take() else {
+            self.done = true;
+            return Some((Ok(self.encoder.footer()), self));
+        };
+        let chunk = match self
+            .uc
+            .export_todos(self.user_id.clone(), Some(cursor))
+            .await
+        {
+            Ok(page) => {
+                self.cursor = page.next_cursor;
+                self.encoder.page(page.todos).map_err(Into::into)
+            }
+            Err(err) => Err(err),
+        };
+        if let Err(err) = &chunk {
+            // 응답을 이미 보내기 시작했으므로 연결을 끊어 파일이 덜 받아졌음을 알린다.
+            error!(error = ?err, "export_todos: failed while streaming");
+            self.done = true;
+        }
+        Some((chunk, self))
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/v1/todo/export",
+    params(TransferQuery),
+    operation_id = stringify!(export_todos),
+    responses(
+        (status = OK, description = "all todos except the trash as a JSON array, CSV with a header row, or iCalendar VTODOs",
+            content(
+                (String = "application/json"),
+                (String = "text/csv"),
+                (String = "text/calendar")
+            )
+        )
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo",
+)]
+pub async fn export_todos(
+    _: ApiVersion,
+    Query(query): Query<TransferQuery>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+) -> Result<Response, AppError> {
+    let format = query.format.unwrap_or_default();
+    info!(format = ?format, current_user_id = %current_user.id, "export_todos");
+    let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
+    // 첫 페이지는 응답을 보내기 전에 읽어 오류를 일반 응답으로 알린다.
+    let first = uc
+        .export_todos(current_user.id.clone(), None)
+        .await
+        .map_err(usecase_error)?;
+    let mut encoder = TodoExportEncoder::new(format);
+    let mut head = encoder.header();
+    head.push_str(&encoder.page(first.todos).map_err(internal_error)?);
+    let pages = ExportPages {
+        uc,
+        user_id: current_user.id,
+        encoder,
+        cursor: first.next_cursor,
+        done: false,
+    };
+    let body = stream::once(async move { anyhow::Ok(head) })
+        .chain(stream::unfold(pages, ExportPages::next_chunk));
+    let headers = [
+        (header::CONTENT_TYPE, format.content_type().to_string()),
+        (
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}\"", format.file_name()),
+        ),
+    ];
+    Ok((StatusCode::OK, headers, Body::from_stream(body)).into_response())
+}
+
+#[utoipa::path(
+    post,
+    path = "/v1/todo/import",
+    params(TransferQuery),
+    request_body(
+        description = "a JSON array of todos, CSV with a header row, or iCalendar VTODOs. Each row follows the rules of creating a todo and may have `statusCode`.",
+        content(
+            (String = "application/json"),
+            (String = "text/csv"),
+            (String = "text/calendar")
+        )
+    ),
+    operation_id = stringify!(import_todos),
+    responses(
+        (status = OK, description = "valid rows imported; invalid rows reported in `errors` by row number (1-based, excluding the CSV header)", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "todo",
+)]
+pub async fn import_todos(
+    _: ApiVersion,
+    Query(query): Query<TransferQuery>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    body: Bytes,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    let format = query.format.unwrap_or_default();
+    info!(format = ?format, size_bytes = body.len(), current_user_id = %current_user.id, "import_todos");
+    let body = std::str::from_utf8(&body).map_err(|_| Message::ImportNotUtf8)?;
+    let values = read_import_rows(format, body)?;
+    if values.len() > MAX_IMPORT_ROWS {
+        return Err(Message::ImportTooManyRows(MAX_IMPORT_ROWS).into());
+    }
+    let mut rows = Vec::with_capacity(values.len());
+    let mut rejected = vec![];
+    for (index, value) in values.into_iter().enumerate() {
+        let row = index + 1;
+        match to_import_row(row, value) {
+            Ok(source) => rows.push(source),
+            Err(messages) => rejected.push(JsonTodoImportError { row, messages }),
+        }
+    }
+    let uc: Arc<dyn ITodoUseCase> = state.module.resolve();
+    let view = uc
+        .import_todos(current_user.id, rows)
+        .await
+        .map_err(usecase_error)?;
+    let json = JsonTodoImport::new(view, rejected);
+    info!(
+        imported = json.imported,
+        errors = json.errors.len(),
+        "import_todos: succeeded"
+    );
+    let response = ApiResponse::success("success", json!({ "importView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
@@ -1,8 +1,11 @@
 use crate::context::api_response::{internal_error, ApiResponse};
 use crate::context::api_version::ApiVersion;
 use crate::context::errors::AppError;
+use crate::context::message::Message;
 use crate::context::validate::ValidatedRequest;
-use crate::model::user::{JsonCreateUser, JsonLoginUser, JsonUser, TokenClaims, UserQuery};
+use crate::model::user::{
+    JsonCreateUser, JsonLoginUser, JsonUpdateUserLocale, JsonUser, TokenClaims, UserQuery,
+};
 use crate::module::usecase_module::AppState;
 use axum::extract::{Path, Query, State};
 use axum::http::{header, HeaderValue, StatusCode};
@@ -38,7 +41,7 @@ fn generate_jwt_token(
     )
     .map_err(|e| {
         error!(error = ?e, "JWT encoding failed");
-        AppError::Error("서버 오류가 발생했습니다".to_string())
+        AppError::from(Message::InternalError)
     })
 }
 
@@ -92,7 +95,7 @@ pub async fn get_user(
 ) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
     info!(user_id = %id, current_user_id = %current_user.id, "get_user");
     if current_user.id != id {
-        return Err(AppError::Forbidden("forbidden".to_string()));
+        return Err(AppError::Forbidden(Message::Forbidden));
     }
     let uc: Arc<dyn IUserUseCase> = state.module.resolve();
     let resp = uc.get_user(id).await;
@@ -106,7 +109,7 @@ pub async fn get_user(
             })
             .ok_or_else(|| {
                 error!("get_user: user not found");
-                AppError::Error("data not found".to_string())
+                AppError::from(Message::NotFound)
             }),
         Err(err) => Err(internal_error(err)),
     }
@@ -133,10 +136,10 @@ pub async fn get_user_by_username(
 ) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
     info!(current_user_id = %current_user.id, "get_user_by_username");
     if query.username.is_empty() {
-        return Err(AppError::Error("username is empty".to_string()));
+        return Err(Message::Empty("username".to_string()).into());
     }
     if current_user.username != query.username {
-        return Err(AppError::Forbidden("forbidden".to_string()));
+        return Err(AppError::Forbidden(Message::Forbidden));
     }
     let uc: Arc<dyn IUserUseCase> = state.module.resolve();
     let user_view = uc
@@ -154,6 +157,7 @@ pub async fn get_user_by_username(
         None => {
             let response: ApiResponse<Value> = ApiResponse {
                 result: true,
+                code: None,
                 message: "user not found.".to_string(),
                 data: None,
             };
@@ -202,7 +206,7 @@ pub async fn login_user(
             let cookie_header = cookie
                 .to_string()
                 .parse::<HeaderValue>()
-                .map_err(|_| AppError::Error("서버 오류가 발생했습니다".to_string()))?;
+                .map_err(|_| AppError::from(Message::InternalError))?;
             let json_user: JsonUser = uv.into();
             let body = ApiResponse::success("success.", json!({ "userView": json_user }));
             let mut response = (StatusCode::OK, Json(body)).into_response();
@@ -215,6 +219,48 @@ pub async fn login_user(
     }
 }
 
+#[utoipa::path(
+    put,
+    path = "/v1/user/{id}/locale",
+    request_body(
+        content = JsonUpdateUserLocale,
+        content_type = "application/json"
+    ),
+    operation_id = stringify!(update_user_locale),
+    responses(
+        (status = OK, description = "response language of the user changed successfully", body = ApiResponse<Value>)
+    ),
+    security(
+        ("Authorization" = [])
+    ),
+    tag = "user",
+)]
+pub async fn update_user_locale(
+    _: ApiVersion,
+    Path((_v, id)): Path<(ApiVersion, String)>,
+    State(state): State<Arc<AppState>>,
+    Extension(current_user): Extension<UserView>,
+    ValidatedRequest(source): ValidatedRequest<JsonUpdateUserLocale>,
+) -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
+    info!(user_id = %id, current_user_id = %current_user.id, "update_user_locale");
+    if current_user.id != id {
+        return Err(AppError::Forbidden(Message::Forbidden));
+    }
+    let locale = source.try_into_locale()?;
+    let uc: Arc<dyn IUserUseCase> = state.module.resolve();
+    let uv = uc
+        .update_locale(id, locale)
+        .await
+        .map_err(internal_error)?
+        .ok_or_else(|| {
+            error!("update_user_locale: user not found");
+            AppError::from(Message::NotFound)
+        })?;
+    let json: JsonUser = uv.into();
+    let response = ApiResponse::success("success", json!({ "userView": json }));
+    Ok((StatusCode::OK, Json(response)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
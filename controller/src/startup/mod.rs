@@ -1,19 +1,45 @@
 use crate::context::api_doc::ApiDoc;
 use crate::context::api_response::ApiResponse;
-use crate::context::auth_resolver::auth;
+use crate::context::auth_resolver::{auth, require_admin};
 use crate::context::errors::AppError;
+use crate::context::idempotency::{idempotency, IDEMPOTENCY_KEY, IDEMPOTENT_REPLAYED};
+use crate::context::locale::resolve_locale;
+use crate::context::message::Message;
+use crate::context::timeout::request_timeout;
 use crate::module::usecase_module::AppState;
+use crate::routes::attachment::{
+    delete_attachment, download_attachment, find_attachments, upload_attachment,
+};
+use crate::routes::comment::{create_comment, delete_comment, find_comments, update_comment};
 use crate::routes::health_check::{hc, hc_postgres};
+use crate::routes::project::{
+    archive_project, create_project, delete_project, find_project_todos, find_projects,
+    get_project, unarchive_project, update_project,
+};
+use crate::routes::share::{find_todo_shares, share_todo, unshare_todo};
+use crate::routes::status::{
+    create_todo_status, disable_todo_status, enable_todo_status, find_todo_statuses,
+    rename_todo_status,
+};
+use crate::routes::tag::{create_tag, delete_tag, find_tags, get_tag, update_tag};
 use crate::routes::todo::{
-    create_todo, delete_todo, find_todo, get_todo, update_todo, upsert_todo,
+    create_todo, delete_todo, find_todo, find_todo_children, find_todo_history,
+    find_todo_occurrences, get_status_transitions, get_todo, get_todo_stats, move_todo,
+    purge_trash, restore_todo, run_todo_batch, snooze_todo, stop_todo_recurrence, update_todo,
+    upsert_todo,
 };
-use crate::routes::user::{create_user, get_user, get_user_by_username, login_user};
-use axum::error_handling::HandleErrorLayer;
-use axum::routing::{get, post};
+use crate::routes::transfer::{export_todos, import_todos};
+use crate::routes::user::{
+    create_user, get_user, get_user_by_username, login_user, update_user_locale,
+};
+use crate::worker::idempotency::spawn_idempotency_sweeper;
+use crate::worker::reminder::spawn_reminder_worker;
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{delete, get, patch, post, put};
 use axum::{middleware, Json, Router};
 use http::header::{
     ACCEPT, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_REQUEST_HEADERS,
-    ACCESS_CONTROL_REQUEST_METHOD, AUTHORIZATION, CONTENT_TYPE, ORIGIN,
+    ACCESS_CONTROL_REQUEST_METHOD, AUTHORIZATION, CONTENT_TYPE, ETAG, IF_MATCH, ORIGIN,
 };
 use http::{HeaderValue, Method, StatusCode};
 use serde_json::Value;
@@ -22,7 +48,7 @@ use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::net::TcpListener;
-use tower::{BoxError, ServiceBuilder};
+use tokio_util::sync::CancellationToken;
 use tower_http::cors::CorsLayer;
 use tower_http::trace::TraceLayer;
 use tracing::info;
@@ -30,6 +56,15 @@ use utoipa::openapi::{Info, OpenApiBuilder};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
+/// 첨부 파일 업로드 요청에서 파일 본문 외에 허용하는 multipart 부가 데이터 크기.
+const MULTIPART_OVERHEAD_BYTES: usize = 64 * 1024;
+
+/// 요청 하나를 처리하는 시간 제한.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 첨부 파일 업로드의 시간 제한. 한도에 가까운 파일을 느린 연결로 올려도 끊기지 않도록 넉넉히 둔다.
+const ATTACHMENT_UPLOAD_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
 /// 라우터와 미들웨어를 조합하여 `Router`를 반환한다.
 ///
 /// 테스트에서 `tower::ServiceExt::oneshot`으로 직접 호출하거나
@@ -58,6 +93,8 @@ pub fn build_router(app_state: Arc<AppState>) -> anyhow::Result<Router> {
             ACCESS_CONTROL_REQUEST_METHOD,
             CONTENT_TYPE,
             ACCESS_CONTROL_ALLOW_HEADERS,
+            IF_MATCH,
+            IDEMPOTENCY_KEY,
         ])
         .expose_headers(vec![
             ORIGIN,
@@ -67,6 +104,8 @@ pub fn build_router(app_state: Arc<AppState>) -> anyhow::Result<Router> {
             ACCESS_CONTROL_REQUEST_METHOD,
             CONTENT_TYPE,
             ACCESS_CONTROL_ALLOW_HEADERS,
+            ETAG,
+            IDEMPOTENT_REPLAYED,
         ])
         .allow_origin(allowed_origin);
     let mut openapi = OpenApiBuilder::default()
@@ -82,8 +121,26 @@ pub fn build_router(app_state: Arc<AppState>) -> anyhow::Result<Router> {
         .route("/create", post(create_user))
         .route("/login", post(login_user));
 
+    // multipart 경계와 part 헤더가 들어갈 여유를 더해 첨부 파일 한도만큼 본문을 받는다.
+    let attachment_body_limit = usize::try_from(app_state.config.attachment_max_bytes)
+        .unwrap_or(usize::MAX)
+        .saturating_add(MULTIPART_OVERHEAD_BYTES);
+
+    // 만들기와 일괄 처리는 `Idempotency-Key` 로 재시도해도 한 번만 처리한다.
+    // `auth` 가 넣은 사용자로 키를 구분하므로 `auth` 안쪽에 둔다.
+    let idempotent = middleware::from_fn_with_state(app_state.clone(), idempotency);
+
     let todo_router = Router::new()
-        .route("/", get(find_todo).post(create_todo))
+        .route(
+            "/",
+            get(find_todo).post(create_todo).layer(idempotent.clone()),
+        )
+        .route("/statuses", get(get_status_transitions))
+        .route("/stats", get(get_todo_stats))
+        .route("/trash", delete(purge_trash))
+        .route("/batch", post(run_todo_batch).layer(idempotent))
+        .route("/export", get(export_todos))
+        .route("/import", post(import_todos))
         .route(
             "/:id",
             get(get_todo)
@@ -91,11 +148,73 @@ pub fn build_router(app_state: Arc<AppState>) -> anyhow::Result<Router> {
                 .put(upsert_todo)
                 .delete(delete_todo),
         )
+        .route("/:id/children", get(find_todo_children))
+        .route("/:id/history", get(find_todo_history))
+        .route("/:id/occurrences", get(find_todo_occurrences))
+        .route("/:id/recurrence", delete(stop_todo_recurrence))
+        .route("/:id/snooze", post(snooze_todo))
+        .route("/:id/move", post(move_todo))
+        .route("/:id/attachments", get(find_attachments))
+        .route(
+            "/:id/attachments/:attachment_id",
+            get(download_attachment).delete(delete_attachment),
+        )
+        .route("/:id/comments", get(find_comments).post(create_comment))
+        .route(
+            "/:id/comments/:comment_id",
+            patch(update_comment).delete(delete_comment),
+        )
+        .route("/:id/shares", get(find_todo_shares).post(share_todo))
+        .route("/:id/shares/:username", delete(unshare_todo))
+        .route("/:id/restore", post(restore_todo))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), auth));
+
+    // 첨부 파일 업로드는 큰 본문을 느린 연결로 받을 수 있어 다른 요청보다 시간 제한을 길게 둔다.
+    let attachment_upload_router = Router::new()
+        .route(
+            "/:id/attachments",
+            post(upload_attachment).layer(DefaultBodyLimit::max(attachment_body_limit)),
+        )
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), auth));
+
+    // 관리자 라우트는 `auth` 가 넣은 사용자로 권한을 확인하므로 `require_admin` 을 먼저 붙인다.
+    let todo_status_admin_router = Router::new()
+        .route("/", post(create_todo_status))
+        .route("/:code", patch(rename_todo_status))
+        .route("/:code/disable", post(disable_todo_status))
+        .route("/:code/enable", post(enable_todo_status))
+        .route_layer(middleware::from_fn_with_state(
+            app_state.clone(),
+            require_admin,
+        ));
+
+    let todo_status_router = Router::new()
+        .route("/", get(find_todo_statuses))
+        .merge(todo_status_admin_router)
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), auth));
+
+    let tag_router = Router::new()
+        .route("/", get(find_tags).post(create_tag))
+        .route("/:id", get(get_tag).patch(update_tag).delete(delete_tag))
+        .route_layer(middleware::from_fn_with_state(app_state.clone(), auth));
+
+    let project_router = Router::new()
+        .route("/", get(find_projects).post(create_project))
+        .route(
+            "/:id",
+            get(get_project)
+                .patch(update_project)
+                .delete(delete_project),
+        )
+        .route("/:id/todos", get(find_project_todos))
+        .route("/:id/archive", post(archive_project))
+        .route("/:id/unarchive", post(unarchive_project))
         .route_layer(middleware::from_fn_with_state(app_state.clone(), auth));
 
     let user_router = Router::new()
         .route("/", get(get_user_by_username))
         .route("/:id", get(get_user))
+        .route("/:id/locale", put(update_user_locale))
         .route_layer(middleware::from_fn_with_state(app_state.clone(), auth));
 
     Ok(Router::new()
@@ -103,27 +222,35 @@ pub fn build_router(app_state: Arc<AppState>) -> anyhow::Result<Router> {
         .nest("/:v/hc", hc_router)
         .nest("/:v/auth", auth_router)
         .nest("/:v/todo", todo_router)
+        .nest("/:v/todo-status", todo_status_router)
+        .nest("/:v/tag", tag_router)
+        .nest("/:v/project", project_router)
         .nest("/:v/user", user_router)
         .fallback(fallback)
+        .layer(middleware::from_fn_with_state(
+            REQUEST_TIMEOUT,
+            request_timeout,
+        ))
+        .nest(
+            "/:v/todo",
+            attachment_upload_router.layer(middleware::from_fn_with_state(
+                ATTACHMENT_UPLOAD_TIMEOUT,
+                request_timeout,
+            )),
+        )
         .with_state(app_state)
         .layer(cors)
-        .layer(
-            ServiceBuilder::new()
-                .layer(HandleErrorLayer::new(|error: BoxError| async move {
-                    if error.is::<tower::timeout::error::Elapsed>() {
-                        AppError::Error("time out.".to_string())
-                    } else {
-                        AppError::Error(format!("Unhandled internal error: {error}"))
-                    }
-                }))
-                .timeout(Duration::from_secs(10))
-                .layer(TraceLayer::new_for_http())
-                .into_inner(),
-        ))
+        .layer(TraceLayer::new_for_http())
+        // 시간 초과 응답도 요청 언어로 쓰도록 가장 바깥에 둔다.
+        .layer(middleware::from_fn(resolve_locale)))
 }
 
+/// 서버와 알림·멱등 키 정리 작업을 띄운다. 종료 신호를 받으면 처리 중인 요청과 작업 주기를 마치고 끝낸다.
 pub async fn startup(app_state: Arc<AppState>) -> anyhow::Result<()> {
-    let app = build_router(app_state)?;
+    let app = build_router(app_state.clone())?;
+    let shutdown = CancellationToken::new();
+    let reminder_worker = spawn_reminder_worker(app_state.clone(), shutdown.clone());
+    let idempotency_sweeper = spawn_idempotency_sweeper(app_state, shutdown.clone());
 
     let addr = SocketAddr::from(init_addr());
     let listener: TcpListener = TcpListener::bind(&addr)
@@ -132,13 +259,45 @@ pub async fn startup(app_state: Arc<AppState>) -> anyhow::Result<()> {
     info!("Server listening on {}", addr);
 
     axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal(shutdown.clone()))
         .await
         .unwrap_or_else(|_| panic!("Server cannot launch."));
+    shutdown.cancel();
+    for worker in [reminder_worker, idempotency_sweeper].into_iter().flatten() {
+        worker.await?;
+    }
+    info!("Server stopped");
     Ok(())
 }
 
+/// Ctrl+C 또는 SIGTERM 을 기다렸다가 `shutdown` 을 취소한다.
+async fn shutdown_signal(shutdown: CancellationToken) {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("Ctrl+C handler cannot be installed.");
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("SIGTERM handler cannot be installed.")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+        _ = shutdown.cancelled() => {}
+    }
+    info!("Shutdown signal received");
+    shutdown.cancel();
+}
+
 async fn fallback() -> Result<(StatusCode, Json<ApiResponse<Value>>), AppError> {
-    Err(AppError::Error("abnormal uri".to_string()))
+    Err(Message::UnknownRoute.into())
 }
 
 fn init_addr() -> (IpAddr, u16) {
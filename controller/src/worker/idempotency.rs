@@ -0,0 +1,53 @@
+use crate::module::usecase_module::AppState;
+use shaku::HasComponent;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+use usecase::usecase::idempotency::IIdempotencyUseCase;
+
+/// 한 주기에 지우는 최대 멱등 키 수.
+const SWEEP_BATCH_SIZE: i64 = 1000;
+
+/// 모든 사용자의 만료된 멱등 키를 주기적으로 지우는 백그라운드 작업을 띄운다.
+///
+/// `idempotency_sweep_seconds` 가 0 이면 띄우지 않고 `None` 을 반환한다.
+/// 한 주기에 한도만큼 지웠으면 남은 키가 있다고 보고 기다리지 않고 바로 다음 주기를 돈다.
+/// `shutdown` 이 취소되면 지우던 주기를 마친 뒤 끝난다.
+pub fn spawn_idempotency_sweeper(
+    app_state: Arc<AppState>,
+    shutdown: CancellationToken,
+) -> Option<JoinHandle<()>> {
+    let sweep_seconds = app_state.config.idempotency_sweep_seconds;
+    if sweep_seconds == 0 {
+        info!("idempotency sweeper is disabled");
+        return None;
+    }
+    let uc: Arc<dyn IIdempotencyUseCase> = app_state.module.resolve();
+
+    Some(tokio::spawn(async move {
+        info!(sweep_seconds, "idempotency sweeper started");
+        let mut interval = tokio::time::interval(Duration::from_secs(sweep_seconds));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+            match uc.purge_expired(SWEEP_BATCH_SIZE).await {
+                Ok(deleted) => {
+                    if deleted > 0 {
+                        info!(deleted, "expired idempotency keys deleted");
+                    }
+                    if deleted as i64 >= SWEEP_BATCH_SIZE {
+                        interval.reset_immediately();
+                    }
+                }
+                Err(e) => error!(error = %e, "failed to delete expired idempotency keys"),
+            }
+        }
+        info!("idempotency sweeper stopped");
+    }))
+}
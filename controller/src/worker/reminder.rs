@@ -0,0 +1,51 @@
+use crate::module::usecase_module::AppState;
+use shaku::HasComponent;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
+use usecase::usecase::reminder::IReminderUseCase;
+
+/// 보낼 알림을 주기적으로 찾아 보내는 백그라운드 작업을 띄운다.
+///
+/// `reminder_poll_seconds` 가 0 이면 띄우지 않고 `None` 을 반환한다.
+/// 한 주기에 한도만큼 보냈으면 남은 알림이 있다고 보고 기다리지 않고 바로 다음 주기를 돈다.
+/// `shutdown` 이 취소되면 보내던 주기를 마친 뒤 끝난다.
+pub fn spawn_reminder_worker(
+    app_state: Arc<AppState>,
+    shutdown: CancellationToken,
+) -> Option<JoinHandle<()>> {
+    let poll_seconds = app_state.config.reminder_poll_seconds;
+    if poll_seconds == 0 {
+        info!("reminder worker is disabled");
+        return None;
+    }
+    let batch_size = app_state.config.reminder_batch_size;
+    let uc: Arc<dyn IReminderUseCase> = app_state.module.resolve();
+
+    Some(tokio::spawn(async move {
+        info!(poll_seconds, batch_size, "reminder worker started");
+        let mut interval = tokio::time::interval(Duration::from_secs(poll_seconds));
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+            match uc.deliver_due_reminders(batch_size).await {
+                Ok(delivered) => {
+                    if delivered > 0 {
+                        info!(delivered, "reminders delivered");
+                    }
+                    if delivered as i64 >= batch_size {
+                        interval.reset_immediately();
+                    }
+                }
+                Err(e) => error!(error = %e, "failed to deliver reminders"),
+            }
+        }
+        info!("reminder worker stopped");
+    }))
+}
@@ -612,10 +612,11 @@ async fn get_user_by_username_with_empty_username_returns_error_result() {
 // ─── find_todo: status 없이 호출 → 200 result:false ──────────────────────────
 
 #[tokio::test]
-async fn find_todo_without_status_returns_error_result() {
+async fn find_todo_without_filter_returns_all_owned_todos() {
     let app = common::build_test_app().await;
     let email = unique_email();
     let token = create_user_and_login(&app, &email).await;
+    let id = create_todo_as(&app, &token, "No Filter").await;
     let req = Request::builder()
         .method(Method::GET)
         .uri("/v1/todo")
@@ -625,9 +626,130 @@ async fn find_todo_without_status_returns_error_result() {
     let resp = app.oneshot(req).await.unwrap();
     assert_eq!(resp.status(), StatusCode::OK);
     let json = body_json(resp.into_body()).await;
+    assert_eq!(json["result"], true);
+    let todos = json["data"]["todoView"]["todos"].as_array().unwrap();
+    assert_eq!(todos.len(), 1);
+    assert_eq!(todos[0]["id"], id.as_str());
+}
+
+#[tokio::test]
+async fn find_todo_with_statuses_and_sort_returns_sorted_todos() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    for title in ["b", "c", "a"] {
+        create_todo_as(&app, &token, title).await;
+    }
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri("/v1/todo?status=new,done&sort=-title&createdAfter=2000-01-01T00:00:00Z")
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let json = body_json(resp.into_body()).await;
+    let titles: Vec<&str> = json["data"]["todoView"]["todos"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(titles, vec!["c", "b", "a"]);
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri("/v1/todo?sort=ownerId")
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    let json = body_json(resp.into_body()).await;
+    assert_eq!(json["result"], false);
+}
+
+// ─── find_todo: limit / cursor 페이지네이션 ──────────────────────────────────
+
+#[tokio::test]
+async fn find_todo_with_limit_returns_next_cursor_until_last_page() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    for i in 0..3 {
+        create_todo_as(&app, &token, &format!("Page {i}")).await;
+    }
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri("/v1/todo?status=new&limit=2")
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let json = body_json(resp.into_body()).await;
+    let list = &json["data"]["todoView"];
+    assert_eq!(list["todos"].as_array().unwrap().len(), 2);
+    assert_eq!(list["hasMore"], true);
+    let cursor = list["nextCursor"].as_str().unwrap().to_string();
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/v1/todo?status=new&limit=2&cursor={cursor}"))
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    let json = body_json(resp.into_body()).await;
+    let list = &json["data"]["todoView"];
+    assert_eq!(list["todos"].as_array().unwrap().len(), 1);
+    assert_eq!(list["hasMore"], false);
+    assert!(list["nextCursor"].is_null());
+}
+
+#[tokio::test]
+async fn find_todo_with_invalid_cursor_returns_error_result() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri("/v1/todo?status=new&cursor=broken")
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let json = body_json(resp.into_body()).await;
     assert_eq!(json["result"], false);
 }
 
+// ─── find_todo: q 검색 ────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn find_todo_with_q_returns_hits_with_rank_and_snippet() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let id = create_todo_as(&app, &token, "Quarterly budget").await;
+    create_todo_as(&app, &token, "Something else").await;
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri("/v1/todo?q=budget")
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let json = body_json(resp.into_body()).await;
+    let todos = json["data"]["todoView"]["todos"].as_array().unwrap();
+    assert_eq!(todos.len(), 1);
+    assert_eq!(todos[0]["id"], id.as_str());
+    assert!(todos[0]["rank"].as_f64().unwrap() > 0.0);
+    assert!(todos[0]["snippet"]
+        .as_str()
+        .unwrap()
+        .contains("<mark>budget</mark>"));
+}
+
 // ─── get_todo: 존재하지 않는 ID → 200 result:false ───────────────────────────
 
 #[tokio::test]
@@ -885,3 +1007,2151 @@ async fn protected_route_returns_unauthorized_when_user_not_in_db() {
     let json = body_json(resp.into_body()).await;
     assert_eq!(json["result"], false);
 }
+
+// ─── todo 소유자 격리: 다른 사용자의 todo → 200 result:false ───────────────────
+
+async fn create_todo_as(app: &axum::Router, token: &str, title: &str) -> String {
+    let body = json!({ "title": title, "description": "owner only" });
+    let req = Request::builder()
+        .method(Method::POST)
+        .uri("/v1/todo")
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    let json = body_json(resp.into_body()).await;
+    assert_eq!(json["result"], true, "setup: create_todo must succeed");
+    json["data"]["todoView"]["id"].as_str().unwrap().to_string()
+}
+
+async fn get_todo_as(app: &axum::Router, token: &str, id: &str) -> Value {
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/v1/todo/{id}"))
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    body_json(resp.into_body()).await
+}
+
+#[tokio::test]
+async fn get_todo_owned_by_other_user_returns_error_result() {
+    let app = common::build_test_app().await;
+    let owner_token = create_user_and_login(&app, &unique_email()).await;
+    let other_token = create_user_and_login(&app, &unique_email()).await;
+    let id = create_todo_as(&app, &owner_token, "Owner Todo").await;
+
+    let json = get_todo_as(&app, &other_token, &id).await;
+    assert_eq!(json["result"], false);
+}
+
+#[tokio::test]
+async fn update_todo_owned_by_other_user_returns_error_result_and_keeps_todo() {
+    let app = common::build_test_app().await;
+    let owner_token = create_user_and_login(&app, &unique_email()).await;
+    let other_token = create_user_and_login(&app, &unique_email()).await;
+    let id = create_todo_as(&app, &owner_token, "Owner Todo").await;
+
+    let update_body = json!({ "title": "Hijacked" });
+    let req = Request::builder()
+        .method(Method::PATCH)
+        .uri(format!("/v1/todo/{id}"))
+        .header(header::AUTHORIZATION, format!("Bearer {other_token}"))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(update_body.to_string()))
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let json = body_json(resp.into_body()).await;
+    assert_eq!(json["result"], false);
+
+    let json = get_todo_as(&app, &owner_token, &id).await;
+    assert_eq!(json["data"]["todoView"]["title"], "Owner Todo");
+}
+
+#[tokio::test]
+async fn upsert_todo_owned_by_other_user_returns_error_result_and_keeps_todo() {
+    let app = common::build_test_app().await;
+    let owner_token = create_user_and_login(&app, &unique_email()).await;
+    let other_token = create_user_and_login(&app, &unique_email()).await;
+    let id = create_todo_as(&app, &owner_token, "Owner Todo").await;
+
+    let upsert_body =
+        json!({ "title": "Hijacked", "description": "hijacked", "statusCode": "done" });
+    let req = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/v1/todo/{id}"))
+        .header(header::AUTHORIZATION, format!("Bearer {other_token}"))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(upsert_body.to_string()))
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let json = body_json(resp.into_body()).await;
+    assert_eq!(json["result"], false);
+
+    let json = get_todo_as(&app, &owner_token, &id).await;
+    assert_eq!(json["data"]["todoView"]["title"], "Owner Todo");
+    assert_eq!(json["data"]["todoView"]["status"]["code"], "new");
+}
+
+#[tokio::test]
+async fn delete_todo_owned_by_other_user_returns_error_result_and_keeps_todo() {
+    let app = common::build_test_app().await;
+    let owner_token = create_user_and_login(&app, &unique_email()).await;
+    let other_token = create_user_and_login(&app, &unique_email()).await;
+    let id = create_todo_as(&app, &owner_token, "Owner Todo").await;
+
+    let req = Request::builder()
+        .method(Method::DELETE)
+        .uri(format!("/v1/todo/{id}"))
+        .header(header::AUTHORIZATION, format!("Bearer {other_token}"))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let json = body_json(resp.into_body()).await;
+    assert_eq!(json["result"], false);
+
+    let json = get_todo_as(&app, &owner_token, &id).await;
+    assert_eq!(json["result"], true);
+}
+
+#[tokio::test]
+async fn find_todo_excludes_todos_owned_by_other_user() {
+    let app = common::build_test_app().await;
+    let owner_token = create_user_and_login(&app, &unique_email()).await;
+    let other_token = create_user_and_login(&app, &unique_email()).await;
+    let id = create_todo_as(&app, &owner_token, "Owner Todo").await;
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri("/v1/todo?status=new")
+        .header(header::AUTHORIZATION, format!("Bearer {other_token}"))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let json = body_json(resp.into_body()).await;
+    let todos = json["data"]["todoView"]["todos"].as_array().unwrap();
+    assert!(!todos.iter().any(|t| t["id"] == id.as_str()));
+}
+
+// ─── todo 상태 전이 ──────────────────────────────────────────────────────────
+
+async fn patch_status_as(app: &axum::Router, token: &str, id: &str, code: &str) -> StatusCode {
+    let body = json!({ "statusCode": code });
+    let req = Request::builder()
+        .method(Method::PATCH)
+        .uri(format!("/v1/todo/{id}"))
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    app.clone().oneshot(req).await.unwrap().status()
+}
+
+#[tokio::test]
+async fn update_todo_with_illegal_status_transition_returns_conflict() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let id = create_todo_as(&app, &token, "Workflow Todo").await;
+    assert_eq!(
+        patch_status_as(&app, &token, &id, "done").await,
+        StatusCode::OK
+    );
+
+    let status = patch_status_as(&app, &token, &id, "new").await;
+
+    assert_eq!(status, StatusCode::CONFLICT);
+    let json = get_todo_as(&app, &token, &id).await;
+    assert_eq!(json["data"]["todoView"]["status"]["code"], "done");
+}
+
+#[tokio::test]
+async fn get_status_transitions_returns_next_statuses() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri("/v1/todo/statuses")
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    let json = body_json(resp.into_body()).await;
+    let statuses = json["data"]["statuses"].as_array().unwrap();
+    // 다른 테스트가 추가한 사용자 정의 상태는 기본 상태 뒤에 온다.
+    assert!(statuses.len() >= 7);
+    let deleted = statuses.iter().find(|s| s["code"] == "deleted").unwrap();
+    assert!(deleted["next"].as_array().unwrap().is_empty());
+    let done = statuses.iter().find(|s| s["code"] == "done").unwrap();
+    let next: Vec<&str> = done["next"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["code"].as_str().unwrap())
+        .collect();
+    assert_eq!(next[0], "working");
+    assert!(!next.contains(&"new") && !next.contains(&"deleted"));
+}
+
+// ─── todo 휴지통 ─────────────────────────────────────────────────────────────
+
+async fn send_as(
+    app: &axum::Router,
+    token: &str,
+    method: Method,
+    uri: &str,
+) -> (StatusCode, Value) {
+    let req = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    let status = resp.status();
+    (status, body_json(resp.into_body()).await)
+}
+
+#[tokio::test]
+async fn delete_todo_moves_to_trash_and_restore_brings_it_back() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let id = create_todo_as(&app, &token, "Trash Todo").await;
+
+    let (_, json) = send_as(&app, &token, Method::DELETE, &format!("/v1/todo/{id}")).await;
+    assert_eq!(json["result"], true);
+    assert_eq!(json["data"]["todoView"]["status"]["code"], "deleted");
+    assert!(json["data"]["todoView"]["deletedAt"].is_string());
+
+    let (_, json) = send_as(&app, &token, Method::GET, "/v1/todo").await;
+    let todos = json["data"]["todoView"]["todos"].as_array().unwrap();
+    assert!(!todos.iter().any(|t| t["id"] == id.as_str()));
+
+    let (status, json) = send_as(
+        &app,
+        &token,
+        Method::POST,
+        &format!("/v1/todo/{id}/restore"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["data"]["todoView"]["status"]["code"], "new");
+    assert!(json["data"]["todoView"].get("deletedAt").is_none());
+
+    let (status, _) = send_as(
+        &app,
+        &token,
+        Method::POST,
+        &format!("/v1/todo/{id}/restore"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn delete_todo_with_hard_removes_trashed_todo_permanently() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let id = create_todo_as(&app, &token, "Hard Delete Todo").await;
+
+    // 휴지통을 거치지 않은 todo 는 영구 삭제하지 않는다.
+    let (status, json) = send_as(
+        &app,
+        &token,
+        Method::DELETE,
+        &format!("/v1/todo/{id}?hard=true"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(json["code"], "not_in_trash");
+    send_as(&app, &token, Method::DELETE, &format!("/v1/todo/{id}")).await;
+
+    let (_, json) = send_as(
+        &app,
+        &token,
+        Method::DELETE,
+        &format!("/v1/todo/{id}?hard=true"),
+    )
+    .await;
+    assert_eq!(json["result"], true);
+
+    let json = get_todo_as(&app, &token, &id).await;
+    assert_eq!(json["result"], false);
+}
+
+#[tokio::test]
+async fn purge_trash_keeps_todos_within_retention() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let id = create_todo_as(&app, &token, "Recently Trashed").await;
+    send_as(&app, &token, Method::DELETE, &format!("/v1/todo/{id}")).await;
+
+    let (status, json) = send_as(&app, &token, Method::DELETE, "/v1/todo/trash").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["data"]["purgedCount"], 0);
+    let json = get_todo_as(&app, &token, &id).await;
+    assert_eq!(json["data"]["todoView"]["status"]["code"], "deleted");
+}
+
+// ─── todo 마감일 / 우선순위 ───────────────────────────────────────────────────
+
+async fn create_todo_with_body(app: &axum::Router, token: &str, body: Value) -> Value {
+    let req = Request::builder()
+        .method(Method::POST)
+        .uri("/v1/todo")
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    body_json(resp.into_body()).await
+}
+
+#[tokio::test]
+async fn create_todo_with_due_at_and_priority_returns_them() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+
+    let json = create_todo_with_body(
+        &app,
+        &token,
+        json!({
+            "title": "Due Todo",
+            "description": "desc",
+            "dueAt": "2030-01-02T03:04:05Z",
+            "priority": "high"
+        }),
+    )
+    .await;
+
+    assert_eq!(json["result"], true);
+    let todo = &json["data"]["todoView"];
+    assert_eq!(todo["dueAt"], "2030-01-02T03:04:05+00:00");
+    assert_eq!(todo["priority"], "high");
+
+    let id = create_todo_as(&app, &token, "Default Priority").await;
+    let json = get_todo_as(&app, &token, &id).await;
+    assert_eq!(json["data"]["todoView"]["priority"], "medium");
+    assert!(json["data"]["todoView"].get("dueAt").is_none());
+}
+
+#[tokio::test]
+async fn create_todo_with_unknown_priority_returns_error_result() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+
+    let json = create_todo_with_body(
+        &app,
+        &token,
+        json!({ "title": "Bad", "description": "desc", "priority": "someday" }),
+    )
+    .await;
+
+    assert_eq!(json["result"], false);
+}
+
+#[tokio::test]
+async fn find_todo_with_overdue_returns_only_unfinished_past_due() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let past = json!({ "title": "Late", "description": "d", "dueAt": "2020-01-01T00:00:00Z" });
+    let late = create_todo_with_body(&app, &token, past.clone()).await["data"]["todoView"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let finished = create_todo_with_body(&app, &token, past).await["data"]["todoView"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    patch_status_as(&app, &token, &finished, "done").await;
+    create_todo_with_body(
+        &app,
+        &token,
+        json!({ "title": "Future", "description": "d", "dueAt": "2999-01-01T00:00:00Z" }),
+    )
+    .await;
+    create_todo_as(&app, &token, "No Due").await;
+
+    let (status, json) = send_as(&app, &token, Method::GET, "/v1/todo?overdue=true").await;
+
+    assert_eq!(status, StatusCode::OK);
+    let ids: Vec<&str> = json["data"]["todoView"]["todos"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, [late.as_str()]);
+}
+
+#[tokio::test]
+async fn find_todo_sorted_by_priority_orders_by_priority_then_due_at() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    for (title, priority, due_at) in [
+        ("low", "low", Some("2030-01-01T00:00:00Z")),
+        ("urgent-none", "urgent", None),
+        ("urgent-late", "urgent", Some("2030-02-01T00:00:00Z")),
+        ("urgent-early", "urgent", Some("2030-01-01T00:00:00Z")),
+    ] {
+        let mut body = json!({ "title": title, "description": "d", "priority": priority });
+        if let Some(due_at) = due_at {
+            body["dueAt"] = json!(due_at);
+        }
+        create_todo_with_body(&app, &token, body).await;
+    }
+
+    let (_, json) = send_as(&app, &token, Method::GET, "/v1/todo?sort=priority").await;
+
+    let titles: Vec<&str> = json["data"]["todoView"]["todos"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        titles,
+        ["urgent-early", "urgent-late", "urgent-none", "low"]
+    );
+}
+
+// ─── tag ─────────────────────────────────────────────────────────────────────
+
+async fn send_json_as(
+    app: &axum::Router,
+    token: &str,
+    method: Method,
+    uri: &str,
+    body: Value,
+) -> (StatusCode, Value) {
+    let req = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    let status = resp.status();
+    (status, body_json(resp.into_body()).await)
+}
+
+#[tokio::test]
+async fn tag_crud_round_trip() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+
+    let (status, json) = send_json_as(
+        &app,
+        &token,
+        Method::POST,
+        "/v1/tag",
+        json!({ "name": "work" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let id = json["data"]["tagView"]["id"].as_str().unwrap().to_string();
+
+    let (status, _) = send_json_as(
+        &app,
+        &token,
+        Method::POST,
+        "/v1/tag",
+        json!({ "name": "work" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (_, json) = send_json_as(
+        &app,
+        &token,
+        Method::PATCH,
+        &format!("/v1/tag/{id}"),
+        json!({ "name": "office" }),
+    )
+    .await;
+    assert_eq!(json["data"]["tagView"]["name"], "office");
+
+    let (_, json) = send_as(&app, &token, Method::GET, "/v1/tag").await;
+    let names: Vec<&str> = json["data"]["tags"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["office"]);
+
+    let (_, json) = send_as(&app, &token, Method::DELETE, &format!("/v1/tag/{id}")).await;
+    assert_eq!(json["result"], true);
+    let (_, json) = send_as(&app, &token, Method::GET, &format!("/v1/tag/{id}")).await;
+    assert_eq!(json["result"], false);
+}
+
+#[tokio::test]
+async fn get_tag_owned_by_other_user_returns_error_result() {
+    let app = common::build_test_app().await;
+    let owner = create_user_and_login(&app, &unique_email()).await;
+    let other = create_user_and_login(&app, &unique_email()).await;
+    let (_, json) = send_json_as(
+        &app,
+        &owner,
+        Method::POST,
+        "/v1/tag",
+        json!({ "name": "private" }),
+    )
+    .await;
+    let id = json["data"]["tagView"]["id"].as_str().unwrap();
+
+    let (status, json) = send_as(&app, &other, Method::GET, &format!("/v1/tag/{id}")).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["result"], false);
+}
+
+#[tokio::test]
+async fn find_todo_with_tag_returns_todos_with_all_tags() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let mut tag_ids = vec![];
+    for name in ["work", "urgent"] {
+        let (_, json) = send_json_as(
+            &app,
+            &token,
+            Method::POST,
+            "/v1/tag",
+            json!({ "name": name }),
+        )
+        .await;
+        tag_ids.push(json["data"]["tagView"]["id"].as_str().unwrap().to_string());
+    }
+    let json = create_todo_with_body(
+        &app,
+        &token,
+        json!({ "title": "Both", "description": "d", "tagIds": tag_ids }),
+    )
+    .await;
+    let tags: Vec<&str> = json["data"]["todoView"]["tags"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(tags, ["urgent", "work"]);
+    let only_work = create_todo_as(&app, &token, "Only Work").await;
+    let (_, json) = send_json_as(
+        &app,
+        &token,
+        Method::PATCH,
+        &format!("/v1/todo/{only_work}"),
+        json!({ "addTagIds": [tag_ids[0]] }),
+    )
+    .await;
+    assert_eq!(json["data"]["todoView"]["tags"][0]["name"], "work");
+
+    let (_, json) = send_as(&app, &token, Method::GET, "/v1/todo?tag=work,urgent").await;
+    let titles: Vec<&str> = json["data"]["todoView"]["todos"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(titles, ["Both"]);
+
+    let (_, json) = send_as(&app, &token, Method::GET, "/v1/todo?tag=work").await;
+    assert_eq!(
+        json["data"]["todoView"]["todos"].as_array().unwrap().len(),
+        2
+    );
+}
+
+// ─── subtask ─────────────────────────────────────────────────────────────────
+
+async fn create_subtask(app: &axum::Router, token: &str, title: &str, parent_id: &str) -> String {
+    let json = create_todo_with_body(
+        app,
+        token,
+        json!({ "title": title, "description": "d", "parentId": parent_id }),
+    )
+    .await;
+    assert_eq!(json["data"]["todoView"]["parentId"], parent_id);
+    json["data"]["todoView"]["id"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn get_todo_with_tree_and_children_return_subtasks() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let root = create_todo_as(&app, &token, "Root").await;
+    let child = create_subtask(&app, &token, "Child", &root).await;
+    let grandchild = create_subtask(&app, &token, "Grandchild", &child).await;
+
+    let (_, json) = send_as(
+        &app,
+        &token,
+        Method::GET,
+        &format!("/v1/todo/{root}?tree=true"),
+    )
+    .await;
+    let tree = &json["data"]["todoView"];
+    assert_eq!(tree["id"], root.as_str());
+    assert_eq!(tree["children"][0]["id"], child.as_str());
+    assert_eq!(
+        tree["children"][0]["children"][0]["id"],
+        grandchild.as_str()
+    );
+
+    let (_, json) = send_as(&app, &token, Method::GET, &format!("/v1/todo/{root}")).await;
+    assert!(json["data"]["todoView"].get("children").is_none());
+
+    let (_, json) = send_as(
+        &app,
+        &token,
+        Method::GET,
+        &format!("/v1/todo/{root}/children"),
+    )
+    .await;
+    let ids: Vec<&str> = json["data"]["todoView"]["todos"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, [child.as_str()]);
+}
+
+#[tokio::test]
+async fn find_children_of_unknown_todo_returns_error_result() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let other = create_user_and_login(&app, &unique_email()).await;
+    let root = create_todo_as(&app, &other, "Root").await;
+
+    let (status, json) = send_as(
+        &app,
+        &token,
+        Method::GET,
+        &format!("/v1/todo/{root}/children"),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["result"], false);
+}
+
+#[tokio::test]
+async fn update_todo_parent_to_descendant_returns_conflict() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let root = create_todo_as(&app, &token, "Root").await;
+    let child = create_subtask(&app, &token, "Child", &root).await;
+
+    let (status, _) = send_json_as(
+        &app,
+        &token,
+        Method::PATCH,
+        &format!("/v1/todo/{root}"),
+        json!({ "parentId": child }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (_, json) = send_json_as(
+        &app,
+        &token,
+        Method::PATCH,
+        &format!("/v1/todo/{child}"),
+        json!({ "parentId": "" }),
+    )
+    .await;
+    assert!(json["data"]["todoView"].get("parentId").is_none());
+}
+
+#[tokio::test]
+async fn completing_all_subtasks_marks_parent_done() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let root = create_todo_as(&app, &token, "Root").await;
+    let first = create_subtask(&app, &token, "First", &root).await;
+    let second = create_subtask(&app, &token, "Second", &root).await;
+
+    for (child, expected) in [(first, "new"), (second, "done")] {
+        send_json_as(
+            &app,
+            &token,
+            Method::PATCH,
+            &format!("/v1/todo/{child}"),
+            json!({ "statusCode": "done" }),
+        )
+        .await;
+        let (_, json) = send_as(&app, &token, Method::GET, &format!("/v1/todo/{root}")).await;
+        assert_eq!(json["data"]["todoView"]["status"]["code"], expected);
+    }
+}
+
+// ─── ETag / If-Match ──────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn update_todo_with_stale_if_match_returns_precondition_failed() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let id = create_todo_as(&app, &token, "Versioned").await;
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/v1/todo/{id}"))
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    let etag = resp.headers()[header::ETAG].to_str().unwrap().to_string();
+    assert_eq!(etag, "\"1\"");
+
+    let patch = |if_match: &str, title: &str| {
+        Request::builder()
+            .method(Method::PATCH)
+            .uri(format!("/v1/todo/{id}"))
+            .header(header::AUTHORIZATION, format!("Bearer {token}"))
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::IF_MATCH, if_match)
+            .body(Body::from(json!({ "title": title }).to_string()))
+            .unwrap()
+    };
+    let resp = app.clone().oneshot(patch(&etag, "First")).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.headers()[header::ETAG], "\"2\"");
+    let json = body_json(resp.into_body()).await;
+    assert_eq!(json["data"]["todoView"]["version"], 2);
+
+    let resp = app.clone().oneshot(patch(&etag, "Second")).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::PRECONDITION_FAILED);
+    let resp = app.clone().oneshot(patch("W/\"2\"", "Weak")).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::PRECONDITION_FAILED);
+
+    let req = Request::builder()
+        .method(Method::DELETE)
+        .uri(format!("/v1/todo/{id}"))
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(header::IF_MATCH, &etag)
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::PRECONDITION_FAILED);
+
+    let json = get_todo_as(&app, &token, &id).await;
+    assert_eq!(json["data"]["todoView"]["title"], "First");
+    assert_eq!(json["data"]["todoView"]["status"]["code"], "new");
+}
+
+// ─── Merge Patch / JSON Patch ────────────────────────────────────────────────
+
+async fn patch_todo_as(
+    app: &axum::Router,
+    token: &str,
+    id: &str,
+    content_type: &str,
+    body: Value,
+) -> (StatusCode, Value) {
+    let req = Request::builder()
+        .method(Method::PATCH)
+        .uri(format!("/v1/todo/{id}"))
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(header::CONTENT_TYPE, content_type)
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    let status = resp.status();
+    (status, body_json(resp.into_body()).await)
+}
+
+#[tokio::test]
+async fn update_todo_with_merge_patch_clears_null_fields() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let id = create_todo_as(&app, &token, "Merge").await;
+
+    let due_at = json!({ "dueAt": "2030-05-01T09:00:00Z" });
+    let (_, json) = patch_todo_as(&app, &token, &id, "application/json", due_at).await;
+    assert_eq!(
+        json["data"]["todoView"]["dueAt"],
+        "2030-05-01T09:00:00+00:00"
+    );
+
+    let keep = json!({ "dueAt": null });
+    let (_, json) = patch_todo_as(&app, &token, &id, "application/json", keep).await;
+    assert_eq!(
+        json["data"]["todoView"]["dueAt"],
+        "2030-05-01T09:00:00+00:00"
+    );
+
+    let (status, json) = patch_todo_as(
+        &app,
+        &token,
+        &id,
+        "application/merge-patch+json",
+        json!({ "dueAt": null, "title": "Merged" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["data"]["todoView"]["title"], "Merged");
+    assert!(json["data"]["todoView"].get("dueAt").is_none());
+
+    let (_, json) = patch_todo_as(
+        &app,
+        &token,
+        &id,
+        "application/merge-patch+json",
+        json!({ "title": null }),
+    )
+    .await;
+    assert_eq!(json["result"], false);
+}
+
+#[tokio::test]
+async fn update_todo_with_json_patch_applies_operations_after_test() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let id = create_todo_as(&app, &token, "Operations").await;
+
+    let (status, json) = patch_todo_as(
+        &app,
+        &token,
+        &id,
+        "application/json-patch+json",
+        json!([
+            { "op": "test", "path": "/title", "value": "Operations" },
+            { "op": "replace", "path": "/priority", "value": "high" },
+            { "op": "remove", "path": "/description" }
+        ]),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["data"]["todoView"]["priority"], "high");
+    assert_eq!(json["data"]["todoView"]["description"], "");
+    assert_eq!(json["data"]["todoView"]["version"], 2);
+
+    let (status, _) = patch_todo_as(
+        &app,
+        &token,
+        &id,
+        "application/json-patch+json",
+        json!([
+            { "op": "test", "path": "/priority", "value": "low" },
+            { "op": "replace", "path": "/title", "value": "Ignored" }
+        ]),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _) = patch_todo_as(
+        &app,
+        &token,
+        &id,
+        "application/json-patch+json",
+        json!([{ "op": "move", "from": "/title", "path": "/description" }]),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let json = get_todo_as(&app, &token, &id).await;
+    assert_eq!(json["data"]["todoView"]["title"], "Operations");
+}
+
+// ─── batch ───────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn todo_batch_applies_all_operations_or_rolls_back_with_failed_index() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let id = create_todo_as(&app, &token, "Batch Target").await;
+
+    let (status, json) = send_json_as(
+        &app,
+        &token,
+        Method::POST,
+        "/v1/todo/batch",
+        json!({ "operations": [
+            { "op": "create", "title": "Batch Created", "description": "desc" },
+            { "op": "update", "id": id, "version": 1, "title": "Batch Renamed" }
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let results = json["data"]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0]["title"], "Batch Created");
+    assert_eq!(results[1]["title"], "Batch Renamed");
+    assert_eq!(results[1]["version"], 2);
+
+    let (status, json) = send_json_as(
+        &app,
+        &token,
+        Method::POST,
+        "/v1/todo/batch",
+        json!({ "operations": [
+            { "op": "delete", "id": id },
+            { "op": "update", "id": id, "version": 2, "title": "Stale" }
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+    assert_eq!(json["result"], false);
+    assert_eq!(json["data"]["failedIndex"], 1);
+
+    let json = get_todo_as(&app, &token, &id).await;
+    assert_eq!(json["data"]["todoView"]["title"], "Batch Renamed");
+    assert_eq!(json["data"]["todoView"]["status"]["code"], "new");
+
+    let (status, json) = send_json_as(
+        &app,
+        &token,
+        Method::POST,
+        "/v1/todo/batch",
+        json!({ "operations": [
+            { "op": "delete", "id": id },
+            { "op": "create", "title": "", "description": "desc" }
+        ] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["data"]["failedIndex"], 1);
+}
+
+// ─── history ─────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn get_todo_history_lists_changes_newest_first() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let other = create_user_and_login(&app, &unique_email()).await;
+    let todo = create_todo_as(&app, &token, "Before").await;
+
+    for body in [
+        json!({ "title": "After" }),
+        json!({ "statusCode": "working" }),
+    ] {
+        let (status, _) = send_json_as(
+            &app,
+            &token,
+            Method::PATCH,
+            &format!("/v1/todo/{todo}"),
+            body,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let uri = format!("/v1/todo/{todo}/history");
+    let (status, json) = send_as(&app, &token, Method::GET, &format!("{uri}?limit=1")).await;
+    assert_eq!(status, StatusCode::OK);
+    let history = &json["data"]["historyView"];
+    assert_eq!(history["events"][0]["changes"][0]["field"], "status");
+    assert_eq!(history["events"][0]["changes"][0]["before"], "new");
+    assert_eq!(history["events"][0]["changes"][0]["after"], "working");
+    assert_eq!(history["hasMore"], true);
+
+    let cursor = history["nextCursor"].as_str().unwrap();
+    let (_, json) = send_as(
+        &app,
+        &token,
+        Method::GET,
+        &format!("{uri}?limit=1&cursor={cursor}"),
+    )
+    .await;
+    let change = &json["data"]["historyView"]["events"][0]["changes"][0];
+    assert_eq!(change["field"], "title");
+    assert_eq!(change["before"], "Before");
+    assert_eq!(change["after"], "After");
+
+    let (_, json) = send_as(&app, &other, Method::GET, &uri).await;
+    assert_eq!(json["result"], false);
+}
+
+// ─── comment ─────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn comment_crud_and_pagination() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let todo = create_todo_as(&app, &token, "Discussed").await;
+    let uri = format!("/v1/todo/{todo}/comments");
+
+    let mut ids = vec![];
+    for body in ["first", "second"] {
+        let (status, json) =
+            send_json_as(&app, &token, Method::POST, &uri, json!({ "body": body })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["data"]["commentView"]["body"], body);
+        assert_eq!(json["data"]["commentView"]["todoId"], todo.as_str());
+        ids.push(
+            json["data"]["commentView"]["id"]
+                .as_str()
+                .unwrap()
+                .to_string(),
+        );
+    }
+
+    let (_, json) = send_as(&app, &token, Method::GET, &format!("{uri}?limit=1")).await;
+    let page = &json["data"]["commentView"];
+    assert_eq!(page["comments"][0]["id"], ids[0].as_str());
+    assert_eq!(page["hasMore"], true);
+    let cursor = page["nextCursor"].as_str().unwrap();
+    let (_, json) = send_as(
+        &app,
+        &token,
+        Method::GET,
+        &format!("{uri}?limit=1&cursor={cursor}"),
+    )
+    .await;
+    assert_eq!(
+        json["data"]["commentView"]["comments"][0]["id"],
+        ids[1].as_str()
+    );
+    assert_eq!(json["data"]["commentView"]["hasMore"], false);
+
+    let (status, json) = send_json_as(
+        &app,
+        &token,
+        Method::PATCH,
+        &format!("{uri}/{}", ids[0]),
+        json!({ "body": "edited" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["data"]["commentView"]["body"], "edited");
+
+    let (status, _) = send_as(&app, &token, Method::DELETE, &format!("{uri}/{}", ids[1])).await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, json) = send_as(&app, &token, Method::GET, &uri).await;
+    assert_eq!(
+        json["data"]["commentView"]["comments"]
+            .as_array()
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn comment_on_other_users_todo_returns_error_result() {
+    let app = common::build_test_app().await;
+    let owner = create_user_and_login(&app, &unique_email()).await;
+    let other = create_user_and_login(&app, &unique_email()).await;
+    let todo = create_todo_as(&app, &owner, "Private").await;
+
+    let (_, json) = send_json_as(
+        &app,
+        &other,
+        Method::POST,
+        &format!("/v1/todo/{todo}/comments"),
+        json!({ "body": "hello" }),
+    )
+    .await;
+    assert_eq!(json["result"], false);
+}
+
+// ─── attachment ──────────────────────────────────────────────────────────────
+
+const MULTIPART_BOUNDARY: &str = "test-attachment-boundary";
+
+/// `file` part 하나를 담은 multipart 요청을 보낸다.
+async fn upload_as(
+    app: &axum::Router,
+    token: &str,
+    todo_id: &str,
+    file_name: &str,
+    content_type: &str,
+    content: &[u8],
+) -> (StatusCode, Value) {
+    let mut body = format!(
+        "--{MULTIPART_BOUNDARY}\r\n\
+         Content-Disposition: form-data; name=\"file\"; filename=\"{file_name}\"\r\n\
+         Content-Type: {content_type}\r\n\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(content);
+    body.extend_from_slice(format!("\r\n--{MULTIPART_BOUNDARY}--\r\n").as_bytes());
+    let req = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/v1/todo/{todo_id}/attachments"))
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(
+            header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={MULTIPART_BOUNDARY}"),
+        )
+        .body(Body::from(body))
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    let status = resp.status();
+    (status, body_json(resp.into_body()).await)
+}
+
+#[tokio::test]
+async fn attachment_upload_download_and_delete() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let todo = create_todo_as(&app, &token, "With file").await;
+
+    let (status, json) = upload_as(&app, &token, &todo, "memo.txt", "text/plain", b"hello").await;
+    assert_eq!(status, StatusCode::OK);
+    let view = &json["data"]["attachmentView"];
+    assert_eq!(view["fileName"], "memo.txt");
+    assert_eq!(view["sizeBytes"], 5);
+    let id = view["id"].as_str().unwrap().to_string();
+    let uri = format!("/v1/todo/{todo}/attachments/{id}");
+
+    let (_, json) = send_as(
+        &app,
+        &token,
+        Method::GET,
+        &format!("/v1/todo/{todo}/attachments"),
+    )
+    .await;
+    assert_eq!(json["data"]["attachments"][0]["id"], id.as_str());
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri(&uri)
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.headers()[header::CONTENT_TYPE], "text/plain");
+    assert!(resp.headers()[header::CONTENT_DISPOSITION]
+        .to_str()
+        .unwrap()
+        .contains("filename=\"memo.txt\""));
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(&bytes[..], b"hello");
+
+    let (status, _) = send_as(&app, &token, Method::DELETE, &uri).await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, json) = send_as(&app, &token, Method::GET, &uri).await;
+    assert_eq!(json["result"], false);
+}
+
+#[tokio::test]
+async fn attachment_upload_over_limit_or_disallowed_type_returns_error_result() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let todo = create_todo_as(&app, &token, "Limits").await;
+
+    let too_big = vec![b'a'; common::TEST_ATTACHMENT_MAX_BYTES as usize + 1];
+    let (_, json) = upload_as(&app, &token, &todo, "big.txt", "text/plain", &too_big).await;
+    assert_eq!(json["result"], false);
+
+    let (_, json) = upload_as(
+        &app,
+        &token,
+        &todo,
+        "run.exe",
+        "application/x-msdownload",
+        b"MZ",
+    )
+    .await;
+    assert_eq!(json["result"], false);
+
+    let (_, json) = send_as(
+        &app,
+        &token,
+        Method::GET,
+        &format!("/v1/todo/{todo}/attachments"),
+    )
+    .await;
+    assert!(json["data"]["attachments"].as_array().unwrap().is_empty());
+}
+
+// ─── recurrence ──────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn recurring_todo_previews_occurrences_and_rolls_over_on_completion() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+
+    let (_, json) = send_json_as(
+        &app,
+        &token,
+        Method::POST,
+        "/v1/todo",
+        json!({
+            "title": "Water plants",
+            "description": "desc",
+            "dueAt": "2030-01-01T08:00:00Z",
+            "recurrence": "FREQ=DAILY;INTERVAL=2"
+        }),
+    )
+    .await;
+    assert_eq!(json["result"], true);
+    assert_eq!(
+        json["data"]["todoView"]["recurrence"],
+        "FREQ=DAILY;INTERVAL=2"
+    );
+    let id = json["data"]["todoView"]["id"].as_str().unwrap().to_string();
+
+    let (status, json) = send_as(
+        &app,
+        &token,
+        Method::GET,
+        &format!("/v1/todo/{id}/occurrences?limit=2"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        json["data"]["occurrencesView"]["occurrences"],
+        json!(["2030-01-03T08:00:00+00:00", "2030-01-05T08:00:00+00:00"])
+    );
+
+    let (_, json) = send_json_as(
+        &app,
+        &token,
+        Method::PATCH,
+        &format!("/v1/todo/{id}"),
+        json!({ "statusCode": "done" }),
+    )
+    .await;
+    assert!(json["data"]["todoView"].get("recurrence").is_none());
+
+    let (_, json) = send_as(&app, &token, Method::GET, "/v1/todo").await;
+    let next = json["data"]["todoView"]["todos"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["title"] == "Water plants" && t["id"] != id.as_str())
+        .expect("next occurrence must be created")
+        .clone();
+    assert_eq!(next["dueAt"], "2030-01-03T08:00:00+00:00");
+    assert_eq!(next["recurrence"], "FREQ=DAILY;INTERVAL=2");
+
+    let next_id = next["id"].as_str().unwrap();
+    let (status, json) = send_as(
+        &app,
+        &token,
+        Method::DELETE,
+        &format!("/v1/todo/{next_id}/recurrence"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(json["data"]["todoView"].get("recurrence").is_none());
+}
+
+#[tokio::test]
+async fn create_todo_with_invalid_recurrence_returns_error_result() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+
+    let (status, json) = send_json_as(
+        &app,
+        &token,
+        Method::POST,
+        "/v1/todo",
+        json!({ "title": "Bad", "description": "desc", "recurrence": "FREQ=SECONDLY" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["result"], false);
+}
+
+// ─── reminder ────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn snooze_todo_postpones_reminder() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+
+    let (_, json) = send_json_as(
+        &app,
+        &token,
+        Method::POST,
+        "/v1/todo",
+        json!({ "title": "Call mom", "description": "desc", "remindAt": "2030-01-01T09:00:00Z" }),
+    )
+    .await;
+    assert_eq!(
+        json["data"]["todoView"]["remindAt"],
+        "2030-01-01T09:00:00+00:00"
+    );
+    let id = json["data"]["todoView"]["id"].as_str().unwrap().to_string();
+
+    let (status, json) = send_json_as(
+        &app,
+        &token,
+        Method::POST,
+        &format!("/v1/todo/{id}/snooze"),
+        json!({ "minutes": 30 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let remind_at = json["data"]["todoView"]["remindAt"].as_str().unwrap();
+    let remind_at = chrono::DateTime::parse_from_rfc3339(remind_at).unwrap();
+    assert!(remind_at > chrono::Utc::now() + chrono::Duration::minutes(29));
+
+    let (status, json) = send_json_as(
+        &app,
+        &token,
+        Method::POST,
+        &format!("/v1/todo/{id}/snooze"),
+        json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["result"], false);
+}
+
+// ─── project ─────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn archive_project_hides_its_todos_from_default_list() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+
+    let (status, json) = send_json_as(
+        &app,
+        &token,
+        Method::POST,
+        "/v1/project",
+        json!({ "name": "trip", "color": "#00AA00" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["data"]["projectView"]["color"], "#00aa00");
+    let project_id = json["data"]["projectView"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let json = create_todo_with_body(
+        &app,
+        &token,
+        json!({ "title": "Pack", "description": "desc", "projectId": project_id }),
+    )
+    .await;
+    assert_eq!(json["data"]["todoView"]["projectId"], project_id.as_str());
+
+    let (status, json) = send_as(
+        &app,
+        &token,
+        Method::POST,
+        &format!("/v1/project/{project_id}/archive"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["data"]["projectView"]["archived"], true);
+
+    let (_, json) = send_as(&app, &token, Method::GET, "/v1/todo").await;
+    assert!(
+        json["data"]["todoView"]["todos"]
+            .as_array()
+            .unwrap()
+            .is_empty(),
+        "archived todo must be hidden: {json}"
+    );
+
+    let (_, json) = send_as(
+        &app,
+        &token,
+        Method::GET,
+        &format!("/v1/project/{project_id}/todos"),
+    )
+    .await;
+    let todos = json["data"]["todoView"]["todos"].as_array().unwrap();
+    assert_eq!(todos.len(), 1);
+    assert!(todos[0]["archivedAt"].is_string());
+
+    let (_, json) = send_as(
+        &app,
+        &token,
+        Method::GET,
+        "/v1/project?includeArchived=true",
+    )
+    .await;
+    assert_eq!(json["data"]["projects"].as_array().unwrap().len(), 1);
+}
+
+// ─── share ───────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn shared_todo_is_editable_but_only_owner_can_delete_or_reshare() {
+    let app = common::build_test_app().await;
+    let owner_email = unique_email();
+    let guest_email = unique_email();
+    let owner_token = create_user_and_login(&app, &owner_email).await;
+    let guest_token = create_user_and_login(&app, &guest_email).await;
+
+    let json = create_todo_with_body(
+        &app,
+        &owner_token,
+        json!({ "title": "Shared", "description": "desc" }),
+    )
+    .await;
+    let id = json["data"]["todoView"]["id"].as_str().unwrap().to_string();
+
+    let (status, json) = send_json_as(
+        &app,
+        &owner_token,
+        Method::POST,
+        &format!("/v1/todo/{id}/shares"),
+        json!({ "username": guest_email, "permission": "write" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{json}");
+    assert_eq!(json["data"]["shareView"]["permission"], "write");
+
+    let (status, json) = send_as(&app, &guest_token, Method::GET, &format!("/v1/todo/{id}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["data"]["todoView"]["title"], "Shared");
+
+    let (status, json) = send_json_as(
+        &app,
+        &guest_token,
+        Method::PATCH,
+        &format!("/v1/todo/{id}"),
+        json!({ "title": "Shared edit" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{json}");
+    assert_eq!(json["data"]["todoView"]["title"], "Shared edit");
+
+    let (status, _) = send_as(
+        &app,
+        &guest_token,
+        Method::DELETE,
+        &format!("/v1/todo/{id}"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = send_json_as(
+        &app,
+        &guest_token,
+        Method::POST,
+        &format!("/v1/todo/{id}/shares"),
+        json!({ "username": owner_email, "permission": "read" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, json) = send_as(
+        &app,
+        &owner_token,
+        Method::DELETE,
+        &format!("/v1/todo/{id}/shares/{guest_email}"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{json}");
+    let (_, json) = send_as(
+        &app,
+        &owner_token,
+        Method::GET,
+        &format!("/v1/todo/{id}/shares"),
+    )
+    .await;
+    assert!(json["data"]["shareViews"].as_array().unwrap().is_empty());
+}
+
+// ─── export / import ─────────────────────────────────────────────────────────
+
+/// 인증된 사용자로 본문을 그대로 보내 todo 를 가져온다.
+async fn import_as(app: &axum::Router, token: &str, format: &str, body: &str) -> Value {
+    let req = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/v1/todo/import?format={format}"))
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    body_json(resp.into_body()).await
+}
+
+/// `Accept-Language` 를 붙여 본문 바이트를 그대로 보내 todo 를 가져온다.
+async fn import_in(
+    app: &axum::Router,
+    token: &str,
+    language: &str,
+    format: &str,
+    body: Vec<u8>,
+) -> Value {
+    let req = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/v1/todo/import?format={format}"))
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(header::ACCEPT_LANGUAGE, language)
+        .body(Body::from(body))
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    body_json(resp.into_body()).await
+}
+
+/// 내보내기 응답의 헤더와 본문 문자열을 돌려준다.
+async fn export_as(
+    app: &axum::Router,
+    token: &str,
+    format: &str,
+) -> (axum::http::HeaderMap, String) {
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri(format!("/v1/todo/export?format={format}"))
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let headers = resp.headers().clone();
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    (headers, String::from_utf8(bytes.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn import_csv_reports_invalid_rows_and_export_returns_the_rest() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+
+    let csv = "title,description,statusCode,priority\r\n\
+               Buy milk,\"2L, low fat\",,high\r\n\
+               ,no title,,\r\n\
+               Read book,,done,someday\r\n\
+               Walk dog,,done,\r\n";
+    let json = import_as(&app, &token, "csv", csv).await;
+    assert_eq!(json["result"], true, "{json}");
+    let view = &json["data"]["importView"];
+    assert_eq!(view["imported"], 2);
+    assert_eq!(view["todos"][0]["description"], "2L, low fat");
+    assert_eq!(view["todos"][1]["status"]["code"], "done");
+    assert_eq!(view["errors"][0]["row"], 2);
+    assert_eq!(view["errors"][1]["row"], 3);
+
+    let (headers, body) = export_as(&app, &token, "csv").await;
+    assert!(headers[header::CONTENT_TYPE]
+        .to_str()
+        .unwrap()
+        .starts_with("text/csv"));
+    assert!(headers[header::CONTENT_DISPOSITION]
+        .to_str()
+        .unwrap()
+        .contains("filename=\"todos.csv\""));
+    let lines: Vec<&str> = body.lines().collect();
+    assert!(lines[0].starts_with("id,title,description,statusCode"));
+    assert_eq!(lines.len(), 3);
+    assert!(body.contains("\"2L, low fat\""));
+
+    let json = import_as(&app, &token, "csv", "title\r\n\"unterminated\r\n").await;
+    assert_eq!(json["result"], false);
+}
+
+#[tokio::test]
+async fn import_errors_follow_accept_language() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+
+    let json = import_in(&app, &token, "en", "json", vec![0xff, 0xfe]).await;
+    assert_eq!(json["result"], false);
+    assert_eq!(json["code"], "import_not_utf8");
+    assert_eq!(json["message"], "The file must be UTF-8 text.");
+    let json = import_in(&app, &token, "ko", "csv", b"name\r\nBuy milk\r\n".to_vec()).await;
+    assert_eq!(json["code"], "import_csv_no_title");
+    assert_eq!(
+        json["message"],
+        "CSV 는 `title` 열이 있는 머리 행으로 시작해야 합니다"
+    );
+    let json = import_in(&app, &token, "en", "ics", b"title\r\n".to_vec()).await;
+    assert_eq!(json["code"], "import_not_calendar");
+
+    let rows = json!([
+        { "title": "kept", "description": "" },
+        "not an object",
+        { "title": "unknown status", "description": "", "statusCode": "no_such_status" },
+    ]);
+    let json = import_in(&app, &token, "en", "json", rows.to_string().into_bytes()).await;
+    assert_eq!(json["result"], true, "{json}");
+    let errors = &json["data"]["importView"]["errors"];
+    assert_eq!(errors[0]["row"], 2);
+    assert!(errors[0]["messages"][0]
+        .as_str()
+        .unwrap()
+        .starts_with("The row could not be read: "));
+    assert_eq!(errors[1]["row"], 3);
+    assert_eq!(errors[1]["messages"][0], "`statusCode` is invalid.");
+
+    let json = import_in(&app, &token, "ko", "json", rows.to_string().into_bytes()).await;
+    let errors = &json["data"]["importView"]["errors"];
+    assert!(errors[0]["messages"][0]
+        .as_str()
+        .unwrap()
+        .starts_with("행을 읽을 수 없습니다: "));
+    assert_eq!(
+        errors[1]["messages"][0],
+        "`statusCode` 값이 올바르지 않습니다"
+    );
+}
+
+#[tokio::test]
+async fn export_ics_can_be_imported_again() {
+    let app = common::build_test_app().await;
+    let source = create_user_and_login(&app, &unique_email()).await;
+    let target = create_user_and_login(&app, &unique_email()).await;
+
+    create_todo_with_body(
+        &app,
+        &source,
+        json!({
+            "title": "Dentist; checkup",
+            "description": "bring card",
+            "priority": "urgent",
+            "dueAt": "2030-03-01T09:00:00Z",
+        }),
+    )
+    .await;
+
+    let (headers, ics) = export_as(&app, &source, "ics").await;
+    assert!(headers[header::CONTENT_TYPE]
+        .to_str()
+        .unwrap()
+        .starts_with("text/calendar"));
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.contains("SUMMARY:Dentist\\; checkup\r\n"));
+
+    let json = import_as(&app, &target, "ics", &ics).await;
+    let todo = &json["data"]["importView"]["todos"][0];
+    assert_eq!(todo["title"], "Dentist; checkup");
+    assert_eq!(todo["priority"], "urgent");
+    assert_eq!(todo["dueAt"], "2030-03-01T09:00:00+00:00");
+
+    let (_, body) = export_as(&app, &target, "json").await;
+    let exported: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(exported.as_array().unwrap().len(), 1);
+    assert_eq!(exported[0]["description"], "bring card");
+}
+
+// ─── stats ───────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn todo_stats_counts_statuses_and_is_cacheable() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+
+    create_todo_with_body(
+        &app,
+        &token,
+        json!({ "title": "Late", "description": "", "dueAt": "2020-01-01T00:00:00Z" }),
+    )
+    .await;
+    let json =
+        create_todo_with_body(&app, &token, json!({ "title": "Done", "description": "" })).await;
+    let id = json["data"]["todoView"]["id"].as_str().unwrap().to_string();
+    let (status, json) = send_json_as(
+        &app,
+        &token,
+        Method::PATCH,
+        &format!("/v1/todo/{id}"),
+        json!({ "statusCode": "done" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{json}");
+
+    let req = Request::builder()
+        .method(Method::GET)
+        .uri("/v1/todo/stats?interval=week")
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.headers()[header::CACHE_CONTROL], "private, max-age=60");
+    let json = body_json(resp.into_body()).await;
+    let stats = &json["data"]["statsView"];
+    assert_eq!(stats["interval"], "week");
+    let counts = stats["statusCounts"].as_array().unwrap();
+    assert!(counts.len() >= 7);
+    assert_eq!(counts[0], json!({ "code": "new", "count": 1 }));
+    assert_eq!(counts[3], json!({ "code": "done", "count": 1 }));
+    let buckets = stats["buckets"].as_array().unwrap();
+    let created: i64 = buckets.iter().map(|b| b["created"].as_i64().unwrap()).sum();
+    let completed: i64 = buckets
+        .iter()
+        .map(|b| b["completed"].as_i64().unwrap())
+        .sum();
+    assert_eq!((created, completed), (2, 1));
+    assert!(stats["averageCompletionSeconds"].as_f64().is_some());
+    assert_eq!(stats["overdue"], 1);
+
+    let (status, json) = send_as(&app, &token, Method::GET, "/v1/todo/stats?from=2026-13-01").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["result"], false);
+}
+
+// ─── todo status catalog ─────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn todo_status_catalog_is_managed_by_admins_only() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let admin_token = create_user_and_login(&app, common::ADMIN_USERNAME).await;
+
+    let (status, json) = send_as(&app, &token, Method::GET, "/v1/todo-status").await;
+    assert_eq!(status, StatusCode::OK);
+    let statuses = json["data"]["statuses"].as_array().unwrap();
+    assert_eq!(
+        statuses[0],
+        json!({ "code": "new", "name": "신규", "builtin": true, "enabled": true })
+    );
+
+    let body = json!({ "code": "api_review", "name": "검토" });
+    let (status, _) =
+        send_json_as(&app, &token, Method::POST, "/v1/todo-status", body.clone()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, json) = send_json_as(
+        &app,
+        &admin_token,
+        Method::POST,
+        "/v1/todo-status",
+        body.clone(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{json}");
+    assert_eq!(json["data"]["statusView"]["builtin"], false);
+    let (status, _) = send_json_as(&app, &admin_token, Method::POST, "/v1/todo-status", body).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, json) = send_json_as(
+        &app,
+        &admin_token,
+        Method::PATCH,
+        "/v1/todo-status/api_review",
+        json!({ "name": "리뷰" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{json}");
+    assert_eq!(json["data"]["statusView"]["name"], "리뷰");
+
+    let todo = create_todo_with_body(
+        &app,
+        &token,
+        json!({ "title": "Review", "description": "" }),
+    )
+    .await;
+    let id = todo["data"]["todoView"]["id"].as_str().unwrap().to_string();
+    let (status, json) = send_json_as(
+        &app,
+        &token,
+        Method::PATCH,
+        &format!("/v1/todo/{id}"),
+        json!({ "statusCode": "api_review" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        json["data"]["todoView"]["status"]["code"], "api_review",
+        "{json}"
+    );
+
+    let (status, _) = send_as(
+        &app,
+        &token,
+        Method::POST,
+        "/v1/todo-status/api_review/disable",
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send_as(
+        &app,
+        &admin_token,
+        Method::POST,
+        "/v1/todo-status/done/disable",
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, json) = send_as(
+        &app,
+        &admin_token,
+        Method::POST,
+        "/v1/todo-status/api_review/disable",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["data"]["statusView"]["enabled"], false);
+
+    let other =
+        create_todo_with_body(&app, &token, json!({ "title": "Other", "description": "" })).await;
+    let other_id = other["data"]["todoView"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let (status, json) = send_json_as(
+        &app,
+        &token,
+        Method::PATCH,
+        &format!("/v1/todo/{other_id}"),
+        json!({ "statusCode": "api_review" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        json["result"], false,
+        "disabled status must not be assignable"
+    );
+
+    let (status, json) = send_as(
+        &app,
+        &admin_token,
+        Method::POST,
+        "/v1/todo-status/api_review/enable",
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["data"]["statusView"]["enabled"], true);
+}
+
+// ─── localization ────────────────────────────────────────────────────────────
+
+/// `Accept-Language` 를 붙여 보낸다. `token` 이 없으면 인증 없이 보낸다.
+async fn send_in(
+    app: &axum::Router,
+    token: Option<&str>,
+    language: &str,
+    method: Method,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, http::HeaderMap, Value) {
+    let mut req = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header(header::ACCEPT_LANGUAGE, language);
+    if let Some(token) = token {
+        req = req.header(header::AUTHORIZATION, format!("Bearer {token}"));
+    }
+    let req = match body {
+        Some(body) => req
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string())),
+        None => req.body(Body::empty()),
+    }
+    .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    let status = resp.status();
+    let headers = resp.headers().clone();
+    (status, headers, body_json(resp.into_body()).await)
+}
+
+#[tokio::test]
+async fn error_messages_follow_accept_language_with_stable_codes() {
+    let app = common::build_test_app().await;
+
+    let (status, headers, json) =
+        send_in(&app, None, "en-US,en;q=0.9", Method::GET, "/v1/todo", None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(json["code"], "unauthorized");
+    assert_eq!(json["message"], "Authentication is required.");
+    assert!(headers
+        .get_all(header::VARY)
+        .iter()
+        .any(|v| v == "accept-language"));
+
+    let (_, _, json) = send_in(&app, None, "ko", Method::GET, "/v1/todo", None).await;
+    assert_eq!(json["code"], "unauthorized");
+    assert_eq!(json["message"], "인증이 필요합니다");
+
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let body = json!({ "title": "", "description": "" });
+    let (status, _, json) = send_in(
+        &app,
+        Some(&token),
+        "en",
+        Method::POST,
+        "/v1/todo",
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["code"], "validation_failed");
+    assert_eq!(json["message"], "`title` is empty.");
+    let (_, _, json) = send_in(
+        &app,
+        Some(&token),
+        "ko",
+        Method::POST,
+        "/v1/todo",
+        Some(body),
+    )
+    .await;
+    assert_eq!(json["message"], "`title` 값이 비어 있습니다");
+}
+
+#[tokio::test]
+async fn conflict_reasons_follow_accept_language_with_specific_codes() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let body = json!({ "name": "work" });
+    let (status, _, _) = send_in(
+        &app,
+        Some(&token),
+        "en",
+        Method::POST,
+        "/v1/tag",
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _, json) = send_in(
+        &app,
+        Some(&token),
+        "en",
+        Method::POST,
+        "/v1/tag",
+        Some(body.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(json["code"], "tag_name_taken");
+    assert_eq!(json["message"], "A tag named `work` already exists.");
+    let (_, _, json) = send_in(
+        &app,
+        Some(&token),
+        "ko",
+        Method::POST,
+        "/v1/tag",
+        Some(body),
+    )
+    .await;
+    assert_eq!(json["code"], "tag_name_taken");
+    assert_eq!(json["message"], "`work` 태그가 이미 있습니다");
+}
+
+#[tokio::test]
+async fn status_names_follow_user_locale_over_accept_language() {
+    let app = common::build_test_app().await;
+    let email = unique_email();
+    let token = create_user_and_login(&app, &email).await;
+
+    let (_, _, json) = send_in(
+        &app,
+        Some(&token),
+        "en",
+        Method::GET,
+        "/v1/todo/statuses",
+        None,
+    )
+    .await;
+    let done = json["data"]["statuses"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|s| s["code"] == "done")
+        .unwrap()
+        .clone();
+    assert_eq!(done["name"], "Done");
+
+    let (_, _, json) = send_in(
+        &app,
+        Some(&token),
+        "ko",
+        Method::GET,
+        &format!("/v1/user?username={email}"),
+        None,
+    )
+    .await;
+    let user_id = json["data"]["userView"]["id"].as_str().unwrap().to_string();
+    let (status, _, json) = send_in(
+        &app,
+        Some(&token),
+        "ko",
+        Method::PUT,
+        &format!("/v1/user/{user_id}/locale"),
+        Some(json!({ "locale": "fr" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["code"], "invalid_parameter");
+    let (_, _, json) = send_in(
+        &app,
+        Some(&token),
+        "ko",
+        Method::PUT,
+        &format!("/v1/user/{user_id}/locale"),
+        Some(json!({ "locale": "en-GB" })),
+    )
+    .await;
+    assert_eq!(json["data"]["userView"]["locale"], "en", "{json}");
+
+    // 사용자가 고른 언어가 Accept-Language 보다 앞선다.
+    let todo =
+        create_todo_with_body(&app, &token, json!({ "title": "Hello", "description": "" })).await;
+    let id = todo["data"]["todoView"]["id"].as_str().unwrap().to_string();
+    let (_, _, json) = send_in(
+        &app,
+        Some(&token),
+        "ko",
+        Method::GET,
+        &format!("/v1/todo/{id}"),
+        None,
+    )
+    .await;
+    assert_eq!(json["data"]["todoView"]["status"]["name"], "New");
+}
+
+// ─── manual ordering ─────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn move_todo_reorders_todos_listed_by_position() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+
+    let mut ids = vec![];
+    let mut versions = vec![];
+    for title in ["a", "b", "c"] {
+        let json =
+            create_todo_with_body(&app, &token, json!({ "title": title, "description": "" })).await;
+        ids.push(json["data"]["todoView"]["id"].as_str().unwrap().to_string());
+        versions.push(json["data"]["todoView"]["version"].as_i64().unwrap());
+    }
+
+    let (status, json) = send_json_as(
+        &app,
+        &token,
+        Method::POST,
+        &format!("/v1/todo/{}/move", ids[2]),
+        json!({ "after": ids[0] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["result"], true, "{json}");
+    assert!(json["data"]["todoView"]["position"].is_string());
+    assert_eq!(json["data"]["todoView"]["version"], versions[2] + 1);
+
+    let (_, json) = send_as(&app, &token, Method::GET, "/v1/todo?sort=position").await;
+    let titles: Vec<&str> = json["data"]["todoView"]["todos"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["title"].as_str().unwrap())
+        .collect();
+    assert_eq!(titles, vec!["a", "c", "b"]);
+
+    let (_, json) = send_json_as(
+        &app,
+        &token,
+        Method::POST,
+        &format!("/v1/todo/{}/move", ids[2]),
+        json!({}),
+    )
+    .await;
+    assert_eq!(json["result"], false);
+    assert_eq!(json["code"], "invalid_parameter");
+}
+
+// ─── idempotency key ─────────────────────────────────────────────────────────
+
+/// `Idempotency-Key` 를 붙여 POST 한다.
+async fn post_with_idempotency_key(
+    app: &axum::Router,
+    token: &str,
+    uri: &str,
+    key: &str,
+    body: Value,
+) -> (StatusCode, http::HeaderMap, Value) {
+    let req = Request::builder()
+        .method(Method::POST)
+        .uri(uri)
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(header::CONTENT_TYPE, "application/json")
+        .header("idempotency-key", key)
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let resp = app.clone().oneshot(req).await.unwrap();
+    let status = resp.status();
+    let headers = resp.headers().clone();
+    (status, headers, body_json(resp.into_body()).await)
+}
+
+#[tokio::test]
+async fn create_todo_with_same_idempotency_key_replays_first_response() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let body = json!({ "title": "Pay rent", "description": "" });
+
+    let (status, headers, first) =
+        post_with_idempotency_key(&app, &token, "/v1/todo", "rent-1", body.clone()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(first["result"], true, "{first}");
+    assert!(headers.get("idempotent-replayed").is_none());
+
+    let (status, headers, replayed) =
+        post_with_idempotency_key(&app, &token, "/v1/todo", "rent-1", body.clone()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["idempotent-replayed"], "true");
+    assert_eq!(headers[header::ETAG], "\"1\"");
+    assert_eq!(replayed, first);
+
+    let (_, json) = send_as(&app, &token, Method::GET, "/v1/todo").await;
+    let todos = json["data"]["todoView"]["todos"].as_array().unwrap();
+    assert_eq!(todos.len(), 1);
+
+    // 같은 키로 다른 본문을 보내면 처리하지 않는다.
+    let (status, _, json) = post_with_idempotency_key(
+        &app,
+        &token,
+        "/v1/todo",
+        "rent-1",
+        json!({ "title": "Pay bills", "description": "" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(json["code"], "idempotency_key_reused");
+
+    // 키는 사용자별이고, 헤더가 없으면 매번 새로 만든다.
+    let other = create_user_and_login(&app, &unique_email()).await;
+    let (_, headers, json) =
+        post_with_idempotency_key(&app, &other, "/v1/todo", "rent-1", body.clone()).await;
+    assert!(headers.get("idempotent-replayed").is_none());
+    assert_ne!(
+        json["data"]["todoView"]["id"],
+        first["data"]["todoView"]["id"]
+    );
+    create_todo_with_body(&app, &token, body.clone()).await;
+    create_todo_with_body(&app, &token, body).await;
+    let (_, json) = send_as(&app, &token, Method::GET, "/v1/todo").await;
+    assert_eq!(
+        json["data"]["todoView"]["todos"].as_array().unwrap().len(),
+        3
+    );
+}
+
+#[tokio::test]
+async fn todo_batch_with_same_idempotency_key_runs_once() {
+    let app = common::build_test_app().await;
+    let token = create_user_and_login(&app, &unique_email()).await;
+    let body = json!({ "operations": [
+        { "op": "create", "title": "Batch Once", "description": "" }
+    ] });
+
+    let (_, _, first) =
+        post_with_idempotency_key(&app, &token, "/v1/todo/batch", "batch-1", body.clone()).await;
+    assert_eq!(first["result"], true, "{first}");
+    let (_, headers, replayed) =
+        post_with_idempotency_key(&app, &token, "/v1/todo/batch", "batch-1", body.clone()).await;
+    assert_eq!(headers["idempotent-replayed"], "true");
+    assert_eq!(replayed, first);
+
+    let (_, json) = send_as(&app, &token, Method::GET, "/v1/todo").await;
+    assert_eq!(
+        json["data"]["todoView"]["todos"].as_array().unwrap().len(),
+        1
+    );
+
+    // 같은 키라도 경로가 다르면 다른 요청이다.
+    let (status, _, json) =
+        post_with_idempotency_key(&app, &token, "/v1/todo", "batch-1", body).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(json["code"], "idempotency_key_reused");
+}
@@ -2,6 +2,7 @@ use axum::Router;
 use common::config::ApplicationConfig;
 use controller::module::usecase_module::{AppModule, AppState};
 use infra::db::{Db, DbParameters};
+use infra::storage::{LocalBlobStorage, LocalBlobStorageParameters};
 use sqlx::postgres::PgPoolOptions;
 use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Duration;
@@ -109,6 +110,12 @@ fn postgres_url() -> String {
         .clone()
 }
 
+/// `ADMIN_USERNAMES` 로 관리자로 지정하는 테스트 사용자 이름.
+pub const ADMIN_USERNAME: &str = "admin@example.com";
+
+/// 테스트에서 한도 초과를 쉽게 만들 수 있도록 첨부 파일 한도를 작게 둔다.
+pub const TEST_ATTACHMENT_MAX_BYTES: u64 = 1024;
+
 fn test_config(database_url: String) -> ApplicationConfig {
     ApplicationConfig {
         debug: true,
@@ -117,6 +124,20 @@ fn test_config(database_url: String) -> ApplicationConfig {
         allowed_origin: "http://localhost:3000".to_string(),
         jwt_duration: 60,
         jwt_max_age: 1,
+        todo_retention_days: 30,
+        todo_rollup_completion: true,
+        attachment_dir: std::env::temp_dir()
+            .join("controller-test-attachments")
+            .to_string_lossy()
+            .into_owned(),
+        attachment_max_bytes: TEST_ATTACHMENT_MAX_BYTES,
+        attachment_content_types: vec!["text/plain".to_string(), "image/png".to_string()],
+        reminder_poll_seconds: 0,
+        reminder_batch_size: 100,
+        reminder_webhook_url: None,
+        admin_usernames: vec![ADMIN_USERNAME.to_string()],
+        idempotency_ttl_seconds: 60,
+        idempotency_sweep_seconds: 0,
     }
 }
 
@@ -160,6 +181,9 @@ pub async fn build_test_app() -> Router {
     let module = Arc::new(
         AppModule::builder()
             .with_component_parameters::<Db>(DbParameters { pool })
+            .with_component_parameters::<LocalBlobStorage>(LocalBlobStorageParameters {
+                root: config.attachment_dir.clone().into(),
+            })
             .build(),
     );
     let state = Arc::new(AppState::new(module, config));
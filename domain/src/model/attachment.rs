@@ -0,0 +1,81 @@
+use crate::model::todo::Todo;
+use crate::model::user::User;
+use crate::model::Id;
+use chrono::{DateTime, Utc};
+
+/// 첨부 파일 이름 최대 길이(문자 수).
+pub const ATTACHMENT_FILE_NAME_MAX_LEN: usize = 255;
+
+/// Todo 에 첨부한 파일의 메타데이터. 본문은 blob 저장소의 `storage_key` 에 있다.
+#[derive(Debug, Clone)]
+pub struct Attachment {
+    pub id: Id<Attachment>,
+    pub todo_id: Id<Todo>,
+    pub uploader_id: Id<User>,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Attachment {
+    /// 첨부 파일 본문을 저장할 blob key. todo 별로 묶어 둔다.
+    pub fn storage_key_of(todo_id: &Id<Todo>, id: &Id<Attachment>) -> String {
+        format!("todos/{}/{}", todo_id.value, id.value)
+    }
+}
+
+pub struct NewAttachment {
+    pub id: Id<Attachment>,
+    pub todo_id: Id<Todo>,
+    pub uploader_id: Id<User>,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_key: String,
+}
+
+impl NewAttachment {
+    pub fn new(
+        id: Id<Attachment>,
+        todo_id: Id<Todo>,
+        uploader_id: Id<User>,
+        file_name: String,
+        content_type: String,
+        size_bytes: i64,
+    ) -> Self {
+        let storage_key = Attachment::storage_key_of(&todo_id, &id);
+        Self {
+            id,
+            todo_id,
+            uploader_id,
+            file_name,
+            content_type,
+            size_bytes,
+            storage_key,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ulid::Ulid;
+
+    #[test]
+    fn new_attachment_new_derives_storage_key_from_ids() {
+        let ulid = Ulid::new();
+        let todo_ulid = Ulid::new();
+        let na = NewAttachment::new(
+            Id::new(ulid),
+            Id::new(todo_ulid),
+            Id::gen(),
+            "report.pdf".to_string(),
+            "application/pdf".to_string(),
+            42,
+        );
+        assert_eq!(na.storage_key, format!("todos/{todo_ulid}/{ulid}"));
+        assert_eq!(na.size_bytes, 42);
+    }
+}
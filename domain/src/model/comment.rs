@@ -0,0 +1,85 @@
+use crate::model::todo::Todo;
+use crate::model::user::User;
+use crate::model::Id;
+use chrono::{DateTime, Utc};
+
+/// 댓글 본문 최대 길이(문자 수).
+pub const COMMENT_BODY_MAX_LEN: usize = 4000;
+
+/// Todo 에 달린 댓글. 작성자만 고치거나 지울 수 있다.
+#[derive(Debug, Clone)]
+pub struct Comment {
+    pub id: Id<Comment>,
+    pub todo_id: Id<Todo>,
+    pub author_id: Id<User>,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub struct NewComment {
+    pub id: Id<Comment>,
+    pub todo_id: Id<Todo>,
+    pub author_id: Id<User>,
+    pub body: String,
+}
+
+impl NewComment {
+    pub fn new(id: Id<Comment>, todo_id: Id<Todo>, author_id: Id<User>, body: String) -> Self {
+        Self {
+            id,
+            todo_id,
+            author_id,
+            body,
+        }
+    }
+}
+
+/// 댓글 본문 수정.
+pub struct UpdateComment {
+    pub id: Id<Comment>,
+    pub todo_id: Id<Todo>,
+    pub body: String,
+}
+
+impl UpdateComment {
+    pub fn new(id: Id<Comment>, todo_id: Id<Todo>, body: String) -> Self {
+        Self { id, todo_id, body }
+    }
+}
+
+/// 댓글 목록 keyset 위치.
+/// (created_at, id) 오름차순으로 정렬된 목록에서 이 위치 다음 항목부터 조회한다.
+pub struct CommentCursor {
+    pub created_at: DateTime<Utc>,
+    pub id: Id<Comment>,
+}
+
+impl CommentCursor {
+    pub fn new(created_at: DateTime<Utc>, id: Id<Comment>) -> Self {
+        Self { created_at, id }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ulid::Ulid;
+
+    #[test]
+    fn new_comment_new_stores_all_fields() {
+        let ulid = Ulid::new();
+        let todo_ulid = Ulid::new();
+        let author_ulid = Ulid::new();
+        let nc = NewComment::new(
+            Id::new(ulid),
+            Id::new(todo_ulid),
+            Id::new(author_ulid),
+            "확인했습니다".to_string(),
+        );
+        assert_eq!(nc.id.value, ulid);
+        assert_eq!(nc.todo_id.value, todo_ulid);
+        assert_eq!(nc.author_id.value, author_ulid);
+        assert_eq!(nc.body, "확인했습니다");
+    }
+}
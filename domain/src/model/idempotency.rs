@@ -0,0 +1,51 @@
+use crate::model::user::User;
+use crate::model::Id;
+use chrono::{DateTime, Utc};
+
+/// 멱등 키 최대 길이. DB `idempotency_keys.idempotency_key` 컬럼 크기와 같다.
+pub const IDEMPOTENCY_KEY_MAX_LEN: usize = 255;
+
+/// 사용자가 `Idempotency-Key` 헤더로 보낸 키와 그 키로 처음 처리한 요청.
+///
+/// `response` 가 `None` 이면 처음 요청을 아직 처리하고 있다.
+#[derive(Debug, Clone)]
+pub struct IdempotencyKey {
+    pub user_id: Id<User>,
+    pub key: String,
+    /// 처음 요청의 메서드, 경로, 본문으로 만든 해시. 같은 키로 다른 요청을 보냈는지 가린다.
+    pub request_hash: String,
+    pub response: Option<IdempotentResponse>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// 다시 보낸 요청에 그대로 돌려줄 처음 응답.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdempotentResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+pub struct NewIdempotencyKey {
+    pub user_id: Id<User>,
+    pub key: String,
+    pub request_hash: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl NewIdempotencyKey {
+    pub fn new(
+        user_id: Id<User>,
+        key: String,
+        request_hash: String,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            user_id,
+            key,
+            request_hash,
+            expires_at,
+        }
+    }
+}
@@ -2,6 +2,11 @@ use anyhow::anyhow;
 use std::marker::PhantomData;
 use ulid::Ulid;
 
+pub mod attachment;
+pub mod comment;
+pub mod idempotency;
+pub mod project;
+pub mod tag;
 pub mod todo;
 pub mod user;
 
@@ -34,12 +39,53 @@ impl<T> TryFrom<String> for Id<T> {
     }
 }
 
+/// 부분 수정에서 값을 지울 수 있는 필드의 변경 내용.
+/// 그대로 두기, 지우기, 새 값으로 바꾸기를 구분한다.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum FieldUpdate<T> {
+    #[default]
+    Keep,
+    Clear,
+    Set(T),
+}
+
+impl<T> FieldUpdate<T> {
+    /// 바꿀 값이 있으면 `Some`. 지우는 경우는 `Some(None)` 이다.
+    pub fn into_change(self) -> Option<Option<T>> {
+        match self {
+            FieldUpdate::Keep => None,
+            FieldUpdate::Clear => Some(None),
+            FieldUpdate::Set(value) => Some(Some(value)),
+        }
+    }
+}
+
+/// 값이 없으면 그대로 두고 있으면 그 값으로 바꾼다.
+impl<T> From<Option<T>> for FieldUpdate<T> {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(FieldUpdate::Keep, FieldUpdate::Set)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     struct Dummy;
 
+    #[test]
+    fn field_update_from_option_keeps_on_none() {
+        assert_eq!(FieldUpdate::<i32>::from(None), FieldUpdate::Keep);
+        assert_eq!(FieldUpdate::from(Some(1)), FieldUpdate::Set(1));
+    }
+
+    #[test]
+    fn field_update_into_change_distinguishes_clear() {
+        assert_eq!(FieldUpdate::<i32>::Keep.into_change(), None);
+        assert_eq!(FieldUpdate::<i32>::Clear.into_change(), Some(None));
+        assert_eq!(FieldUpdate::Set(2).into_change(), Some(Some(2)));
+    }
+
     #[test]
     fn id_gen_creates_unique_ids() {
         let id1 = Id::<Dummy>::gen();
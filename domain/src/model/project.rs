@@ -0,0 +1,106 @@
+use crate::model::user::User;
+use crate::model::Id;
+
+/// 프로젝트 이름 최대 길이. DB `projects.name` 컬럼 크기와 같다.
+pub const PROJECT_NAME_MAX_LEN: usize = 64;
+/// 색을 정하지 않은 프로젝트의 색.
+pub const DEFAULT_PROJECT_COLOR: &str = "#9e9e9e";
+
+/// Todo 를 묶는 프로젝트(목록). 사용자별로 관리하며 이름은 사용자 안에서 유일하다.
+/// 보관(`archived`)한 프로젝트의 todo 는 함께 보관되어 기본 목록에서 빠진다.
+#[derive(Debug, Clone)]
+pub struct Project {
+    pub id: Id<Project>,
+    pub owner_id: Id<User>,
+    pub name: String,
+    /// `#rrggbb` 형식의 색.
+    pub color: String,
+    pub archived: bool,
+}
+
+impl Project {
+    pub fn new(id: Id<Project>, owner_id: Id<User>, name: String, color: String) -> Self {
+        Self {
+            id,
+            owner_id,
+            name,
+            color,
+            archived: false,
+        }
+    }
+}
+
+pub struct NewProject {
+    pub id: Id<Project>,
+    pub owner_id: Id<User>,
+    pub name: String,
+    pub color: String,
+}
+
+impl NewProject {
+    pub fn new(id: Id<Project>, owner_id: Id<User>, name: String, color: String) -> Self {
+        Self {
+            id,
+            owner_id,
+            name,
+            color,
+        }
+    }
+}
+
+/// 프로젝트 이름·색 변경. `None` 인 필드는 바꾸지 않는다.
+pub struct UpdateProject {
+    pub id: Id<Project>,
+    pub owner_id: Id<User>,
+    pub name: Option<String>,
+    pub color: Option<String>,
+}
+
+impl UpdateProject {
+    pub fn new(
+        id: Id<Project>,
+        owner_id: Id<User>,
+        name: Option<String>,
+        color: Option<String>,
+    ) -> Self {
+        Self {
+            id,
+            owner_id,
+            name,
+            color,
+        }
+    }
+}
+
+/// `#rrggbb` 형식의 색인지 확인한다.
+pub fn is_valid_color(color: &str) -> bool {
+    color.len() == 7 && color.starts_with('#') && color[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ulid::Ulid;
+
+    #[test]
+    fn project_new_is_not_archived() {
+        let ulid = Ulid::new();
+        let project = Project::new(
+            Id::new(ulid),
+            Id::gen(),
+            "집안일".to_string(),
+            DEFAULT_PROJECT_COLOR.to_string(),
+        );
+        assert_eq!(project.id.value, ulid);
+        assert!(!project.archived);
+    }
+
+    #[test]
+    fn is_valid_color_accepts_only_hex_rgb() {
+        assert!(is_valid_color("#1A2b3c"));
+        assert!(!is_valid_color("1a2b3c"));
+        assert!(!is_valid_color("#1a2b3"));
+        assert!(!is_valid_color("#1a2b3g"));
+        assert!(!is_valid_color("#가나다"));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::model::user::User;
+use crate::model::Id;
+
+/// 태그 이름 최대 길이. DB `tags.name` 컬럼 크기와 같다.
+pub const TAG_NAME_MAX_LEN: usize = 32;
+
+/// Todo 를 분류하는 태그. 사용자별로 관리하며 이름은 사용자 안에서 유일하다.
+#[derive(Debug, Clone)]
+pub struct Tag {
+    pub id: Id<Tag>,
+    pub owner_id: Id<User>,
+    pub name: String,
+}
+
+impl Tag {
+    pub fn new(id: Id<Tag>, owner_id: Id<User>, name: String) -> Self {
+        Self { id, owner_id, name }
+    }
+}
+
+pub struct NewTag {
+    pub id: Id<Tag>,
+    pub owner_id: Id<User>,
+    pub name: String,
+}
+
+impl NewTag {
+    pub fn new(id: Id<Tag>, owner_id: Id<User>, name: String) -> Self {
+        Self { id, owner_id, name }
+    }
+}
+
+/// 태그 이름 변경.
+pub struct UpdateTag {
+    pub id: Id<Tag>,
+    pub owner_id: Id<User>,
+    pub name: String,
+}
+
+impl UpdateTag {
+    pub fn new(id: Id<Tag>, owner_id: Id<User>, name: String) -> Self {
+        Self { id, owner_id, name }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ulid::Ulid;
+
+    #[test]
+    fn new_tag_new_stores_all_fields() {
+        let ulid = Ulid::new();
+        let owner_ulid = Ulid::new();
+        let nt = NewTag::new(Id::new(ulid), Id::new(owner_ulid), "work".to_string());
+        assert_eq!(nt.id.value, ulid);
+        assert_eq!(nt.owner_id.value, owner_ulid);
+        assert_eq!(nt.name, "work");
+    }
+}
@@ -1,75 +1,191 @@
+pub mod event;
+pub mod filter;
+pub mod priority;
+pub mod rank;
+pub mod recurrence;
+pub mod reminder;
+pub mod share;
+pub mod stats;
 pub mod status;
 
+use crate::model::project::Project;
+use crate::model::tag::Tag;
+use crate::model::todo::filter::TodoSortValue;
+use crate::model::todo::priority::TodoPriority;
+use crate::model::todo::recurrence::RecurrenceRule;
 use crate::model::todo::status::TodoStatus;
-use crate::model::Id;
+use crate::model::user::User;
+use crate::model::{FieldUpdate, Id};
 use chrono::{DateTime, Utc};
 
+#[derive(Debug, Clone)]
 pub struct Todo {
     pub id: Id<Todo>,
+    pub owner_id: Id<User>,
+    /// 상위 todo. 최상위 todo 이면 `None` 이다.
+    pub parent_id: Option<Id<Todo>>,
     pub title: String,
     pub description: String,
     pub status: TodoStatus,
+    pub due_at: Option<DateTime<Utc>>,
+    pub priority: TodoPriority,
+    /// 붙어 있는 태그. 이름 순이다.
+    pub tags: Vec<Tag>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// 휴지통으로 옮긴 시각. `deleted` 상태일 때만 채워진다.
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// 내용이 바뀔 때마다 1 씩 오르는 버전. 동시 수정을 막는 데 쓴다.
+    pub version: i64,
+    /// 반복 규칙. 완료하면 다음 회차 todo 를 만든다.
+    pub recurrence: Option<RecurrenceRule>,
+    /// 알림을 보낼 시각. 보낸 뒤에도 남아 있으며, 다시 정하면 새로 보낸다.
+    pub remind_at: Option<DateTime<Utc>>,
+    /// 속한 프로젝트. 어느 프로젝트에도 속하지 않으면 `None` 이다.
+    pub project_id: Option<Id<Project>>,
+    /// 프로젝트와 함께 보관한 시각. 보관한 프로젝트의 todo 에만 채워진다.
+    pub archived_at: Option<DateTime<Utc>>,
+    /// 사용자가 직접 정한 순서의 순위. 사용자의 todo 끼리 문자열로 비교한다. [`rank`] 참고.
+    pub position: String,
 }
 
+/// 새 Todo. 프로젝트 없이 최상위에 마감일 없이 기본 우선순위로 만들어지며, 필요하면 필드를 직접 채운다.
 pub struct NewTodo {
     pub id: Id<Todo>,
+    pub owner_id: Id<User>,
+    pub parent_id: Option<Id<Todo>>,
     pub title: String,
     pub description: String,
+    pub due_at: Option<DateTime<Utc>>,
+    pub priority: TodoPriority,
+    pub recurrence: Option<RecurrenceRule>,
+    pub remind_at: Option<DateTime<Utc>>,
+    pub project_id: Option<Id<Project>>,
 }
 
 impl NewTodo {
-    pub fn new(id: Id<Todo>, title: String, description: String) -> Self {
+    pub fn new(id: Id<Todo>, owner_id: Id<User>, title: String, description: String) -> Self {
         Self {
             id,
+            owner_id,
+            parent_id: None,
             title,
             description,
+            due_at: None,
+            priority: TodoPriority::default(),
+            recurrence: None,
+            remind_at: None,
+            project_id: None,
         }
     }
 }
 
+/// Todo 부분 수정. `None` 인 필드는 바꾸지 않는다.
+/// `due_at`, `recurrence`, `remind_at` 은 지울 수도 있어 `FieldUpdate` 로 구분한다.
+/// `expected_version` 이 있으면 현재 버전이 같을 때만 바꾼다.
 pub struct UpdateTodo {
     pub id: Id<Todo>,
+    pub owner_id: Id<User>,
     pub title: Option<String>,
     pub description: Option<String>,
     pub status: Option<TodoStatus>,
+    pub due_at: FieldUpdate<DateTime<Utc>>,
+    pub priority: Option<TodoPriority>,
+    pub recurrence: FieldUpdate<RecurrenceRule>,
+    pub remind_at: FieldUpdate<DateTime<Utc>>,
+    pub expected_version: Option<i64>,
 }
 
 impl UpdateTodo {
     pub fn new(
         id: Id<Todo>,
+        owner_id: Id<User>,
         title: Option<String>,
         description: Option<String>,
         status: Option<TodoStatus>,
     ) -> Self {
         Self {
             id,
+            owner_id,
             title,
             description,
             status,
+            due_at: FieldUpdate::Keep,
+            priority: None,
+            recurrence: FieldUpdate::Keep,
+            remind_at: FieldUpdate::Keep,
+            expected_version: None,
         }
     }
 }
 
+/// Todo 전체 교체. 마감일이 없으면 지우고 우선순위는 그대로 덮어쓴다.
+/// `expected_version` 이 있으면 이미 있는 todo 의 버전이 같을 때만 덮어쓴다.
 pub struct UpsertTodo {
     pub id: Id<Todo>,
+    pub owner_id: Id<User>,
     pub title: String,
     pub description: String,
     pub status: TodoStatus,
+    pub due_at: Option<DateTime<Utc>>,
+    pub priority: TodoPriority,
+    pub expected_version: Option<i64>,
 }
 
 impl UpsertTodo {
-    pub fn new(id: Id<Todo>, title: String, description: String, status: TodoStatus) -> Self {
+    pub fn new(
+        id: Id<Todo>,
+        owner_id: Id<User>,
+        title: String,
+        description: String,
+        status: TodoStatus,
+    ) -> Self {
         Self {
             id,
+            owner_id,
             title,
             description,
             status,
+            due_at: None,
+            priority: TodoPriority::default(),
+            expected_version: None,
         }
     }
 }
 
+/// 목록 조회 keyset 위치.
+/// (정렬 키, id) 순으로 정렬된 목록에서 이 위치 다음 항목부터 조회한다.
+pub struct TodoCursor {
+    pub value: TodoSortValue,
+    pub id: Id<Todo>,
+}
+
+impl TodoCursor {
+    pub fn new(value: TodoSortValue, id: Id<Todo>) -> Self {
+        Self { value, id }
+    }
+}
+
+/// 검색 결과 한 건. 관련도 순위와 강조 표시된 본문 발췌를 함께 가진다.
+pub struct TodoSearchHit {
+    pub todo: Todo,
+    pub rank: f32,
+    pub snippet: String,
+}
+
+/// 검색 결과 keyset 위치.
+/// (rank 내림차순, id 오름차순) 으로 정렬된 결과에서 이 위치 다음 항목부터 조회한다.
+pub struct TodoSearchCursor {
+    pub rank: f32,
+    pub id: Id<Todo>,
+}
+
+impl TodoSearchCursor {
+    pub fn new(rank: f32, id: Id<Todo>) -> Self {
+        Self { rank, id }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,10 +204,18 @@ mod tests {
     #[test]
     fn new_todo_new_stores_all_fields() {
         let ulid = Ulid::new();
-        let nt = NewTodo::new(Id::new(ulid), "Title".to_string(), "Desc".to_string());
+        let owner_ulid = Ulid::new();
+        let nt = NewTodo::new(
+            Id::new(ulid),
+            Id::new(owner_ulid),
+            "Title".to_string(),
+            "Desc".to_string(),
+        );
         assert_eq!(nt.id.value, ulid);
+        assert_eq!(nt.owner_id.value, owner_ulid);
         assert_eq!(nt.title, "Title");
         assert_eq!(nt.description, "Desc");
+        assert!(nt.parent_id.is_none());
     }
 
     #[test]
@@ -100,6 +224,7 @@ mod tests {
         let status = make_status();
         let ut = UpdateTodo::new(
             Id::new(ulid),
+            Id::gen(),
             Some("New Title".to_string()),
             None,
             Some(status),
@@ -117,6 +242,7 @@ mod tests {
         let status_ulid = status.id.value;
         let ut = UpsertTodo::new(
             Id::new(ulid),
+            Id::gen(),
             "Title".to_string(),
             "Desc".to_string(),
             status,
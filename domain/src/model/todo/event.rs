@@ -0,0 +1,199 @@
+use crate::model::todo::Todo;
+use crate::model::user::User;
+use crate::model::Id;
+use chrono::{DateTime, SecondsFormat, Utc};
+
+/// 필드 하나의 변경 내용. 값은 API 에 보이는 문자열 형태로 남기며, 값이 없으면 `None` 이다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TodoFieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl TodoFieldChange {
+    pub fn new(field: &str, before: Option<String>, after: Option<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            before,
+            after,
+        }
+    }
+
+    /// 두 todo 를 비교해 값이 달라진 필드만 반환한다. 필드 이름은 API 의 JSON 이름을 쓴다.
+    pub fn diff(before: &Todo, after: &Todo) -> Vec<TodoFieldChange> {
+        let fields = [
+            (
+                "title",
+                Some(before.title.clone()),
+                Some(after.title.clone()),
+            ),
+            (
+                "description",
+                Some(before.description.clone()),
+                Some(after.description.clone()),
+            ),
+            (
+                "status",
+                Some(before.status.code.as_str().to_string()),
+                Some(after.status.code.as_str().to_string()),
+            ),
+            ("dueAt", rfc3339(before.due_at), rfc3339(after.due_at)),
+            (
+                "priority",
+                Some(before.priority.as_str().to_string()),
+                Some(after.priority.as_str().to_string()),
+            ),
+            (
+                "parentId",
+                before.parent_id.as_ref().map(|id| id.value.to_string()),
+                after.parent_id.as_ref().map(|id| id.value.to_string()),
+            ),
+            ("tags", tag_names(before), tag_names(after)),
+            (
+                "recurrence",
+                before.recurrence.as_ref().map(|r| r.to_string()),
+                after.recurrence.as_ref().map(|r| r.to_string()),
+            ),
+            (
+                "remindAt",
+                rfc3339(before.remind_at),
+                rfc3339(after.remind_at),
+            ),
+            (
+                "projectId",
+                before.project_id.as_ref().map(|id| id.value.to_string()),
+                after.project_id.as_ref().map(|id| id.value.to_string()),
+            ),
+        ];
+        fields
+            .into_iter()
+            .filter(|(_, before, after)| before != after)
+            .map(|(field, before, after)| TodoFieldChange::new(field, before, after))
+            .collect()
+    }
+}
+
+fn rfc3339(at: Option<DateTime<Utc>>) -> Option<String> {
+    at.map(|at| at.to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
+/// 태그 이름을 쉼표로 이은 값. 태그가 없으면 `None` 이다.
+fn tag_names(todo: &Todo) -> Option<String> {
+    if todo.tags.is_empty() {
+        return None;
+    }
+    let names: Vec<&str> = todo.tags.iter().map(|tag| tag.name.as_str()).collect();
+    Some(names.join(","))
+}
+
+/// Todo 변경 이력 한 건. 누가(`actor_id`) 언제 어떤 필드를 바꿨는지 남긴다.
+#[derive(Debug, Clone)]
+pub struct TodoEvent {
+    pub id: Id<TodoEvent>,
+    pub todo_id: Id<Todo>,
+    pub actor_id: Id<User>,
+    pub changes: Vec<TodoFieldChange>,
+    pub created_at: DateTime<Utc>,
+}
+
+pub struct NewTodoEvent {
+    pub id: Id<TodoEvent>,
+    pub todo_id: Id<Todo>,
+    pub actor_id: Id<User>,
+    pub changes: Vec<TodoFieldChange>,
+}
+
+impl NewTodoEvent {
+    pub fn new(
+        id: Id<TodoEvent>,
+        todo_id: Id<Todo>,
+        actor_id: Id<User>,
+        changes: Vec<TodoFieldChange>,
+    ) -> Self {
+        Self {
+            id,
+            todo_id,
+            actor_id,
+            changes,
+        }
+    }
+}
+
+/// 이력 목록 keyset 위치.
+/// (created_at, id) 내림차순으로 정렬된 목록에서 이 위치 다음 항목부터 조회한다.
+pub struct TodoEventCursor {
+    pub created_at: DateTime<Utc>,
+    pub id: Id<TodoEvent>,
+}
+
+impl TodoEventCursor {
+    pub fn new(created_at: DateTime<Utc>, id: Id<TodoEvent>) -> Self {
+        Self { created_at, id }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::tag::Tag;
+    use crate::model::todo::priority::TodoPriority;
+    use crate::model::todo::status::{TodoStatus, TodoStatusCode};
+
+    fn make_todo() -> Todo {
+        Todo {
+            id: Id::gen(),
+            owner_id: Id::gen(),
+            parent_id: None,
+            title: "before".to_string(),
+            description: "desc".to_string(),
+            status: TodoStatus::new(Id::gen(), TodoStatusCode::New, "신규".to_string()),
+            due_at: None,
+            priority: TodoPriority::default(),
+            tags: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+            version: 1,
+            recurrence: None,
+            remind_at: None,
+            project_id: None,
+            archived_at: None,
+            position: "i".to_string(),
+        }
+    }
+
+    #[test]
+    fn diff_returns_only_changed_fields() {
+        let before = make_todo();
+        let mut after = before.clone();
+        after.title = "after".to_string();
+        after.status = TodoStatus::new(Id::gen(), TodoStatusCode::Working, "진행중".to_string());
+        after.tags = vec![Tag::new(Id::gen(), Id::gen(), "work".to_string())];
+
+        let changes = TodoFieldChange::diff(&before, &after);
+
+        assert_eq!(
+            changes,
+            vec![
+                TodoFieldChange::new(
+                    "title",
+                    Some("before".to_string()),
+                    Some("after".to_string())
+                ),
+                TodoFieldChange::new(
+                    "status",
+                    Some("new".to_string()),
+                    Some("working".to_string())
+                ),
+                TodoFieldChange::new("tags", None, Some("work".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_of_same_todo_is_empty() {
+        let todo = make_todo();
+        assert!(TodoFieldChange::diff(&todo, &todo).is_empty());
+    }
+}
@@ -0,0 +1,167 @@
+use crate::model::project::Project;
+use crate::model::todo::status::TodoStatus;
+use crate::model::todo::Todo;
+use crate::model::Id;
+use chrono::{DateTime, Utc};
+
+/// Todo 목록 조회 조건. 비어 있는 조건은 적용하지 않는다.
+#[derive(Debug, Clone, Default)]
+pub struct TodoFilter {
+    /// 하나라도 일치하면 포함한다. 비어 있으면 휴지통(`deleted`)의 todo 를 제외한 전체이다.
+    pub statuses: Vec<TodoStatus>,
+    /// created_at >= created_after
+    pub created_after: Option<DateTime<Utc>>,
+    /// created_at < created_before
+    pub created_before: Option<DateTime<Utc>>,
+    /// updated_at >= updated_since
+    pub updated_since: Option<DateTime<Utc>>,
+    /// due_at < due_before
+    pub due_before: Option<DateTime<Utc>>,
+    /// 마감일이 지났는데 끝나지 않은(`done`, `discontinued` 가 아닌) todo 만 포함한다.
+    pub overdue: bool,
+    /// 태그 이름. 지정한 태그가 모두 붙어 있는 todo 만 포함한다.
+    pub tag_names: Vec<String>,
+    /// 지정하면 이 todo 의 바로 아래 하위 작업만 포함한다.
+    pub parent_id: Option<Id<Todo>>,
+    /// 지정하면 이 프로젝트의 todo 만 보관한 것까지 포함한다.
+    /// 지정하지 않으면 `include_archived` 가 아닌 한 보관한 프로젝트의 todo 는 제외한다.
+    pub project_id: Option<Id<Project>>,
+    /// 프로젝트를 지정하지 않아도 보관한 프로젝트의 todo 를 포함한다.
+    pub include_archived: bool,
+}
+
+/// Todo 목록 정렬 키.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TodoSortKey {
+    CreatedAt,
+    UpdatedAt,
+    Title,
+    /// 우선순위가 높은 순, 같으면 마감일이 빠른 순(마감일 없음은 마지막)이다.
+    Priority,
+    /// 사용자가 직접 정한 순서이다.
+    Position,
+}
+
+/// Todo 목록 정렬. 같은 값은 id 로 같은 방향으로 정렬한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TodoSort {
+    pub key: TodoSortKey,
+    pub descending: bool,
+}
+
+impl Default for TodoSort {
+    fn default() -> Self {
+        Self {
+            key: TodoSortKey::CreatedAt,
+            descending: false,
+        }
+    }
+}
+
+impl TodoSort {
+    pub fn as_str(&self) -> &'static str {
+        match (self.key, self.descending) {
+            (TodoSortKey::CreatedAt, false) => "createdAt",
+            (TodoSortKey::CreatedAt, true) => "-createdAt",
+            (TodoSortKey::UpdatedAt, false) => "updatedAt",
+            (TodoSortKey::UpdatedAt, true) => "-updatedAt",
+            (TodoSortKey::Title, false) => "title",
+            (TodoSortKey::Title, true) => "-title",
+            (TodoSortKey::Priority, false) => "priority",
+            (TodoSortKey::Priority, true) => "-priority",
+            (TodoSortKey::Position, false) => "position",
+            (TodoSortKey::Position, true) => "-position",
+        }
+    }
+
+    /// todo 에서 이 정렬 키의 값을 꺼낸다. keyset cursor 를 만들 때 쓴다.
+    pub fn value_of(&self, todo: &Todo) -> TodoSortValue {
+        match self.key {
+            TodoSortKey::CreatedAt => TodoSortValue::Timestamp(todo.created_at),
+            TodoSortKey::UpdatedAt => TodoSortValue::Timestamp(todo.updated_at),
+            TodoSortKey::Title => TodoSortValue::Text(todo.title.clone()),
+            TodoSortKey::Priority => TodoSortValue::Priority {
+                rank: todo.priority.rank(),
+                due_at: todo.due_at,
+            },
+            TodoSortKey::Position => TodoSortValue::Text(todo.position.clone()),
+        }
+    }
+}
+
+/// `createdAt`, `-updatedAt`, `title`, `priority`, `position` 형식. `-` 접두사는 역순이다.
+impl TryFrom<&str> for TodoSort {
+    type Error = anyhow::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (descending, name) = match s.strip_prefix('-') {
+            Some(name) => (true, name),
+            None => (false, s),
+        };
+        let key = match name {
+            "createdAt" => TodoSortKey::CreatedAt,
+            "updatedAt" => TodoSortKey::UpdatedAt,
+            "title" => TodoSortKey::Title,
+            "priority" => TodoSortKey::Priority,
+            "position" => TodoSortKey::Position,
+            other => return Err(anyhow::anyhow!("unknown sort key: {other}")),
+        };
+        Ok(Self { key, descending })
+    }
+}
+
+/// keyset cursor 가 가리키는 정렬 키 값.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TodoSortValue {
+    Timestamp(DateTime<Utc>),
+    Text(String),
+    Priority {
+        rank: i16,
+        due_at: Option<DateTime<Utc>>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn todo_sort_try_from_parses_direction_and_key() {
+        let sort = TodoSort::try_from("-updatedAt").unwrap();
+        assert_eq!(sort.key, TodoSortKey::UpdatedAt);
+        assert!(sort.descending);
+
+        let sort = TodoSort::try_from("title").unwrap();
+        assert_eq!(sort.key, TodoSortKey::Title);
+        assert!(!sort.descending);
+    }
+
+    #[test]
+    fn todo_sort_try_from_unknown_key_returns_error() {
+        assert!(TodoSort::try_from("ownerId").is_err());
+        assert!(TodoSort::try_from("--title").is_err());
+    }
+
+    #[test]
+    fn todo_sort_as_str_round_trips() {
+        for s in [
+            "createdAt",
+            "-createdAt",
+            "updatedAt",
+            "-updatedAt",
+            "title",
+            "-title",
+            "priority",
+            "-priority",
+            "position",
+            "-position",
+        ] {
+            assert_eq!(TodoSort::try_from(s).unwrap().as_str(), s);
+        }
+    }
+
+    #[test]
+    fn todo_sort_default_is_created_at_ascending() {
+        assert_eq!(TodoSort::default().as_str(), "createdAt");
+    }
+}
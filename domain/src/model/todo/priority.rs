@@ -0,0 +1,91 @@
+/// Todo 우선순위. DB 에는 `rank` 값(smallint)으로 저장하며 클수록 급하다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TodoPriority {
+    Low,
+    #[default]
+    Medium,
+    High,
+    Urgent,
+}
+
+impl TodoPriority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+            Self::Urgent => "urgent",
+        }
+    }
+
+    pub fn rank(&self) -> i16 {
+        match self {
+            Self::Low => 0,
+            Self::Medium => 1,
+            Self::High => 2,
+            Self::Urgent => 3,
+        }
+    }
+
+    pub fn from_rank(rank: i16) -> anyhow::Result<Self> {
+        match rank {
+            0 => Ok(Self::Low),
+            1 => Ok(Self::Medium),
+            2 => Ok(Self::High),
+            3 => Ok(Self::Urgent),
+            other => Err(anyhow::anyhow!("unknown priority rank: {other}")),
+        }
+    }
+}
+
+impl TryFrom<&str> for TodoPriority {
+    type Error = anyhow::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "low" => Ok(Self::Low),
+            "medium" => Ok(Self::Medium),
+            "high" => Ok(Self::High),
+            "urgent" => Ok(Self::Urgent),
+            other => Err(anyhow::anyhow!("unknown priority: {other}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [TodoPriority; 4] = [
+        TodoPriority::Low,
+        TodoPriority::Medium,
+        TodoPriority::High,
+        TodoPriority::Urgent,
+    ];
+
+    #[test]
+    fn todo_priority_as_str_roundtrips() {
+        for priority in ALL {
+            assert_eq!(TodoPriority::try_from(priority.as_str()).unwrap(), priority);
+        }
+    }
+
+    #[test]
+    fn todo_priority_rank_roundtrips_and_increases_with_urgency() {
+        for priority in ALL {
+            assert_eq!(TodoPriority::from_rank(priority.rank()).unwrap(), priority);
+        }
+        assert!(TodoPriority::Urgent.rank() > TodoPriority::Low.rank());
+    }
+
+    #[test]
+    fn todo_priority_invalid_values_return_error() {
+        assert!(TodoPriority::try_from("critical").is_err());
+        assert!(TodoPriority::from_rank(9).is_err());
+    }
+
+    #[test]
+    fn todo_priority_default_is_medium() {
+        assert_eq!(TodoPriority::default(), TodoPriority::Medium);
+    }
+}
@@ -0,0 +1,188 @@
+/// 순위 문자열에 쓰는 숫자. 바이트 순서가 곧 크기 순서이므로 순위는 문자열 비교(`"C"` collation)로 정렬한다.
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+const BASE: u128 = DIGITS.len() as u128;
+/// 맨 앞·맨 뒤에 붙일 때 보는 앞자리 수와 그 자리에서 띄우는 간격.
+/// 재배치한 순위도 같은 간격으로 만들어 뒤에 붙이기만 해서는 길어지지 않는다.
+const HEAD_LEN: usize = 6;
+const STEP: u128 = BASE.pow(3);
+
+/// 순위 문자열의 최대 길이. 사이 값이 이보다 길어지면 목록을 다시 배치해야 한다.
+pub const MAX_RANK_LEN: usize = 32;
+
+/// `lower` 와 `upper` 사이에 오는 순위를 만든다. `None` 인 쪽은 끝이 열려 있다.
+///
+/// 순위는 `0-9a-z` 로 이루어진 문자열이며 `0` 으로 끝나지 않는다. 그래서 어느 두 순위 사이에도
+/// 다른 순위를 만들 수 있고, 옮긴 todo 하나만 바꾸면 된다.
+/// `lower` 가 `upper` 보다 작지 않거나, 형식이 틀리거나, 만든 순위가 [`MAX_RANK_LEN`] 보다 길면
+/// `None` 이며 [`spread_ranks`] 로 다시 배치해야 한다.
+pub fn rank_between(lower: Option<&str>, upper: Option<&str>) -> Option<String> {
+    if !lower.is_none_or(is_valid) || !upper.is_none_or(is_valid) {
+        return None;
+    }
+    let rank = match (lower, upper) {
+        (Some(lower), Some(upper)) if lower >= upper => return None,
+        (Some(lower), Some(upper)) => midpoint(lower.as_bytes(), Some(upper.as_bytes())),
+        (Some(lower), None) => {
+            step_after(lower).unwrap_or_else(|| midpoint(lower.as_bytes(), None))
+        }
+        (None, Some(upper)) => {
+            step_before(upper).unwrap_or_else(|| midpoint(b"", Some(upper.as_bytes())))
+        }
+        (None, None) => midpoint(b"", None),
+    };
+    (rank.len() <= MAX_RANK_LEN).then_some(rank)
+}
+
+/// `count` 개의 순위를 같은 간격으로 만든다. 목록을 다시 배치할 때 쓰며, 앞뒤로 붙일 자리가 남도록 가운데에 모은다.
+/// `position` 컬럼을 추가하는 migration 도 기존 todo 에 같은 방식으로 순위를 채운다.
+pub fn spread_ranks(count: usize) -> Vec<String> {
+    let count = count as u128;
+    let mut width = HEAD_LEN;
+    while (count + 1) * STEP >= BASE.pow(width as u32) {
+        width += 1;
+    }
+    let first = (BASE.pow(width as u32) / STEP - count) / 2;
+    (first..first + count)
+        .map(|slot| encode(slot * STEP, width))
+        .collect()
+}
+
+fn digit(c: u8) -> Option<usize> {
+    DIGITS.iter().position(|&d| d == c)
+}
+
+fn is_valid(rank: &str) -> bool {
+    !rank.is_empty() && !rank.ends_with('0') && rank.bytes().all(|c| digit(c).is_some())
+}
+
+/// 앞 `HEAD_LEN` 자리를 수로 읽는다. 짧으면 `0` 으로 채운다.
+fn head(rank: &str) -> u128 {
+    let bytes = rank.as_bytes();
+    (0..HEAD_LEN).fold(0, |value, i| {
+        let d = bytes.get(i).and_then(|&c| digit(c)).unwrap_or(0);
+        value * BASE + d as u128
+    })
+}
+
+/// `value` 를 `width` 자리로 쓰고 끝의 `0` 을 뗀다. 떼어도 순서는 그대로이다.
+fn encode(mut value: u128, width: usize) -> String {
+    let mut bytes = vec![b'0'; width];
+    for slot in bytes.iter_mut().rev() {
+        *slot = DIGITS[(value % BASE) as usize];
+        value /= BASE;
+    }
+    String::from_utf8(bytes)
+        .unwrap_or_default()
+        .trim_end_matches('0')
+        .to_string()
+}
+
+fn step_after(lower: &str) -> Option<String> {
+    let value = head(lower) + STEP;
+    (value < BASE.pow(HEAD_LEN as u32)).then(|| encode(value, HEAD_LEN))
+}
+
+fn step_before(upper: &str) -> Option<String> {
+    let value = head(upper);
+    (value > STEP).then(|| encode(value - STEP, HEAD_LEN))
+}
+
+/// `a < b` 인 두 순위 사이의 값. `a` 가 비어 있으면 맨 앞, `b` 가 `None` 이면 맨 뒤까지 열려 있다.
+fn midpoint(a: &[u8], b: Option<&[u8]>) -> String {
+    if let Some(b) = b {
+        // 같은 앞부분은 그대로 두고 나머지에서 사이 값을 찾는다.
+        let common = b
+            .iter()
+            .enumerate()
+            .take_while(|(i, &c)| a.get(*i).copied().unwrap_or(b'0') == c)
+            .count();
+        if common > 0 {
+            let rest = midpoint(a.get(common..).unwrap_or_default(), Some(&b[common..]));
+            return format!("{}{rest}", String::from_utf8_lossy(&b[..common]));
+        }
+    }
+    let low = a.first().and_then(|&c| digit(c)).unwrap_or(0);
+    let high = b.and_then(|b| digit(b[0])).unwrap_or(DIGITS.len());
+    if high - low > 1 {
+        return char::from(DIGITS[(low + high).div_ceil(2)]).to_string();
+    }
+    match b {
+        Some(b) if b.len() > 1 => char::from(b[0]).to_string(),
+        _ => format!(
+            "{}{}",
+            char::from(DIGITS[low]),
+            midpoint(a.get(1..).unwrap_or_default(), None)
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn between(lower: &str, upper: &str) -> String {
+        let rank = rank_between(Some(lower), Some(upper)).unwrap();
+        assert!(
+            lower < rank.as_str() && rank.as_str() < upper,
+            "{lower} < {rank} < {upper}"
+        );
+        rank
+    }
+
+    #[test]
+    fn rank_between_finds_value_between_neighbours() {
+        assert_eq!(between("a", "c"), "b");
+        assert_eq!(between("a", "b"), "ai");
+        assert_eq!(between("a1", "a2"), "a1i");
+        assert_eq!(between("9", "a"), "9i");
+        assert_eq!(between("001", "01"), "00j");
+        assert_eq!(rank_between(None, None).unwrap(), "i");
+    }
+
+    #[test]
+    fn rank_between_steps_at_the_ends_without_growing() {
+        let mut last = "i".to_string();
+        for _ in 0..1000 {
+            let next = rank_between(Some(&last), None).unwrap();
+            assert!(next > last);
+            assert!(next.len() <= HEAD_LEN);
+            last = next;
+        }
+        let mut first = "i".to_string();
+        for _ in 0..100 {
+            let prev = rank_between(None, Some(&first)).unwrap();
+            assert!(prev < first);
+            assert!(prev.len() <= HEAD_LEN);
+            first = prev;
+        }
+    }
+
+    #[test]
+    fn rank_between_returns_none_when_there_is_no_room() {
+        assert_eq!(rank_between(Some("b"), Some("a")), None);
+        assert_eq!(rank_between(Some("a"), Some("a")), None);
+        assert_eq!(rank_between(Some("a0"), None), None);
+        assert_eq!(rank_between(Some("A"), None), None);
+
+        // 같은 자리에 계속 끼워 넣으면 길어지다가 한도를 넘는다.
+        let (lower, mut upper) = ("a".to_string(), "b".to_string());
+        let mut count = 0;
+        while let Some(rank) = rank_between(Some(&lower), Some(&upper)) {
+            upper = rank;
+            count += 1;
+        }
+        assert!(count > 100);
+        assert!(upper.len() <= MAX_RANK_LEN);
+    }
+
+    #[test]
+    fn spread_ranks_are_sorted_and_leave_room_around() {
+        let ranks = spread_ranks(500);
+        assert_eq!(ranks.len(), 500);
+        assert!(ranks.windows(2).all(|w| w[0] < w[1]));
+        assert!(ranks.iter().all(|r| is_valid(r)));
+        assert!(rank_between(None, Some(&ranks[0])).is_some());
+        assert!(rank_between(Some(&ranks[499]), None).is_some());
+        assert!(spread_ranks(0).is_empty());
+    }
+}
@@ -0,0 +1,423 @@
+use anyhow::{anyhow, bail};
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveDateTime, Utc, Weekday};
+use std::fmt;
+
+/// 다음 회차를 찾을 때 건너뛰어 볼 최대 주기 수. 31일이나 2월 29일처럼 드문 날짜도 이 안에 나온다.
+const MAX_SEARCH_STEPS: u32 = 100;
+
+/// 반복 주기. RRULE 의 `FREQ` 값이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurrenceFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl RecurrenceFrequency {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Daily => "DAILY",
+            Self::Weekly => "WEEKLY",
+            Self::Monthly => "MONTHLY",
+            Self::Yearly => "YEARLY",
+        }
+    }
+}
+
+impl TryFrom<&str> for RecurrenceFrequency {
+    type Error = anyhow::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "DAILY" => Ok(Self::Daily),
+            "WEEKLY" => Ok(Self::Weekly),
+            "MONTHLY" => Ok(Self::Monthly),
+            "YEARLY" => Ok(Self::Yearly),
+            other => Err(anyhow!("unsupported FREQ: {other}")),
+        }
+    }
+}
+
+/// todo 반복 규칙. iCalendar (RFC 5545) RRULE 중 일부를 지원한다.
+///
+/// `FREQ` 는 필수이고 `INTERVAL`, `BYDAY` (`WEEKLY` 만), `BYMONTHDAY` (`MONTHLY` 만, 음수는 말일부터),
+/// `COUNT`, `UNTIL` 을 쓸 수 있다. 회차는 UTC 로 계산하며 시각은 기준 회차의 시각을 따른다.
+/// `COUNT` 는 지금 회차를 포함해 남은 회차 수이며, 다음 회차로 넘어갈 때마다 1 씩 줄어든다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    pub frequency: RecurrenceFrequency,
+    pub interval: u32,
+    /// 월요일부터 순서대로 정렬되어 있다.
+    pub by_day: Vec<Weekday>,
+    /// 오름차순으로 정렬되어 있다.
+    pub by_month_day: Vec<i8>,
+    pub count: Option<u32>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl RecurrenceRule {
+    pub fn new(frequency: RecurrenceFrequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+            by_day: vec![],
+            by_month_day: vec![],
+            count: None,
+            until: None,
+        }
+    }
+
+    /// `start` 회차 다음 회차와 그 회차부터 적용할 규칙. 남은 회차가 없으면 `None` 이다.
+    pub fn next_occurrence(&self, start: DateTime<Utc>) -> Option<(DateTime<Utc>, Self)> {
+        if self.count.is_some_and(|count| count <= 1) {
+            return None;
+        }
+        let date = self.next_date(start.date_naive())?;
+        let at = NaiveDateTime::new(date, start.time()).and_utc();
+        if self.until.is_some_and(|until| at > until) {
+            return None;
+        }
+        let mut rest = self.clone();
+        rest.count = self.count.map(|count| count - 1);
+        Some((at, rest))
+    }
+
+    /// `start` 다음 회차부터 최대 `limit` 개의 회차.
+    pub fn upcoming(&self, start: DateTime<Utc>, limit: usize) -> Vec<DateTime<Utc>> {
+        let mut occurrences = Vec::with_capacity(limit);
+        let mut rule = self.clone();
+        let mut at = start;
+        while occurrences.len() < limit {
+            let Some((next, rest)) = rule.next_occurrence(at) else {
+                break;
+            };
+            occurrences.push(next);
+            at = next;
+            rule = rest;
+        }
+        occurrences
+    }
+
+    fn next_date(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let interval = self.interval;
+        match self.frequency {
+            RecurrenceFrequency::Daily => date.checked_add_days(Days::new(interval.into())),
+            RecurrenceFrequency::Weekly if self.by_day.is_empty() => {
+                date.checked_add_days(Days::new(7 * u64::from(interval)))
+            }
+            RecurrenceFrequency::Weekly => {
+                // 같은 주에 남은 요일이 없으면 `interval` 주 뒤의 첫 요일로 넘어간다.
+                let today = date.weekday().num_days_from_monday();
+                let days = self.by_day.iter().map(|d| d.num_days_from_monday());
+                match days.clone().find(|day| *day > today) {
+                    Some(day) => date.checked_add_days(Days::new((day - today).into())),
+                    None => {
+                        let first = days.min()?;
+                        let offset = 7 * u64::from(interval) + u64::from(first);
+                        date.checked_sub_days(Days::new(today.into()))?
+                            .checked_add_days(Days::new(offset))
+                    }
+                }
+            }
+            RecurrenceFrequency::Monthly => {
+                let month_days = if self.by_month_day.is_empty() {
+                    vec![date.day() as i8]
+                } else {
+                    self.by_month_day.clone()
+                };
+                let first_of_month = date.with_day(1)?;
+                (0..MAX_SEARCH_STEPS).find_map(|step| {
+                    let months = step.checked_mul(interval)?;
+                    let month = first_of_month.checked_add_months(Months::new(months))?;
+                    let mut candidates: Vec<NaiveDate> = month_days
+                        .iter()
+                        .filter_map(|day| resolve_month_day(month, *day))
+                        .filter(|candidate| *candidate > date)
+                        .collect();
+                    candidates.sort();
+                    candidates.into_iter().next()
+                })
+            }
+            RecurrenceFrequency::Yearly => (1..MAX_SEARCH_STEPS).find_map(|step| {
+                let years = i32::try_from(step.checked_mul(interval)?).ok()?;
+                let year = date.year().checked_add(years)?;
+                NaiveDate::from_ymd_opt(year, date.month(), date.day())
+            }),
+        }
+    }
+}
+
+/// `month` 달의 `day` 일. 음수면 말일부터 센다. 그 달에 없는 날이면 `None` 이다.
+fn resolve_month_day(month: NaiveDate, day: i8) -> Option<NaiveDate> {
+    if day > 0 {
+        return month.with_day(day as u32);
+    }
+    let last = month
+        .checked_add_months(Months::new(1))?
+        .checked_sub_days(Days::new(1))?;
+    let resolved = i64::from(last.day()) + i64::from(day) + 1;
+    if resolved < 1 {
+        return None;
+    }
+    last.with_day(resolved as u32)
+}
+
+impl TryFrom<&str> for RecurrenceRule {
+    type Error = anyhow::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let body = s.trim();
+        let body = body.strip_prefix("RRULE:").unwrap_or(body);
+        let mut frequency = None;
+        let mut interval = None;
+        let mut by_day = None;
+        let mut by_month_day = None;
+        let mut count = None;
+        let mut until = None;
+
+        for part in body.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| anyhow!("malformed RRULE part: {part}"))?;
+            let duplicated = match key.to_ascii_uppercase().as_str() {
+                "FREQ" => frequency
+                    .replace(RecurrenceFrequency::try_from(
+                        value.to_ascii_uppercase().as_str(),
+                    )?)
+                    .is_some(),
+                "INTERVAL" => interval.replace(parse_positive(key, value)?).is_some(),
+                "BYDAY" => by_day.replace(parse_weekdays(value)?).is_some(),
+                "BYMONTHDAY" => by_month_day.replace(parse_month_days(value)?).is_some(),
+                "COUNT" => count.replace(parse_positive(key, value)?).is_some(),
+                "UNTIL" => until.replace(parse_until(value)?).is_some(),
+                other => bail!("unsupported RRULE part: {other}"),
+            };
+            if duplicated {
+                bail!("duplicated RRULE part: {key}");
+            }
+        }
+
+        let mut rule = Self::new(frequency.ok_or_else(|| anyhow!("FREQ is required"))?);
+        rule.interval = interval.unwrap_or(1);
+        rule.by_day = by_day.unwrap_or_default();
+        rule.by_month_day = by_month_day.unwrap_or_default();
+        rule.count = count;
+        rule.until = until;
+        if !rule.by_day.is_empty() && rule.frequency != RecurrenceFrequency::Weekly {
+            bail!("BYDAY is only supported with FREQ=WEEKLY");
+        }
+        if !rule.by_month_day.is_empty() && rule.frequency != RecurrenceFrequency::Monthly {
+            bail!("BYMONTHDAY is only supported with FREQ=MONTHLY");
+        }
+        if rule.count.is_some() && rule.until.is_some() {
+            bail!("COUNT and UNTIL cannot be used together");
+        }
+        Ok(rule)
+    }
+}
+
+/// 정규화한 RRULE 문자열. `INTERVAL=1` 은 생략한다.
+impl fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FREQ={}", self.frequency.as_str())?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<&str> = self.by_day.iter().map(|d| weekday_code(*d)).collect();
+            write!(f, ";BYDAY={}", days.join(","))?;
+        }
+        if !self.by_month_day.is_empty() {
+            let days: Vec<String> = self.by_month_day.iter().map(|d| d.to_string()).collect();
+            write!(f, ";BYMONTHDAY={}", days.join(","))?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={count}")?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until.format("%Y%m%dT%H%M%SZ"))?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_positive(key: &str, value: &str) -> anyhow::Result<u32> {
+    match value.parse::<u32>() {
+        Ok(v) if v > 0 => Ok(v),
+        _ => Err(anyhow!("{key} must be a positive integer: {value}")),
+    }
+}
+
+fn parse_weekdays(value: &str) -> anyhow::Result<Vec<Weekday>> {
+    let mut days = value
+        .split(',')
+        .map(|code| match code.to_ascii_uppercase().as_str() {
+            "MO" => Ok(Weekday::Mon),
+            "TU" => Ok(Weekday::Tue),
+            "WE" => Ok(Weekday::Wed),
+            "TH" => Ok(Weekday::Thu),
+            "FR" => Ok(Weekday::Fri),
+            "SA" => Ok(Weekday::Sat),
+            "SU" => Ok(Weekday::Sun),
+            other => Err(anyhow!("unsupported BYDAY value: {other}")),
+        })
+        .collect::<anyhow::Result<Vec<Weekday>>>()?;
+    days.sort_by_key(|d| d.num_days_from_monday());
+    days.dedup();
+    Ok(days)
+}
+
+fn parse_month_days(value: &str) -> anyhow::Result<Vec<i8>> {
+    let mut days = value
+        .split(',')
+        .map(|day| match day.parse::<i8>() {
+            Ok(d) if (1..=31).contains(&d) || (-31..=-1).contains(&d) => Ok(d),
+            _ => Err(anyhow!("unsupported BYMONTHDAY value: {day}")),
+        })
+        .collect::<anyhow::Result<Vec<i8>>>()?;
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+/// `UNTIL` 은 UTC 시각(`20301231T235959Z`)이나 날짜(`20301231`, 그날 끝까지)로 받는다.
+fn parse_until(value: &str) -> anyhow::Result<DateTime<Utc>> {
+    if let Ok(at) = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ") {
+        return Ok(at.and_utc());
+    }
+    NaiveDate::parse_from_str(value, "%Y%m%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(23, 59, 59))
+        .map(|at| at.and_utc())
+        .ok_or_else(|| anyhow!("malformed UNTIL: {value}"))
+}
+
+fn weekday_code(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, m: u32, d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, 9, 0, 0).unwrap()
+    }
+
+    fn rule(s: &str) -> RecurrenceRule {
+        RecurrenceRule::try_from(s).unwrap()
+    }
+
+    #[test]
+    fn parse_normalizes_and_roundtrips() {
+        let r = rule("RRULE:freq=weekly;byday=FR,MO,WE,MO;INTERVAL=1");
+        assert_eq!(r.to_string(), "FREQ=WEEKLY;BYDAY=MO,WE,FR");
+        assert_eq!(rule(&r.to_string()), r);
+
+        let r = rule("FREQ=MONTHLY;BYMONTHDAY=-1,1;UNTIL=20301231");
+        assert_eq!(
+            r.to_string(),
+            "FREQ=MONTHLY;BYMONTHDAY=-1,1;UNTIL=20301231T235959Z"
+        );
+    }
+
+    #[test]
+    fn parse_rejects_unsupported_rules() {
+        for s in [
+            "",
+            "INTERVAL=2",
+            "FREQ=HOURLY",
+            "FREQ=DAILY;INTERVAL=0",
+            "FREQ=DAILY;BYDAY=MO",
+            "FREQ=WEEKLY;BYDAY=1MO",
+            "FREQ=WEEKLY;BYMONTHDAY=1",
+            "FREQ=MONTHLY;BYMONTHDAY=32",
+            "FREQ=DAILY;COUNT=2;UNTIL=20301231",
+            "FREQ=DAILY;FREQ=WEEKLY",
+            "FREQ=DAILY;BYSETPOS=1",
+        ] {
+            assert!(RecurrenceRule::try_from(s).is_err(), "{s} must be rejected");
+        }
+    }
+
+    #[test]
+    fn huge_interval_has_no_next_occurrence() {
+        for s in [
+            "FREQ=DAILY;INTERVAL=4294967295",
+            "FREQ=WEEKLY;INTERVAL=4294967295",
+            "FREQ=WEEKLY;BYDAY=MO;INTERVAL=4294967295",
+            "FREQ=MONTHLY;INTERVAL=4294967295",
+            "FREQ=YEARLY;INTERVAL=4294967295",
+            "FREQ=YEARLY;INTERVAL=2147483647",
+        ] {
+            assert!(rule(s).upcoming(at(2030, 1, 3), 2).is_empty(), "{s}");
+        }
+    }
+
+    #[test]
+    fn daily_with_interval_keeps_time_of_day() {
+        let next = rule("FREQ=DAILY;INTERVAL=3").upcoming(at(2030, 1, 30), 2);
+        assert_eq!(next, vec![at(2030, 2, 2), at(2030, 2, 5)]);
+    }
+
+    #[test]
+    fn weekly_on_weekdays_skips_weekend() {
+        // 2030-01-03 은 목요일이다.
+        let next = rule("FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR").upcoming(at(2030, 1, 3), 3);
+        assert_eq!(next, vec![at(2030, 1, 4), at(2030, 1, 7), at(2030, 1, 8)]);
+    }
+
+    #[test]
+    fn weekly_with_interval_jumps_to_first_day_of_later_week() {
+        // 2030-01-09 는 수요일이다.
+        let next = rule("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE").upcoming(at(2030, 1, 9), 2);
+        assert_eq!(next, vec![at(2030, 1, 21), at(2030, 1, 23)]);
+    }
+
+    #[test]
+    fn monthly_on_first_and_last_day() {
+        let next = rule("FREQ=MONTHLY;BYMONTHDAY=1").upcoming(at(2030, 1, 15), 2);
+        assert_eq!(next, vec![at(2030, 2, 1), at(2030, 3, 1)]);
+
+        let next = rule("FREQ=MONTHLY;BYMONTHDAY=-1").upcoming(at(2030, 1, 31), 2);
+        assert_eq!(next, vec![at(2030, 2, 28), at(2030, 3, 31)]);
+    }
+
+    #[test]
+    fn monthly_without_day_skips_months_missing_that_day() {
+        let next = rule("FREQ=MONTHLY").upcoming(at(2030, 1, 31), 2);
+        assert_eq!(next, vec![at(2030, 3, 31), at(2030, 5, 31)]);
+    }
+
+    #[test]
+    fn yearly_on_leap_day_waits_for_leap_year() {
+        let next = rule("FREQ=YEARLY").upcoming(at(2028, 2, 29), 1);
+        assert_eq!(next, vec![at(2032, 2, 29)]);
+    }
+
+    #[test]
+    fn count_and_until_end_the_series() {
+        let (next, rest) = rule("FREQ=DAILY;COUNT=3")
+            .next_occurrence(at(2030, 1, 1))
+            .unwrap();
+        assert_eq!(next, at(2030, 1, 2));
+        assert_eq!(rest.count, Some(2));
+        assert_eq!(rest.upcoming(next, 5), vec![at(2030, 1, 3)]);
+        assert!(rule("FREQ=DAILY;COUNT=1")
+            .next_occurrence(at(2030, 1, 1))
+            .is_none());
+
+        let next = rule("FREQ=DAILY;UNTIL=20300103").upcoming(at(2030, 1, 1), 5);
+        assert_eq!(next, vec![at(2030, 1, 2), at(2030, 1, 3)]);
+    }
+}
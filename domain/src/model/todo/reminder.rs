@@ -0,0 +1,16 @@
+use crate::model::todo::Todo;
+use crate::model::user::User;
+use crate::model::Id;
+use chrono::{DateTime, Utc};
+
+/// 보낼 때가 된 todo 알림 한 건. 알림 채널로 보내는 내용이다.
+#[derive(Debug, Clone)]
+pub struct TodoReminder {
+    pub todo_id: Id<Todo>,
+    pub owner_id: Id<User>,
+    pub title: String,
+    pub due_at: Option<DateTime<Utc>>,
+    pub remind_at: DateTime<Utc>,
+    /// 이번을 포함해 이 알림을 보내려 한 횟수.
+    pub attempts: i32,
+}
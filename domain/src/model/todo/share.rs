@@ -0,0 +1,95 @@
+use crate::model::todo::Todo;
+use crate::model::user::User;
+use crate::model::Id;
+use chrono::{DateTime, Utc};
+
+/// 공유 권한 — DB `todo_shares.permission` 컬럼의 유효값.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TodoSharePermission {
+    /// 조회만 할 수 있다.
+    Read,
+    /// 조회와 수정을 할 수 있다.
+    Write,
+}
+
+impl TodoSharePermission {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Read => "read",
+            Self::Write => "write",
+        }
+    }
+
+    /// 이 권한으로 todo 를 고칠 수 있는지 여부.
+    pub fn can_write(&self) -> bool {
+        *self == Self::Write
+    }
+}
+
+impl TryFrom<&str> for TodoSharePermission {
+    type Error = anyhow::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "read" => Ok(Self::Read),
+            "write" => Ok(Self::Write),
+            other => Err(anyhow::anyhow!("unknown share permission: {other}")),
+        }
+    }
+}
+
+/// todo 를 공유받은 사용자 한 명.
+#[derive(Debug, Clone)]
+pub struct TodoShare {
+    pub todo_id: Id<Todo>,
+    pub user_id: Id<User>,
+    pub username: String,
+    pub permission: TodoSharePermission,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 공유 추가. 이미 공유한 사용자이면 권한만 바꾼다.
+pub struct NewTodoShare {
+    pub todo_id: Id<Todo>,
+    pub user_id: Id<User>,
+    pub permission: TodoSharePermission,
+}
+
+impl NewTodoShare {
+    pub fn new(todo_id: Id<Todo>, user_id: Id<User>, permission: TodoSharePermission) -> Self {
+        Self {
+            todo_id,
+            user_id,
+            permission,
+        }
+    }
+}
+
+/// 공유받은 사용자 입장에서 본 공유. 소유자 범위의 조회·수정에 `owner_id` 를 쓴다.
+#[derive(Debug, Clone)]
+pub struct TodoGrant {
+    /// 공유된 todo. 상위 todo 의 공유로 하위 작업에 접근하면 그 상위 todo 이다.
+    pub todo_id: Id<Todo>,
+    pub owner_id: Id<User>,
+    pub permission: TodoSharePermission,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn permission_round_trips_through_str() {
+        for permission in [TodoSharePermission::Read, TodoSharePermission::Write] {
+            let parsed = TodoSharePermission::try_from(permission.as_str()).unwrap();
+            assert_eq!(parsed, permission);
+        }
+        assert!(TodoSharePermission::try_from("admin").is_err());
+    }
+
+    #[test]
+    fn only_write_permission_can_write() {
+        assert!(!TodoSharePermission::Read.can_write());
+        assert!(TodoSharePermission::Write.can_write());
+    }
+}
@@ -0,0 +1,91 @@
+use crate::model::todo::status::TodoStatusCode;
+use chrono::{DateTime, Utc};
+
+/// 통계 기간을 나누는 단위. 값은 PostgreSQL `date_trunc` 의 필드 이름과 같다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TodoStatsInterval {
+    Day,
+    /// 월요일에 시작하는 ISO 주.
+    Week,
+}
+
+impl TodoStatsInterval {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Week => "week",
+        }
+    }
+}
+
+impl TryFrom<&str> for TodoStatsInterval {
+    type Error = anyhow::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "day" => Ok(Self::Day),
+            "week" => Ok(Self::Week),
+            other => Err(anyhow::anyhow!("unknown stats interval: {other}")),
+        }
+    }
+}
+
+/// 생성·완료 추이를 셀 기간. `from` 이상 `to` 미만이며 UTC 기준으로 `interval` 단위로 나눈다.
+#[derive(Debug, Clone)]
+pub struct TodoStatsRange {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub interval: TodoStatsInterval,
+}
+
+impl TodoStatsRange {
+    pub fn new(from: DateTime<Utc>, to: DateTime<Utc>, interval: TodoStatsInterval) -> Self {
+        Self { from, to, interval }
+    }
+}
+
+/// 상태별 todo 건수.
+#[derive(Debug, Clone)]
+pub struct TodoStatusCount {
+    pub code: TodoStatusCode,
+    pub count: i64,
+}
+
+/// 기간 한 칸의 생성·완료 건수. `start` 는 칸의 시작 시각이다.
+#[derive(Debug, Clone)]
+pub struct TodoStatsBucket {
+    pub start: DateTime<Utc>,
+    pub created: i64,
+    pub completed: i64,
+}
+
+/// 사용자 한 명의 todo 통계.
+///
+/// 완료 시각은 `done` 으로 바뀐 마지막 이력의 시각이며, 이력이 없으면 마지막 수정 시각으로 본다.
+/// 완료 건수와 평균 완료 시간은 지금 `done` 인 todo 만 센다.
+/// `average_completion_seconds` 는 기간 안에 완료한 todo 의 생성부터 완료까지 걸린 평균 초이며,
+/// 완료한 todo 가 없으면 `None` 이다.
+/// `overdue` 는 마감일이 지났지만 끝나지도(`done`, `discontinued`) 휴지통에 있지도 않은 todo 건수이다.
+#[derive(Debug, Clone)]
+pub struct TodoStats {
+    pub status_counts: Vec<TodoStatusCount>,
+    pub buckets: Vec<TodoStatsBucket>,
+    pub average_completion_seconds: Option<f64>,
+    pub overdue: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn todo_stats_interval_as_str_roundtrips() {
+        for interval in [TodoStatsInterval::Day, TodoStatsInterval::Week] {
+            assert_eq!(
+                TodoStatsInterval::try_from(interval.as_str()).unwrap(),
+                interval
+            );
+        }
+        assert!(TodoStatsInterval::try_from("month").is_err());
+    }
+}
@@ -1,6 +1,11 @@
 use crate::model::Id;
+use chrono::{DateTime, Utc};
 
 /// Todo 상태 코드 — DB `todo_statuses.code` 컬럼의 유효값.
+///
+/// 마이그레이션으로 넣는 기본 상태 말고 관리자가 추가한 상태는 `Custom` 이다.
+/// 사용자 정의 상태는 진행 중인 상태로 보아 `Working` 과 같은 곳에서 들어오고 나갈 수 있으며,
+/// 사용자 정의 상태끼리도 서로 바꿀 수 있다.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TodoStatusCode {
     New,
@@ -10,10 +15,14 @@ pub enum TodoStatusCode {
     Discontinued,
     Pending,
     Deleted,
+    Custom(String),
 }
 
+/// 사용자 정의 상태 코드의 최대 길이. DB `todo_statuses.code` 컬럼 길이와 같다.
+pub const MAX_STATUS_CODE_LEN: usize = 32;
+
 impl TodoStatusCode {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             Self::New => "new",
             Self::Working => "working",
@@ -22,23 +31,90 @@ impl TodoStatusCode {
             Self::Discontinued => "discontinued",
             Self::Pending => "pending",
             Self::Deleted => "deleted",
+            Self::Custom(code) => code,
         }
     }
-}
 
-impl TryFrom<&str> for TodoStatusCode {
-    type Error = anyhow::Error;
+    /// 새 사용자 정의 상태 코드를 만든다.
+    /// 소문자로 시작하고 소문자·숫자·`_` 로 된 2 ~ 32자여야 하며, 기본 상태 코드와 겹치면 안 된다.
+    pub fn custom(code: &str) -> anyhow::Result<Self> {
+        let valid_chars = code.starts_with(|c: char| c.is_ascii_lowercase())
+            && code
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if !valid_chars || !(2..=MAX_STATUS_CODE_LEN).contains(&code.len()) {
+            return Err(anyhow::anyhow!("invalid custom status code: {code}"));
+        }
+        match Self::from(code) {
+            Self::Custom(code) => Ok(Self::Custom(code)),
+            _ => Err(anyhow::anyhow!("`{code}` is a built-in status code")),
+        }
+    }
+
+    /// 관리자가 추가한 상태인지 여부.
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Self::Custom(_))
+    }
 
-    fn try_from(s: &str) -> Result<Self, Self::Error> {
+    /// 목록에 보일 순서. 기본 상태는 선언 순서이고 사용자 정의 상태는 그 뒤이다.
+    pub fn position(&self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|code| code == self)
+            .unwrap_or(Self::ALL.len())
+    }
+
+    /// 기본 상태 코드. 선언 순서이다.
+    pub const ALL: [TodoStatusCode; 7] = [
+        Self::New,
+        Self::Working,
+        Self::Waiting,
+        Self::Done,
+        Self::Discontinued,
+        Self::Pending,
+        Self::Deleted,
+    ];
+
+    /// 이 상태에서 바꿀 수 있는 다음 상태 목록.
+    /// 끝난 작업(`done`, `discontinued`)은 다시 착수하는 것만 허용한다.
+    /// `deleted` 는 삭제·복원으로만 드나들 수 있으므로 어느 목록에도 없다.
+    pub fn next_codes(&self) -> &'static [TodoStatusCode] {
+        use TodoStatusCode::*;
+        match self {
+            New => &[Working, Waiting, Done, Discontinued, Pending],
+            Working => &[Waiting, Done, Discontinued, Pending],
+            Waiting => &[Working, Discontinued, Pending],
+            Pending => &[Working, Waiting, Discontinued],
+            Done => &[Working],
+            Discontinued => &[Working],
+            Deleted => &[],
+            Custom(_) => &[Working, Waiting, Done, Discontinued, Pending],
+        }
+    }
+
+    /// `next` 상태로 바꿀 수 있는지 여부. 같은 상태를 유지하는 것은 항상 허용한다.
+    /// 사용자 정의 상태로는 `Working` 으로 바꿀 수 있는 상태나 다른 사용자 정의 상태에서 바꿀 수 있다.
+    pub fn can_transition_to(&self, next: &TodoStatusCode) -> bool {
+        self == next
+            || match next {
+                Self::Custom(_) => self.is_custom() || self.can_transition_to(&Self::Working),
+                next => self.next_codes().contains(next),
+            }
+    }
+}
+
+/// 기본 상태가 아닌 코드는 모두 사용자 정의 상태로 본다.
+impl From<&str> for TodoStatusCode {
+    fn from(s: &str) -> Self {
         match s {
-            "new" => Ok(Self::New),
-            "working" => Ok(Self::Working),
-            "waiting" => Ok(Self::Waiting),
-            "done" => Ok(Self::Done),
-            "discontinued" => Ok(Self::Discontinued),
-            "pending" => Ok(Self::Pending),
-            "deleted" => Ok(Self::Deleted),
-            other => Err(anyhow::anyhow!("unknown status code: {other}")),
+            "new" => Self::New,
+            "working" => Self::Working,
+            "waiting" => Self::Waiting,
+            "done" => Self::Done,
+            "discontinued" => Self::Discontinued,
+            "pending" => Self::Pending,
+            "deleted" => Self::Deleted,
+            other => Self::Custom(other.to_string()),
         }
     }
 }
@@ -56,6 +132,27 @@ impl TodoStatus {
     }
 }
 
+/// 상태 목록의 한 항목.
+/// 끈(`disabled_at` 이 있는) 상태는 새로 고를 수 없지만, 이미 그 상태인 todo 는 그대로 둔다.
+#[derive(Debug, Clone)]
+pub struct TodoStatusEntry {
+    pub status: TodoStatus,
+    pub disabled_at: Option<DateTime<Utc>>,
+}
+
+/// 사용자 정의 상태 추가.
+pub struct NewTodoStatus {
+    pub id: Id<TodoStatus>,
+    pub code: TodoStatusCode,
+    pub name: String,
+}
+
+impl NewTodoStatus {
+    pub fn new(id: Id<TodoStatus>, code: TodoStatusCode, name: String) -> Self {
+        Self { id, code, name }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,20 +169,41 @@ mod tests {
     }
 
     #[test]
-    fn todo_status_code_try_from_valid_str() {
-        assert_eq!(
-            TodoStatusCode::try_from("new").unwrap(),
-            TodoStatusCode::New
-        );
+    fn todo_status_code_from_known_str() {
+        assert_eq!(TodoStatusCode::from("new"), TodoStatusCode::New);
+        assert_eq!(TodoStatusCode::from("done"), TodoStatusCode::Done);
+    }
+
+    #[test]
+    fn todo_status_code_from_unknown_str_falls_back_to_custom() {
         assert_eq!(
-            TodoStatusCode::try_from("done").unwrap(),
-            TodoStatusCode::Done
+            TodoStatusCode::from("review"),
+            TodoStatusCode::Custom("review".to_string())
         );
+        assert_eq!(TodoStatusCode::from("review").as_str(), "review");
     }
 
     #[test]
-    fn todo_status_code_try_from_invalid_str_returns_error() {
-        assert!(TodoStatusCode::try_from("invalid").is_err());
+    fn todo_status_code_custom_validates_format_and_rejects_built_in() {
+        assert!(TodoStatusCode::custom("in_review2").unwrap().is_custom());
+        for code in ["done", "Review", "2nd", "a", "has-dash", &"x".repeat(33)] {
+            assert!(TodoStatusCode::custom(code).is_err(), "{code}");
+        }
+    }
+
+    #[test]
+    fn todo_status_code_custom_behaves_like_working() {
+        let review = TodoStatusCode::Custom("review".to_string());
+        let qa = TodoStatusCode::Custom("qa".to_string());
+        assert!(TodoStatusCode::New.can_transition_to(&review));
+        assert!(TodoStatusCode::Done.can_transition_to(&review));
+        assert!(review.can_transition_to(&TodoStatusCode::Done));
+        assert!(review.can_transition_to(&qa));
+        assert!(!review.can_transition_to(&TodoStatusCode::New));
+        assert!(!TodoStatusCode::Deleted.can_transition_to(&review));
+        assert!(!review.can_transition_to(&TodoStatusCode::Deleted));
+        assert_eq!(review.position(), TodoStatusCode::ALL.len());
+        assert_eq!(TodoStatusCode::Done.position(), 3);
     }
 
     #[test]
@@ -101,7 +219,34 @@ mod tests {
         ];
         for code in codes {
             let s = code.as_str();
-            assert_eq!(TodoStatusCode::try_from(s).unwrap(), code);
+            assert_eq!(TodoStatusCode::from(s), code);
+        }
+    }
+
+    #[test]
+    fn todo_status_code_can_transition_to_follows_workflow() {
+        assert!(TodoStatusCode::New.can_transition_to(&TodoStatusCode::Working));
+        assert!(TodoStatusCode::Working.can_transition_to(&TodoStatusCode::Done));
+        assert!(TodoStatusCode::Done.can_transition_to(&TodoStatusCode::Working));
+        assert!(!TodoStatusCode::Done.can_transition_to(&TodoStatusCode::New));
+        assert!(!TodoStatusCode::Waiting.can_transition_to(&TodoStatusCode::Done));
+    }
+
+    #[test]
+    fn todo_status_code_can_transition_to_same_status() {
+        for code in TodoStatusCode::ALL {
+            assert!(code.can_transition_to(&code));
+        }
+    }
+
+    #[test]
+    fn todo_status_code_deleted_is_not_reachable_by_transition() {
+        assert!(TodoStatusCode::Deleted.next_codes().is_empty());
+        for code in TodoStatusCode::ALL {
+            if code != TodoStatusCode::Deleted {
+                assert!(!TodoStatusCode::Deleted.can_transition_to(&code));
+                assert!(!code.can_transition_to(&TodoStatusCode::Deleted));
+            }
         }
     }
 }
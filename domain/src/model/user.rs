@@ -7,6 +7,8 @@ pub struct User {
     pub email: String,
     pub password: String,
     pub fullname: String,
+    /// 사용자가 고른 응답 언어 (`ko`, `en` 등). 없으면 요청의 `Accept-Language` 를 따른다.
+    pub locale: Option<String>,
 }
 
 impl std::fmt::Debug for User {
@@ -17,6 +19,7 @@ impl std::fmt::Debug for User {
             .field("email", &self.email)
             .field("password", &"****")
             .field("fullname", &self.fullname)
+            .field("locale", &self.locale)
             .finish()
     }
 }
@@ -35,6 +38,7 @@ impl User {
             email,
             password,
             fullname,
+            locale: None,
         }
     }
 }
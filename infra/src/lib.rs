@@ -1,3 +1,7 @@
+#![allow(clippy::module_inception)]
+
 pub mod db;
 pub mod model;
+pub mod notifier;
 pub mod repository;
+pub mod storage;
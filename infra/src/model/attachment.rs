@@ -0,0 +1,82 @@
+use chrono::{DateTime, Utc};
+use domain::model::attachment::{Attachment, NewAttachment};
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct StoredAttachment {
+    pub id: String,
+    pub todo_id: String,
+    pub uploader_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TryFrom<StoredAttachment> for Attachment {
+    type Error = anyhow::Error;
+
+    fn try_from(a: StoredAttachment) -> Result<Self, Self::Error> {
+        Ok(Attachment {
+            id: a.id.try_into()?,
+            todo_id: a.todo_id.try_into()?,
+            uploader_id: a.uploader_id.try_into()?,
+            file_name: a.file_name,
+            content_type: a.content_type,
+            size_bytes: a.size_bytes,
+            storage_key: a.storage_key,
+            created_at: a.created_at,
+        })
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct InsertAttachment {
+    pub id: String,
+    pub todo_id: String,
+    pub uploader_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_key: String,
+}
+
+impl From<NewAttachment> for InsertAttachment {
+    fn from(na: NewAttachment) -> Self {
+        InsertAttachment {
+            id: na.id.value.to_string(),
+            todo_id: na.todo_id.value.to_string(),
+            uploader_id: na.uploader_id.value.to_string(),
+            file_name: na.file_name,
+            content_type: na.content_type,
+            size_bytes: na.size_bytes,
+            storage_key: na.storage_key,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::model::Id;
+
+    #[test]
+    fn insert_attachment_from_new_attachment_maps_all_fields() {
+        let id: Id<Attachment> = Id::gen();
+        let ulid_str = id.value.to_string();
+        let na = NewAttachment::new(
+            id,
+            Id::gen(),
+            Id::gen(),
+            "memo.txt".to_string(),
+            "text/plain".to_string(),
+            7,
+        );
+        let storage_key = na.storage_key.clone();
+        let insert: InsertAttachment = na.into();
+        assert_eq!(insert.id, ulid_str);
+        assert_eq!(insert.storage_key, storage_key);
+        assert_eq!(insert.size_bytes, 7);
+    }
+}
@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use domain::model::comment::{Comment, NewComment};
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct StoredComment {
+    pub id: String,
+    pub todo_id: String,
+    pub author_id: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<StoredComment> for Comment {
+    type Error = anyhow::Error;
+
+    fn try_from(c: StoredComment) -> Result<Self, Self::Error> {
+        Ok(Comment {
+            id: c.id.try_into()?,
+            todo_id: c.todo_id.try_into()?,
+            author_id: c.author_id.try_into()?,
+            body: c.body,
+            created_at: c.created_at,
+            updated_at: c.updated_at,
+        })
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct InsertComment {
+    pub id: String,
+    pub todo_id: String,
+    pub author_id: String,
+    pub body: String,
+}
+
+impl From<NewComment> for InsertComment {
+    fn from(nc: NewComment) -> Self {
+        InsertComment {
+            id: nc.id.value.to_string(),
+            todo_id: nc.todo_id.value.to_string(),
+            author_id: nc.author_id.value.to_string(),
+            body: nc.body,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::model::Id;
+
+    #[test]
+    fn insert_comment_from_new_comment_maps_all_fields() {
+        let id: Id<Comment> = Id::gen();
+        let ulid_str = id.value.to_string();
+        let nc = NewComment::new(id, Id::gen(), Id::gen(), "body".to_string());
+        let insert: InsertComment = nc.into();
+        assert_eq!(insert.id, ulid_str);
+        assert_eq!(insert.body, "body");
+    }
+
+    #[test]
+    fn stored_comment_try_into_comment_fails_with_invalid_author_id() {
+        let now = Utc::now();
+        let stored = StoredComment {
+            id: Id::<Comment>::gen().value.to_string(),
+            todo_id: Id::<Comment>::gen().value.to_string(),
+            author_id: "not-a-ulid".to_string(),
+            body: "body".to_string(),
+            created_at: now,
+            updated_at: now,
+        };
+        let result: Result<Comment, _> = stored.try_into();
+        assert!(result.is_err());
+    }
+}
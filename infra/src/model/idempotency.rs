@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use domain::model::idempotency::{IdempotencyKey, IdempotentResponse, NewIdempotencyKey};
+use sqlx::types::Json;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct StoredIdempotencyKey {
+    pub user_id: String,
+    pub idempotency_key: String,
+    pub request_hash: String,
+    pub status: Option<i16>,
+    /// 응답 헤더의 `[이름, 값]` 배열.
+    pub headers: Option<Json<Vec<(String, String)>>>,
+    pub body: Option<Vec<u8>>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl TryFrom<StoredIdempotencyKey> for IdempotencyKey {
+    type Error = anyhow::Error;
+
+    fn try_from(k: StoredIdempotencyKey) -> Result<Self, Self::Error> {
+        let response = match k.status {
+            Some(status) => Some(IdempotentResponse {
+                status: u16::try_from(status)?,
+                headers: k.headers.map(|h| h.0).unwrap_or_default(),
+                body: k.body.unwrap_or_default(),
+            }),
+            None => None,
+        };
+        Ok(IdempotencyKey {
+            user_id: k.user_id.try_into()?,
+            key: k.idempotency_key,
+            request_hash: k.request_hash,
+            response,
+            created_at: k.created_at,
+            expires_at: k.expires_at,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct InsertIdempotencyKey {
+    pub user_id: String,
+    pub idempotency_key: String,
+    pub request_hash: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<NewIdempotencyKey> for InsertIdempotencyKey {
+    fn from(nk: NewIdempotencyKey) -> Self {
+        InsertIdempotencyKey {
+            user_id: nk.user_id.value.to_string(),
+            idempotency_key: nk.key,
+            request_hash: nk.request_hash,
+            expires_at: nk.expires_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::model::Id;
+
+    fn stored(status: Option<i16>) -> StoredIdempotencyKey {
+        StoredIdempotencyKey {
+            user_id: Id::<()>::gen().value.to_string(),
+            idempotency_key: "key-1".to_string(),
+            request_hash: "hash".to_string(),
+            status,
+            headers: status.map(|_| Json(vec![("etag".to_string(), "\"1\"".to_string())])),
+            body: status.map(|_| b"{}".to_vec()),
+            created_at: Utc::now(),
+            expires_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn stored_key_without_status_has_no_response() {
+        let key: IdempotencyKey = stored(None).try_into().unwrap();
+        assert!(key.response.is_none());
+    }
+
+    #[test]
+    fn stored_key_with_status_maps_response() {
+        let key: IdempotencyKey = stored(Some(200)).try_into().unwrap();
+        let response = key.response.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.headers[0].0, "etag");
+        assert_eq!(response.body, b"{}");
+    }
+}
@@ -1,2 +1,7 @@
+pub mod attachment;
+pub mod comment;
+pub mod idempotency;
+pub mod project;
+pub mod tag;
 pub mod todo;
 pub mod user;
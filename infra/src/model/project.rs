@@ -0,0 +1,74 @@
+use domain::model::project::{NewProject, Project};
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct StoredProject {
+    pub id: String,
+    pub owner_id: String,
+    pub name: String,
+    pub color: String,
+    pub archived: bool,
+}
+
+impl TryFrom<StoredProject> for Project {
+    type Error = anyhow::Error;
+
+    fn try_from(p: StoredProject) -> Result<Self, Self::Error> {
+        Ok(Project {
+            id: p.id.try_into()?,
+            owner_id: p.owner_id.try_into()?,
+            name: p.name,
+            color: p.color,
+            archived: p.archived,
+        })
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct InsertProject {
+    pub id: String,
+    pub owner_id: String,
+    pub name: String,
+    pub color: String,
+}
+
+impl From<NewProject> for InsertProject {
+    fn from(np: NewProject) -> Self {
+        InsertProject {
+            id: np.id.value.to_string(),
+            owner_id: np.owner_id.value.to_string(),
+            name: np.name,
+            color: np.color,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::model::Id;
+
+    #[test]
+    fn insert_project_from_new_project_maps_all_fields() {
+        let id: Id<Project> = Id::gen();
+        let ulid_str = id.value.to_string();
+        let np = NewProject::new(id, Id::gen(), "집안일".to_string(), "#ff8800".to_string());
+        let insert: InsertProject = np.into();
+        assert_eq!(insert.id, ulid_str);
+        assert_eq!(insert.name, "집안일");
+        assert_eq!(insert.color, "#ff8800");
+    }
+
+    #[test]
+    fn stored_project_try_into_project_keeps_archived_flag() {
+        let stored = StoredProject {
+            id: Id::<Project>::gen().value.to_string(),
+            owner_id: Id::<Project>::gen().value.to_string(),
+            name: "work".to_string(),
+            color: "#9e9e9e".to_string(),
+            archived: true,
+        };
+        let project: Project = stored.try_into().unwrap();
+        assert!(project.archived);
+    }
+}
@@ -0,0 +1,65 @@
+use domain::model::tag::{NewTag, Tag};
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct StoredTag {
+    pub id: String,
+    pub owner_id: String,
+    pub name: String,
+}
+
+impl TryFrom<StoredTag> for Tag {
+    type Error = anyhow::Error;
+
+    fn try_from(t: StoredTag) -> Result<Self, Self::Error> {
+        Ok(Tag {
+            id: t.id.try_into()?,
+            owner_id: t.owner_id.try_into()?,
+            name: t.name,
+        })
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct InsertTag {
+    pub id: String,
+    pub owner_id: String,
+    pub name: String,
+}
+
+impl From<NewTag> for InsertTag {
+    fn from(nt: NewTag) -> Self {
+        InsertTag {
+            id: nt.id.value.to_string(),
+            owner_id: nt.owner_id.value.to_string(),
+            name: nt.name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::model::Id;
+
+    #[test]
+    fn insert_tag_from_new_tag_maps_all_fields() {
+        let id: Id<Tag> = Id::gen();
+        let ulid_str = id.value.to_string();
+        let nt = NewTag::new(id, Id::gen(), "work".to_string());
+        let insert: InsertTag = nt.into();
+        assert_eq!(insert.id, ulid_str);
+        assert_eq!(insert.name, "work");
+    }
+
+    #[test]
+    fn stored_tag_try_into_tag_fails_with_invalid_id() {
+        let stored = StoredTag {
+            id: "not-a-ulid".to_string(),
+            owner_id: Id::<Tag>::gen().value.to_string(),
+            name: "work".to_string(),
+        };
+        let result: Result<Tag, _> = stored.try_into();
+        assert!(result.is_err());
+    }
+}
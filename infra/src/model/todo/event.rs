@@ -0,0 +1,99 @@
+use chrono::{DateTime, Utc};
+use domain::model::todo::event::{NewTodoEvent, TodoEvent, TodoFieldChange};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use sqlx::FromRow;
+
+/// `todo_events.changes` jsonb 배열의 원소.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StoredTodoFieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl From<TodoFieldChange> for StoredTodoFieldChange {
+    fn from(c: TodoFieldChange) -> Self {
+        Self {
+            field: c.field,
+            before: c.before,
+            after: c.after,
+        }
+    }
+}
+
+impl From<StoredTodoFieldChange> for TodoFieldChange {
+    fn from(c: StoredTodoFieldChange) -> Self {
+        Self {
+            field: c.field,
+            before: c.before,
+            after: c.after,
+        }
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct StoredTodoEvent {
+    pub id: String,
+    pub todo_id: String,
+    pub actor_id: String,
+    pub changes: Json<Vec<StoredTodoFieldChange>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TryFrom<StoredTodoEvent> for TodoEvent {
+    type Error = anyhow::Error;
+
+    fn try_from(e: StoredTodoEvent) -> Result<Self, Self::Error> {
+        Ok(TodoEvent {
+            id: e.id.try_into()?,
+            todo_id: e.todo_id.try_into()?,
+            actor_id: e.actor_id.try_into()?,
+            changes: e.changes.0.into_iter().map(Into::into).collect(),
+            created_at: e.created_at,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct InsertTodoEvent {
+    pub id: String,
+    pub todo_id: String,
+    pub actor_id: String,
+    pub changes: Json<Vec<StoredTodoFieldChange>>,
+}
+
+impl From<NewTodoEvent> for InsertTodoEvent {
+    fn from(ne: NewTodoEvent) -> Self {
+        InsertTodoEvent {
+            id: ne.id.value.to_string(),
+            todo_id: ne.todo_id.value.to_string(),
+            actor_id: ne.actor_id.value.to_string(),
+            changes: Json(ne.changes.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::model::Id;
+
+    #[test]
+    fn insert_todo_event_from_new_todo_event_keeps_changes() {
+        let ne = NewTodoEvent::new(
+            Id::gen(),
+            Id::gen(),
+            Id::gen(),
+            vec![TodoFieldChange::new(
+                "title",
+                Some("old".to_string()),
+                Some("new".to_string()),
+            )],
+        );
+        let insert: InsertTodoEvent = ne.into();
+        assert_eq!(insert.changes.0.len(), 1);
+        assert_eq!(insert.changes.0[0].field, "title");
+        assert_eq!(insert.changes.0[0].after.as_deref(), Some("new"));
+    }
+}
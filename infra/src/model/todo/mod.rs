@@ -1,37 +1,103 @@
+pub mod event;
+pub mod reminder;
+pub mod share;
+pub mod stats;
 pub mod status;
 
 use chrono::{DateTime, Utc};
+use domain::model::tag::Tag;
+use domain::model::todo::priority::TodoPriority;
+use domain::model::todo::recurrence::RecurrenceRule;
 use domain::model::todo::status::{TodoStatus, TodoStatusCode};
-use domain::model::todo::{NewTodo, Todo, UpdateTodo, UpsertTodo};
+use domain::model::todo::{NewTodo, Todo, TodoSearchHit, UpdateTodo, UpsertTodo};
+use domain::model::user::User;
+use domain::model::Id;
 use sqlx::FromRow;
 
 #[derive(FromRow, Debug)]
 pub struct StoredTodo {
     pub id: String,
+    pub owner_id: String,
+    pub parent_id: Option<String>,
     pub title: String,
     pub description: String,
     pub status_id: String,
     pub status_code: String,
     pub status_name: String,
+    pub due_at: Option<DateTime<Utc>>,
+    pub priority: i16,
+    /// 붙어 있는 태그의 id 와 이름. 같은 순서(이름 순)로 나란히 조회한다.
+    pub tag_ids: Vec<String>,
+    pub tag_names: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub version: i64,
+    pub recurrence: Option<String>,
+    pub remind_at: Option<DateTime<Utc>>,
+    pub project_id: Option<String>,
+    pub archived_at: Option<DateTime<Utc>>,
+    pub position: String,
 }
 
 impl TryFrom<StoredTodo> for Todo {
     type Error = anyhow::Error;
 
     fn try_from(t: StoredTodo) -> Result<Self, Self::Error> {
+        let owner_id: Id<User> = t.owner_id.try_into()?;
+        let tags = t
+            .tag_ids
+            .into_iter()
+            .zip(t.tag_names)
+            .map(|(id, name)| Ok(Tag::new(id.try_into()?, owner_id.clone(), name)))
+            .collect::<anyhow::Result<Vec<Tag>>>()?;
         Ok(Todo {
             id: t.id.try_into()?,
+            owner_id,
+            parent_id: t.parent_id.map(TryInto::try_into).transpose()?,
             title: t.title,
             description: t.description,
             status: TodoStatus::new(
                 t.status_id.try_into()?,
-                TodoStatusCode::try_from(t.status_code.as_str())?,
+                TodoStatusCode::from(t.status_code.as_str()),
                 t.status_name,
             ),
+            due_at: t.due_at,
+            priority: TodoPriority::from_rank(t.priority)?,
+            tags,
             created_at: t.created_at,
             updated_at: t.updated_at,
+            deleted_at: t.deleted_at,
+            version: t.version,
+            recurrence: t
+                .recurrence
+                .as_deref()
+                .map(RecurrenceRule::try_from)
+                .transpose()?,
+            remind_at: t.remind_at,
+            project_id: t.project_id.map(TryInto::try_into).transpose()?,
+            archived_at: t.archived_at,
+            position: t.position,
+        })
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct StoredTodoSearchHit {
+    #[sqlx(flatten)]
+    pub todo: StoredTodo,
+    pub rank: f32,
+    pub snippet: String,
+}
+
+impl TryFrom<StoredTodoSearchHit> for TodoSearchHit {
+    type Error = anyhow::Error;
+
+    fn try_from(h: StoredTodoSearchHit) -> Result<Self, Self::Error> {
+        Ok(TodoSearchHit {
+            todo: h.todo.try_into()?,
+            rank: h.rank,
+            snippet: h.snippet,
         })
     }
 }
@@ -39,54 +105,100 @@ impl TryFrom<StoredTodo> for Todo {
 #[derive(FromRow, Debug)]
 pub struct InsertTodo {
     pub id: String,
+    pub owner_id: String,
+    pub parent_id: Option<String>,
     pub title: String,
     pub description: String,
+    pub due_at: Option<DateTime<Utc>>,
+    pub priority: i16,
+    pub recurrence: Option<String>,
+    pub remind_at: Option<DateTime<Utc>>,
+    pub project_id: Option<String>,
 }
 
 impl From<NewTodo> for InsertTodo {
     fn from(nt: NewTodo) -> Self {
         InsertTodo {
             id: nt.id.value.to_string(),
+            owner_id: nt.owner_id.value.to_string(),
+            parent_id: nt.parent_id.map(|p| p.value.to_string()),
             title: nt.title,
             description: nt.description,
+            due_at: nt.due_at,
+            priority: nt.priority.rank(),
+            recurrence: nt.recurrence.map(|r| r.to_string()),
+            remind_at: nt.remind_at,
+            project_id: nt.project_id.map(|p| p.value.to_string()),
         }
     }
 }
 
 pub struct UpdateStoredTodo {
     pub id: String,
+    pub owner_id: String,
     pub title: Option<String>,
     pub description: Option<String>,
     pub status_id: Option<String>,
+    /// `due_at` 을 바꿀지 여부. `true` 이고 `due_at` 이 `None` 이면 마감일을 지운다.
+    pub set_due_at: bool,
+    pub due_at: Option<DateTime<Utc>>,
+    pub priority: Option<i16>,
+    /// `recurrence` 를 바꿀지 여부. `due_at` 과 같은 방식이다.
+    pub set_recurrence: bool,
+    pub recurrence: Option<String>,
+    /// `remind_at` 을 바꿀지 여부. `due_at` 과 같은 방식이며, 바꾸면 알림을 다시 보낸다.
+    pub set_remind_at: bool,
+    pub remind_at: Option<DateTime<Utc>>,
+    pub expected_version: Option<i64>,
 }
 
 impl From<UpdateTodo> for UpdateStoredTodo {
     fn from(ut: UpdateTodo) -> Self {
         let status_id = ut.status.map(|s| s.id.value.to_string());
+        let due_at = ut.due_at.into_change();
+        let recurrence = ut.recurrence.into_change();
+        let remind_at = ut.remind_at.into_change();
 
         UpdateStoredTodo {
             id: ut.id.value.to_string(),
+            owner_id: ut.owner_id.value.to_string(),
             title: ut.title,
             description: ut.description,
             status_id,
+            set_due_at: due_at.is_some(),
+            due_at: due_at.flatten(),
+            priority: ut.priority.map(|p| p.rank()),
+            set_recurrence: recurrence.is_some(),
+            recurrence: recurrence.flatten().map(|r| r.to_string()),
+            set_remind_at: remind_at.is_some(),
+            remind_at: remind_at.flatten(),
+            expected_version: ut.expected_version,
         }
     }
 }
 
 pub struct UpsertStoredTodo {
     pub id: String,
+    pub owner_id: String,
     pub title: String,
     pub description: String,
     pub status_id: String,
+    pub due_at: Option<DateTime<Utc>>,
+    pub priority: i16,
+    pub expected_version: Option<i64>,
 }
 
 impl From<UpsertTodo> for UpsertStoredTodo {
     fn from(ut: UpsertTodo) -> Self {
         UpsertStoredTodo {
             id: ut.id.value.to_string(),
+            owner_id: ut.owner_id.value.to_string(),
             title: ut.title,
             description: ut.description,
             status_id: ut.status.id.value.to_string(),
+            due_at: ut.due_at,
+            priority: ut.priority.rank(),
+            expected_version: ut.expected_version,
         }
     }
 }
@@ -97,7 +209,8 @@ mod tests {
     use chrono::Utc;
     use domain::model::todo::status::{TodoStatus, TodoStatusCode};
     use domain::model::todo::{NewTodo, Todo, UpdateTodo, UpsertTodo};
-    use domain::model::Id;
+    use domain::model::user::User;
+    use domain::model::{FieldUpdate, Id};
 
     fn make_todo_status() -> TodoStatus {
         TodoStatus::new(Id::gen(), TodoStatusCode::New, "신규".to_string())
@@ -107,9 +220,12 @@ mod tests {
     fn insert_todo_from_new_todo_maps_all_fields() {
         let id: Id<domain::model::todo::Todo> = Id::gen();
         let ulid_str = id.value.to_string();
-        let nt = NewTodo::new(id, "Task".to_string(), "Do it".to_string());
+        let owner_id: Id<User> = Id::gen();
+        let owner_str = owner_id.value.to_string();
+        let nt = NewTodo::new(id, owner_id, "Task".to_string(), "Do it".to_string());
         let insert: InsertTodo = nt.into();
         assert_eq!(insert.id, ulid_str);
+        assert_eq!(insert.owner_id, owner_str);
         assert_eq!(insert.title, "Task");
         assert_eq!(insert.description, "Do it");
     }
@@ -118,23 +234,51 @@ mod tests {
     fn stored_todo_try_into_todo_succeeds_with_valid_ids() {
         let todo_id: Id<domain::model::todo::Todo> = Id::gen();
         let status_id: Id<domain::model::todo::status::TodoStatus> = Id::gen();
+        let owner_id: Id<User> = Id::gen();
         let todo_ulid = todo_id.value;
         let status_ulid = status_id.value;
+        let owner_ulid = owner_id.value;
+        let tag_ulid = Id::<domain::model::tag::Tag>::gen().value;
+        let parent_ulid = Id::<Todo>::gen().value;
         let now = Utc::now();
         let stored = StoredTodo {
             id: todo_ulid.to_string(),
+            owner_id: owner_ulid.to_string(),
+            parent_id: Some(parent_ulid.to_string()),
             title: "My Todo".to_string(),
             description: "Details".to_string(),
             status_id: status_ulid.to_string(),
             status_code: "new".to_string(),
             status_name: "신규".to_string(),
+            due_at: None,
+            priority: 1,
+            tag_ids: vec![tag_ulid.to_string()],
+            tag_names: vec!["work".to_string()],
             created_at: now,
             updated_at: now,
+            deleted_at: None,
+            version: 3,
+            recurrence: Some("FREQ=WEEKLY;BYDAY=MO".to_string()),
+            remind_at: Some(now),
+            project_id: None,
+            archived_at: None,
+            position: "i".to_string(),
         };
         let todo: Todo = stored.try_into().unwrap();
         assert_eq!(todo.id.value, todo_ulid);
+        assert_eq!(todo.owner_id.value, owner_ulid);
+        assert_eq!(todo.parent_id.map(|p| p.value), Some(parent_ulid));
         assert_eq!(todo.title, "My Todo");
         assert_eq!(todo.status.id.value, status_ulid);
+        assert_eq!(todo.tags.len(), 1);
+        assert_eq!(todo.tags[0].id.value, tag_ulid);
+        assert_eq!(todo.tags[0].owner_id.value, owner_ulid);
+        assert_eq!(todo.tags[0].name, "work");
+        assert_eq!(todo.version, 3);
+        assert_eq!(
+            todo.recurrence.map(|r| r.to_string()).as_deref(),
+            Some("FREQ=WEEKLY;BYDAY=MO")
+        );
     }
 
     #[test]
@@ -143,12 +287,29 @@ mod tests {
         let ulid_str = id.value.to_string();
         let status = make_todo_status();
         let status_ulid = status.id.value;
-        let ut = UpdateTodo::new(id, Some("Updated".to_string()), None, Some(status));
+        let ut = UpdateTodo::new(
+            id,
+            Id::gen(),
+            Some("Updated".to_string()),
+            None,
+            Some(status),
+        );
         let stored: UpdateStoredTodo = ut.into();
         assert_eq!(stored.id, ulid_str);
         assert_eq!(stored.title, Some("Updated".to_string()));
         assert!(stored.description.is_none());
         assert_eq!(stored.status_id, Some(status_ulid.to_string()));
+        assert!(!stored.set_due_at);
+    }
+
+    #[test]
+    fn update_stored_todo_from_update_todo_clears_due_at() {
+        let mut ut = UpdateTodo::new(Id::gen(), Id::gen(), None, None, None);
+        ut.due_at = FieldUpdate::Clear;
+        let stored: UpdateStoredTodo = ut.into();
+        assert!(stored.set_due_at);
+        assert!(stored.due_at.is_none());
+        assert!(!stored.set_remind_at);
     }
 
     #[test]
@@ -157,9 +318,18 @@ mod tests {
         let ulid_str = id.value.to_string();
         let status = make_todo_status();
         let status_ulid = status.id.value;
-        let ut = UpsertTodo::new(id, "Title".to_string(), "Desc".to_string(), status);
+        let owner_id: Id<User> = Id::gen();
+        let owner_str = owner_id.value.to_string();
+        let ut = UpsertTodo::new(
+            id,
+            owner_id,
+            "Title".to_string(),
+            "Desc".to_string(),
+            status,
+        );
         let stored: UpsertStoredTodo = ut.into();
         assert_eq!(stored.id, ulid_str);
+        assert_eq!(stored.owner_id, owner_str);
         assert_eq!(stored.title, "Title");
         assert_eq!(stored.description, "Desc");
         assert_eq!(stored.status_id, status_ulid.to_string());
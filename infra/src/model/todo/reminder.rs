@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use domain::model::todo::reminder::TodoReminder;
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct StoredTodoReminder {
+    pub id: String,
+    pub owner_id: String,
+    pub title: String,
+    pub due_at: Option<DateTime<Utc>>,
+    pub remind_at: DateTime<Utc>,
+    pub reminder_attempts: i32,
+}
+
+impl TryFrom<StoredTodoReminder> for TodoReminder {
+    type Error = anyhow::Error;
+
+    fn try_from(r: StoredTodoReminder) -> Result<Self, Self::Error> {
+        Ok(TodoReminder {
+            todo_id: r.id.try_into()?,
+            owner_id: r.owner_id.try_into()?,
+            title: r.title,
+            due_at: r.due_at,
+            remind_at: r.remind_at,
+            attempts: r.reminder_attempts,
+        })
+    }
+}
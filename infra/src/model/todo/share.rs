@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+use domain::model::todo::share::{NewTodoShare, TodoGrant, TodoShare};
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct StoredTodoShare {
+    pub todo_id: String,
+    pub user_id: String,
+    pub username: String,
+    pub permission: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TryFrom<StoredTodoShare> for TodoShare {
+    type Error = anyhow::Error;
+
+    fn try_from(s: StoredTodoShare) -> Result<Self, Self::Error> {
+        Ok(TodoShare {
+            todo_id: s.todo_id.try_into()?,
+            user_id: s.user_id.try_into()?,
+            username: s.username,
+            permission: s.permission.as_str().try_into()?,
+            created_at: s.created_at,
+        })
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct StoredTodoGrant {
+    pub todo_id: String,
+    pub owner_id: String,
+    pub permission: String,
+}
+
+impl TryFrom<StoredTodoGrant> for TodoGrant {
+    type Error = anyhow::Error;
+
+    fn try_from(g: StoredTodoGrant) -> Result<Self, Self::Error> {
+        Ok(TodoGrant {
+            todo_id: g.todo_id.try_into()?,
+            owner_id: g.owner_id.try_into()?,
+            permission: g.permission.as_str().try_into()?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct InsertTodoShare {
+    pub todo_id: String,
+    pub user_id: String,
+    pub permission: String,
+}
+
+impl From<NewTodoShare> for InsertTodoShare {
+    fn from(ns: NewTodoShare) -> Self {
+        InsertTodoShare {
+            todo_id: ns.todo_id.value.to_string(),
+            user_id: ns.user_id.value.to_string(),
+            permission: ns.permission.as_str().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::model::todo::share::TodoSharePermission;
+    use domain::model::Id;
+
+    #[test]
+    fn stored_todo_share_rejects_unknown_permission() {
+        let stored = StoredTodoShare {
+            todo_id: Id::<()>::gen().value.to_string(),
+            user_id: Id::<()>::gen().value.to_string(),
+            username: "guest".to_string(),
+            permission: "admin".to_string(),
+            created_at: Utc::now(),
+        };
+        assert!(TodoShare::try_from(stored).is_err());
+    }
+
+    #[test]
+    fn insert_todo_share_stores_permission_code() {
+        let insert: InsertTodoShare =
+            NewTodoShare::new(Id::gen(), Id::gen(), TodoSharePermission::Write).into();
+        assert_eq!(insert.permission, "write");
+    }
+}
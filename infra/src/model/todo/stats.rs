@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use domain::model::todo::stats::{TodoStatsBucket, TodoStatusCount};
+use sqlx::FromRow;
+
+#[derive(FromRow, Debug)]
+pub struct StoredTodoStatusCount {
+    pub code: String,
+    pub count: i64,
+}
+
+impl From<StoredTodoStatusCount> for TodoStatusCount {
+    fn from(s: StoredTodoStatusCount) -> Self {
+        TodoStatusCount {
+            code: s.code.as_str().into(),
+            count: s.count,
+        }
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct StoredTodoStatsBucket {
+    pub start: DateTime<Utc>,
+    pub created: i64,
+    pub completed: i64,
+}
+
+impl From<StoredTodoStatsBucket> for TodoStatsBucket {
+    fn from(b: StoredTodoStatsBucket) -> Self {
+        TodoStatsBucket {
+            start: b.start,
+            created: b.created,
+            completed: b.completed,
+        }
+    }
+}
+
+#[derive(FromRow, Debug)]
+pub struct StoredTodoStatsSummary {
+    pub average_completion_seconds: Option<f64>,
+    pub overdue: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::model::todo::status::TodoStatusCode;
+
+    #[test]
+    fn stored_todo_status_count_converts_code() {
+        let stored = StoredTodoStatusCount {
+            code: "done".to_string(),
+            count: 3,
+        };
+        let count: TodoStatusCount = stored.into();
+        assert_eq!(count.code, TodoStatusCode::Done);
+        assert_eq!(count.count, 3);
+    }
+}
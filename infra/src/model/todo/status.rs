@@ -1,4 +1,5 @@
-use domain::model::todo::status::{TodoStatus, TodoStatusCode};
+use chrono::{DateTime, Utc};
+use domain::model::todo::status::{NewTodoStatus, TodoStatus, TodoStatusCode, TodoStatusEntry};
 use sqlx::FromRow;
 
 #[derive(FromRow, Debug)]
@@ -14,12 +15,53 @@ impl TryFrom<StoredTodoStatus> for TodoStatus {
     fn try_from(ts: StoredTodoStatus) -> Result<Self, Self::Error> {
         Ok(TodoStatus {
             id: ts.id.try_into()?,
-            code: TodoStatusCode::try_from(ts.code.as_str())?,
+            code: TodoStatusCode::from(ts.code.as_str()),
             name: ts.name,
         })
     }
 }
 
+#[derive(FromRow, Debug)]
+pub struct StoredTodoStatusEntry {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub disabled_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<StoredTodoStatusEntry> for TodoStatusEntry {
+    type Error = anyhow::Error;
+
+    fn try_from(te: StoredTodoStatusEntry) -> Result<Self, Self::Error> {
+        let status = StoredTodoStatus {
+            id: te.id,
+            code: te.code,
+            name: te.name,
+        };
+        Ok(TodoStatusEntry {
+            status: status.try_into()?,
+            disabled_at: te.disabled_at,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct InsertTodoStatus {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+}
+
+impl From<NewTodoStatus> for InsertTodoStatus {
+    fn from(ns: NewTodoStatus) -> Self {
+        InsertTodoStatus {
+            id: ns.id.value.to_string(),
+            code: ns.code.as_str().to_string(),
+            name: ns.name,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -52,14 +94,28 @@ mod tests {
     }
 
     #[test]
-    fn stored_todo_status_try_into_todo_status_fails_with_unknown_code() {
+    fn stored_todo_status_try_into_todo_status_keeps_unknown_code_as_custom() {
         let id: Id<domain::model::todo::status::TodoStatus> = Id::gen();
         let stored = StoredTodoStatus {
             id: id.value.to_string(),
-            code: "unknown_status".to_string(),
-            name: "Unknown".to_string(),
+            code: "in_review".to_string(),
+            name: "검토".to_string(),
         };
-        let result: Result<domain::model::todo::status::TodoStatus, _> = stored.try_into();
-        assert!(result.is_err());
+        let status: domain::model::todo::status::TodoStatus = stored.try_into().unwrap();
+        assert_eq!(status.code, TodoStatusCode::Custom("in_review".to_string()));
+    }
+
+    #[test]
+    fn stored_todo_status_entry_keeps_disabled_at() {
+        let id: Id<domain::model::todo::status::TodoStatus> = Id::gen();
+        let stored = StoredTodoStatusEntry {
+            id: id.value.to_string(),
+            code: "in_review".to_string(),
+            name: "검토".to_string(),
+            disabled_at: Some(Utc::now()),
+        };
+        let entry: TodoStatusEntry = stored.try_into().unwrap();
+        assert_eq!(entry.status.name, "검토");
+        assert!(entry.disabled_at.is_some());
     }
 }
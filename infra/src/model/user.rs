@@ -8,6 +8,7 @@ pub struct StoredUser {
     pub email: String,
     pub password: String,
     pub fullname: String,
+    pub locale: Option<String>,
 }
 
 impl TryFrom<StoredUser> for User {
@@ -20,6 +21,7 @@ impl TryFrom<StoredUser> for User {
             email: u.email,
             password: u.password,
             fullname: u.fullname,
+            locale: u.locale,
         })
     }
 }
@@ -76,11 +78,13 @@ mod tests {
             email: "dave@example.com".to_string(),
             password: "hashed".to_string(),
             fullname: "Dave".to_string(),
+            locale: Some("en".to_string()),
         };
         let user: User = stored.try_into().unwrap();
         assert_eq!(user.id.value, ulid);
         assert_eq!(user.username, "dave");
         assert_eq!(user.email, "dave@example.com");
+        assert_eq!(user.locale.as_deref(), Some("en"));
     }
 
     #[test]
@@ -91,6 +95,7 @@ mod tests {
             email: "eve@example.com".to_string(),
             password: "pw".to_string(),
             fullname: "Eve".to_string(),
+            locale: None,
         };
         let result: Result<User, _> = stored.try_into();
         assert!(result.is_err());
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use domain::model::todo::reminder::TodoReminder;
+
+/// todo 알림을 사용자에게 보내는 채널 인터페이스.
+///
+/// 기본 구현은 로그로 남기는 `LogNotifier` 이고, 웹훅 등 다른 구현은
+/// `with_component_override::<dyn INotifier>` 로 바꿔 끼운다.
+/// 실패하면 알림을 보내지 않은 것으로 보고 다음 주기에 다시 보낸다.
+#[async_trait]
+pub trait INotifier: shaku::Interface {
+    async fn notify(&self, reminder: &TodoReminder) -> anyhow::Result<()>;
+}
@@ -0,0 +1,24 @@
+use super::interface::INotifier;
+use async_trait::async_trait;
+use domain::model::todo::reminder::TodoReminder;
+use shaku::Component;
+use tracing::info;
+
+/// 알림을 로그로만 남기는 구현체. 알림 채널을 정하지 않았을 때 쓴다.
+#[derive(Component)]
+#[shaku(interface = INotifier)]
+pub struct LogNotifier;
+
+#[async_trait]
+impl INotifier for LogNotifier {
+    async fn notify(&self, reminder: &TodoReminder) -> anyhow::Result<()> {
+        info!(
+            todo_id = %reminder.todo_id.value,
+            owner_id = %reminder.owner_id.value,
+            title = %reminder.title,
+            remind_at = %reminder.remind_at,
+            "todo reminder"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+mod interface;
+mod log;
+mod webhook;
+
+pub use interface::INotifier;
+pub use log::LogNotifier;
+pub use webhook::WebhookNotifier;
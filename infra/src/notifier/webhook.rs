@@ -0,0 +1,80 @@
+use super::interface::INotifier;
+use async_trait::async_trait;
+use chrono::SecondsFormat;
+use domain::model::todo::reminder::TodoReminder;
+use serde_json::{json, Value};
+use shaku::Component;
+use std::time::Duration;
+
+/// 웹훅 요청 하나를 기다리는 최대 시간.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 알림을 JSON 으로 웹훅 URL 에 POST 하는 구현체.
+///
+/// `WebhookNotifier::new(url)` 로 만들어 `with_component_override::<dyn INotifier>` 로 끼운다.
+/// 2xx 가 아닌 응답은 실패로 본다.
+#[derive(Component)]
+#[shaku(interface = INotifier)]
+pub struct WebhookNotifier {
+    url: String,
+    #[shaku(default)]
+    client: reqwest::Client,
+}
+
+impl WebhookNotifier {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl INotifier for WebhookNotifier {
+    async fn notify(&self, reminder: &TodoReminder) -> anyhow::Result<()> {
+        self.client
+            .post(&self.url)
+            .timeout(WEBHOOK_TIMEOUT)
+            .json(&payload(reminder))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+/// 웹훅 본문. 시각은 RFC 3339 문자열이다.
+fn payload(reminder: &TodoReminder) -> Value {
+    json!({
+        "todoId": reminder.todo_id.value.to_string(),
+        "ownerId": reminder.owner_id.value.to_string(),
+        "title": reminder.title,
+        "dueAt": reminder.due_at.map(|at| at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+        "remindAt": reminder.remind_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use domain::model::Id;
+
+    #[test]
+    fn payload_uses_camel_case_and_rfc3339() {
+        let reminder = TodoReminder {
+            todo_id: Id::gen(),
+            owner_id: Id::gen(),
+            title: "Pay rent".to_string(),
+            due_at: None,
+            remind_at: Utc.with_ymd_and_hms(2030, 1, 1, 9, 0, 0).unwrap(),
+            attempts: 1,
+        };
+        let body = payload(&reminder);
+        assert_eq!(body["todoId"], reminder.todo_id.value.to_string());
+        assert_eq!(body["title"], "Pay rent");
+        assert_eq!(body["dueAt"], Value::Null);
+        assert_eq!(body["remindAt"], "2030-01-01T09:00:00Z");
+    }
+}
@@ -0,0 +1,121 @@
+use super::interface::IAttachmentRepository;
+use crate::db::IDatabasePool;
+use crate::model::attachment::{InsertAttachment, StoredAttachment};
+use crate::repository::PgTx;
+use async_trait::async_trait;
+use domain::model::attachment::{Attachment, NewAttachment};
+use domain::model::todo::Todo;
+use domain::model::Id;
+use shaku::Component;
+use sqlx::{query_as, query_scalar};
+use std::sync::Arc;
+
+/// PostgreSQL Attachment 레포지토리 구현체.
+#[derive(Component)]
+#[shaku(interface = IAttachmentRepository)]
+pub struct AttachmentRepository {
+    #[shaku(inject)]
+    db: Arc<dyn IDatabasePool>,
+}
+
+#[async_trait]
+impl IAttachmentRepository for AttachmentRepository {
+    async fn find(&self, todo_id: &Id<Todo>) -> anyhow::Result<Vec<Attachment>> {
+        let sql = r#"
+            SELECT id, todo_id, uploader_id, file_name, content_type, size_bytes, storage_key, created_at
+            FROM attachments
+            WHERE todo_id = $1
+            ORDER BY created_at, id
+        "#;
+        let stored = query_as::<_, StoredAttachment>(sql)
+            .bind(todo_id.value.to_string())
+            .fetch_all(self.db.pool())
+            .await?;
+        stored
+            .into_iter()
+            .map(|sa| sa.try_into())
+            .collect::<anyhow::Result<Vec<Attachment>>>()
+    }
+
+    async fn get(
+        &self,
+        todo_id: &Id<Todo>,
+        id: &Id<Attachment>,
+    ) -> anyhow::Result<Option<Attachment>> {
+        let sql = r#"
+            SELECT id, todo_id, uploader_id, file_name, content_type, size_bytes, storage_key, created_at
+            FROM attachments
+            WHERE id = $1 AND todo_id = $2
+        "#;
+        query_as::<_, StoredAttachment>(sql)
+            .bind(id.value.to_string())
+            .bind(todo_id.value.to_string())
+            .fetch_optional(self.db.pool())
+            .await?
+            .map(|sa| sa.try_into())
+            .transpose()
+    }
+
+    async fn insert_tx(&self, tx: &mut PgTx, source: NewAttachment) -> anyhow::Result<Attachment> {
+        let attachment: InsertAttachment = source.into();
+        let sql = r#"
+            INSERT INTO attachments (id, todo_id, uploader_id, file_name, content_type, size_bytes, storage_key)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, todo_id, uploader_id, file_name, content_type, size_bytes, storage_key, created_at
+        "#;
+        let stored = query_as::<_, StoredAttachment>(sql)
+            .bind(attachment.id)
+            .bind(attachment.todo_id)
+            .bind(attachment.uploader_id)
+            .bind(attachment.file_name)
+            .bind(attachment.content_type)
+            .bind(attachment.size_bytes)
+            .bind(attachment.storage_key)
+            .fetch_one(&mut **tx)
+            .await?;
+        stored.try_into()
+    }
+
+    async fn delete_tx(
+        &self,
+        tx: &mut PgTx,
+        todo_id: &Id<Todo>,
+        id: &Id<Attachment>,
+    ) -> anyhow::Result<Option<Attachment>> {
+        let sql = r#"
+            DELETE FROM attachments
+            WHERE id = $1 AND todo_id = $2
+            RETURNING id, todo_id, uploader_id, file_name, content_type, size_bytes, storage_key, created_at
+        "#;
+        query_as::<_, StoredAttachment>(sql)
+            .bind(id.value.to_string())
+            .bind(todo_id.value.to_string())
+            .fetch_optional(&mut **tx)
+            .await?
+            .map(|sa| sa.try_into())
+            .transpose()
+    }
+
+    async fn find_storage_keys_in_subtrees_tx(
+        &self,
+        tx: &mut PgTx,
+        todo_ids: &[Id<Todo>],
+    ) -> anyhow::Result<Vec<String>> {
+        let ids: Vec<String> = todo_ids.iter().map(|id| id.value.to_string()).collect();
+        let sql = r#"
+            WITH RECURSIVE subtree AS (
+                SELECT id FROM todos WHERE id = ANY($1)
+                UNION
+                SELECT t.id FROM todos t INNER JOIN subtree s ON t.parent_id = s.id
+            )
+            SELECT a.storage_key
+            FROM attachments a
+            INNER JOIN subtree s ON s.id = a.todo_id
+        "#;
+        let keys = query_scalar::<_, String>(sql)
+            .bind(ids)
+            .fetch_all(&mut **tx)
+            .await?;
+        Ok(keys)
+    }
+}
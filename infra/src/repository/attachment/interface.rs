@@ -0,0 +1,37 @@
+use crate::repository::PgTx;
+use async_trait::async_trait;
+use domain::model::attachment::{Attachment, NewAttachment};
+use domain::model::todo::Todo;
+use domain::model::Id;
+
+/// Attachment 레포지토리 인터페이스.
+///
+/// 메타데이터만 다루며 파일 본문은 `IBlobStorage` 가 맡는다.
+/// 모든 조회·변경은 `todo_id` 로 범위가 제한된다. todo 에 접근할 수 있는지는 호출하는 쪽에서 확인한다.
+#[async_trait]
+pub trait IAttachmentRepository: shaku::Interface {
+    async fn find(&self, todo_id: &Id<Todo>) -> anyhow::Result<Vec<Attachment>>;
+    async fn get(
+        &self,
+        todo_id: &Id<Todo>,
+        id: &Id<Attachment>,
+    ) -> anyhow::Result<Option<Attachment>>;
+    async fn insert_tx(
+        &self,
+        tx: &mut PgTx,
+        attachment: NewAttachment,
+    ) -> anyhow::Result<Attachment>;
+    async fn delete_tx(
+        &self,
+        tx: &mut PgTx,
+        todo_id: &Id<Todo>,
+        id: &Id<Attachment>,
+    ) -> anyhow::Result<Option<Attachment>>;
+    /// `todo_ids` 와 그 하위 작업에 붙은 첨부 파일의 저장소 키.
+    /// todo 를 영구 삭제하면 메타데이터는 FK 로 함께 지워지므로, 본문을 지우려면 삭제 전에 모아 둔다.
+    async fn find_storage_keys_in_subtrees_tx(
+        &self,
+        tx: &mut PgTx,
+        todo_ids: &[Id<Todo>],
+    ) -> anyhow::Result<Vec<String>>;
+}
@@ -0,0 +1,5 @@
+mod attachment;
+mod interface;
+
+pub use attachment::AttachmentRepository;
+pub use interface::IAttachmentRepository;
@@ -0,0 +1,127 @@
+use super::interface::ICommentRepository;
+use crate::db::IDatabasePool;
+use crate::model::comment::{InsertComment, StoredComment};
+use crate::repository::PgTx;
+use async_trait::async_trait;
+use domain::model::comment::{Comment, CommentCursor, NewComment, UpdateComment};
+use domain::model::todo::Todo;
+use domain::model::Id;
+use shaku::Component;
+use sqlx::{query_as, Postgres, QueryBuilder};
+use std::sync::Arc;
+
+/// PostgreSQL Comment 레포지토리 구현체.
+#[derive(Component)]
+#[shaku(interface = ICommentRepository)]
+pub struct CommentRepository {
+    #[shaku(inject)]
+    db: Arc<dyn IDatabasePool>,
+}
+
+#[async_trait]
+impl ICommentRepository for CommentRepository {
+    async fn find(
+        &self,
+        todo_id: &Id<Todo>,
+        cursor: Option<CommentCursor>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<Comment>> {
+        let mut qb = QueryBuilder::<Postgres>::new(
+            "SELECT id, todo_id, author_id, body, created_at, updated_at \
+             FROM comments WHERE todo_id = ",
+        );
+        qb.push_bind(todo_id.value.to_string());
+        if let Some(c) = cursor {
+            qb.push(" AND (created_at, id) > (");
+            qb.push_bind(c.created_at);
+            qb.push(", ");
+            qb.push_bind(c.id.value.to_string());
+            qb.push(")");
+        }
+        qb.push(" ORDER BY created_at, id LIMIT ");
+        qb.push_bind(limit);
+
+        let stored: Vec<StoredComment> = qb.build_query_as().fetch_all(self.db.pool()).await?;
+        stored
+            .into_iter()
+            .map(|sc| sc.try_into())
+            .collect::<anyhow::Result<Vec<Comment>>>()
+    }
+
+    async fn get_tx(
+        &self,
+        tx: &mut PgTx,
+        todo_id: &Id<Todo>,
+        id: &Id<Comment>,
+    ) -> anyhow::Result<Option<Comment>> {
+        let sql = r#"
+            SELECT id, todo_id, author_id, body, created_at, updated_at
+            FROM comments
+            WHERE id = $1 AND todo_id = $2
+        "#;
+        query_as::<_, StoredComment>(sql)
+            .bind(id.value.to_string())
+            .bind(todo_id.value.to_string())
+            .fetch_optional(&mut **tx)
+            .await?
+            .map(|sc| sc.try_into())
+            .transpose()
+    }
+
+    async fn insert_tx(&self, tx: &mut PgTx, source: NewComment) -> anyhow::Result<Comment> {
+        let comment: InsertComment = source.into();
+        let sql = r#"
+            INSERT INTO comments (id, todo_id, author_id, body)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, todo_id, author_id, body, created_at, updated_at
+        "#;
+        let stored = query_as::<_, StoredComment>(sql)
+            .bind(comment.id)
+            .bind(comment.todo_id)
+            .bind(comment.author_id)
+            .bind(comment.body)
+            .fetch_one(&mut **tx)
+            .await?;
+        stored.try_into()
+    }
+
+    async fn update_tx(
+        &self,
+        tx: &mut PgTx,
+        source: UpdateComment,
+    ) -> anyhow::Result<Option<Comment>> {
+        let sql = r#"
+            UPDATE comments SET body = $3, updated_at = current_timestamp
+            WHERE id = $1 AND todo_id = $2
+            RETURNING id, todo_id, author_id, body, created_at, updated_at
+        "#;
+        query_as::<_, StoredComment>(sql)
+            .bind(source.id.value.to_string())
+            .bind(source.todo_id.value.to_string())
+            .bind(source.body)
+            .fetch_optional(&mut **tx)
+            .await?
+            .map(|sc| sc.try_into())
+            .transpose()
+    }
+
+    async fn delete_tx(
+        &self,
+        tx: &mut PgTx,
+        todo_id: &Id<Todo>,
+        id: &Id<Comment>,
+    ) -> anyhow::Result<Option<Comment>> {
+        let sql = r#"
+            DELETE FROM comments
+            WHERE id = $1 AND todo_id = $2
+            RETURNING id, todo_id, author_id, body, created_at, updated_at
+        "#;
+        query_as::<_, StoredComment>(sql)
+            .bind(id.value.to_string())
+            .bind(todo_id.value.to_string())
+            .fetch_optional(&mut **tx)
+            .await?
+            .map(|sc| sc.try_into())
+            .transpose()
+    }
+}
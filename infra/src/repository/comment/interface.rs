@@ -0,0 +1,38 @@
+use crate::repository::PgTx;
+use async_trait::async_trait;
+use domain::model::comment::{Comment, CommentCursor, NewComment, UpdateComment};
+use domain::model::todo::Todo;
+use domain::model::Id;
+
+/// Comment 레포지토리 인터페이스.
+///
+/// 모든 조회·변경은 `todo_id` 로 범위가 제한된다. todo 에 접근할 수 있는지는 호출하는 쪽에서 확인한다.
+/// 목록은 (created_at, id) 오름차순 keyset 으로 페이지를 나누며,
+/// `cursor` 다음 항목부터 최대 `limit` 건을 반환한다.
+#[async_trait]
+pub trait ICommentRepository: shaku::Interface {
+    async fn find(
+        &self,
+        todo_id: &Id<Todo>,
+        cursor: Option<CommentCursor>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<Comment>>;
+    async fn get_tx(
+        &self,
+        tx: &mut PgTx,
+        todo_id: &Id<Todo>,
+        id: &Id<Comment>,
+    ) -> anyhow::Result<Option<Comment>>;
+    async fn insert_tx(&self, tx: &mut PgTx, comment: NewComment) -> anyhow::Result<Comment>;
+    async fn update_tx(
+        &self,
+        tx: &mut PgTx,
+        comment: UpdateComment,
+    ) -> anyhow::Result<Option<Comment>>;
+    async fn delete_tx(
+        &self,
+        tx: &mut PgTx,
+        todo_id: &Id<Todo>,
+        id: &Id<Comment>,
+    ) -> anyhow::Result<Option<Comment>>;
+}
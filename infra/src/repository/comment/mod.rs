@@ -0,0 +1,5 @@
+mod comment;
+mod interface;
+
+pub use comment::CommentRepository;
+pub use interface::ICommentRepository;
@@ -0,0 +1,132 @@
+use super::interface::IIdempotencyRepository;
+use crate::db::IDatabasePool;
+use crate::model::idempotency::{InsertIdempotencyKey, StoredIdempotencyKey};
+use crate::repository::PgTx;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::model::idempotency::{IdempotencyKey, IdempotentResponse, NewIdempotencyKey};
+use domain::model::user::User;
+use domain::model::Id;
+use shaku::Component;
+use sqlx::types::Json;
+use sqlx::{query, query_as};
+use std::sync::Arc;
+
+/// PostgreSQL 멱등 키 레포지토리 구현체.
+#[derive(Component)]
+#[shaku(interface = IIdempotencyRepository)]
+pub struct IdempotencyRepository {
+    #[shaku(inject)]
+    db: Arc<dyn IDatabasePool>,
+}
+
+#[async_trait]
+impl IIdempotencyRepository for IdempotencyRepository {
+    async fn claim_tx(
+        &self,
+        tx: &mut PgTx,
+        source: NewIdempotencyKey,
+        stale_before: DateTime<Utc>,
+    ) -> anyhow::Result<bool> {
+        let key: InsertIdempotencyKey = source.into();
+        let sql = r#"
+            INSERT INTO idempotency_keys (user_id, idempotency_key, request_hash, expires_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id, idempotency_key) DO UPDATE
+            SET request_hash = EXCLUDED.request_hash,
+                status = NULL,
+                headers = NULL,
+                body = NULL,
+                created_at = current_timestamp,
+                expires_at = EXCLUDED.expires_at
+            WHERE idempotency_keys.expires_at <= current_timestamp
+               OR (idempotency_keys.status IS NULL AND idempotency_keys.created_at <= $5)
+            RETURNING user_id
+        "#;
+        let claimed = query(sql)
+            .bind(&key.user_id)
+            .bind(&key.idempotency_key)
+            .bind(&key.request_hash)
+            .bind(key.expires_at)
+            .bind(stale_before)
+            .fetch_optional(&mut **tx)
+            .await?;
+        Ok(claimed.is_some())
+    }
+
+    async fn get(&self, user_id: &Id<User>, key: &str) -> anyhow::Result<Option<IdempotencyKey>> {
+        let sql = r#"
+            SELECT user_id, idempotency_key, request_hash, status, headers, body,
+                   created_at, expires_at
+            FROM idempotency_keys
+            WHERE user_id = $1 AND idempotency_key = $2 AND expires_at > current_timestamp
+        "#;
+        query_as::<_, StoredIdempotencyKey>(sql)
+            .bind(user_id.value.to_string())
+            .bind(key)
+            .fetch_optional(self.db.pool())
+            .await?
+            .map(|sk| sk.try_into())
+            .transpose()
+    }
+
+    async fn complete_tx(
+        &self,
+        tx: &mut PgTx,
+        user_id: &Id<User>,
+        key: &str,
+        response: &IdempotentResponse,
+    ) -> anyhow::Result<bool> {
+        let sql = r#"
+            UPDATE idempotency_keys SET status = $3, headers = $4, body = $5
+            WHERE user_id = $1 AND idempotency_key = $2 AND status IS NULL
+        "#;
+        let result = query(sql)
+            .bind(user_id.value.to_string())
+            .bind(key)
+            .bind(i16::try_from(response.status)?)
+            .bind(Json(&response.headers))
+            .bind(&response.body)
+            .execute(&mut **tx)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn release_tx(&self, tx: &mut PgTx, user_id: &Id<User>, key: &str) -> anyhow::Result<()> {
+        let sql = r#"
+            DELETE FROM idempotency_keys
+            WHERE user_id = $1 AND idempotency_key = $2 AND status IS NULL
+        "#;
+        query(sql)
+            .bind(user_id.value.to_string())
+            .bind(key)
+            .execute(&mut **tx)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_expired_tx(&self, tx: &mut PgTx, user_id: &Id<User>) -> anyhow::Result<u64> {
+        let sql = r#"
+            DELETE FROM idempotency_keys
+            WHERE user_id = $1 AND expires_at <= current_timestamp
+        "#;
+        let result = query(sql)
+            .bind(user_id.value.to_string())
+            .execute(&mut **tx)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn delete_all_expired(&self, limit: i64) -> anyhow::Result<u64> {
+        let sql = r#"
+            DELETE FROM idempotency_keys
+            WHERE (user_id, idempotency_key) IN (
+                SELECT user_id, idempotency_key FROM idempotency_keys
+                WHERE expires_at <= current_timestamp
+                LIMIT $1
+            )
+        "#;
+        let result = query(sql).bind(limit).execute(self.db.pool()).await?;
+        Ok(result.rows_affected())
+    }
+}
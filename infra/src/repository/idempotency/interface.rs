@@ -0,0 +1,37 @@
+use crate::repository::PgTx;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::model::idempotency::{IdempotencyKey, IdempotentResponse, NewIdempotencyKey};
+use domain::model::user::User;
+use domain::model::Id;
+
+/// 멱등 키 레포지토리 인터페이스.
+///
+/// 키는 사용자별로 유일하며 모든 조회·변경은 `user_id` 로 범위가 제한된다.
+/// 만료된 키는 없는 것과 같이 다루며 `claim_tx` 로 다시 차지할 수 있다.
+#[async_trait]
+pub trait IIdempotencyRepository: shaku::Interface {
+    /// 키를 차지하여 처리 중으로 기록한다. 차지하면 `true` 이다.
+    /// 같은 키가 이미 있으면 만료되었거나 `stale_before` 전부터 처리 중으로 남은 경우에만 새로 차지한다.
+    async fn claim_tx(
+        &self,
+        tx: &mut PgTx,
+        key: NewIdempotencyKey,
+        stale_before: DateTime<Utc>,
+    ) -> anyhow::Result<bool>;
+    async fn get(&self, user_id: &Id<User>, key: &str) -> anyhow::Result<Option<IdempotencyKey>>;
+    /// 처리 중인 키에 응답을 남긴다. 처리 중인 키가 없으면 `false` 이다.
+    async fn complete_tx(
+        &self,
+        tx: &mut PgTx,
+        user_id: &Id<User>,
+        key: &str,
+        response: &IdempotentResponse,
+    ) -> anyhow::Result<bool>;
+    /// 처리 중인 키를 지워 같은 키로 다시 시도할 수 있게 한다.
+    async fn release_tx(&self, tx: &mut PgTx, user_id: &Id<User>, key: &str) -> anyhow::Result<()>;
+    /// 사용자의 만료된 키를 지우고 지운 건수를 반환한다.
+    async fn delete_expired_tx(&self, tx: &mut PgTx, user_id: &Id<User>) -> anyhow::Result<u64>;
+    /// 모든 사용자의 만료된 키를 최대 `limit` 건 지우고 지운 건수를 반환한다.
+    async fn delete_all_expired(&self, limit: i64) -> anyhow::Result<u64>;
+}
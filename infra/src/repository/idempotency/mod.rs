@@ -0,0 +1,5 @@
+mod idempotency;
+mod interface;
+
+pub use idempotency::IdempotencyRepository;
+pub use interface::IIdempotencyRepository;
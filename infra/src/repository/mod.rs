@@ -1,4 +1,9 @@
+pub mod attachment;
+pub mod comment;
 pub mod health_check;
+pub mod idempotency;
+pub mod project;
+pub mod tag;
 pub mod todo;
 pub mod user;
 
@@ -0,0 +1,54 @@
+use crate::repository::PgTx;
+use async_trait::async_trait;
+use domain::model::project::{NewProject, Project, UpdateProject};
+use domain::model::user::User;
+use domain::model::Id;
+
+/// Project 레포지토리 인터페이스.
+///
+/// 모든 조회·변경은 `owner_id` 로 범위가 제한된다.
+/// 다른 사용자의 프로젝트는 존재하지 않는 것과 같이 `None` 으로 처리한다.
+/// 목록은 이름 순이다.
+///
+/// `set_archived_tx` 는 프로젝트의 보관 여부만 바꾼다. 속한 todo 는 `ITodoRepository` 로 함께 바꾼다.
+#[async_trait]
+pub trait IProjectRepository: shaku::Interface {
+    async fn get(&self, owner_id: &Id<User>, id: &Id<Project>) -> anyhow::Result<Option<Project>>;
+    /// `include_archived` 가 아니면 보관한 프로젝트는 제외한다.
+    async fn find_all(
+        &self,
+        owner_id: &Id<User>,
+        include_archived: bool,
+    ) -> anyhow::Result<Vec<Project>>;
+    async fn get_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Project>,
+    ) -> anyhow::Result<Option<Project>>;
+    async fn get_by_name_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        name: &str,
+    ) -> anyhow::Result<Option<Project>>;
+    async fn insert_tx(&self, tx: &mut PgTx, project: NewProject) -> anyhow::Result<Project>;
+    async fn update_tx(
+        &self,
+        tx: &mut PgTx,
+        project: UpdateProject,
+    ) -> anyhow::Result<Option<Project>>;
+    async fn set_archived_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Project>,
+        archived: bool,
+    ) -> anyhow::Result<Option<Project>>;
+    async fn delete_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Project>,
+    ) -> anyhow::Result<Option<Project>>;
+}
@@ -0,0 +1,5 @@
+mod interface;
+mod project;
+
+pub use interface::IProjectRepository;
+pub use project::ProjectRepository;
@@ -0,0 +1,177 @@
+use super::interface::IProjectRepository;
+use crate::db::IDatabasePool;
+use crate::model::project::{InsertProject, StoredProject};
+use crate::repository::PgTx;
+use async_trait::async_trait;
+use domain::model::project::{NewProject, Project, UpdateProject};
+use domain::model::user::User;
+use domain::model::Id;
+use shaku::Component;
+use sqlx::query_as;
+use std::sync::Arc;
+
+/// PostgreSQL Project 레포지토리 구현체.
+#[derive(Component)]
+#[shaku(interface = IProjectRepository)]
+pub struct ProjectRepository {
+    #[shaku(inject)]
+    db: Arc<dyn IDatabasePool>,
+}
+
+const SELECT_PROJECT_BY_ID: &str = r#"
+    SELECT id, owner_id, name, color, archived
+    FROM projects
+    WHERE id = $1 AND owner_id = $2
+"#;
+
+#[async_trait]
+impl IProjectRepository for ProjectRepository {
+    async fn get(&self, owner_id: &Id<User>, id: &Id<Project>) -> anyhow::Result<Option<Project>> {
+        query_as::<_, StoredProject>(SELECT_PROJECT_BY_ID)
+            .bind(id.value.to_string())
+            .bind(owner_id.value.to_string())
+            .fetch_optional(self.db.pool())
+            .await?
+            .map(|sp| sp.try_into())
+            .transpose()
+    }
+
+    async fn find_all(
+        &self,
+        owner_id: &Id<User>,
+        include_archived: bool,
+    ) -> anyhow::Result<Vec<Project>> {
+        let sql = r#"
+            SELECT id, owner_id, name, color, archived
+            FROM projects
+            WHERE owner_id = $1 AND ($2 OR NOT archived)
+            ORDER BY name
+        "#;
+        let stored = query_as::<_, StoredProject>(sql)
+            .bind(owner_id.value.to_string())
+            .bind(include_archived)
+            .fetch_all(self.db.pool())
+            .await?;
+        stored
+            .into_iter()
+            .map(|sp| sp.try_into())
+            .collect::<anyhow::Result<Vec<Project>>>()
+    }
+
+    async fn get_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Project>,
+    ) -> anyhow::Result<Option<Project>> {
+        query_as::<_, StoredProject>(SELECT_PROJECT_BY_ID)
+            .bind(id.value.to_string())
+            .bind(owner_id.value.to_string())
+            .fetch_optional(&mut **tx)
+            .await?
+            .map(|sp| sp.try_into())
+            .transpose()
+    }
+
+    async fn get_by_name_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        name: &str,
+    ) -> anyhow::Result<Option<Project>> {
+        let sql = r#"
+            SELECT id, owner_id, name, color, archived
+            FROM projects
+            WHERE owner_id = $1 AND name = $2
+        "#;
+        query_as::<_, StoredProject>(sql)
+            .bind(owner_id.value.to_string())
+            .bind(name)
+            .fetch_optional(&mut **tx)
+            .await?
+            .map(|sp| sp.try_into())
+            .transpose()
+    }
+
+    async fn insert_tx(&self, tx: &mut PgTx, source: NewProject) -> anyhow::Result<Project> {
+        let project: InsertProject = source.into();
+        let sql = r#"
+            INSERT INTO projects (id, owner_id, name, color) VALUES ($1, $2, $3, $4)
+            RETURNING id, owner_id, name, color, archived
+        "#;
+        query_as::<_, StoredProject>(sql)
+            .bind(&project.id)
+            .bind(&project.owner_id)
+            .bind(&project.name)
+            .bind(&project.color)
+            .fetch_one(&mut **tx)
+            .await?
+            .try_into()
+    }
+
+    async fn update_tx(
+        &self,
+        tx: &mut PgTx,
+        source: UpdateProject,
+    ) -> anyhow::Result<Option<Project>> {
+        let sql = r#"
+            UPDATE projects SET
+                name  = COALESCE($3, name),
+                color = COALESCE($4, color)
+            WHERE id = $1 AND owner_id = $2
+            RETURNING id, owner_id, name, color, archived
+        "#;
+        query_as::<_, StoredProject>(sql)
+            .bind(source.id.value.to_string())
+            .bind(source.owner_id.value.to_string())
+            .bind(source.name)
+            .bind(source.color)
+            .fetch_optional(&mut **tx)
+            .await?
+            .map(|sp| sp.try_into())
+            .transpose()
+    }
+
+    async fn set_archived_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Project>,
+        archived: bool,
+    ) -> anyhow::Result<Option<Project>> {
+        let sql = r#"
+            UPDATE projects SET archived = $3
+            WHERE id = $1 AND owner_id = $2
+            RETURNING id, owner_id, name, color, archived
+        "#;
+        query_as::<_, StoredProject>(sql)
+            .bind(id.value.to_string())
+            .bind(owner_id.value.to_string())
+            .bind(archived)
+            .fetch_optional(&mut **tx)
+            .await?
+            .map(|sp| sp.try_into())
+            .transpose()
+    }
+
+    async fn delete_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Project>,
+    ) -> anyhow::Result<Option<Project>> {
+        // 속한 todo 의 project_id 는 외래 키로 비워진다.
+        let sql = r#"
+            DELETE FROM projects
+            WHERE id = $1 AND owner_id = $2
+            RETURNING id, owner_id, name, color, archived
+        "#;
+        query_as::<_, StoredProject>(sql)
+            .bind(id.value.to_string())
+            .bind(owner_id.value.to_string())
+            .fetch_optional(&mut **tx)
+            .await?
+            .map(|sp| sp.try_into())
+            .transpose()
+    }
+}
@@ -0,0 +1,55 @@
+use crate::repository::PgTx;
+use async_trait::async_trait;
+use domain::model::tag::{NewTag, Tag, UpdateTag};
+use domain::model::todo::Todo;
+use domain::model::user::User;
+use domain::model::Id;
+
+/// Tag 레포지토리 인터페이스.
+///
+/// 모든 조회·변경은 `owner_id` 로 범위가 제한된다.
+/// 다른 사용자의 태그는 존재하지 않는 것과 같이 `None` 으로 처리한다.
+/// 목록은 이름 순이다.
+///
+/// `attach_tx`, `detach_tx` 는 소유자의 todo 에만 태그를 붙이거나 뗀다.
+/// 이미 붙어 있거나 붙어 있지 않은 태그는 무시한다.
+#[async_trait]
+pub trait ITagRepository: shaku::Interface {
+    async fn get(&self, owner_id: &Id<User>, id: &Id<Tag>) -> anyhow::Result<Option<Tag>>;
+    async fn find_all(&self, owner_id: &Id<User>) -> anyhow::Result<Vec<Tag>>;
+    async fn get_by_name_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        name: &str,
+    ) -> anyhow::Result<Option<Tag>>;
+    /// `ids` 중 소유자의 태그만 반환한다.
+    async fn find_by_ids_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        ids: &[Id<Tag>],
+    ) -> anyhow::Result<Vec<Tag>>;
+    async fn insert_tx(&self, tx: &mut PgTx, tag: NewTag) -> anyhow::Result<Tag>;
+    async fn update_tx(&self, tx: &mut PgTx, tag: UpdateTag) -> anyhow::Result<Option<Tag>>;
+    async fn delete_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Tag>,
+    ) -> anyhow::Result<Option<Tag>>;
+    async fn attach_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        todo_id: &Id<Todo>,
+        tags: &[Tag],
+    ) -> anyhow::Result<()>;
+    async fn detach_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        todo_id: &Id<Todo>,
+        tags: &[Tag],
+    ) -> anyhow::Result<()>;
+}
@@ -0,0 +1,5 @@
+mod interface;
+mod tag;
+
+pub use interface::ITagRepository;
+pub use tag::TagRepository;
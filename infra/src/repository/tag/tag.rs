@@ -0,0 +1,198 @@
+use super::interface::ITagRepository;
+use crate::db::IDatabasePool;
+use crate::model::tag::{InsertTag, StoredTag};
+use crate::repository::PgTx;
+use async_trait::async_trait;
+use domain::model::tag::{NewTag, Tag, UpdateTag};
+use domain::model::todo::Todo;
+use domain::model::user::User;
+use domain::model::Id;
+use shaku::Component;
+use sqlx::{query, query_as};
+use std::sync::Arc;
+
+/// PostgreSQL Tag 레포지토리 구현체.
+#[derive(Component)]
+#[shaku(interface = ITagRepository)]
+pub struct TagRepository {
+    #[shaku(inject)]
+    db: Arc<dyn IDatabasePool>,
+}
+
+#[async_trait]
+impl ITagRepository for TagRepository {
+    async fn get(&self, owner_id: &Id<User>, id: &Id<Tag>) -> anyhow::Result<Option<Tag>> {
+        let sql = r#"
+            SELECT id, owner_id, name
+            FROM tags
+            WHERE id = $1 AND owner_id = $2
+        "#;
+        query_as::<_, StoredTag>(sql)
+            .bind(id.value.to_string())
+            .bind(owner_id.value.to_string())
+            .fetch_optional(self.db.pool())
+            .await?
+            .map(|st| st.try_into())
+            .transpose()
+    }
+
+    async fn find_all(&self, owner_id: &Id<User>) -> anyhow::Result<Vec<Tag>> {
+        let sql = r#"
+            SELECT id, owner_id, name
+            FROM tags
+            WHERE owner_id = $1
+            ORDER BY name
+        "#;
+        let stored = query_as::<_, StoredTag>(sql)
+            .bind(owner_id.value.to_string())
+            .fetch_all(self.db.pool())
+            .await?;
+        stored
+            .into_iter()
+            .map(|st| st.try_into())
+            .collect::<anyhow::Result<Vec<Tag>>>()
+    }
+
+    async fn get_by_name_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        name: &str,
+    ) -> anyhow::Result<Option<Tag>> {
+        let sql = r#"
+            SELECT id, owner_id, name
+            FROM tags
+            WHERE owner_id = $1 AND name = $2
+        "#;
+        query_as::<_, StoredTag>(sql)
+            .bind(owner_id.value.to_string())
+            .bind(name)
+            .fetch_optional(&mut **tx)
+            .await?
+            .map(|st| st.try_into())
+            .transpose()
+    }
+
+    async fn find_by_ids_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        ids: &[Id<Tag>],
+    ) -> anyhow::Result<Vec<Tag>> {
+        let ids: Vec<String> = ids.iter().map(|id| id.value.to_string()).collect();
+        let sql = r#"
+            SELECT id, owner_id, name
+            FROM tags
+            WHERE owner_id = $1 AND id = ANY($2)
+            ORDER BY name
+        "#;
+        let stored = query_as::<_, StoredTag>(sql)
+            .bind(owner_id.value.to_string())
+            .bind(ids)
+            .fetch_all(&mut **tx)
+            .await?;
+        stored
+            .into_iter()
+            .map(|st| st.try_into())
+            .collect::<anyhow::Result<Vec<Tag>>>()
+    }
+
+    async fn insert_tx(&self, tx: &mut PgTx, source: NewTag) -> anyhow::Result<Tag> {
+        let tag: InsertTag = source.into();
+        let sql = r#"
+            INSERT INTO tags (id, owner_id, name) VALUES ($1, $2, $3)
+            RETURNING id, owner_id, name
+        "#;
+        query_as::<_, StoredTag>(sql)
+            .bind(&tag.id)
+            .bind(&tag.owner_id)
+            .bind(&tag.name)
+            .fetch_one(&mut **tx)
+            .await?
+            .try_into()
+    }
+
+    async fn update_tx(&self, tx: &mut PgTx, source: UpdateTag) -> anyhow::Result<Option<Tag>> {
+        let sql = r#"
+            UPDATE tags SET name = $3
+            WHERE id = $1 AND owner_id = $2
+            RETURNING id, owner_id, name
+        "#;
+        query_as::<_, StoredTag>(sql)
+            .bind(source.id.value.to_string())
+            .bind(source.owner_id.value.to_string())
+            .bind(&source.name)
+            .fetch_optional(&mut **tx)
+            .await?
+            .map(|st| st.try_into())
+            .transpose()
+    }
+
+    async fn delete_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Tag>,
+    ) -> anyhow::Result<Option<Tag>> {
+        // todo_tags 의 연결은 외래 키로 함께 삭제된다.
+        let sql = r#"
+            DELETE FROM tags
+            WHERE id = $1 AND owner_id = $2
+            RETURNING id, owner_id, name
+        "#;
+        query_as::<_, StoredTag>(sql)
+            .bind(id.value.to_string())
+            .bind(owner_id.value.to_string())
+            .fetch_optional(&mut **tx)
+            .await?
+            .map(|st| st.try_into())
+            .transpose()
+    }
+
+    async fn attach_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        todo_id: &Id<Todo>,
+        tags: &[Tag],
+    ) -> anyhow::Result<()> {
+        let tag_ids: Vec<String> = tags.iter().map(|t| t.id.value.to_string()).collect();
+        let sql = r#"
+            INSERT INTO todo_tags (todo_id, tag_id)
+            SELECT t.id, tg.id
+            FROM todos t
+            INNER JOIN tags tg ON tg.owner_id = t.owner_id
+            WHERE t.id = $1 AND t.owner_id = $2 AND tg.id = ANY($3)
+            ON CONFLICT ON CONSTRAINT pk_todo_tags DO NOTHING
+        "#;
+        query(sql)
+            .bind(todo_id.value.to_string())
+            .bind(owner_id.value.to_string())
+            .bind(tag_ids)
+            .execute(&mut **tx)
+            .await?;
+        Ok(())
+    }
+
+    async fn detach_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        todo_id: &Id<Todo>,
+        tags: &[Tag],
+    ) -> anyhow::Result<()> {
+        let tag_ids: Vec<String> = tags.iter().map(|t| t.id.value.to_string()).collect();
+        let sql = r#"
+            DELETE FROM todo_tags tt
+            USING todos t
+            WHERE t.id = tt.todo_id AND tt.todo_id = $1 AND t.owner_id = $2 AND tt.tag_id = ANY($3)
+        "#;
+        query(sql)
+            .bind(todo_id.value.to_string())
+            .bind(owner_id.value.to_string())
+            .bind(tag_ids)
+            .execute(&mut **tx)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use crate::db::IDatabasePool;
+use crate::model::todo::event::{InsertTodoEvent, StoredTodoEvent};
+use crate::repository::PgTx;
+use async_trait::async_trait;
+use domain::model::todo::event::{NewTodoEvent, TodoEvent, TodoEventCursor};
+use domain::model::todo::Todo;
+use domain::model::Id;
+use shaku::Component;
+use sqlx::{query_as, Postgres, QueryBuilder};
+use std::sync::Arc;
+
+/// TodoEvent(변경 이력) 레포지토리 인터페이스.
+///
+/// 이력은 todo 를 고치는 트랜잭션 안에서 `insert_tx` 로 남기며 고치거나 지우지 않는다.
+/// 목록은 (created_at, id) 내림차순 keyset 으로 페이지를 나누며,
+/// `cursor` 다음 항목부터 최대 `limit` 건을 반환한다.
+#[async_trait]
+pub trait ITodoEventRepository: shaku::Interface {
+    async fn find(
+        &self,
+        todo_id: &Id<Todo>,
+        cursor: Option<TodoEventCursor>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<TodoEvent>>;
+    async fn insert_tx(&self, tx: &mut PgTx, event: NewTodoEvent) -> anyhow::Result<TodoEvent>;
+}
+
+/// PostgreSQL TodoEvent 레포지토리 구현체.
+#[derive(Component)]
+#[shaku(interface = ITodoEventRepository)]
+pub struct TodoEventRepository {
+    #[shaku(inject)]
+    db: Arc<dyn IDatabasePool>,
+}
+
+#[async_trait]
+impl ITodoEventRepository for TodoEventRepository {
+    async fn find(
+        &self,
+        todo_id: &Id<Todo>,
+        cursor: Option<TodoEventCursor>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<TodoEvent>> {
+        let mut qb = QueryBuilder::<Postgres>::new(
+            "SELECT id, todo_id, actor_id, changes, created_at \
+             FROM todo_events WHERE todo_id = ",
+        );
+        qb.push_bind(todo_id.value.to_string());
+        if let Some(c) = cursor {
+            qb.push(" AND (created_at, id) < (");
+            qb.push_bind(c.created_at);
+            qb.push(", ");
+            qb.push_bind(c.id.value.to_string());
+            qb.push(")");
+        }
+        qb.push(" ORDER BY created_at DESC, id DESC LIMIT ");
+        qb.push_bind(limit);
+
+        let stored: Vec<StoredTodoEvent> = qb.build_query_as().fetch_all(self.db.pool()).await?;
+        stored
+            .into_iter()
+            .map(|se| se.try_into())
+            .collect::<anyhow::Result<Vec<TodoEvent>>>()
+    }
+
+    async fn insert_tx(&self, tx: &mut PgTx, source: NewTodoEvent) -> anyhow::Result<TodoEvent> {
+        let event: InsertTodoEvent = source.into();
+        let sql = r#"
+            INSERT INTO todo_events (id, todo_id, actor_id, changes)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, todo_id, actor_id, changes, created_at
+        "#;
+        let stored = query_as::<_, StoredTodoEvent>(sql)
+            .bind(event.id)
+            .bind(event.todo_id)
+            .bind(event.actor_id)
+            .bind(event.changes)
+            .fetch_one(&mut **tx)
+            .await?;
+        stored.try_into()
+    }
+}
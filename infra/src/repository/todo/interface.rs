@@ -1,19 +1,193 @@
 use crate::repository::PgTx;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::model::project::Project;
+use domain::model::todo::filter::{TodoFilter, TodoSort};
+use domain::model::todo::stats::{TodoStats, TodoStatsRange};
 use domain::model::todo::status::TodoStatus;
-use domain::model::todo::{NewTodo, Todo, UpdateTodo, UpsertTodo};
+use domain::model::todo::{
+    NewTodo, Todo, TodoCursor, TodoSearchCursor, TodoSearchHit, UpdateTodo, UpsertTodo,
+};
+use domain::model::user::User;
 use domain::model::Id;
 
+/// 순위 기준으로 찾을 이웃의 방향.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Adjacent {
+    Before,
+    After,
+}
+
 /// Todo 레포지토리 인터페이스.
+///
+/// 모든 조회·변경은 `owner_id` 로 범위가 제한된다.
+/// 다른 사용자의 todo 는 존재하지 않는 것과 같이 `None` 으로 처리한다.
+///
+/// 목록 조회는 `filter` 조건에 맞는 todo 를 `sort` 기준 (정렬 키, id) keyset 으로 페이지를 나누며,
+/// `cursor` 다음 항목부터 최대 `limit` 건을 반환한다.
+/// 검색은 관련도 내림차순이며 같은 방식으로 페이지를 나눈다.
+/// 상태 조건이 없으면 휴지통(`deleted_at` 이 있는) todo 는 목록·검색에서 제외한다.
+/// 프로젝트 조건이 없으면 보관한(`archived_at` 이 있는) todo 도 제외한다.
+///
+/// `delete_tx` 는 영구 삭제이고, `soft_delete_tx` 는 `deleted` 상태로 옮기며 직전 상태를 기억한다.
+/// `restore_tx` 는 기억한 직전 상태(없으면 `fallback`)로 되돌린다.
+///
+/// 수정·upsert·휴지통 이동·복원은 `version` 을 1 올린다.
+/// `update_tx`·`upsert_tx`·`delete_tx` 는 기대한 버전과 다르거나 대상이 없어 바꾸지 못하면 `None` 을 반환하며,
+/// `soft_delete_tx` 는 버전이 다르면 옮기지 않고 현재 todo 를 그대로 반환한다.
+///
+/// 순서(`position`)는 사용자의 todo 전체에서 매기며, 새 todo 는 맨 뒤에 온다.
+///
+/// `find_subtree` 는 `id` 의 todo 를 첫 번째로, 그 아래 모든 하위 작업을 깊이 순으로 반환한다.
+/// todo 가 없으면 빈 목록이다.
 #[async_trait]
 pub trait ITodoRepository: shaku::Interface {
-    async fn get(&self, id: &Id<Todo>) -> anyhow::Result<Option<Todo>>;
-    async fn find(&self, status: Option<TodoStatus>) -> anyhow::Result<Vec<Todo>>;
-    async fn get_tx(&self, tx: &mut PgTx, id: &Id<Todo>) -> anyhow::Result<Option<Todo>>;
-    async fn find_tx(&self, tx: &mut PgTx, status: Option<TodoStatus>)
-        -> anyhow::Result<Vec<Todo>>;
+    async fn get(&self, owner_id: &Id<User>, id: &Id<Todo>) -> anyhow::Result<Option<Todo>>;
+    async fn find(
+        &self,
+        owner_id: &Id<User>,
+        filter: &TodoFilter,
+        sort: TodoSort,
+        cursor: Option<TodoCursor>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<Todo>>;
+    async fn search(
+        &self,
+        owner_id: &Id<User>,
+        q: &str,
+        filter: &TodoFilter,
+        cursor: Option<TodoSearchCursor>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<TodoSearchHit>>;
+    async fn get_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+    ) -> anyhow::Result<Option<Todo>>;
+    async fn find_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        filter: &TodoFilter,
+        sort: TodoSort,
+        cursor: Option<TodoCursor>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<Todo>>;
     async fn insert_tx(&self, tx: &mut PgTx, todo: NewTodo) -> anyhow::Result<Todo>;
-    async fn update_tx(&self, tx: &mut PgTx, todo: UpdateTodo) -> anyhow::Result<Todo>;
-    async fn upsert_tx(&self, tx: &mut PgTx, todo: UpsertTodo) -> anyhow::Result<Todo>;
-    async fn delete_tx(&self, tx: &mut PgTx, id: &Id<Todo>) -> anyhow::Result<Option<Todo>>;
+    async fn update_tx(&self, tx: &mut PgTx, todo: UpdateTodo) -> anyhow::Result<Option<Todo>>;
+    async fn upsert_tx(&self, tx: &mut PgTx, todo: UpsertTodo) -> anyhow::Result<Option<Todo>>;
+    async fn delete_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<Option<Todo>>;
+    async fn soft_delete_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        deleted: &TodoStatus,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<Option<Todo>>;
+    async fn restore_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        fallback: &TodoStatus,
+    ) -> anyhow::Result<Option<Todo>>;
+    /// 상위 todo 를 바꾸고 버전을 올린다. `None` 이면 최상위로 옮긴다. 순환 여부는 확인하지 않는다.
+    /// `expected_version` 과 버전이 다르면 바꾸지 않고 `None` 이다.
+    async fn set_parent_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        parent_id: Option<&Id<Todo>>,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<Option<Todo>>;
+    /// 프로젝트를 바꾸고 버전을 올린다. `None` 이면 프로젝트에서 뺀다. 보관 표시는 지운다.
+    /// `expected_version` 과 버전이 다르면 바꾸지 않고 `None` 이다.
+    async fn set_project_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        project_id: Option<&Id<Project>>,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<Option<Todo>>;
+    /// 프로젝트에 속한 todo 를 모두 `archived_at` 에 보관하거나 `None` 이면 보관을 푼다.
+    /// 바꾼 todo 의 버전을 올리고 건수를 반환한다.
+    async fn set_archived_by_project_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        project_id: &Id<Project>,
+        archived_at: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<u64>;
+    /// `exclude_id` 를 뺀 사용자의 todo 중 `position` 바로 앞이나 뒤의 순위를 반환한다. 없으면 `None` 이다.
+    async fn get_adjacent_position_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        position: &str,
+        direction: Adjacent,
+        exclude_id: &Id<Todo>,
+    ) -> anyhow::Result<Option<String>>;
+    /// 사용자의 todo 순위를 트랜잭션이 끝날 때까지 잠근다. 새 순위를 고르기 전에 불러
+    /// 동시에 옮기거나 만든 todo 가 같은 순위를 받지 않게 한다. todo 를 만들 때는 저장소가 알아서 잠근다.
+    async fn lock_positions_tx(&self, tx: &mut PgTx, owner_id: &Id<User>) -> anyhow::Result<()>;
+    /// 순위를 바꾸고 버전을 올린다.
+    async fn set_position_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        position: &str,
+    ) -> anyhow::Result<Option<Todo>>;
+    /// 사용자의 todo 를 지금 순서 그대로 같은 간격의 순위로 다시 매기고 바꾼 건수를 반환한다.
+    async fn rebalance_positions_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+    ) -> anyhow::Result<u64>;
+    async fn find_subtree(&self, owner_id: &Id<User>, id: &Id<Todo>) -> anyhow::Result<Vec<Todo>>;
+    async fn find_subtree_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+    ) -> anyhow::Result<Vec<Todo>>;
+    /// 휴지통에 없는 바로 아래 하위 작업 중 완료(`done`)나 중단(`discontinued`)되지 않은 건수를 반환한다.
+    async fn count_unfinished_children_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+    ) -> anyhow::Result<i64>;
+    /// `purge_deleted_tx` 가 영구 삭제할 todo 의 id. 삭제할 때까지 바뀌지 않도록 잠근다.
+    async fn find_purgeable_ids_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        deleted_before: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<Id<Todo>>>;
+    /// `deleted_before` 이전에 휴지통으로 옮긴 todo 를 영구 삭제하고 삭제한 건수를 반환한다.
+    async fn purge_deleted_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        deleted_before: DateTime<Utc>,
+    ) -> anyhow::Result<u64>;
+    /// 상태별 건수, `range` 의 칸별 생성·완료 건수, 평균 완료 시간, `now` 기준 지연 건수를
+    /// SQL 집계로 구한다. 지연 건수 말고는 휴지통과 보관한 todo 도 센다.
+    async fn get_stats(
+        &self,
+        owner_id: &Id<User>,
+        range: &TodoStatsRange,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<TodoStats>;
 }
@@ -1,7 +1,10 @@
+pub mod event;
+pub mod reminder;
+pub mod share;
 pub mod status;
 
 mod interface;
 mod todo;
 
-pub use interface::ITodoRepository;
+pub use interface::{Adjacent, ITodoRepository};
 pub use todo::TodoRepository;
@@ -0,0 +1,131 @@
+use crate::model::todo::reminder::StoredTodoReminder;
+use crate::repository::PgTx;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::model::todo::reminder::TodoReminder;
+use domain::model::todo::status::TodoStatusCode;
+use shaku::Component;
+use sqlx::{query, query_as};
+
+/// Todo 알림 레포지토리 인터페이스.
+///
+/// 알림을 보내는 동안 행을 잠가 두지 않도록, 보낼 알림을 `claim_due_tx` 로 짧은 트랜잭션에서 잡아
+/// 다른 서버가 정해진 시각까지 잡지 않게 표시하고 바로 커밋한다.
+/// 보낸 뒤에는 `mark_delivered_tx` 나 `mark_failed_tx` 로 결과를 표시한다.
+#[async_trait]
+pub trait ITodoReminderRepository: shaku::Interface {
+    /// `now` 까지 보낼 때가 됐지만 아직 보내지 않은 알림을 알림 시각 순으로 최대 `limit` 건 잡는다.
+    /// 잡은 알림은 보내려 한 횟수를 올리고 `lease_until` 까지 다시 잡지 않는다.
+    /// 끝났거나(`done`, `discontinued`) 휴지통에 있거나 보관한 todo, 다시 보낼 시각이 아직 오지 않았거나
+    /// 이미 `max_attempts` 번 보내려 한 알림은 제외한다. 다른 트랜잭션이 잡고 있는 행은 건너뛴다.
+    async fn claim_due_tx(
+        &self,
+        tx: &mut PgTx,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        max_attempts: i32,
+        limit: i64,
+    ) -> anyhow::Result<Vec<TodoReminder>>;
+    /// 잡은 알림을 `delivered_at` 에 보냈다고 표시한다. todo 의 버전은 바꾸지 않는다.
+    /// 그 사이 알림 시각을 다시 정했으면 새 알림이므로 표시하지 않는다.
+    async fn mark_delivered_tx(
+        &self,
+        tx: &mut PgTx,
+        reminder: &TodoReminder,
+        delivered_at: DateTime<Utc>,
+    ) -> anyhow::Result<()>;
+    /// 보내지 못한 알림을 `retry_at` 까지 다시 잡지 않는다. todo 의 버전은 바꾸지 않는다.
+    /// 그 사이 알림 시각을 다시 정했으면 새 알림이므로 표시하지 않는다.
+    async fn mark_failed_tx(
+        &self,
+        tx: &mut PgTx,
+        reminder: &TodoReminder,
+        retry_at: DateTime<Utc>,
+    ) -> anyhow::Result<()>;
+}
+
+/// PostgreSQL Todo 알림 레포지토리 구현체.
+#[derive(Component)]
+#[shaku(interface = ITodoReminderRepository)]
+pub struct TodoReminderRepository;
+
+#[async_trait]
+impl ITodoReminderRepository for TodoReminderRepository {
+    async fn claim_due_tx(
+        &self,
+        tx: &mut PgTx,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        max_attempts: i32,
+        limit: i64,
+    ) -> anyhow::Result<Vec<TodoReminder>> {
+        let sql = r#"
+            WITH due AS (
+                SELECT t.id
+                FROM todos t
+                INNER JOIN todo_statuses ts ON ts.id = t.status_id
+                WHERE t.remind_at <= $1 AND t.reminded_at IS NULL AND t.archived_at IS NULL
+                  AND ts.code NOT IN ($5, $6, $7)
+                  AND (t.reminder_next_attempt_at IS NULL OR t.reminder_next_attempt_at <= $1)
+                  AND t.reminder_attempts < $3
+                ORDER BY t.remind_at, t.id
+                LIMIT $2
+                FOR UPDATE OF t SKIP LOCKED
+            ), claimed AS (
+                UPDATE todos t SET
+                    reminder_attempts = t.reminder_attempts + 1,
+                    reminder_next_attempt_at = $4
+                FROM due
+                WHERE t.id = due.id
+                RETURNING t.id, t.owner_id, t.title, t.due_at, t.remind_at, t.reminder_attempts
+            )
+            SELECT id, owner_id, title, due_at, remind_at, reminder_attempts
+            FROM claimed
+            ORDER BY remind_at, id
+        "#;
+        let stored = query_as::<_, StoredTodoReminder>(sql)
+            .bind(now)
+            .bind(limit)
+            .bind(max_attempts)
+            .bind(lease_until)
+            .bind(TodoStatusCode::Done.as_str())
+            .bind(TodoStatusCode::Discontinued.as_str())
+            .bind(TodoStatusCode::Deleted.as_str())
+            .fetch_all(&mut **tx)
+            .await?;
+        stored
+            .into_iter()
+            .map(|sr| sr.try_into())
+            .collect::<anyhow::Result<Vec<TodoReminder>>>()
+    }
+
+    async fn mark_delivered_tx(
+        &self,
+        tx: &mut PgTx,
+        reminder: &TodoReminder,
+        delivered_at: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        query("UPDATE todos SET reminded_at = $3 WHERE id = $1 AND remind_at = $2")
+            .bind(reminder.todo_id.value.to_string())
+            .bind(reminder.remind_at)
+            .bind(delivered_at)
+            .execute(&mut **tx)
+            .await?;
+        Ok(())
+    }
+
+    async fn mark_failed_tx(
+        &self,
+        tx: &mut PgTx,
+        reminder: &TodoReminder,
+        retry_at: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        query("UPDATE todos SET reminder_next_attempt_at = $3 WHERE id = $1 AND remind_at = $2")
+            .bind(reminder.todo_id.value.to_string())
+            .bind(reminder.remind_at)
+            .bind(retry_at)
+            .execute(&mut **tx)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use crate::db::IDatabasePool;
+use crate::model::todo::share::{InsertTodoShare, StoredTodoGrant, StoredTodoShare};
+use crate::repository::PgTx;
+use async_trait::async_trait;
+use domain::model::todo::share::{NewTodoShare, TodoGrant, TodoShare};
+use domain::model::todo::Todo;
+use domain::model::user::User;
+use domain::model::Id;
+use shaku::Component;
+use sqlx::{query_as, Executor, Postgres};
+use std::sync::Arc;
+
+/// Todo 공유 레포지토리 인터페이스.
+///
+/// 공유 목록과 공유 추가·삭제는 소유자 확인을 마친 유스케이스에서 호출하므로 todo id 로만 찾는다.
+/// `get_grant` 는 공유받은 사용자 입장에서 todo 의 소유자와 권한을 찾으며, 공유가 없으면 `None` 이다.
+/// 상위 todo 의 공유는 하위 작업에도 미치며, 여러 단계에 공유가 있으면 가장 가까운 공유를 따른다.
+#[async_trait]
+pub trait ITodoShareRepository: shaku::Interface {
+    /// todo 를 공유받은 사용자 목록. 사용자 이름 순이다.
+    async fn find(&self, todo_id: &Id<Todo>) -> anyhow::Result<Vec<TodoShare>>;
+    async fn get_grant(
+        &self,
+        user_id: &Id<User>,
+        todo_id: &Id<Todo>,
+    ) -> anyhow::Result<Option<TodoGrant>>;
+    async fn get_grant_tx(
+        &self,
+        tx: &mut PgTx,
+        user_id: &Id<User>,
+        todo_id: &Id<Todo>,
+    ) -> anyhow::Result<Option<TodoGrant>>;
+    /// 공유를 추가한다. 이미 공유한 사용자이면 권한을 바꾼다.
+    async fn upsert_tx(&self, tx: &mut PgTx, source: NewTodoShare) -> anyhow::Result<TodoShare>;
+    /// 공유를 삭제하고 삭제한 공유를 반환한다. 공유가 없으면 `None` 이다.
+    async fn delete_tx(
+        &self,
+        tx: &mut PgTx,
+        todo_id: &Id<Todo>,
+        user_id: &Id<User>,
+    ) -> anyhow::Result<Option<TodoShare>>;
+}
+
+/// PostgreSQL Todo 공유 레포지토리 구현체.
+#[derive(Component)]
+#[shaku(interface = ITodoShareRepository)]
+pub struct TodoShareRepository {
+    #[shaku(inject)]
+    db: Arc<dyn IDatabasePool>,
+}
+
+#[async_trait]
+impl ITodoShareRepository for TodoShareRepository {
+    async fn find(&self, todo_id: &Id<Todo>) -> anyhow::Result<Vec<TodoShare>> {
+        let sql = r#"
+            SELECT s.todo_id, s.user_id, u.username, s.permission, s.created_at
+            FROM todo_shares s
+            INNER JOIN users u ON u.id = s.user_id
+            WHERE s.todo_id = $1
+            ORDER BY u.username
+        "#;
+        let stored = query_as::<_, StoredTodoShare>(sql)
+            .bind(todo_id.value.to_string())
+            .fetch_all(self.db.pool())
+            .await?;
+        stored
+            .into_iter()
+            .map(|ss| ss.try_into())
+            .collect::<anyhow::Result<Vec<TodoShare>>>()
+    }
+
+    async fn get_grant(
+        &self,
+        user_id: &Id<User>,
+        todo_id: &Id<Todo>,
+    ) -> anyhow::Result<Option<TodoGrant>> {
+        find_grant(self.db.pool(), user_id, todo_id).await
+    }
+
+    async fn get_grant_tx(
+        &self,
+        tx: &mut PgTx,
+        user_id: &Id<User>,
+        todo_id: &Id<Todo>,
+    ) -> anyhow::Result<Option<TodoGrant>> {
+        find_grant(&mut **tx, user_id, todo_id).await
+    }
+
+    async fn upsert_tx(&self, tx: &mut PgTx, source: NewTodoShare) -> anyhow::Result<TodoShare> {
+        let share: InsertTodoShare = source.into();
+        let sql = r#"
+            WITH s AS (
+                INSERT INTO todo_shares (todo_id, user_id, permission)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (todo_id, user_id) DO UPDATE SET permission = EXCLUDED.permission
+                RETURNING todo_id, user_id, permission, created_at
+            )
+            SELECT s.todo_id, s.user_id, u.username, s.permission, s.created_at
+            FROM s
+            INNER JOIN users u ON u.id = s.user_id
+        "#;
+        let stored = query_as::<_, StoredTodoShare>(sql)
+            .bind(share.todo_id)
+            .bind(share.user_id)
+            .bind(share.permission)
+            .fetch_one(&mut **tx)
+            .await?;
+        stored.try_into()
+    }
+
+    async fn delete_tx(
+        &self,
+        tx: &mut PgTx,
+        todo_id: &Id<Todo>,
+        user_id: &Id<User>,
+    ) -> anyhow::Result<Option<TodoShare>> {
+        let sql = r#"
+            WITH s AS (
+                DELETE FROM todo_shares WHERE todo_id = $1 AND user_id = $2
+                RETURNING todo_id, user_id, permission, created_at
+            )
+            SELECT s.todo_id, s.user_id, u.username, s.permission, s.created_at
+            FROM s
+            INNER JOIN users u ON u.id = s.user_id
+        "#;
+        let stored = query_as::<_, StoredTodoShare>(sql)
+            .bind(todo_id.value.to_string())
+            .bind(user_id.value.to_string())
+            .fetch_optional(&mut **tx)
+            .await?;
+        stored.map(|ss| ss.try_into()).transpose()
+    }
+}
+
+async fn find_grant<'e, E>(
+    executor: E,
+    user_id: &Id<User>,
+    todo_id: &Id<Todo>,
+) -> anyhow::Result<Option<TodoGrant>>
+where
+    E: Executor<'e, Database = Postgres>,
+{
+    let sql = r#"
+        WITH RECURSIVE ancestors AS (
+            SELECT id, parent_id, owner_id, 0 AS depth
+            FROM todos
+            WHERE id = $2
+            UNION ALL
+            SELECT t.id, t.parent_id, t.owner_id, a.depth + 1
+            FROM todos t
+            INNER JOIN ancestors a ON t.id = a.parent_id
+        )
+        SELECT s.todo_id, a.owner_id, s.permission
+        FROM ancestors a
+        INNER JOIN todo_shares s ON s.todo_id = a.id
+        WHERE s.user_id = $1
+        ORDER BY a.depth
+        LIMIT 1
+    "#;
+    let stored = query_as::<_, StoredTodoGrant>(sql)
+        .bind(user_id.value.to_string())
+        .bind(todo_id.value.to_string())
+        .fetch_optional(executor)
+        .await?;
+    stored.map(|sg| sg.try_into()).transpose()
+}
@@ -1,18 +1,54 @@
 use crate::db::IDatabasePool;
-use crate::model::todo::status::StoredTodoStatus;
+use crate::model::todo::status::{InsertTodoStatus, StoredTodoStatus, StoredTodoStatusEntry};
 use crate::repository::PgTx;
 use anyhow::anyhow;
 use async_trait::async_trait;
-use domain::model::todo::status::TodoStatus;
+use chrono::{DateTime, Utc};
+use domain::model::todo::status::{NewTodoStatus, TodoStatus, TodoStatusEntry};
 use shaku::Component;
 use sqlx::query_as;
 use std::sync::Arc;
 
 /// TodoStatus 레포지토리 인터페이스.
+///
+/// `get_by_code`·`find_all` 은 끈 상태도 찾는다. 새로 고를 상태는 `get_enabled_by_code_tx` 로 찾으며,
+/// 없거나 끈 상태이면 `None` 이다.
+/// 목록(`find_entries`)은 기본 상태를 선언 순서로, 그 뒤에 사용자 정의 상태를 코드 순으로 반환한다.
+/// `rename_tx`·`set_disabled_tx` 는 대상이 없으면 `None` 을 반환한다.
 #[async_trait]
 pub trait ITodoStatusRepository: shaku::Interface {
     async fn get_by_code(&self, code: &str) -> anyhow::Result<TodoStatus>;
     async fn get_by_code_tx(&self, tx: &mut PgTx, code: &str) -> anyhow::Result<TodoStatus>;
+    async fn find_all(&self) -> anyhow::Result<Vec<TodoStatus>>;
+    async fn get_enabled_by_code_tx(
+        &self,
+        tx: &mut PgTx,
+        code: &str,
+    ) -> anyhow::Result<Option<TodoStatus>>;
+    async fn find_entries(&self) -> anyhow::Result<Vec<TodoStatusEntry>>;
+    async fn get_entry_by_code_tx(
+        &self,
+        tx: &mut PgTx,
+        code: &str,
+    ) -> anyhow::Result<Option<TodoStatusEntry>>;
+    async fn insert_tx(
+        &self,
+        tx: &mut PgTx,
+        status: NewTodoStatus,
+    ) -> anyhow::Result<TodoStatusEntry>;
+    async fn rename_tx(
+        &self,
+        tx: &mut PgTx,
+        code: &str,
+        name: &str,
+    ) -> anyhow::Result<Option<TodoStatusEntry>>;
+    /// 상태를 `disabled_at` 에 끄거나 `None` 이면 다시 켠다.
+    async fn set_disabled_tx(
+        &self,
+        tx: &mut PgTx,
+        code: &str,
+        disabled_at: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<Option<TodoStatusEntry>>;
 }
 
 /// PostgreSQL TodoStatus 레포지토리 구현체.
@@ -32,6 +68,133 @@ impl ITodoStatusRepository for TodoStatusRepository {
     async fn get_by_code_tx(&self, tx: &mut PgTx, code: &str) -> anyhow::Result<TodoStatus> {
         Self::get_by_code_impl(code, &mut **tx).await
     }
+
+    async fn find_all(&self) -> anyhow::Result<Vec<TodoStatus>> {
+        let sql = r#"
+            SELECT id, code, name
+            FROM todo_statuses
+        "#;
+        let stored = query_as::<_, StoredTodoStatus>(sql)
+            .fetch_all(self.db.pool())
+            .await?;
+        stored
+            .into_iter()
+            .map(|st| st.try_into())
+            .collect::<anyhow::Result<Vec<TodoStatus>>>()
+    }
+
+    async fn get_enabled_by_code_tx(
+        &self,
+        tx: &mut PgTx,
+        code: &str,
+    ) -> anyhow::Result<Option<TodoStatus>> {
+        let sql = r#"
+            SELECT id, code, name
+            FROM todo_statuses
+            WHERE code = $1 AND disabled_at IS NULL
+        "#;
+        let result = query_as::<_, StoredTodoStatus>(sql)
+            .bind(code)
+            .fetch_optional(&mut **tx)
+            .await?;
+        result.map(TryInto::try_into).transpose()
+    }
+
+    async fn find_entries(&self) -> anyhow::Result<Vec<TodoStatusEntry>> {
+        let sql = r#"
+            SELECT id, code, name, disabled_at
+            FROM todo_statuses
+        "#;
+        let mut entries = query_as::<_, StoredTodoStatusEntry>(sql)
+            .fetch_all(self.db.pool())
+            .await?
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<anyhow::Result<Vec<TodoStatusEntry>>>()?;
+        entries.sort_by(|a, b| {
+            let (a, b) = (&a.status.code, &b.status.code);
+            (a.position(), a.as_str()).cmp(&(b.position(), b.as_str()))
+        });
+        Ok(entries)
+    }
+
+    async fn get_entry_by_code_tx(
+        &self,
+        tx: &mut PgTx,
+        code: &str,
+    ) -> anyhow::Result<Option<TodoStatusEntry>> {
+        let sql = r#"
+            SELECT id, code, name, disabled_at
+            FROM todo_statuses
+            WHERE code = $1
+        "#;
+        let result = query_as::<_, StoredTodoStatusEntry>(sql)
+            .bind(code)
+            .fetch_optional(&mut **tx)
+            .await?;
+        result.map(TryInto::try_into).transpose()
+    }
+
+    async fn insert_tx(
+        &self,
+        tx: &mut PgTx,
+        status: NewTodoStatus,
+    ) -> anyhow::Result<TodoStatusEntry> {
+        let status: InsertTodoStatus = status.into();
+        let sql = r#"
+            INSERT INTO todo_statuses (id, code, name)
+            VALUES ($1, $2, $3)
+            RETURNING id, code, name, disabled_at
+        "#;
+        let stored = query_as::<_, StoredTodoStatusEntry>(sql)
+            .bind(&status.id)
+            .bind(&status.code)
+            .bind(&status.name)
+            .fetch_one(&mut **tx)
+            .await?;
+        stored.try_into()
+    }
+
+    async fn rename_tx(
+        &self,
+        tx: &mut PgTx,
+        code: &str,
+        name: &str,
+    ) -> anyhow::Result<Option<TodoStatusEntry>> {
+        let sql = r#"
+            UPDATE todo_statuses SET name = $2
+            WHERE code = $1
+            RETURNING id, code, name, disabled_at
+        "#;
+        let result = query_as::<_, StoredTodoStatusEntry>(sql)
+            .bind(code)
+            .bind(name)
+            .fetch_optional(&mut **tx)
+            .await?;
+        result.map(TryInto::try_into).transpose()
+    }
+
+    async fn set_disabled_tx(
+        &self,
+        tx: &mut PgTx,
+        code: &str,
+        disabled_at: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<Option<TodoStatusEntry>> {
+        // 이미 끈 상태를 다시 끄면 처음 끈 시각을 그대로 둔다.
+        let sql = r#"
+            UPDATE todo_statuses
+            SET disabled_at = CASE WHEN $2::timestamptz IS NULL THEN NULL
+                                   ELSE coalesce(disabled_at, $2) END
+            WHERE code = $1
+            RETURNING id, code, name, disabled_at
+        "#;
+        let result = query_as::<_, StoredTodoStatusEntry>(sql)
+            .bind(code)
+            .bind(disabled_at)
+            .fetch_optional(&mut **tx)
+            .await?;
+        result.map(TryInto::try_into).transpose()
+    }
 }
 
 impl TodoStatusRepository {
@@ -1,14 +1,27 @@
-use super::interface::ITodoRepository;
+use super::interface::{Adjacent, ITodoRepository};
 use crate::db::IDatabasePool;
-use crate::model::todo::{InsertTodo, StoredTodo, UpdateStoredTodo, UpsertStoredTodo};
+use crate::model::todo::stats::{
+    StoredTodoStatsBucket, StoredTodoStatsSummary, StoredTodoStatusCount,
+};
+use crate::model::todo::{
+    InsertTodo, StoredTodo, StoredTodoSearchHit, UpdateStoredTodo, UpsertStoredTodo,
+};
 use crate::repository::PgTx;
 use anyhow::Context;
 use async_trait::async_trait;
-use domain::model::todo::status::TodoStatus;
-use domain::model::todo::{NewTodo, Todo, UpdateTodo, UpsertTodo};
+use chrono::{DateTime, Utc};
+use domain::model::project::Project;
+use domain::model::todo::filter::{TodoFilter, TodoSort, TodoSortKey, TodoSortValue};
+use domain::model::todo::rank::{rank_between, spread_ranks};
+use domain::model::todo::stats::{TodoStats, TodoStatsBucket, TodoStatsRange, TodoStatusCount};
+use domain::model::todo::status::{TodoStatus, TodoStatusCode};
+use domain::model::todo::{
+    NewTodo, Todo, TodoCursor, TodoSearchCursor, TodoSearchHit, UpdateTodo, UpsertTodo,
+};
+use domain::model::user::User;
 use domain::model::Id;
 use shaku::Component;
-use sqlx::{query, query_as};
+use sqlx::{query, query_as, query_scalar, Postgres, QueryBuilder};
 use std::sync::Arc;
 
 /// PostgreSQL Todo 레포지토리 구현체.
@@ -20,19 +33,62 @@ pub struct TodoRepository {
 }
 
 const SELECT_TODO_BY_ID: &str = r#"
-    SELECT t.id, t.title, t.description,
+    SELECT t.id, t.owner_id, t.parent_id, t.title, t.description,
            ts.id AS status_id, ts.code AS status_code, ts.name AS status_name,
-           t.created_at, t.updated_at
+           t.due_at, t.priority,
+           ARRAY(SELECT tg.id::text FROM todo_tags tt INNER JOIN tags tg ON tg.id = tt.tag_id
+                 WHERE tt.todo_id = t.id ORDER BY tg.name) AS tag_ids,
+           ARRAY(SELECT tg.name::text FROM todo_tags tt INNER JOIN tags tg ON tg.id = tt.tag_id
+                 WHERE tt.todo_id = t.id ORDER BY tg.name) AS tag_names,
+           t.created_at, t.updated_at, t.deleted_at, t.version, t.recurrence,
+           t.remind_at, t.project_id, t.archived_at, t.position
     FROM todos t
     INNER JOIN todo_statuses ts ON ts.id = t.status_id
-    WHERE t.id = $1
+    WHERE t.id = $1 AND t.owner_id = $2
 "#;
 
+const SELECT_TODOS: &str = r#"
+    SELECT t.id, t.owner_id, t.parent_id, t.title, t.description,
+           ts.id AS status_id, ts.code AS status_code, ts.name AS status_name,
+           t.due_at, t.priority,
+           ARRAY(SELECT tg.id::text FROM todo_tags tt INNER JOIN tags tg ON tg.id = tt.tag_id
+                 WHERE tt.todo_id = t.id ORDER BY tg.name) AS tag_ids,
+           ARRAY(SELECT tg.name::text FROM todo_tags tt INNER JOIN tags tg ON tg.id = tt.tag_id
+                 WHERE tt.todo_id = t.id ORDER BY tg.name) AS tag_names,
+           t.created_at, t.updated_at, t.deleted_at, t.version, t.recurrence,
+           t.remind_at, t.project_id, t.archived_at, t.position
+    FROM todos t
+    INNER JOIN todo_statuses ts ON ts.id = t.status_id
+"#;
+
+// 지금 완료 상태인 todo 의 생성·완료 시각. `$1` 은 소유자, `$5` 는 `done` 코드이다.
+// 완료 시각은 `done` 으로 바뀐 마지막 이력이며, 이력이 없으면 마지막 수정 시각으로 본다.
+const COMPLETED_TODOS: &str = r#"
+    SELECT t.created_at,
+           coalesce((SELECT max(e.created_at)
+                     FROM todo_events e, jsonb_array_elements(e.changes) ch
+                     WHERE e.todo_id = t.id AND ch->>'field' = 'status' AND ch->>'after' = $5),
+                    t.updated_at) AS completed_at
+    FROM todos t
+    INNER JOIN todo_statuses ts ON ts.id = t.status_id
+    WHERE t.owner_id = $1 AND ts.code = $5
+"#;
+
+// idx_todos_search_vector 인덱스와 같은 표현식이어야 인덱스를 탄다.
+const SEARCH_VECTOR: &str = "(setweight(to_tsvector('simple', t.title), 'A') \
+     || setweight(to_tsvector('simple', t.description), 'B'))";
+
+// 발췌의 원문. 제목과 본문을 HTML 이스케이프한 뒤 강조하므로 발췌에는 `<mark>` 외의 태그가 없다.
+const SNIPPET_SOURCE: &str = "replace(replace(replace(replace(replace(\
+     t.title || ' ' || t.description, '&', '&amp;'), '<', '&lt;'), '>', '&gt;'), \
+     '\"', '&quot;'), '''', '&#39;')";
+
 #[async_trait]
 impl ITodoRepository for TodoRepository {
-    async fn get(&self, id: &Id<Todo>) -> anyhow::Result<Option<Todo>> {
+    async fn get(&self, owner_id: &Id<User>, id: &Id<Todo>) -> anyhow::Result<Option<Todo>> {
         let result = query_as::<_, StoredTodo>(SELECT_TODO_BY_ID)
             .bind(id.value.to_string())
+            .bind(owner_id.value.to_string())
             .fetch_optional(self.db.pool())
             .await?;
         match result {
@@ -41,13 +97,97 @@ impl ITodoRepository for TodoRepository {
         }
     }
 
-    async fn find(&self, status: Option<TodoStatus>) -> anyhow::Result<Vec<Todo>> {
-        find_todos(self.db.pool(), status).await
+    async fn find(
+        &self,
+        owner_id: &Id<User>,
+        filter: &TodoFilter,
+        sort: TodoSort,
+        cursor: Option<TodoCursor>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<Todo>> {
+        find_todos(self.db.pool(), owner_id, filter, sort, cursor, limit).await
+    }
+
+    async fn search(
+        &self,
+        owner_id: &Id<User>,
+        q: &str,
+        filter: &TodoFilter,
+        cursor: Option<TodoSearchCursor>,
+        limit: i64,
+    ) -> anyhow::Result<Vec<TodoSearchHit>> {
+        // tsquery 가 맞지 않는 한국어 등은 ILIKE(trigram 인덱스) 로 찾고 정규식으로 강조한다.
+        let pattern = format!("%{}%", escape_like(q));
+        let mut qb = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT t.id, t.owner_id, t.parent_id, t.title, t.description,
+                   ts.id AS status_id, ts.code AS status_code, ts.name AS status_name,
+                   t.due_at, t.priority,
+                   ARRAY(SELECT tg.id::text FROM todo_tags tt INNER JOIN tags tg ON tg.id = tt.tag_id
+                         WHERE tt.todo_id = t.id ORDER BY tg.name) AS tag_ids,
+                   ARRAY(SELECT tg.name::text FROM todo_tags tt INNER JOIN tags tg ON tg.id = tt.tag_id
+                         WHERE tt.todo_id = t.id ORDER BY tg.name) AS tag_names,
+                   t.created_at, t.updated_at, t.deleted_at, t.version, t.recurrence,
+                   t.remind_at, t.project_id, t.archived_at, t.position,
+                   hit.rank, hit.snippet
+            FROM todos t
+            INNER JOIN todo_statuses ts ON ts.id = t.status_id
+            CROSS JOIN websearch_to_tsquery('simple', "#,
+        );
+        qb.push_bind(q);
+        qb.push(") AS query CROSS JOIN LATERAL (SELECT (ts_rank(");
+        qb.push(SEARCH_VECTOR);
+        qb.push(", query) + similarity(t.title, ");
+        qb.push_bind(q);
+        qb.push("))::real AS rank, CASE WHEN ");
+        qb.push(SEARCH_VECTOR);
+        qb.push(" @@ query THEN ts_headline('simple', ");
+        qb.push(SNIPPET_SOURCE);
+        qb.push(
+            ", query, 'StartSel=<mark>, StopSel=</mark>, MaxWords=20, MinWords=5') \
+             ELSE regexp_replace(",
+        );
+        qb.push(SNIPPET_SOURCE);
+        qb.push(", ");
+        qb.push_bind(escape_regex(&escape_html(q)));
+        qb.push(", '<mark>\\&</mark>', 'gi') END AS snippet) AS hit");
+        push_filter(&mut qb, owner_id, filter);
+        qb.push(" AND (");
+        qb.push(SEARCH_VECTOR);
+        qb.push(" @@ query OR t.title ILIKE ");
+        qb.push_bind(pattern.clone());
+        qb.push(" OR t.description ILIKE ");
+        qb.push_bind(pattern);
+        qb.push(")");
+        if let Some(c) = cursor {
+            qb.push(" AND (hit.rank < ");
+            qb.push_bind(c.rank);
+            qb.push(" OR (hit.rank = ");
+            qb.push_bind(c.rank);
+            qb.push(" AND t.id > ");
+            qb.push_bind(c.id.value.to_string());
+            qb.push("))");
+        }
+        qb.push(" ORDER BY hit.rank DESC, t.id ASC LIMIT ");
+        qb.push_bind(limit);
+
+        let stored: Vec<StoredTodoSearchHit> =
+            qb.build_query_as().fetch_all(self.db.pool()).await?;
+        stored
+            .into_iter()
+            .map(|h| h.try_into())
+            .collect::<anyhow::Result<Vec<TodoSearchHit>>>()
     }
 
-    async fn get_tx(&self, tx: &mut PgTx, id: &Id<Todo>) -> anyhow::Result<Option<Todo>> {
+    async fn get_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+    ) -> anyhow::Result<Option<Todo>> {
         let result = query_as::<_, StoredTodo>(SELECT_TODO_BY_ID)
             .bind(id.value.to_string())
+            .bind(owner_id.value.to_string())
             .fetch_optional(&mut **tx)
             .await?;
         match result {
@@ -59,93 +199,169 @@ impl ITodoRepository for TodoRepository {
     async fn find_tx(
         &self,
         tx: &mut PgTx,
-        status: Option<TodoStatus>,
+        owner_id: &Id<User>,
+        filter: &TodoFilter,
+        sort: TodoSort,
+        cursor: Option<TodoCursor>,
+        limit: i64,
     ) -> anyhow::Result<Vec<Todo>> {
-        find_todos(&mut **tx, status).await
+        find_todos(&mut **tx, owner_id, filter, sort, cursor, limit).await
     }
 
     async fn insert_tx(&self, tx: &mut PgTx, source: NewTodo) -> anyhow::Result<Todo> {
+        let owner_id = source.owner_id.clone();
         let todo: InsertTodo = source.into();
+        let position = append_position(tx, &owner_id).await?;
 
-        query("INSERT INTO todos (id, title, description) VALUES ($1, $2, $3)")
+        let insert_sql = r#"
+            INSERT INTO todos (id, owner_id, title, description, due_at, priority, parent_id,
+                               recurrence, remind_at, project_id, position)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        "#;
+        query(insert_sql)
             .bind(&todo.id)
+            .bind(&todo.owner_id)
             .bind(&todo.title)
             .bind(&todo.description)
+            .bind(todo.due_at)
+            .bind(todo.priority)
+            .bind(&todo.parent_id)
+            .bind(&todo.recurrence)
+            .bind(todo.remind_at)
+            .bind(&todo.project_id)
+            .bind(position)
             .execute(&mut **tx)
             .await?;
 
         let stored = query_as::<_, StoredTodo>(SELECT_TODO_BY_ID)
             .bind(&todo.id)
+            .bind(&todo.owner_id)
             .fetch_one(&mut **tx)
             .await?;
         stored.try_into()
     }
 
-    async fn update_tx(&self, tx: &mut PgTx, source: UpdateTodo) -> anyhow::Result<Todo> {
+    async fn update_tx(&self, tx: &mut PgTx, source: UpdateTodo) -> anyhow::Result<Option<Todo>> {
         let todo: UpdateStoredTodo = source.into();
 
+        // 버전 비교는 갱신 대상 행에 걸어야 동시에 들어온 수정 중 하나만 통과한다.
         let update_sql = r#"
             UPDATE todos AS target SET
-                title       = CASE WHEN $2 IS NOT NULL THEN $2 ELSE current_todo.title END,
-                description = CASE WHEN $3 IS NOT NULL THEN $3 ELSE current_todo.description END,
-                status_id   = CASE WHEN $4 IS NOT NULL THEN $4 ELSE current_todo.status_id END,
-                updated_at  = current_timestamp
-            FROM (SELECT * FROM todos WHERE id = $1) AS current_todo
-            WHERE target.id = $1
+                title       = CASE WHEN $3 IS NOT NULL THEN $3 ELSE current_todo.title END,
+                description = CASE WHEN $4 IS NOT NULL THEN $4 ELSE current_todo.description END,
+                status_id   = CASE WHEN $5 IS NOT NULL THEN $5 ELSE current_todo.status_id END,
+                due_at      = CASE WHEN $9 THEN $6 ELSE current_todo.due_at END,
+                priority    = CASE WHEN $7 IS NOT NULL THEN $7 ELSE current_todo.priority END,
+                recurrence  = CASE WHEN $10 THEN $11 ELSE current_todo.recurrence END,
+                remind_at   = CASE WHEN $12 THEN $13 ELSE current_todo.remind_at END,
+                reminded_at = CASE WHEN $12 THEN NULL ELSE current_todo.reminded_at END,
+                reminder_attempts = CASE WHEN $12 THEN 0 ELSE current_todo.reminder_attempts END,
+                reminder_next_attempt_at = CASE WHEN $12 THEN NULL ELSE current_todo.reminder_next_attempt_at END,
+                updated_at  = current_timestamp,
+                version     = target.version + 1
+            FROM (SELECT * FROM todos WHERE id = $1 AND owner_id = $2) AS current_todo
+            WHERE target.id = current_todo.id
+              AND ($8::bigint IS NULL OR target.version = $8)
         "#;
-        query(update_sql)
+        let result = query(update_sql)
             .bind(&todo.id)
+            .bind(&todo.owner_id)
             .bind(todo.title)
             .bind(todo.description)
             .bind(todo.status_id)
+            .bind(todo.due_at)
+            .bind(todo.priority)
+            .bind(todo.expected_version)
+            .bind(todo.set_due_at)
+            .bind(todo.set_recurrence)
+            .bind(todo.recurrence)
+            .bind(todo.set_remind_at)
+            .bind(todo.remind_at)
             .execute(&mut **tx)
             .await?;
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
 
         let stored = query_as::<_, StoredTodo>(SELECT_TODO_BY_ID)
             .bind(&todo.id)
-            .fetch_one(&mut **tx)
+            .bind(&todo.owner_id)
+            .fetch_optional(&mut **tx)
             .await?;
-        stored.try_into()
+        stored.map(|st| st.try_into()).transpose()
     }
 
-    async fn upsert_tx(&self, tx: &mut PgTx, source: UpsertTodo) -> anyhow::Result<Todo> {
+    async fn upsert_tx(&self, tx: &mut PgTx, source: UpsertTodo) -> anyhow::Result<Option<Todo>> {
+        let owner_id = source.owner_id.clone();
         let todo: UpsertStoredTodo = source.into();
+        let position = append_position(tx, &owner_id).await?;
 
+        // 다른 사용자가 소유한 id 와 충돌하거나 버전이 다르면 WHERE 절에 걸려 아무것도 갱신하지 않는다.
+        // 이미 있는 todo 의 순서는 바꾸지 않는다.
         let upsert_sql = r#"
-            INSERT INTO todos (id, title, description, status_id) VALUES ($1, $2, $3, $4)
+            INSERT INTO todos (id, owner_id, title, description, status_id, due_at, priority, position)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $9)
             ON CONFLICT ON CONSTRAINT pk_todos_id
-            DO UPDATE SET title = $2, description = $3, status_id = $4, updated_at = current_timestamp
+            DO UPDATE SET title = $3, description = $4, status_id = $5, due_at = $6, priority = $7,
+                          updated_at = current_timestamp, version = todos.version + 1
+            WHERE todos.owner_id = $2 AND ($8::bigint IS NULL OR todos.version = $8)
         "#;
-        query(upsert_sql)
+        let result = query(upsert_sql)
             .bind(&todo.id)
+            .bind(&todo.owner_id)
             .bind(todo.title)
             .bind(todo.description)
             .bind(todo.status_id)
+            .bind(todo.due_at)
+            .bind(todo.priority)
+            .bind(todo.expected_version)
+            .bind(position)
             .execute(&mut **tx)
             .await
             .context(format!(r#"failed to upsert "{}" into todos"#, todo.id))?;
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
 
         let stored = query_as::<_, StoredTodo>(SELECT_TODO_BY_ID)
             .bind(&todo.id)
-            .fetch_one(&mut **tx)
+            .bind(&todo.owner_id)
+            .fetch_optional(&mut **tx)
             .await?;
-        stored.try_into()
+        stored.map(|st| st.try_into()).transpose()
     }
 
-    async fn delete_tx(&self, tx: &mut PgTx, id: &Id<Todo>) -> anyhow::Result<Option<Todo>> {
+    async fn delete_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<Option<Todo>> {
         let sql = r#"
             WITH deleted AS (
-                DELETE FROM todos WHERE id = $1
-                RETURNING id, title, description, status_id, created_at, updated_at
+                DELETE FROM todos
+                WHERE id = $1 AND owner_id = $2 AND ($3::bigint IS NULL OR version = $3)
+                RETURNING id, owner_id, parent_id, title, description, status_id,
+                          due_at, priority, created_at, updated_at, deleted_at, version,
+                          recurrence, remind_at, project_id, archived_at, position
             )
-            SELECT d.id, d.title, d.description,
+            SELECT d.id, d.owner_id, d.parent_id, d.title, d.description,
                    ts.id AS status_id, ts.code AS status_code, ts.name AS status_name,
-                   d.created_at, d.updated_at
+                   d.due_at, d.priority,
+                   ARRAY(SELECT tg.id::text FROM todo_tags tt INNER JOIN tags tg ON tg.id = tt.tag_id
+                         WHERE tt.todo_id = d.id ORDER BY tg.name) AS tag_ids,
+                   ARRAY(SELECT tg.name::text FROM todo_tags tt INNER JOIN tags tg ON tg.id = tt.tag_id
+                         WHERE tt.todo_id = d.id ORDER BY tg.name) AS tag_names,
+                   d.created_at, d.updated_at, d.deleted_at, d.version, d.recurrence,
+                   d.remind_at, d.project_id, d.archived_at, d.position
             FROM deleted d
             INNER JOIN todo_statuses ts ON ts.id = d.status_id
         "#;
         let result = query_as::<_, StoredTodo>(sql)
             .bind(id.value.to_string())
+            .bind(owner_id.value.to_string())
+            .bind(expected_version)
             .fetch_optional(&mut **tx)
             .await?;
         match result {
@@ -153,46 +369,707 @@ impl ITodoRepository for TodoRepository {
             None => Ok(None),
         }
     }
+
+    async fn soft_delete_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        deleted: &TodoStatus,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<Option<Todo>> {
+        // 이미 휴지통에 있으면 삭제 시각과 직전 상태를 덮어쓰지 않는다.
+        let sql = r#"
+            UPDATE todos SET
+                previous_status_id = status_id,
+                status_id          = $3,
+                deleted_at         = current_timestamp,
+                updated_at         = current_timestamp,
+                version            = version + 1
+            WHERE id = $1 AND owner_id = $2 AND deleted_at IS NULL
+              AND ($4::bigint IS NULL OR version = $4)
+        "#;
+        let id = id.value.to_string();
+        let owner_id = owner_id.value.to_string();
+        query(sql)
+            .bind(&id)
+            .bind(&owner_id)
+            .bind(deleted.id.value.to_string())
+            .bind(expected_version)
+            .execute(&mut **tx)
+            .await?;
+
+        let stored = query_as::<_, StoredTodo>(SELECT_TODO_BY_ID)
+            .bind(&id)
+            .bind(&owner_id)
+            .fetch_optional(&mut **tx)
+            .await?;
+        stored.map(|st| st.try_into()).transpose()
+    }
+
+    async fn restore_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        fallback: &TodoStatus,
+    ) -> anyhow::Result<Option<Todo>> {
+        let sql = r#"
+            UPDATE todos SET
+                status_id          = COALESCE(previous_status_id, $3),
+                previous_status_id = NULL,
+                deleted_at         = NULL,
+                updated_at         = current_timestamp,
+                version            = version + 1
+            WHERE id = $1 AND owner_id = $2 AND deleted_at IS NOT NULL
+        "#;
+        let id = id.value.to_string();
+        let owner_id = owner_id.value.to_string();
+        query(sql)
+            .bind(&id)
+            .bind(&owner_id)
+            .bind(fallback.id.value.to_string())
+            .execute(&mut **tx)
+            .await?;
+
+        let stored = query_as::<_, StoredTodo>(SELECT_TODO_BY_ID)
+            .bind(&id)
+            .bind(&owner_id)
+            .fetch_optional(&mut **tx)
+            .await?;
+        stored.map(|st| st.try_into()).transpose()
+    }
+
+    async fn set_parent_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        parent_id: Option<&Id<Todo>>,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<Option<Todo>> {
+        let sql = r#"
+            UPDATE todos SET
+                parent_id  = $3,
+                updated_at = current_timestamp,
+                version    = version + 1
+            WHERE id = $1 AND owner_id = $2 AND ($4::bigint IS NULL OR version = $4)
+        "#;
+        let id = id.value.to_string();
+        let owner_id = owner_id.value.to_string();
+        let result = query(sql)
+            .bind(&id)
+            .bind(&owner_id)
+            .bind(parent_id.map(|p| p.value.to_string()))
+            .bind(expected_version)
+            .execute(&mut **tx)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        let stored = query_as::<_, StoredTodo>(SELECT_TODO_BY_ID)
+            .bind(&id)
+            .bind(&owner_id)
+            .fetch_optional(&mut **tx)
+            .await?;
+        stored.map(|st| st.try_into()).transpose()
+    }
+
+    async fn set_project_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        project_id: Option<&Id<Project>>,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<Option<Todo>> {
+        // 보관하지 않은 프로젝트로만 옮기므로 보관 표시는 지운다.
+        let sql = r#"
+            UPDATE todos SET
+                project_id  = $3,
+                archived_at = NULL,
+                updated_at  = current_timestamp,
+                version     = version + 1
+            WHERE id = $1 AND owner_id = $2 AND ($4::bigint IS NULL OR version = $4)
+        "#;
+        let id = id.value.to_string();
+        let owner_id = owner_id.value.to_string();
+        let result = query(sql)
+            .bind(&id)
+            .bind(&owner_id)
+            .bind(project_id.map(|p| p.value.to_string()))
+            .bind(expected_version)
+            .execute(&mut **tx)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        let stored = query_as::<_, StoredTodo>(SELECT_TODO_BY_ID)
+            .bind(&id)
+            .bind(&owner_id)
+            .fetch_optional(&mut **tx)
+            .await?;
+        stored.map(|st| st.try_into()).transpose()
+    }
+
+    async fn set_archived_by_project_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        project_id: &Id<Project>,
+        archived_at: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<u64> {
+        let sql = r#"
+            UPDATE todos SET
+                archived_at = $3,
+                updated_at  = current_timestamp,
+                version     = version + 1
+            WHERE project_id = $1 AND owner_id = $2
+              AND archived_at IS DISTINCT FROM $3
+        "#;
+        let result = query(sql)
+            .bind(project_id.value.to_string())
+            .bind(owner_id.value.to_string())
+            .bind(archived_at)
+            .execute(&mut **tx)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn get_adjacent_position_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        position: &str,
+        direction: Adjacent,
+        exclude_id: &Id<Todo>,
+    ) -> anyhow::Result<Option<String>> {
+        let sql = match direction {
+            Adjacent::Before => {
+                r#"
+                SELECT max(position) FROM todos
+                WHERE owner_id = $1 AND position < $2 AND id <> $3
+                "#
+            }
+            Adjacent::After => {
+                r#"
+                SELECT min(position) FROM todos
+                WHERE owner_id = $1 AND position > $2 AND id <> $3
+                "#
+            }
+        };
+        let position: Option<String> = query_scalar(sql)
+            .bind(owner_id.value.to_string())
+            .bind(position)
+            .bind(exclude_id.value.to_string())
+            .fetch_one(&mut **tx)
+            .await?;
+        Ok(position)
+    }
+
+    async fn lock_positions_tx(&self, tx: &mut PgTx, owner_id: &Id<User>) -> anyhow::Result<()> {
+        lock_positions(tx, owner_id).await
+    }
+
+    async fn set_position_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        position: &str,
+    ) -> anyhow::Result<Option<Todo>> {
+        let sql = r#"
+            UPDATE todos SET position = $3, updated_at = current_timestamp, version = version + 1
+            WHERE id = $1 AND owner_id = $2
+        "#;
+        let id = id.value.to_string();
+        let owner_id = owner_id.value.to_string();
+        query(sql)
+            .bind(&id)
+            .bind(&owner_id)
+            .bind(position)
+            .execute(&mut **tx)
+            .await?;
+
+        let stored = query_as::<_, StoredTodo>(SELECT_TODO_BY_ID)
+            .bind(&id)
+            .bind(&owner_id)
+            .fetch_optional(&mut **tx)
+            .await?;
+        stored.map(|st| st.try_into()).transpose()
+    }
+
+    async fn rebalance_positions_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+    ) -> anyhow::Result<u64> {
+        rebalance_positions(tx, owner_id).await
+    }
+
+    async fn find_subtree(&self, owner_id: &Id<User>, id: &Id<Todo>) -> anyhow::Result<Vec<Todo>> {
+        find_subtree(self.db.pool(), owner_id, id).await
+    }
+
+    async fn find_subtree_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+    ) -> anyhow::Result<Vec<Todo>> {
+        find_subtree(&mut **tx, owner_id, id).await
+    }
+
+    async fn count_unfinished_children_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+    ) -> anyhow::Result<i64> {
+        let sql = r#"
+            SELECT count(*)
+            FROM todos t
+            INNER JOIN todo_statuses ts ON ts.id = t.status_id
+            WHERE t.parent_id = $1 AND t.owner_id = $2 AND t.deleted_at IS NULL
+              AND ts.code NOT IN ($3, $4)
+        "#;
+        let count: i64 = query_scalar(sql)
+            .bind(id.value.to_string())
+            .bind(owner_id.value.to_string())
+            .bind(TodoStatusCode::Done.as_str())
+            .bind(TodoStatusCode::Discontinued.as_str())
+            .fetch_one(&mut **tx)
+            .await?;
+        Ok(count)
+    }
+
+    async fn find_purgeable_ids_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        deleted_before: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<Id<Todo>>> {
+        let sql = r#"
+            SELECT id FROM todos
+            WHERE owner_id = $1 AND deleted_at IS NOT NULL AND deleted_at < $2
+            FOR UPDATE
+        "#;
+        let ids = query_scalar::<_, String>(sql)
+            .bind(owner_id.value.to_string())
+            .bind(deleted_before)
+            .fetch_all(&mut **tx)
+            .await?;
+        ids.into_iter().map(Id::try_from).collect()
+    }
+
+    async fn purge_deleted_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        deleted_before: DateTime<Utc>,
+    ) -> anyhow::Result<u64> {
+        let sql = r#"
+            DELETE FROM todos
+            WHERE owner_id = $1 AND deleted_at IS NOT NULL AND deleted_at < $2
+        "#;
+        let result = query(sql)
+            .bind(owner_id.value.to_string())
+            .bind(deleted_before)
+            .execute(&mut **tx)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn get_stats(
+        &self,
+        owner_id: &Id<User>,
+        range: &TodoStatsRange,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<TodoStats> {
+        let owner = owner_id.value.to_string();
+        let done = TodoStatusCode::Done.as_str();
+
+        let sql = r#"
+            SELECT ts.code, count(t.id) AS count
+            FROM todo_statuses ts
+            LEFT JOIN todos t ON t.status_id = ts.id AND t.owner_id = $1
+            GROUP BY ts.code
+        "#;
+        let mut status_counts: Vec<TodoStatusCount> = query_as::<_, StoredTodoStatusCount>(sql)
+            .bind(&owner)
+            .fetch_all(self.db.pool())
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        status_counts.sort_by(|a, b| {
+            (a.code.position(), a.code.as_str()).cmp(&(b.code.position(), b.code.as_str()))
+        });
+
+        // 칸은 UTC 로 자른 시각이며, 건수가 없는 칸도 0 으로 채운다.
+        let sql = format!(
+            r#"
+            WITH buckets AS (
+                SELECT generate_series(
+                    date_trunc($2::text, $3 AT TIME ZONE 'UTC'),
+                    $4 AT TIME ZONE 'UTC' - interval '1 microsecond',
+                    ('1 ' || $2::text)::interval
+                ) AS bucket
+            ),
+            created AS (
+                SELECT date_trunc($2::text, t.created_at AT TIME ZONE 'UTC') AS bucket, count(*) AS count
+                FROM todos t
+                WHERE t.owner_id = $1 AND t.created_at >= $3 AND t.created_at < $4
+                GROUP BY 1
+            ),
+            completed AS (
+                SELECT date_trunc($2::text, c.completed_at AT TIME ZONE 'UTC') AS bucket, count(*) AS count
+                FROM ({COMPLETED_TODOS}) c
+                WHERE c.completed_at >= $3 AND c.completed_at < $4
+                GROUP BY 1
+            )
+            SELECT b.bucket AT TIME ZONE 'UTC' AS start,
+                   coalesce(cr.count, 0) AS created, coalesce(co.count, 0) AS completed
+            FROM buckets b
+            LEFT JOIN created cr ON cr.bucket = b.bucket
+            LEFT JOIN completed co ON co.bucket = b.bucket
+            ORDER BY b.bucket
+        "#
+        );
+        let buckets = query_as::<_, StoredTodoStatsBucket>(&sql)
+            .bind(&owner)
+            .bind(range.interval.as_str())
+            .bind(range.from)
+            .bind(range.to)
+            .bind(done)
+            .fetch_all(self.db.pool())
+            .await?
+            .into_iter()
+            .map(TodoStatsBucket::from)
+            .collect();
+
+        let sql = format!(
+            r#"
+            SELECT
+                (SELECT avg(extract(epoch FROM c.completed_at - c.created_at))::float8
+                 FROM ({COMPLETED_TODOS}) c
+                 WHERE c.completed_at >= $2 AND c.completed_at < $3) AS average_completion_seconds,
+                (SELECT count(*)
+                 FROM todos t
+                 INNER JOIN todo_statuses ts ON ts.id = t.status_id
+                 WHERE t.owner_id = $1 AND t.due_at < $4 AND t.deleted_at IS NULL
+                   AND ts.code NOT IN ($5, $6)) AS overdue
+        "#
+        );
+        let summary = query_as::<_, StoredTodoStatsSummary>(&sql)
+            .bind(&owner)
+            .bind(range.from)
+            .bind(range.to)
+            .bind(now)
+            .bind(done)
+            .bind(TodoStatusCode::Discontinued.as_str())
+            .fetch_one(self.db.pool())
+            .await?;
+
+        Ok(TodoStats {
+            status_counts,
+            buckets,
+            average_completion_seconds: summary.average_completion_seconds,
+            overdue: summary.overdue,
+        })
+    }
 }
 
 // ---------------------------------------------------------------------------
 // Private helpers — 제네릭 Executor로 pool / tx 모두 처리
 // ---------------------------------------------------------------------------
 
-async fn find_todos<'e, E>(executor: E, status: Option<TodoStatus>) -> anyhow::Result<Vec<Todo>>
+async fn find_todos<'e, E>(
+    executor: E,
+    owner_id: &Id<User>,
+    filter: &TodoFilter,
+    sort: TodoSort,
+    cursor: Option<TodoCursor>,
+    limit: i64,
+) -> anyhow::Result<Vec<Todo>>
 where
     E: sqlx::Executor<'e, Database = sqlx::Postgres>,
 {
-    let stored: Vec<StoredTodo> = match status {
-        Some(s) => {
-            let sql = r#"
-                SELECT t.id, t.title, t.description,
-                       ts.id AS status_id, ts.code AS status_code, ts.name AS status_name,
-                       t.created_at, t.updated_at
-                FROM todos t
-                INNER JOIN todo_statuses ts ON ts.id = t.status_id
-                WHERE t.status_id = $1
-                ORDER BY t.created_at ASC
-            "#;
-            query_as::<_, StoredTodo>(sql)
-                .bind(s.id.value.to_string())
-                .fetch_all(executor)
-                .await?
-        }
-        None => {
-            let sql = r#"
-                SELECT t.id, t.title, t.description,
-                       ts.id AS status_id, ts.code AS status_code, ts.name AS status_name,
-                       t.created_at, t.updated_at
-                FROM todos t
-                INNER JOIN todo_statuses ts ON ts.id = t.status_id
-                ORDER BY t.created_at ASC
-            "#;
-            query_as::<_, StoredTodo>(sql).fetch_all(executor).await?
-        }
+    // 우선순위는 높은 순이 기본이므로 부호를 뒤집고, 마감일 없음은 가장 늦은 것으로 본다.
+    let columns: &[&str] = match sort.key {
+        TodoSortKey::CreatedAt => &["t.created_at"],
+        TodoSortKey::UpdatedAt => &["t.updated_at"],
+        TodoSortKey::Title => &["t.title"],
+        TodoSortKey::Priority => &["-t.priority", "COALESCE(t.due_at, 'infinity')"],
+        TodoSortKey::Position => &["t.position"],
+    };
+    let (direction, op) = if sort.descending {
+        ("DESC", "<")
+    } else {
+        ("ASC", ">")
     };
+
+    let mut qb = QueryBuilder::<Postgres>::new(SELECT_TODOS);
+    push_filter(&mut qb, owner_id, filter);
+    // (정렬 키, id) 행 비교로 cursor 이후 항목만 조회한다.
+    if let Some(c) = cursor {
+        qb.push(format!(" AND ({}, t.id) {op} (", columns.join(", ")));
+        match c.value {
+            TodoSortValue::Timestamp(v) => {
+                qb.push_bind(v);
+            }
+            TodoSortValue::Text(v) => {
+                qb.push_bind(v);
+            }
+            TodoSortValue::Priority { rank, due_at } => {
+                qb.push_bind(-rank);
+                qb.push(", COALESCE(");
+                qb.push_bind(due_at);
+                qb.push("::timestamptz, 'infinity')");
+            }
+        }
+        qb.push(", ");
+        qb.push_bind(c.id.value.to_string());
+        qb.push(")");
+    }
+    let order_by: Vec<String> = columns
+        .iter()
+        .map(|column| format!("{column} {direction}"))
+        .collect();
+    qb.push(format!(
+        " ORDER BY {}, t.id {direction} LIMIT ",
+        order_by.join(", ")
+    ));
+    qb.push_bind(limit);
+
+    let stored: Vec<StoredTodo> = qb.build_query_as().fetch_all(executor).await?;
     stored
         .into_iter()
         .map(|st| st.try_into())
         .collect::<anyhow::Result<Vec<Todo>>>()
 }
+
+/// 사용자의 todo 순위를 트랜잭션이 끝날 때까지 이 트랜잭션만 정하도록 잠근다.
+/// 동시에 만든 todo 가 같은 순위를 받지 않도록 순위를 고르기 전에 부른다.
+async fn lock_positions(tx: &mut PgTx, owner_id: &Id<User>) -> anyhow::Result<()> {
+    query("SELECT pg_advisory_xact_lock(hashtextextended('todos.position:' || $1, 0))")
+        .bind(owner_id.value.to_string())
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
+
+/// 사용자의 마지막 todo 뒤에 올 순위를 만든다. 자리가 없으면 사용자의 todo 를 다시 배치하고 만든다.
+async fn append_position(tx: &mut PgTx, owner_id: &Id<User>) -> anyhow::Result<String> {
+    lock_positions(tx, owner_id).await?;
+    let sql = "SELECT max(position) FROM todos WHERE owner_id = $1";
+    let last: Option<String> = query_scalar(sql)
+        .bind(owner_id.value.to_string())
+        .fetch_one(&mut **tx)
+        .await?;
+    if let Some(position) = rank_between(last.as_deref(), None) {
+        return Ok(position);
+    }
+    rebalance_positions(tx, owner_id).await?;
+    let last: Option<String> = query_scalar(sql)
+        .bind(owner_id.value.to_string())
+        .fetch_one(&mut **tx)
+        .await?;
+    rank_between(last.as_deref(), None).context("no room for a new todo position")
+}
+
+/// 사용자의 todo 를 지금 순서 그대로 같은 간격의 순위로 다시 매기고 바꾼 건수를 반환한다.
+/// 버전과 수정 시각은 바꾸지 않는다.
+async fn rebalance_positions(tx: &mut PgTx, owner_id: &Id<User>) -> anyhow::Result<u64> {
+    let owner_id = owner_id.value.to_string();
+    let sql = "SELECT id FROM todos WHERE owner_id = $1 ORDER BY position, id FOR UPDATE";
+    let ids: Vec<String> = query_scalar(sql)
+        .bind(&owner_id)
+        .fetch_all(&mut **tx)
+        .await?;
+    let positions = spread_ranks(ids.len());
+    let sql = r#"
+        UPDATE todos t SET position = v.position
+        FROM unnest($2::text[], $3::text[]) AS v(id, position)
+        WHERE t.id = v.id AND t.owner_id = $1
+    "#;
+    let result = query(sql)
+        .bind(&owner_id)
+        .bind(ids)
+        .bind(positions)
+        .execute(&mut **tx)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+/// `id` 와 그 아래 모든 하위 작업을 재귀적으로 조회한다.
+/// 깊이 순, 같은 깊이에서는 생성 순이며 휴지통에 있는 하위 작업도 포함한다.
+async fn find_subtree<'e, E>(
+    executor: E,
+    owner_id: &Id<User>,
+    id: &Id<Todo>,
+) -> anyhow::Result<Vec<Todo>>
+where
+    E: sqlx::Executor<'e, Database = sqlx::Postgres>,
+{
+    let sql = format!(
+        r#"
+        WITH RECURSIVE subtree AS (
+            SELECT id, 0 AS depth FROM todos WHERE id = $1 AND owner_id = $2
+            UNION ALL
+            SELECT c.id, s.depth + 1
+            FROM todos c
+            INNER JOIN subtree s ON c.parent_id = s.id
+            WHERE c.owner_id = $2
+        )
+        {SELECT_TODOS}
+        INNER JOIN subtree s ON s.id = t.id
+        ORDER BY s.depth, t.created_at, t.id
+        "#
+    );
+    let stored: Vec<StoredTodo> = query_as(&sql)
+        .bind(id.value.to_string())
+        .bind(owner_id.value.to_string())
+        .fetch_all(executor)
+        .await?;
+    stored
+        .into_iter()
+        .map(|st| st.try_into())
+        .collect::<anyhow::Result<Vec<Todo>>>()
+}
+
+/// 소유자와 목록 조회 조건을 WHERE 절로 붙인다. 값은 모두 bind 한다.
+fn push_filter(qb: &mut QueryBuilder<'_, Postgres>, owner_id: &Id<User>, filter: &TodoFilter) {
+    qb.push(" WHERE t.owner_id = ");
+    qb.push_bind(owner_id.value.to_string());
+    // 상태를 지정하지 않으면 휴지통의 todo 는 보이지 않는다.
+    if filter.statuses.is_empty() {
+        qb.push(" AND t.deleted_at IS NULL");
+    } else {
+        let status_ids: Vec<String> = filter
+            .statuses
+            .iter()
+            .map(|s| s.id.value.to_string())
+            .collect();
+        qb.push(" AND t.status_id = ANY(");
+        qb.push_bind(status_ids);
+        qb.push(")");
+    }
+    if let Some(after) = filter.created_after {
+        qb.push(" AND t.created_at >= ");
+        qb.push_bind(after);
+    }
+    if let Some(before) = filter.created_before {
+        qb.push(" AND t.created_at < ");
+        qb.push_bind(before);
+    }
+    if let Some(since) = filter.updated_since {
+        qb.push(" AND t.updated_at >= ");
+        qb.push_bind(since);
+    }
+    if let Some(before) = filter.due_before {
+        qb.push(" AND t.due_at < ");
+        qb.push_bind(before);
+    }
+    if filter.overdue {
+        qb.push(" AND t.due_at < current_timestamp AND ts.code NOT IN (");
+        qb.push_bind(TodoStatusCode::Done.as_str());
+        qb.push(", ");
+        qb.push_bind(TodoStatusCode::Discontinued.as_str());
+        qb.push(")");
+    }
+    if let Some(parent_id) = &filter.parent_id {
+        qb.push(" AND t.parent_id = ");
+        qb.push_bind(parent_id.value.to_string());
+    }
+    // 프로젝트를 지정하지 않으면 보관한 프로젝트의 todo 는 따로 요청해야 보인다.
+    match &filter.project_id {
+        Some(project_id) => {
+            qb.push(" AND t.project_id = ");
+            qb.push_bind(project_id.value.to_string());
+        }
+        None if filter.include_archived => {}
+        None => {
+            qb.push(" AND t.archived_at IS NULL");
+        }
+    }
+    // 지정한 태그가 모두 붙어 있어야 한다. 태그는 소유자별이므로 이름으로 비교해도 된다.
+    if !filter.tag_names.is_empty() {
+        qb.push(
+            " AND t.id IN (SELECT tt.todo_id FROM todo_tags tt \
+             INNER JOIN tags tg ON tg.id = tt.tag_id WHERE tg.name = ANY(",
+        );
+        qb.push_bind(filter.tag_names.clone());
+        qb.push(") GROUP BY tt.todo_id HAVING count(*) = ");
+        qb.push_bind(filter.tag_names.len() as i64);
+        qb.push(")");
+    }
+}
+
+/// LIKE 패턴의 와일드카드를 문자 그대로 비교하도록 이스케이프한다.
+fn escape_like(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// HTML 특수 문자를 엔터티로 바꾼다. `SNIPPET_SOURCE` 와 같은 규칙이다.
+fn escape_html(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 정규식 메타 문자를 문자 그대로 비교하도록 이스케이프한다.
+fn escape_regex(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if "\\.^$|?*+()[]{}".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_like_escapes_wildcards() {
+        assert_eq!(escape_like("50%_off\\"), "50\\%\\_off\\\\");
+        assert_eq!(escape_like("할일"), "할일");
+    }
+
+    #[test]
+    fn escape_regex_escapes_meta_characters() {
+        assert_eq!(escape_regex("a.b*(c)"), "a\\.b\\*\\(c\\)");
+        assert_eq!(escape_regex("회의"), "회의");
+    }
+
+    #[test]
+    fn escape_html_escapes_markup_characters() {
+        assert_eq!(
+            escape_html("<a href=\"x\">'&'</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+        );
+        assert_eq!(escape_html("장보기"), "장보기");
+    }
+}
@@ -15,4 +15,11 @@ pub trait IUserRepository: shaku::Interface {
         username: &str,
     ) -> anyhow::Result<Option<User>>;
     async fn insert_tx(&self, tx: &mut PgTx, source: NewUser) -> anyhow::Result<User>;
+    /// 응답 언어를 바꾼다. `None` 이면 지운다. 사용자가 없으면 `None` 을 돌려준다.
+    async fn update_locale_tx(
+        &self,
+        tx: &mut PgTx,
+        id: &Id<User>,
+        locale: Option<&str>,
+    ) -> anyhow::Result<Option<User>>;
 }
@@ -54,7 +54,7 @@ impl IUserRepository for UserRepository {
         .await?;
 
         let sql = r#"
-            SELECT id, username, email, password, fullname
+            SELECT id, username, email, password, fullname, locale
             FROM users
             WHERE id = $1
         "#;
@@ -64,6 +64,26 @@ impl IUserRepository for UserRepository {
             .await?;
         result.try_into()
     }
+
+    async fn update_locale_tx(
+        &self,
+        tx: &mut PgTx,
+        id: &Id<User>,
+        locale: Option<&str>,
+    ) -> anyhow::Result<Option<User>> {
+        let sql = r#"
+            UPDATE users SET locale = $2
+            WHERE id = $1
+            RETURNING id, username, email, password, fullname, locale
+        "#;
+        query_as::<_, StoredUser>(sql)
+            .bind(id.value.to_string())
+            .bind(locale)
+            .fetch_optional(&mut **tx)
+            .await?
+            .map(|su| su.try_into())
+            .transpose()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -75,7 +95,7 @@ where
     E: sqlx::Executor<'e, Database = sqlx::Postgres>,
 {
     let sql = r#"
-        SELECT id, username, email, password, fullname
+        SELECT id, username, email, password, fullname, locale
         FROM users
         WHERE id = $1
     "#;
@@ -92,7 +112,7 @@ where
     E: sqlx::Executor<'e, Database = sqlx::Postgres>,
 {
     let sql = r#"
-        SELECT id, username, email, password, fullname
+        SELECT id, username, email, password, fullname, locale
         FROM users
         WHERE username = $1
     "#;
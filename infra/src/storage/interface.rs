@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use std::pin::Pin;
+use tokio::io::AsyncRead;
+
+/// 저장소로 보내거나 저장소에서 읽어 오는 파일 본문.
+/// 파일 전체를 메모리에 올리지 않도록 스트림으로 주고받는다.
+pub type BlobReader<'a> = Pin<Box<dyn AsyncRead + Send + 'a>>;
+
+/// 첨부 파일 본문을 보관하는 저장소 인터페이스.
+///
+/// `key` 는 `/` 로 구분한 영문·숫자·`-`·`_` 경로이며, 메타데이터는 DB 에 따로 둔다.
+/// 기본 구현은 로컬 파일 시스템(`LocalBlobStorage`)이고, S3 호환 저장소 등 다른 구현은
+/// `with_component_override::<dyn IBlobStorage>` 로 바꿔 끼운다.
+#[async_trait]
+pub trait IBlobStorage: shaku::Interface {
+    /// `body` 를 끝까지 읽어 `key` 에 저장하고 저장한 바이트 수를 반환한다. 같은 key 는 덮어쓴다.
+    async fn put(&self, key: &str, body: BlobReader<'_>) -> anyhow::Result<u64>;
+    /// `key` 의 본문을 연다. 없으면 `None` 이다.
+    async fn get(&self, key: &str) -> anyhow::Result<Option<BlobReader<'static>>>;
+    /// `key` 를 삭제한다. 이미 없으면 아무것도 하지 않는다.
+    async fn delete(&self, key: &str) -> anyhow::Result<()>;
+}
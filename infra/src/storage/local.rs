@@ -0,0 +1,163 @@
+use super::interface::{BlobReader, IBlobStorage};
+use anyhow::bail;
+use async_trait::async_trait;
+use shaku::Component;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+/// 로컬 파일 시스템 저장소 구현체.
+///
+/// `root` 는 shaku 파라미터로 제공된다.
+/// `with_component_parameters::<LocalBlobStorage>(LocalBlobStorageParameters { root })` 로 초기화한다.
+/// 쓰는 도중 실패하거나 취소되어도 반쯤 쓴 파일이 남지 않도록 `.part` 파일에 쓴 뒤 이름을 바꾼다.
+#[derive(Component)]
+#[shaku(interface = IBlobStorage)]
+pub struct LocalBlobStorage {
+    root: PathBuf,
+}
+
+impl LocalBlobStorage {
+    fn path_of(&self, key: &str) -> anyhow::Result<PathBuf> {
+        let valid = !key.is_empty()
+            && key.split('/').all(|segment| {
+                !segment.is_empty()
+                    && segment
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            });
+        if !valid {
+            bail!("invalid blob key: {key}");
+        }
+        Ok(self.root.join(key))
+    }
+}
+
+/// 쓰는 중인 `.part` 파일. 이름을 바꾸기 전에 버려지면 지운다.
+/// `put` 이 실패하거나 future 가 중간에 버려져도(요청 취소·시간 초과) 파일이 남지 않는다.
+struct PartFile {
+    path: PathBuf,
+    committed: bool,
+}
+
+impl Drop for PartFile {
+    fn drop(&mut self) {
+        if !self.committed {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+#[async_trait]
+impl IBlobStorage for LocalBlobStorage {
+    async fn put(&self, key: &str, mut body: BlobReader<'_>) -> anyhow::Result<u64> {
+        let path = self.path_of(key)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).await?;
+        }
+        let mut part = PartFile {
+            path: path.with_extension("part"),
+            committed: false,
+        };
+        let mut file = fs::File::create(&part.path).await?;
+        let written = tokio::io::copy(&mut body, &mut file).await?;
+        file.flush().await?;
+        drop(file);
+        fs::rename(&part.path, &path).await?;
+        part.committed = true;
+        Ok(written)
+    }
+
+    async fn get(&self, key: &str) -> anyhow::Result<Option<BlobReader<'static>>> {
+        let path = self.path_of(key)?;
+        match fs::File::open(path).await {
+            Ok(file) => Ok(Some(Box::pin(file))),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> anyhow::Result<()> {
+        let path = self.path_of(key)?;
+        match fs::remove_file(path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+
+    fn storage() -> LocalBlobStorage {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        LocalBlobStorage {
+            root: std::env::temp_dir().join(format!("blob-test-{}-{nanos}", std::process::id())),
+        }
+    }
+
+    #[tokio::test]
+    async fn put_get_delete_round_trip() {
+        let storage = storage();
+        let written = storage
+            .put("todos/a/b", Box::pin(&b"hello"[..]))
+            .await
+            .unwrap();
+        assert_eq!(written, 5);
+
+        let mut body = String::new();
+        storage
+            .get("todos/a/b")
+            .await
+            .unwrap()
+            .expect("stored blob should be found")
+            .read_to_string(&mut body)
+            .await
+            .unwrap();
+        assert_eq!(body, "hello");
+
+        storage.delete("todos/a/b").await.unwrap();
+        assert!(storage.get("todos/a/b").await.unwrap().is_none());
+        storage
+            .delete("todos/a/b")
+            .await
+            .expect("deleting a missing blob is not an error");
+    }
+
+    #[tokio::test]
+    async fn cancelled_put_leaves_no_part_file() {
+        let storage = storage();
+        // 쓰는 쪽을 살려 둔 채 아무것도 보내지 않아 본문을 끝까지 읽지 못하게 한다.
+        let (_writer, reader) = tokio::io::duplex(64);
+        let body = Box::pin((&b"partial"[..]).chain(reader));
+        let put = storage.put("todos/a/slow", body);
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(50), put)
+                .await
+                .is_err()
+        );
+
+        let part = storage
+            .path_of("todos/a/slow")
+            .unwrap()
+            .with_extension("part");
+        assert!(storage.root.join("todos/a").exists());
+        assert!(!part.exists());
+        assert!(storage.get("todos/a/slow").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn key_with_parent_segment_is_rejected() {
+        let storage = storage();
+        assert!(storage.get("../etc/passwd").await.is_err());
+        assert!(storage.get("todos//x").await.is_err());
+        assert!(storage.get("").await.is_err());
+    }
+}
@@ -0,0 +1,5 @@
+mod interface;
+mod local;
+
+pub use interface::{BlobReader, IBlobStorage};
+pub use local::{LocalBlobStorage, LocalBlobStorageParameters};
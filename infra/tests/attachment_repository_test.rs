@@ -0,0 +1,125 @@
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::{fixture_new_todo, fixture_new_user};
+use common::module::build_test_module;
+use domain::model::attachment::NewAttachment;
+use domain::model::todo::Todo;
+use domain::model::user::User;
+use domain::model::Id;
+use infra::repository::attachment::IAttachmentRepository;
+use infra::repository::todo::ITodoRepository;
+use infra::repository::user::IUserRepository;
+use infra::repository::PgTx;
+use shaku::HasComponent;
+use std::sync::Arc;
+
+/// 첨부 파일을 올리는 사용자를 같은 트랜잭션에 생성한다.
+/// fullname 길이 제한(32자)에 맞도록 ULID 의 뒤 12자만 suffix 로 사용한다.
+async fn insert_uploader(repo: &Arc<dyn IUserRepository>, tx: &mut PgTx) -> Id<User> {
+    let ulid = Id::<User>::gen().value.to_string().to_lowercase();
+    let suffix = &ulid[ulid.len() - 12..];
+    repo.insert_tx(tx, fixture_new_user(suffix))
+        .await
+        .unwrap()
+        .id
+}
+
+fn new_attachment(todo_id: &Id<Todo>, uploader_id: &Id<User>, file_name: &str) -> NewAttachment {
+    NewAttachment::new(
+        Id::gen(),
+        todo_id.clone(),
+        uploader_id.clone(),
+        file_name.to_string(),
+        "text/plain".to_string(),
+        12,
+    )
+}
+
+/// insert → find / get 으로 조회, 다른 todo 의 첨부 파일은 섞이지 않는다
+#[tokio::test]
+async fn insert_then_find_and_get_attachments() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn IAttachmentRepository> = module.resolve();
+    let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+
+    // find 와 get 은 pool 로 조회하므로 데이터를 커밋한다.
+    let mut tx = pool.begin().await.unwrap();
+    let uploader_id = insert_uploader(&user_repo, &mut tx).await;
+    let todo = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(uploader_id.clone()))
+        .await
+        .unwrap();
+    let other_todo = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(uploader_id.clone()))
+        .await
+        .unwrap();
+    let inserted = repo
+        .insert_tx(&mut tx, new_attachment(&todo.id, &uploader_id, "memo.txt"))
+        .await
+        .unwrap();
+    repo.insert_tx(
+        &mut tx,
+        new_attachment(&other_todo.id, &uploader_id, "other.txt"),
+    )
+    .await
+    .unwrap();
+    tx.commit().await.unwrap();
+
+    let found = repo.find(&todo.id).await.unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].file_name, "memo.txt");
+    assert_eq!(found[0].size_bytes, 12);
+
+    let got = repo
+        .get(&todo.id, &inserted.id)
+        .await
+        .unwrap()
+        .expect("inserted attachment should be found");
+    assert_eq!(got.storage_key, inserted.storage_key);
+    assert!(repo
+        .get(&other_todo.id, &inserted.id)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+/// delete: 삭제한 메타데이터를 반환하고, 같은 todo 범위에서만 삭제된다
+#[tokio::test]
+async fn delete_attachment_returns_deleted_row() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn IAttachmentRepository> = module.resolve();
+    let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let uploader_id = insert_uploader(&user_repo, &mut tx).await;
+    let todo = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(uploader_id.clone()))
+        .await
+        .unwrap();
+    let other_todo = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(uploader_id.clone()))
+        .await
+        .unwrap();
+    let inserted = repo
+        .insert_tx(&mut tx, new_attachment(&todo.id, &uploader_id, "memo.txt"))
+        .await
+        .unwrap();
+
+    assert!(repo
+        .delete_tx(&mut tx, &other_todo.id, &inserted.id)
+        .await
+        .unwrap()
+        .is_none());
+    let deleted = repo
+        .delete_tx(&mut tx, &todo.id, &inserted.id)
+        .await
+        .unwrap()
+        .expect("inserted attachment should be deleted");
+    assert_eq!(deleted.id.value, inserted.id.value);
+    tx.rollback().await.unwrap();
+}
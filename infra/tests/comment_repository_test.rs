@@ -0,0 +1,195 @@
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::{fixture_new_comment, fixture_new_todo, fixture_new_user};
+use common::module::build_test_module;
+use domain::model::comment::{CommentCursor, UpdateComment};
+use domain::model::user::User;
+use domain::model::Id;
+use infra::repository::comment::ICommentRepository;
+use infra::repository::todo::ITodoRepository;
+use infra::repository::user::IUserRepository;
+use infra::repository::PgTx;
+use shaku::HasComponent;
+use std::sync::Arc;
+
+/// 댓글 작성자로 사용할 사용자를 같은 트랜잭션에 생성한다.
+/// fullname 길이 제한(32자)에 맞도록 ULID 의 뒤 12자만 suffix 로 사용한다.
+async fn insert_author(repo: &Arc<dyn IUserRepository>, tx: &mut PgTx) -> Id<User> {
+    let ulid = Id::<User>::gen().value.to_string().to_lowercase();
+    let suffix = &ulid[ulid.len() - 12..];
+    repo.insert_tx(tx, fixture_new_user(suffix))
+        .await
+        .unwrap()
+        .id
+}
+
+/// find: cursor 로 이어 받으면 겹치지 않고 모두 조회되며, 다른 todo 의 댓글은 섞이지 않는다
+#[tokio::test]
+async fn find_comments_pages_by_cursor() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ICommentRepository> = module.resolve();
+    let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+
+    // find 는 pool 로 조회하므로 데이터를 커밋한다.
+    let mut tx = pool.begin().await.unwrap();
+    let author_id = insert_author(&user_repo, &mut tx).await;
+    let todo = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(author_id.clone()))
+        .await
+        .unwrap();
+    let other_todo = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(author_id.clone()))
+        .await
+        .unwrap();
+    for body in ["first", "second", "third"] {
+        repo.insert_tx(
+            &mut tx,
+            fixture_new_comment(todo.id.clone(), author_id.clone(), body),
+        )
+        .await
+        .unwrap();
+    }
+    repo.insert_tx(
+        &mut tx,
+        fixture_new_comment(other_todo.id.clone(), author_id.clone(), "other"),
+    )
+    .await
+    .unwrap();
+    tx.commit().await.unwrap();
+
+    let first_page = repo.find(&todo.id, None, 2).await.unwrap();
+    assert_eq!(first_page.len(), 2);
+    let last = first_page.last().unwrap();
+    let cursor = CommentCursor::new(last.created_at, last.id.clone());
+    let second_page = repo.find(&todo.id, Some(cursor), 2).await.unwrap();
+    assert_eq!(second_page.len(), 1);
+
+    let mut ids: Vec<String> = first_page
+        .iter()
+        .chain(second_page.iter())
+        .map(|c| c.id.value.to_string())
+        .collect();
+    ids.dedup();
+    assert_eq!(ids.len(), 3);
+    assert!(first_page
+        .iter()
+        .chain(second_page.iter())
+        .all(|c| c.todo_id.value == todo.id.value));
+}
+
+/// update: 본문이 바뀌고, 다른 todo 로 지정하면 갱신되지 않는다
+#[tokio::test]
+async fn update_comment_changes_body_within_todo() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ICommentRepository> = module.resolve();
+    let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let author_id = insert_author(&user_repo, &mut tx).await;
+    let todo = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(author_id.clone()))
+        .await
+        .unwrap();
+    let other_todo = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(author_id.clone()))
+        .await
+        .unwrap();
+    let inserted = repo
+        .insert_tx(
+            &mut tx,
+            fixture_new_comment(todo.id.clone(), author_id.clone(), "before"),
+        )
+        .await
+        .unwrap();
+
+    let wrong_todo = UpdateComment::new(
+        inserted.id.clone(),
+        other_todo.id.clone(),
+        "after".to_string(),
+    );
+    assert!(repo.update_tx(&mut tx, wrong_todo).await.unwrap().is_none());
+
+    let update = UpdateComment::new(inserted.id.clone(), todo.id.clone(), "after".to_string());
+    let updated = repo.update_tx(&mut tx, update).await.unwrap().unwrap();
+    assert_eq!(updated.body, "after");
+    assert_eq!(updated.author_id.value, author_id.value);
+    tx.rollback().await.unwrap();
+}
+
+/// delete: 삭제한 댓글은 다시 조회되지 않는다
+#[tokio::test]
+async fn delete_comment_removes_row() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ICommentRepository> = module.resolve();
+    let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let author_id = insert_author(&user_repo, &mut tx).await;
+    let todo = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(author_id.clone()))
+        .await
+        .unwrap();
+    let inserted = repo
+        .insert_tx(
+            &mut tx,
+            fixture_new_comment(todo.id.clone(), author_id.clone(), "bye"),
+        )
+        .await
+        .unwrap();
+
+    let deleted = repo
+        .delete_tx(&mut tx, &todo.id, &inserted.id)
+        .await
+        .unwrap()
+        .expect("inserted comment should be deleted");
+    assert_eq!(deleted.id.value, inserted.id.value);
+    assert!(repo
+        .get_tx(&mut tx, &todo.id, &inserted.id)
+        .await
+        .unwrap()
+        .is_none());
+    tx.rollback().await.unwrap();
+}
+
+/// todo 를 영구 삭제하면 댓글도 함께 삭제된다 (ON DELETE CASCADE)
+#[tokio::test]
+async fn delete_todo_cascades_to_comments() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ICommentRepository> = module.resolve();
+    let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let author_id = insert_author(&user_repo, &mut tx).await;
+    let todo = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(author_id.clone()))
+        .await
+        .unwrap();
+    let inserted = repo
+        .insert_tx(
+            &mut tx,
+            fixture_new_comment(todo.id.clone(), author_id.clone(), "gone"),
+        )
+        .await
+        .unwrap();
+
+    todo_repo
+        .delete_tx(&mut tx, &author_id, &todo.id, None)
+        .await
+        .unwrap()
+        .expect("todo should be deleted");
+    assert!(repo
+        .get_tx(&mut tx, &todo.id, &inserted.id)
+        .await
+        .unwrap()
+        .is_none());
+    tx.rollback().await.unwrap();
+}
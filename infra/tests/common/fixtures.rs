@@ -1,7 +1,11 @@
 #![allow(dead_code)]
 
+use domain::model::comment::NewComment;
+use domain::model::project::{NewProject, DEFAULT_PROJECT_COLOR};
+use domain::model::tag::NewTag;
 use domain::model::todo::NewTodo;
-use domain::model::user::NewUser;
+use domain::model::todo::Todo;
+use domain::model::user::{NewUser, User};
 use domain::model::Id;
 
 /// 고유한 suffix로 중복 username 충돌을 방지하는 NewUser 픽스처.
@@ -15,10 +19,31 @@ pub fn fixture_new_user(suffix: &str) -> NewUser {
 }
 
 /// 기본값으로 채워진 NewTodo 픽스처.
-pub fn fixture_new_todo() -> NewTodo {
+pub fn fixture_new_todo(owner_id: Id<User>) -> NewTodo {
     NewTodo::new(
         Id::gen(),
+        owner_id,
         "Test Todo Title".to_string(),
         "Test Todo Description".to_string(),
     )
 }
+
+/// 이름을 지정한 NewTag 픽스처.
+pub fn fixture_new_tag(owner_id: Id<User>, name: &str) -> NewTag {
+    NewTag::new(Id::gen(), owner_id, name.to_string())
+}
+
+/// 이름을 지정하고 기본 색을 쓰는 NewProject 픽스처.
+pub fn fixture_new_project(owner_id: Id<User>, name: &str) -> NewProject {
+    NewProject::new(
+        Id::gen(),
+        owner_id,
+        name.to_string(),
+        DEFAULT_PROJECT_COLOR.to_string(),
+    )
+}
+
+/// 본문을 지정한 NewComment 픽스처.
+pub fn fixture_new_comment(todo_id: Id<Todo>, author_id: Id<User>, body: &str) -> NewComment {
+    NewComment::new(Id::gen(), todo_id, author_id, body.to_string())
+}
@@ -1,6 +1,14 @@
 #![allow(unused_imports)]
 use infra::db::{Db, DbParameters};
+use infra::repository::attachment::AttachmentRepository;
+use infra::repository::comment::CommentRepository;
 use infra::repository::health_check::HealthCheckRepository;
+use infra::repository::idempotency::IdempotencyRepository;
+use infra::repository::project::ProjectRepository;
+use infra::repository::tag::TagRepository;
+use infra::repository::todo::event::TodoEventRepository;
+use infra::repository::todo::reminder::TodoReminderRepository;
+use infra::repository::todo::share::TodoShareRepository;
 use infra::repository::todo::status::TodoStatusRepository;
 use infra::repository::todo::TodoRepository;
 use infra::repository::user::UserRepository;
@@ -13,8 +21,16 @@ module! {
             Db,
             TodoRepository,
             TodoStatusRepository,
+            TodoEventRepository,
+            TodoReminderRepository,
+            TodoShareRepository,
+            TagRepository,
+            ProjectRepository,
+            CommentRepository,
+            AttachmentRepository,
             UserRepository,
             HealthCheckRepository,
+            IdempotencyRepository,
         ],
         providers = []
     }
@@ -0,0 +1,168 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::db::setup_test_db;
+use common::fixtures::fixture_new_user;
+use common::module::build_test_module;
+use domain::model::idempotency::{IdempotentResponse, NewIdempotencyKey};
+use domain::model::user::User;
+use domain::model::Id;
+use infra::repository::idempotency::IIdempotencyRepository;
+use infra::repository::user::IUserRepository;
+use shaku::HasComponent;
+use std::sync::Arc;
+
+/// 키 주인으로 사용할 사용자를 만들어 커밋한다. `get` 은 트랜잭션 밖에서 읽기 때문이다.
+async fn insert_owner(pool: &sqlx::PgPool, repo: &Arc<dyn IUserRepository>) -> Id<User> {
+    let ulid = Id::<User>::gen().value.to_string().to_lowercase();
+    let suffix = &ulid[ulid.len() - 12..];
+    let mut tx = pool.begin().await.unwrap();
+    let id = repo
+        .insert_tx(&mut tx, fixture_new_user(suffix))
+        .await
+        .unwrap()
+        .id;
+    tx.commit().await.unwrap();
+    id
+}
+
+fn new_key(user_id: &Id<User>, hash: &str, expires_in: Duration) -> NewIdempotencyKey {
+    NewIdempotencyKey::new(
+        user_id.clone(),
+        "key-1".to_string(),
+        hash.to_string(),
+        Utc::now() + expires_in,
+    )
+}
+
+/// claim → 다시 claim 은 실패 → complete 로 응답을 남기고 get 으로 읽는다
+#[tokio::test]
+async fn claim_complete_then_get_returns_stored_response() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn IIdempotencyRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let owner_id = insert_owner(&pool, &user_repo).await;
+    let stale_before = Utc::now() - Duration::minutes(1);
+
+    let mut tx = pool.begin().await.unwrap();
+    assert!(repo
+        .claim_tx(
+            &mut tx,
+            new_key(&owner_id, "h1", Duration::hours(1)),
+            stale_before
+        )
+        .await
+        .unwrap());
+    assert!(!repo
+        .claim_tx(
+            &mut tx,
+            new_key(&owner_id, "h2", Duration::hours(1)),
+            stale_before
+        )
+        .await
+        .unwrap());
+    let response = IdempotentResponse {
+        status: 200,
+        headers: vec![("content-type".to_string(), "application/json".to_string())],
+        body: b"{\"result\":true}".to_vec(),
+    };
+    assert!(repo
+        .complete_tx(&mut tx, &owner_id, "key-1", &response)
+        .await
+        .unwrap());
+    // 응답을 남긴 키는 처리 중이 아니므로 지우지 않는다.
+    repo.release_tx(&mut tx, &owner_id, "key-1").await.unwrap();
+    tx.commit().await.unwrap();
+
+    let stored = repo
+        .get(&owner_id, "key-1")
+        .await
+        .unwrap()
+        .expect("completed key should be found");
+    assert_eq!(stored.request_hash, "h1");
+    assert_eq!(stored.response, Some(response));
+}
+
+/// 만료된 키와 오래 처리 중으로 남은 키는 다시 차지할 수 있고, 만료된 키는 get 으로 보이지 않는다
+#[tokio::test]
+async fn expired_or_stale_keys_can_be_claimed_again() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn IIdempotencyRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let owner_id = insert_owner(&pool, &user_repo).await;
+
+    let mut tx = pool.begin().await.unwrap();
+    assert!(repo
+        .claim_tx(
+            &mut tx,
+            new_key(&owner_id, "h1", -Duration::seconds(1)),
+            Utc::now() - Duration::minutes(1),
+        )
+        .await
+        .unwrap());
+    tx.commit().await.unwrap();
+    assert!(repo.get(&owner_id, "key-1").await.unwrap().is_none());
+
+    let mut tx = pool.begin().await.unwrap();
+    assert!(repo
+        .claim_tx(
+            &mut tx,
+            new_key(&owner_id, "h2", Duration::hours(1)),
+            Utc::now() - Duration::minutes(1),
+        )
+        .await
+        .unwrap());
+    // 처리 중인 키도 `stale_before` 가 지금보다 뒤이면 오래된 것으로 보고 다시 차지한다.
+    assert!(repo
+        .claim_tx(
+            &mut tx,
+            new_key(&owner_id, "h3", Duration::hours(1)),
+            Utc::now() + Duration::minutes(1),
+        )
+        .await
+        .unwrap());
+    repo.release_tx(&mut tx, &owner_id, "key-1").await.unwrap();
+    assert_eq!(repo.delete_expired_tx(&mut tx, &owner_id).await.unwrap(), 0);
+    tx.commit().await.unwrap();
+    assert!(repo.get(&owner_id, "key-1").await.unwrap().is_none());
+}
+
+/// 만료된 키는 사용자와 관계없이 지우고, 만료되지 않은 키는 남긴다
+#[tokio::test]
+async fn delete_all_expired_sweeps_every_user() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn IIdempotencyRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let expired_owner = insert_owner(&pool, &user_repo).await;
+    let live_owner = insert_owner(&pool, &user_repo).await;
+
+    let mut tx = pool.begin().await.unwrap();
+    for (owner_id, expires_in) in [
+        (&expired_owner, -Duration::seconds(1)),
+        (&live_owner, Duration::hours(1)),
+    ] {
+        assert!(repo
+            .claim_tx(
+                &mut tx,
+                new_key(owner_id, "h1", expires_in),
+                Utc::now() - Duration::minutes(1),
+            )
+            .await
+            .unwrap());
+    }
+    tx.commit().await.unwrap();
+
+    assert!(repo.delete_all_expired(i64::MAX).await.unwrap() >= 1);
+    let remaining: Vec<String> = sqlx::query_scalar(
+        "SELECT user_id FROM idempotency_keys WHERE user_id = $1 OR user_id = $2",
+    )
+    .bind(expired_owner.value.to_string())
+    .bind(live_owner.value.to_string())
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(remaining, vec![live_owner.value.to_string()]);
+}
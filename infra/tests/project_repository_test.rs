@@ -0,0 +1,185 @@
+mod common;
+
+use chrono::Utc;
+use common::db::setup_test_db;
+use common::fixtures::{fixture_new_project, fixture_new_todo, fixture_new_user};
+use common::module::build_test_module;
+use domain::model::project::UpdateProject;
+use domain::model::todo::filter::{TodoFilter, TodoSort};
+use domain::model::user::User;
+use domain::model::Id;
+use infra::repository::project::IProjectRepository;
+use infra::repository::todo::ITodoRepository;
+use infra::repository::user::IUserRepository;
+use infra::repository::PgTx;
+use shaku::HasComponent;
+use std::sync::Arc;
+
+/// 프로젝트 소유자로 사용할 사용자를 같은 트랜잭션에 생성한다.
+/// fullname 길이 제한(32자)에 맞도록 ULID 의 뒤 12자만 suffix 로 사용한다.
+async fn insert_owner(repo: &Arc<dyn IUserRepository>, tx: &mut PgTx) -> Id<User> {
+    let ulid = Id::<User>::gen().value.to_string().to_lowercase();
+    let suffix = &ulid[ulid.len() - 12..];
+    repo.insert_tx(tx, fixture_new_user(suffix))
+        .await
+        .unwrap()
+        .id
+}
+
+/// insert → update 로 이름만 바꾸면 색은 그대로 남는다
+#[tokio::test]
+async fn insert_then_rename_project_keeps_color() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn IProjectRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let inserted = repo
+        .insert_tx(&mut tx, fixture_new_project(owner_id.clone(), "work"))
+        .await
+        .unwrap();
+    assert!(!inserted.archived);
+
+    let update = UpdateProject::new(
+        inserted.id.clone(),
+        owner_id.clone(),
+        Some("office".to_string()),
+        None,
+    );
+    let renamed = repo.update_tx(&mut tx, update).await.unwrap().unwrap();
+    assert_eq!(renamed.name, "office");
+    assert_eq!(renamed.color, inserted.color);
+    assert!(repo
+        .get_by_name_tx(&mut tx, &owner_id, "work")
+        .await
+        .unwrap()
+        .is_none());
+    tx.rollback().await.unwrap();
+}
+
+/// 프로젝트로 옮긴 todo 는 프로젝트 조건으로 조회되고, 보관하면 기본 목록에서 빠진다
+#[tokio::test]
+async fn archived_project_todos_are_hidden_unless_project_is_given() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn IProjectRepository> = module.resolve();
+    let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let project = repo
+        .insert_tx(&mut tx, fixture_new_project(owner_id.clone(), "home"))
+        .await
+        .unwrap();
+    let inside = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    let moved = todo_repo
+        .set_project_tx(&mut tx, &owner_id, &inside.id, Some(&project.id), None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        moved.project_id.map(|id| id.value.to_string()),
+        Some(project.id.value.to_string())
+    );
+    assert_eq!(moved.version, inside.version + 1);
+
+    let in_project = TodoFilter {
+        project_id: Some(project.id.clone()),
+        ..Default::default()
+    };
+    let found = todo_repo
+        .find_tx(
+            &mut tx,
+            &owner_id,
+            &in_project,
+            TodoSort::default(),
+            None,
+            10,
+        )
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 1);
+
+    repo.set_archived_tx(&mut tx, &owner_id, &project.id, true)
+        .await
+        .unwrap()
+        .unwrap();
+    let archived = todo_repo
+        .set_archived_by_project_tx(&mut tx, &owner_id, &project.id, Some(Utc::now()))
+        .await
+        .unwrap();
+    assert_eq!(archived, 1);
+
+    let all = todo_repo
+        .find_tx(
+            &mut tx,
+            &owner_id,
+            &TodoFilter::default(),
+            TodoSort::default(),
+            None,
+            10,
+        )
+        .await
+        .unwrap();
+    assert_eq!(all.len(), 1, "archived todo must be hidden by default");
+    let found = todo_repo
+        .find_tx(
+            &mut tx,
+            &owner_id,
+            &in_project,
+            TodoSort::default(),
+            None,
+            10,
+        )
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 1);
+    assert!(found[0].archived_at.is_some());
+    assert_eq!(found[0].version, moved.version + 1);
+
+    assert!(repo.find_all(&owner_id, false).await.unwrap().is_empty());
+    tx.rollback().await.unwrap();
+}
+
+/// 프로젝트를 삭제하면 todo 는 프로젝트 없이 남는다
+#[tokio::test]
+async fn delete_project_leaves_todos_without_project() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn IProjectRepository> = module.resolve();
+    let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let project = repo
+        .insert_tx(&mut tx, fixture_new_project(owner_id.clone(), "errands"))
+        .await
+        .unwrap();
+    let mut new_todo = fixture_new_todo(owner_id.clone());
+    new_todo.project_id = Some(project.id.clone());
+    let todo = todo_repo.insert_tx(&mut tx, new_todo).await.unwrap();
+    assert!(todo.project_id.is_some());
+
+    repo.delete_tx(&mut tx, &owner_id, &project.id)
+        .await
+        .unwrap()
+        .expect("owned project should be deleted");
+    let found = todo_repo
+        .get_tx(&mut tx, &owner_id, &todo.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(found.project_id.is_none());
+    tx.rollback().await.unwrap();
+}
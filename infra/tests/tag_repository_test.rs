@@ -0,0 +1,232 @@
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::{fixture_new_tag, fixture_new_todo, fixture_new_user};
+use common::module::build_test_module;
+use domain::model::tag::UpdateTag;
+use domain::model::todo::filter::{TodoFilter, TodoSort};
+use domain::model::user::User;
+use domain::model::Id;
+use infra::repository::tag::ITagRepository;
+use infra::repository::todo::ITodoRepository;
+use infra::repository::user::IUserRepository;
+use infra::repository::PgTx;
+use shaku::HasComponent;
+use std::sync::Arc;
+
+/// 태그 소유자로 사용할 사용자를 같은 트랜잭션에 생성한다.
+/// fullname 길이 제한(32자)에 맞도록 ULID 의 뒤 12자만 suffix 로 사용한다.
+async fn insert_owner(repo: &Arc<dyn IUserRepository>, tx: &mut PgTx) -> Id<User> {
+    let ulid = Id::<User>::gen().value.to_string().to_lowercase();
+    let suffix = &ulid[ulid.len() - 12..];
+    repo.insert_tx(tx, fixture_new_user(suffix))
+        .await
+        .unwrap()
+        .id
+}
+
+/// insert → get_by_name, update 로 이름 변경
+#[tokio::test]
+async fn insert_then_rename_tag() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITagRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let inserted = repo
+        .insert_tx(&mut tx, fixture_new_tag(owner_id.clone(), "work"))
+        .await
+        .unwrap();
+    let found = repo
+        .get_by_name_tx(&mut tx, &owner_id, "work")
+        .await
+        .unwrap()
+        .expect("inserted tag should be found by name");
+    assert_eq!(found.id.value, inserted.id.value);
+
+    let update = UpdateTag::new(inserted.id, owner_id.clone(), "office".to_string());
+    let renamed = repo.update_tx(&mut tx, update).await.unwrap().unwrap();
+    assert_eq!(renamed.name, "office");
+    assert!(repo
+        .get_by_name_tx(&mut tx, &owner_id, "work")
+        .await
+        .unwrap()
+        .is_none());
+    tx.rollback().await.unwrap();
+}
+
+/// 같은 사용자 안에서 같은 이름은 DB 제약으로 막히고, 다른 사용자는 같은 이름을 쓸 수 있다
+#[tokio::test]
+async fn insert_tag_with_duplicate_name_fails_only_for_same_owner() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITagRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let other_id = insert_owner(&user_repo, &mut tx).await;
+    repo.insert_tx(&mut tx, fixture_new_tag(owner_id.clone(), "home"))
+        .await
+        .unwrap();
+    repo.insert_tx(&mut tx, fixture_new_tag(other_id, "home"))
+        .await
+        .expect("other owner may use the same name");
+
+    let result = repo
+        .insert_tx(&mut tx, fixture_new_tag(owner_id, "home"))
+        .await;
+    assert!(
+        result.is_err(),
+        "duplicate name must violate uq_tags_owner_id_name"
+    );
+}
+
+/// find_by_ids: 다른 사용자의 태그는 제외
+#[tokio::test]
+async fn find_tags_by_ids_excludes_other_owner_tags() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITagRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let other_id = insert_owner(&user_repo, &mut tx).await;
+    let mine = repo
+        .insert_tx(&mut tx, fixture_new_tag(owner_id.clone(), "mine"))
+        .await
+        .unwrap();
+    let theirs = repo
+        .insert_tx(&mut tx, fixture_new_tag(other_id, "theirs"))
+        .await
+        .unwrap();
+
+    let found = repo
+        .find_by_ids_tx(&mut tx, &owner_id, &[mine.id, theirs.id])
+        .await
+        .unwrap();
+    let names: Vec<&str> = found.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, vec!["mine"]);
+    tx.rollback().await.unwrap();
+}
+
+/// attach / detach 결과가 todo 조회와 tag 필터에 반영되고, 태그 삭제 시 연결도 사라진다
+#[tokio::test]
+async fn attach_detach_and_delete_tag_update_todo_tags() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITagRepository> = module.resolve();
+    let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let work = repo
+        .insert_tx(&mut tx, fixture_new_tag(owner_id.clone(), "work"))
+        .await
+        .unwrap();
+    let home = repo
+        .insert_tx(&mut tx, fixture_new_tag(owner_id.clone(), "home"))
+        .await
+        .unwrap();
+    let both = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    let only_work = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    repo.attach_tx(&mut tx, &owner_id, &both.id, &[work.clone(), home.clone()])
+        .await
+        .unwrap();
+    repo.attach_tx(
+        &mut tx,
+        &owner_id,
+        &only_work.id,
+        std::slice::from_ref(&work),
+    )
+    .await
+    .unwrap();
+    // 이미 붙어 있는 태그는 무시한다.
+    repo.attach_tx(&mut tx, &owner_id, &both.id, std::slice::from_ref(&work))
+        .await
+        .unwrap();
+
+    let found = todo_repo
+        .get_tx(&mut tx, &owner_id, &both.id)
+        .await
+        .unwrap()
+        .unwrap();
+    let names: Vec<&str> = found.tags.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, vec!["home", "work"]);
+
+    let filter = TodoFilter {
+        tag_names: vec!["work".to_string(), "home".to_string()],
+        ..Default::default()
+    };
+    let found = todo_repo
+        .find_tx(&mut tx, &owner_id, &filter, TodoSort::default(), None, 10)
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].id.value, both.id.value);
+
+    repo.detach_tx(&mut tx, &owner_id, &both.id, std::slice::from_ref(&home))
+        .await
+        .unwrap();
+    let found = todo_repo
+        .find_tx(&mut tx, &owner_id, &filter, TodoSort::default(), None, 10)
+        .await
+        .unwrap();
+    assert!(found.is_empty());
+
+    repo.delete_tx(&mut tx, &owner_id, &work.id)
+        .await
+        .unwrap()
+        .expect("owned tag should be deleted");
+    let found = todo_repo
+        .get_tx(&mut tx, &owner_id, &only_work.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(found.tags.is_empty());
+    tx.rollback().await.unwrap();
+}
+
+/// attach: 다른 사용자의 todo 에는 붙지 않는다
+#[tokio::test]
+async fn attach_tag_to_other_owner_todo_is_ignored() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITagRepository> = module.resolve();
+    let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let other_id = insert_owner(&user_repo, &mut tx).await;
+    let tag = repo
+        .insert_tx(&mut tx, fixture_new_tag(other_id.clone(), "sneaky"))
+        .await
+        .unwrap();
+    let todo = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+
+    repo.attach_tx(&mut tx, &other_id, &todo.id, &[tag])
+        .await
+        .unwrap();
+
+    let found = todo_repo
+        .get_tx(&mut tx, &owner_id, &todo.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(found.tags.is_empty());
+    tx.rollback().await.unwrap();
+}
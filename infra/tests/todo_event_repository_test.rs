@@ -0,0 +1,117 @@
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::{fixture_new_todo, fixture_new_user};
+use common::module::build_test_module;
+use domain::model::todo::event::{NewTodoEvent, TodoEventCursor, TodoFieldChange};
+use domain::model::user::User;
+use domain::model::Id;
+use infra::repository::todo::event::ITodoEventRepository;
+use infra::repository::todo::ITodoRepository;
+use infra::repository::user::IUserRepository;
+use infra::repository::PgTx;
+use shaku::HasComponent;
+use std::sync::Arc;
+
+/// 이력을 남기는 사용자를 같은 트랜잭션에 생성한다.
+/// fullname 길이 제한(32자)에 맞도록 ULID 의 뒤 12자만 suffix 로 사용한다.
+async fn insert_actor(repo: &Arc<dyn IUserRepository>, tx: &mut PgTx) -> Id<User> {
+    let ulid = Id::<User>::gen().value.to_string().to_lowercase();
+    let suffix = &ulid[ulid.len() - 12..];
+    repo.insert_tx(tx, fixture_new_user(suffix))
+        .await
+        .unwrap()
+        .id
+}
+
+fn title_change(before: &str, after: &str) -> Vec<TodoFieldChange> {
+    vec![TodoFieldChange::new(
+        "title",
+        Some(before.to_string()),
+        Some(after.to_string()),
+    )]
+}
+
+/// insert 는 changes 를 jsonb 로 저장하고 그대로 읽어 온다
+#[tokio::test]
+async fn insert_todo_event_round_trips_changes() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoEventRepository> = module.resolve();
+    let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let actor_id = insert_actor(&user_repo, &mut tx).await;
+    let todo = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(actor_id.clone()))
+        .await
+        .unwrap();
+    let changes = vec![
+        TodoFieldChange::new("title", Some("old".to_string()), Some("new".to_string())),
+        TodoFieldChange::new("dueAt", None, Some("2026-10-20T00:00:00Z".to_string())),
+    ];
+    let event = repo
+        .insert_tx(
+            &mut tx,
+            NewTodoEvent::new(
+                Id::gen(),
+                todo.id.clone(),
+                actor_id.clone(),
+                changes.clone(),
+            ),
+        )
+        .await
+        .unwrap();
+    assert_eq!(event.changes, changes);
+    assert_eq!(event.actor_id.value, actor_id.value);
+    tx.rollback().await.unwrap();
+}
+
+/// find: 최신순으로 cursor 다음 항목을 조회한다
+#[tokio::test]
+async fn find_todo_events_pages_newest_first() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoEventRepository> = module.resolve();
+    let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+
+    // find 는 pool 로 조회하므로 데이터를 커밋한다.
+    let mut tx = pool.begin().await.unwrap();
+    let actor_id = insert_actor(&user_repo, &mut tx).await;
+    let todo = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(actor_id.clone()))
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+    // 트랜잭션마다 created_at 이 달라지도록 이력을 따로 커밋한다.
+    for (before, after) in [("a", "b"), ("b", "c"), ("c", "d")] {
+        let mut tx = pool.begin().await.unwrap();
+        repo.insert_tx(
+            &mut tx,
+            NewTodoEvent::new(
+                Id::gen(),
+                todo.id.clone(),
+                actor_id.clone(),
+                title_change(before, after),
+            ),
+        )
+        .await
+        .unwrap();
+        tx.commit().await.unwrap();
+    }
+
+    let first_page = repo.find(&todo.id, None, 2).await.unwrap();
+    let afters: Vec<_> = first_page
+        .iter()
+        .map(|e| e.changes[0].after.clone().unwrap())
+        .collect();
+    assert_eq!(afters, ["d", "c"]);
+
+    let last = first_page.last().unwrap();
+    let cursor = TodoEventCursor::new(last.created_at, last.id.clone());
+    let second_page = repo.find(&todo.id, Some(cursor), 2).await.unwrap();
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page[0].changes[0].after.as_deref(), Some("b"));
+}
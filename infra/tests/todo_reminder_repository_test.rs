@@ -0,0 +1,170 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::db::setup_test_db;
+use common::fixtures::{fixture_new_todo, fixture_new_user};
+use common::module::build_test_module;
+use domain::model::todo::reminder::TodoReminder;
+use domain::model::todo::status::TodoStatusCode;
+use domain::model::todo::{Todo, UpdateTodo};
+use domain::model::user::User;
+use domain::model::{FieldUpdate, Id};
+use infra::repository::todo::reminder::ITodoReminderRepository;
+use infra::repository::todo::ITodoRepository;
+use infra::repository::user::IUserRepository;
+use infra::repository::PgTx;
+use shaku::HasComponent;
+use std::sync::Arc;
+
+/// 알림 시각이 한 시간 지난 todo 를 커밋한다. 다른 트랜잭션에서 잠글 수 있어야 하기 때문이다.
+async fn commit_due_todo(
+    pool: &sqlx::PgPool,
+    user_repo: &Arc<dyn IUserRepository>,
+    todo_repo: &Arc<dyn ITodoRepository>,
+) -> Todo {
+    let mut tx = pool.begin().await.unwrap();
+    let ulid = Id::<User>::gen().value.to_string().to_lowercase();
+    let user = user_repo
+        .insert_tx(&mut tx, fixture_new_user(&ulid[ulid.len() - 12..]))
+        .await
+        .unwrap();
+    let mut new_todo = fixture_new_todo(user.id);
+    new_todo.remind_at = Some(Utc::now() - Duration::hours(1));
+    let todo = todo_repo.insert_tx(&mut tx, new_todo).await.unwrap();
+    tx.commit().await.unwrap();
+    todo
+}
+
+/// 지금 보낼 알림을 잡는다. 잡은 알림은 15분 동안 다시 잡지 않는다.
+async fn claim(repo: &Arc<dyn ITodoReminderRepository>, tx: &mut PgTx) -> Vec<TodoReminder> {
+    repo.claim_due_tx(tx, Utc::now(), Utc::now() + Duration::minutes(15), 5, 1000)
+        .await
+        .unwrap()
+}
+
+fn find<'a>(reminders: &'a [TodoReminder], todo: &Todo) -> Option<&'a TodoReminder> {
+    reminders.iter().find(|r| r.todo_id.value == todo.id.value)
+}
+
+/// 한 트랜잭션이 잡고 있는 알림은 다른 트랜잭션에서 건너뛰고, 잡은 알림은 다시 보낼 시각까지 잡히지 않으며,
+/// 보냈다고 표시하면 다시 잡히지 않고, 알림 시각을 다시 정하면 처음부터 다시 잡히며, 끝난 todo 의 알림은 잡히지 않는다.
+///
+/// 잡은 알림은 같은 DB 를 쓰는 다른 테스트의 조회 결과를 바꾸므로 한 테스트에서 차례로 확인한다.
+#[tokio::test]
+async fn claim_due_skips_claimed_and_delivered_reminders_until_rescheduled() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoReminderRepository> = module.resolve();
+    let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let todo = commit_due_todo(&pool, &user_repo, &todo_repo).await;
+
+    let mut first = pool.begin().await.unwrap();
+    let mut second = pool.begin().await.unwrap();
+    let claimed = claim(&repo, &mut first).await;
+    let reminder = find(&claimed, &todo)
+        .cloned()
+        .expect("due reminder is claimed");
+    assert_eq!(reminder.attempts, 1);
+    assert!(
+        find(&claim(&repo, &mut second).await, &todo).is_none(),
+        "a reminder locked by another transaction must be skipped"
+    );
+    first.commit().await.unwrap();
+    second.rollback().await.unwrap();
+
+    let mut tx = pool.begin().await.unwrap();
+    assert!(
+        find(&claim(&repo, &mut tx).await, &todo).is_none(),
+        "a claimed reminder is not claimed again until its lease ends"
+    );
+    repo.mark_failed_tx(&mut tx, &reminder, Utc::now() - Duration::seconds(1))
+        .await
+        .unwrap();
+    let retried = claim(&repo, &mut tx).await;
+    assert_eq!(
+        find(&retried, &todo).map(|r| r.attempts),
+        Some(2),
+        "a failed reminder is claimed again once its retry time has come"
+    );
+    repo.mark_failed_tx(&mut tx, &reminder, Utc::now() - Duration::seconds(1))
+        .await
+        .unwrap();
+    let exhausted = repo
+        .claim_due_tx(
+            &mut tx,
+            Utc::now(),
+            Utc::now() + Duration::minutes(15),
+            2,
+            1000,
+        )
+        .await
+        .unwrap();
+    assert!(
+        find(&exhausted, &todo).is_none(),
+        "a reminder is not claimed after max attempts"
+    );
+
+    repo.mark_delivered_tx(&mut tx, &reminder, Utc::now())
+        .await
+        .unwrap();
+    repo.mark_failed_tx(&mut tx, &reminder, Utc::now() - Duration::seconds(1))
+        .await
+        .unwrap();
+    assert!(find(&claim(&repo, &mut tx).await, &todo).is_none());
+    let delivered = todo_repo
+        .get_tx(&mut tx, &todo.owner_id, &todo.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        delivered.version, todo.version,
+        "delivery keeps the version"
+    );
+
+    let remind_at = Utc::now() - Duration::minutes(1);
+    let mut update = UpdateTodo::new(todo.id.clone(), todo.owner_id.clone(), None, None, None);
+    update.remind_at = FieldUpdate::Set(remind_at);
+    let updated = todo_repo.update_tx(&mut tx, update).await.unwrap().unwrap();
+    assert_eq!(
+        updated.remind_at.map(|at| at.timestamp_micros()),
+        Some(remind_at.timestamp_micros())
+    );
+    let rescheduled = find(&claim(&repo, &mut tx).await, &todo)
+        .cloned()
+        .expect("rescheduled reminder is claimed");
+    assert_eq!(rescheduled.attempts, 1, "rescheduling starts over");
+    repo.mark_failed_tx(&mut tx, &rescheduled, Utc::now() - Duration::seconds(1))
+        .await
+        .unwrap();
+    repo.mark_delivered_tx(&mut tx, &reminder, Utc::now())
+        .await
+        .unwrap();
+    assert!(
+        find(&claim(&repo, &mut tx).await, &todo).is_some(),
+        "a mark for the previous reminder does not touch the rescheduled one"
+    );
+
+    repo.mark_failed_tx(&mut tx, &rescheduled, Utc::now() - Duration::seconds(1))
+        .await
+        .unwrap();
+    sqlx::query(
+        "UPDATE todos SET status_id = (SELECT id FROM todo_statuses WHERE code = $2) WHERE id = $1",
+    )
+    .bind(todo.id.value.to_string())
+    .bind(TodoStatusCode::Done.as_str())
+    .execute(&mut *tx)
+    .await
+    .unwrap();
+    assert!(
+        find(&claim(&repo, &mut tx).await, &todo).is_none(),
+        "a reminder of a finished todo is not claimed"
+    );
+
+    let mut update = UpdateTodo::new(todo.id.clone(), todo.owner_id.clone(), None, None, None);
+    update.remind_at = FieldUpdate::Clear;
+    let cleared = todo_repo.update_tx(&mut tx, update).await.unwrap().unwrap();
+    assert!(cleared.remind_at.is_none());
+    assert!(find(&claim(&repo, &mut tx).await, &todo).is_none());
+    tx.rollback().await.unwrap();
+}
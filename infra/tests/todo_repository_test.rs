@@ -1,30 +1,58 @@
 mod common;
 
+use chrono::{Duration, Utc};
 use common::db::setup_test_db;
-use common::fixtures::fixture_new_todo;
+use common::fixtures::{fixture_new_todo, fixture_new_user};
 use common::module::build_test_module;
+use domain::model::todo::event::{NewTodoEvent, TodoFieldChange};
+use domain::model::todo::filter::{TodoFilter, TodoSort, TodoSortValue};
+use domain::model::todo::priority::TodoPriority;
+use domain::model::todo::rank::rank_between;
+use domain::model::todo::recurrence::RecurrenceRule;
+use domain::model::todo::stats::{TodoStatsInterval, TodoStatsRange};
 use domain::model::todo::status::TodoStatusCode;
-use domain::model::todo::{NewTodo, Todo, UpdateTodo, UpsertTodo};
-use domain::model::Id;
+use domain::model::todo::{NewTodo, Todo, TodoCursor, UpdateTodo, UpsertTodo};
+use domain::model::user::User;
+use domain::model::{FieldUpdate, Id};
+use infra::repository::todo::event::ITodoEventRepository;
 use infra::repository::todo::status::ITodoStatusRepository;
-use infra::repository::todo::ITodoRepository;
+use infra::repository::todo::{Adjacent, ITodoRepository};
+use infra::repository::user::IUserRepository;
+use infra::repository::PgTx;
 use shaku::HasComponent;
 use std::sync::Arc;
 
+/// todo 소유자로 사용할 사용자를 같은 트랜잭션에 생성한다.
+/// fullname 길이 제한(32자)에 맞도록 ULID 의 뒤 12자만 suffix 로 사용한다.
+async fn insert_owner(repo: &Arc<dyn IUserRepository>, tx: &mut PgTx) -> Id<User> {
+    let ulid = Id::<User>::gen().value.to_string().to_lowercase();
+    let suffix = &ulid[ulid.len() - 12..];
+    repo.insert_tx(tx, fixture_new_user(suffix))
+        .await
+        .unwrap()
+        .id
+}
+
 /// insert → get (id 조회)
 #[tokio::test]
 async fn insert_todo_stores_and_retrieves_by_id() {
     let pool = setup_test_db().await;
     let module = build_test_module(pool.clone());
     let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
     let mut tx = pool.begin().await.unwrap();
 
-    let inserted = repo.insert_tx(&mut tx, fixture_new_todo()).await.unwrap();
-    let found = repo.get_tx(&mut tx, &inserted.id).await.unwrap();
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let inserted = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    let found = repo.get_tx(&mut tx, &owner_id, &inserted.id).await.unwrap();
 
     assert!(found.is_some(), "inserted todo should be retrievable by id");
     let found = found.unwrap();
     assert_eq!(found.id.value, inserted.id.value);
+    assert_eq!(found.owner_id.value, owner_id.value);
     assert_eq!(found.title, "Test Todo Title");
     assert_eq!(found.description, "Test Todo Description");
 
@@ -39,58 +67,658 @@ async fn get_todo_with_nonexistent_id_returns_none() {
     let repo: Arc<dyn ITodoRepository> = module.resolve();
     let mut tx = pool.begin().await.unwrap();
 
+    let owner_id: Id<User> = Id::gen();
     let id: Id<Todo> = Id::gen();
-    let found = repo.get_tx(&mut tx, &id).await.unwrap();
+    let found = repo.get_tx(&mut tx, &owner_id, &id).await.unwrap();
 
     assert!(found.is_none());
     tx.rollback().await.unwrap();
 }
 
-/// 여러 insert 후 find(None) → 전체 목록 포함 확인
+/// 다른 사용자의 todo 조회 → None 반환
+#[tokio::test]
+async fn get_todo_owned_by_other_user_returns_none() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let other_id = insert_owner(&user_repo, &mut tx).await;
+    let inserted = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id))
+        .await
+        .unwrap();
+    let found = repo.get_tx(&mut tx, &other_id, &inserted.id).await.unwrap();
+
+    assert!(found.is_none(), "other user's todo must not be visible");
+    tx.rollback().await.unwrap();
+}
+
+/// 여러 insert 후 find(None) → 소유자의 전체 목록만 포함 확인
 #[tokio::test]
 async fn find_todos_without_filter_returns_all_inserted() {
     let pool = setup_test_db().await;
     let module = build_test_module(pool.clone());
     let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
     let mut tx = pool.begin().await.unwrap();
 
-    repo.insert_tx(&mut tx, fixture_new_todo()).await.unwrap();
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let other_id = insert_owner(&user_repo, &mut tx).await;
+    repo.insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
     repo.insert_tx(
         &mut tx,
         NewTodo::new(
             Id::gen(),
+            owner_id.clone(),
             "Second Todo".to_string(),
             "Second Desc".to_string(),
         ),
     )
     .await
     .unwrap();
+    repo.insert_tx(&mut tx, fixture_new_todo(other_id))
+        .await
+        .unwrap();
+
+    let found = repo
+        .find_tx(
+            &mut tx,
+            &owner_id,
+            &TodoFilter::default(),
+            TodoSort::default(),
+            None,
+            100,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        found.len(),
+        2,
+        "find(None) should return only the owner's 2 todos"
+    );
+    assert!(found.iter().all(|t| t.owner_id.value == owner_id.value));
+    tx.rollback().await.unwrap();
+}
+
+/// find: cursor 이후 항목만 (created_at, id) 순으로 limit 건 반환
+#[tokio::test]
+async fn find_todos_with_cursor_returns_next_page() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    for _ in 0..3 {
+        repo.insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+            .await
+            .unwrap();
+    }
+
+    let first = repo
+        .find_tx(
+            &mut tx,
+            &owner_id,
+            &TodoFilter::default(),
+            TodoSort::default(),
+            None,
+            2,
+        )
+        .await
+        .unwrap();
+    assert_eq!(first.len(), 2);
 
-    let found = repo.find_tx(&mut tx, None).await.unwrap();
+    let last = first.last().unwrap();
+    let cursor = TodoCursor::new(
+        TodoSortValue::Timestamp(last.created_at),
+        Id::new(last.id.value),
+    );
+    let second = repo
+        .find_tx(
+            &mut tx,
+            &owner_id,
+            &TodoFilter::default(),
+            TodoSort::default(),
+            Some(cursor),
+            2,
+        )
+        .await
+        .unwrap();
+    assert_eq!(second.len(), 1);
     assert!(
-        found.len() >= 2,
-        "find(None) should return at least the 2 inserted todos"
+        !first.iter().any(|t| t.id.value == second[0].id.value),
+        "next page must not repeat items"
     );
+    assert!(second[0].id.value > last.id.value);
+    tx.rollback().await.unwrap();
+}
+
+/// find: 상태 필터와 title 내림차순 정렬, cursor 로 다음 페이지 조회
+#[tokio::test]
+async fn find_todos_with_status_filter_and_title_sort() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let status_repo: Arc<dyn ITodoStatusRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let working = status_repo
+        .get_by_code_tx(&mut tx, "working")
+        .await
+        .unwrap();
+    let done = status_repo.get_by_code_tx(&mut tx, "done").await.unwrap();
+    for (title, status) in [
+        ("a", None),
+        ("b", Some(working.clone())),
+        ("c", Some(done.clone())),
+        ("d", Some(working.clone())),
+    ] {
+        let inserted = repo
+            .insert_tx(
+                &mut tx,
+                NewTodo::new(
+                    Id::gen(),
+                    owner_id.clone(),
+                    title.to_string(),
+                    "desc".to_string(),
+                ),
+            )
+            .await
+            .unwrap();
+        if status.is_some() {
+            let update = UpdateTodo::new(inserted.id, owner_id.clone(), None, None, status);
+            repo.update_tx(&mut tx, update).await.unwrap();
+        }
+    }
+
+    let filter = TodoFilter {
+        statuses: vec![working, done],
+        ..Default::default()
+    };
+    let sort = TodoSort::try_from("-title").unwrap();
+    let first = repo
+        .find_tx(&mut tx, &owner_id, &filter, sort, None, 2)
+        .await
+        .unwrap();
+    let titles: Vec<&str> = first.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["d", "c"]);
+
+    let last = first.last().unwrap();
+    let cursor = TodoCursor::new(sort.value_of(last), Id::new(last.id.value));
+    let second = repo
+        .find_tx(&mut tx, &owner_id, &filter, sort, Some(cursor), 2)
+        .await
+        .unwrap();
+    let titles: Vec<&str> = second.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["b"]);
+    tx.rollback().await.unwrap();
+}
+
+/// find: created_after / created_before 범위 밖의 todo 는 제외
+#[tokio::test]
+async fn find_todos_with_created_range_excludes_outside_todos() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let inserted = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+
+    let around = TodoFilter {
+        created_after: Some(inserted.created_at - chrono::Duration::minutes(1)),
+        created_before: Some(inserted.created_at + chrono::Duration::minutes(1)),
+        ..Default::default()
+    };
+    let found = repo
+        .find_tx(&mut tx, &owner_id, &around, TodoSort::default(), None, 10)
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 1);
+
+    let later = TodoFilter {
+        created_after: Some(inserted.created_at + chrono::Duration::minutes(1)),
+        ..Default::default()
+    };
+    let found = repo
+        .find_tx(&mut tx, &owner_id, &later, TodoSort::default(), None, 10)
+        .await
+        .unwrap();
+    assert!(found.is_empty());
+
+    let since = TodoFilter {
+        updated_since: Some(inserted.updated_at + chrono::Duration::minutes(1)),
+        ..Default::default()
+    };
+    let found = repo
+        .find_tx(&mut tx, &owner_id, &since, TodoSort::default(), None, 10)
+        .await
+        .unwrap();
+    assert!(found.is_empty());
+    tx.rollback().await.unwrap();
+}
+
+/// find: 우선순위 정렬은 마감일 없음을 마지막에 두고 cursor 로 이어서 조회한다
+#[tokio::test]
+async fn find_todos_sorted_by_priority_pages_through_due_dates() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let now = chrono::Utc::now();
+    for (title, priority, due_at) in [
+        ("low", TodoPriority::Low, Some(now)),
+        ("high-none", TodoPriority::High, None),
+        (
+            "high-late",
+            TodoPriority::High,
+            Some(now + chrono::Duration::days(2)),
+        ),
+        (
+            "high-early",
+            TodoPriority::High,
+            Some(now + chrono::Duration::days(1)),
+        ),
+    ] {
+        let mut new_todo = NewTodo::new(
+            Id::gen(),
+            owner_id.clone(),
+            title.to_string(),
+            "desc".to_string(),
+        );
+        new_todo.priority = priority;
+        new_todo.due_at = due_at;
+        repo.insert_tx(&mut tx, new_todo).await.unwrap();
+    }
+
+    let sort = TodoSort::try_from("priority").unwrap();
+    let filter = TodoFilter::default();
+    let mut titles = vec![];
+    let mut cursor = None;
+    loop {
+        let page = repo
+            .find_tx(&mut tx, &owner_id, &filter, sort, cursor, 1)
+            .await
+            .unwrap();
+        let Some(last) = page.last() else { break };
+        titles.push(last.title.clone());
+        cursor = Some(TodoCursor::new(sort.value_of(last), Id::new(last.id.value)));
+    }
+    assert_eq!(titles, vec!["high-early", "high-late", "high-none", "low"]);
+    tx.rollback().await.unwrap();
+}
+
+/// find: overdue 는 마감일이 지난 미완료 todo, due_before 는 그 이전 마감 todo 만 포함
+#[tokio::test]
+async fn find_todos_with_due_conditions_filters_by_due_at() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let status_repo: Arc<dyn ITodoStatusRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let done = status_repo.get_by_code_tx(&mut tx, "done").await.unwrap();
+    let now = chrono::Utc::now();
+    for (title, due_at, finished) in [
+        ("late", Some(now - chrono::Duration::days(1)), false),
+        ("late-done", Some(now - chrono::Duration::days(1)), true),
+        ("upcoming", Some(now + chrono::Duration::days(1)), false),
+        ("no-due", None, false),
+    ] {
+        let mut new_todo = NewTodo::new(
+            Id::gen(),
+            owner_id.clone(),
+            title.to_string(),
+            "desc".to_string(),
+        );
+        new_todo.due_at = due_at;
+        let inserted = repo.insert_tx(&mut tx, new_todo).await.unwrap();
+        if finished {
+            let update = UpdateTodo::new(
+                inserted.id,
+                owner_id.clone(),
+                None,
+                None,
+                Some(done.clone()),
+            );
+            repo.update_tx(&mut tx, update).await.unwrap();
+        }
+    }
+
+    let sort = TodoSort::try_from("title").unwrap();
+    let overdue = TodoFilter {
+        overdue: true,
+        ..Default::default()
+    };
+    let found = repo
+        .find_tx(&mut tx, &owner_id, &overdue, sort, None, 10)
+        .await
+        .unwrap();
+    let titles: Vec<&str> = found.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["late"]);
+
+    let due_before = TodoFilter {
+        due_before: Some(now + chrono::Duration::days(2)),
+        ..Default::default()
+    };
+    let found = repo
+        .find_tx(&mut tx, &owner_id, &due_before, sort, None, 10)
+        .await
+        .unwrap();
+    let titles: Vec<&str> = found.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["late", "late-done", "upcoming"]);
     tx.rollback().await.unwrap();
 }
 
+/// search: 소유자의 todo 중 검색어가 포함된 항목만 관련도 순으로 반환
+#[tokio::test]
+async fn search_todos_returns_only_matching_owned_todos() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+
+    // search 는 pool 로 조회하므로 데이터를 커밋한다.
+    let mut tx = pool.begin().await.unwrap();
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let other_id = insert_owner(&user_repo, &mut tx).await;
+    let hit = repo
+        .insert_tx(
+            &mut tx,
+            NewTodo::new(
+                Id::gen(),
+                owner_id.clone(),
+                "장보기 목록".to_string(),
+                "우유와 계란".to_string(),
+            ),
+        )
+        .await
+        .unwrap();
+    repo.insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    repo.insert_tx(
+        &mut tx,
+        NewTodo::new(
+            Id::gen(),
+            other_id,
+            "장보기".to_string(),
+            "다른 사용자".to_string(),
+        ),
+    )
+    .await
+    .unwrap();
+    tx.commit().await.unwrap();
+
+    let found = repo
+        .search(&owner_id, "장보", &TodoFilter::default(), None, 10)
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].todo.id.value, hit.id.value);
+    assert!(found[0].snippet.contains("<mark>장보</mark>"));
+}
+
+/// search: 발췌는 제목과 본문을 HTML 이스케이프한 뒤 강조한다
+#[tokio::test]
+async fn search_snippet_escapes_html_before_highlighting() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+
+    let mut tx = pool.begin().await.unwrap();
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    repo.insert_tx(
+        &mut tx,
+        NewTodo::new(
+            Id::gen(),
+            owner_id.clone(),
+            "milk <img src=x onerror=alert(1)>".to_string(),
+            "\"장보기\" & 'eggs'".to_string(),
+        ),
+    )
+    .await
+    .unwrap();
+    tx.commit().await.unwrap();
+
+    // tsquery 로 찾는 경우(ts_headline)와 ILIKE 로 찾는 경우(정규식) 모두 확인한다.
+    for (q, mark) in [("milk", "<mark>milk</mark>"), ("장보", "<mark>장보</mark>")] {
+        let found = repo
+            .search(&owner_id, q, &TodoFilter::default(), None, 10)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1, "{q}");
+        let snippet = &found[0].snippet;
+        assert!(snippet.contains(mark), "{snippet}");
+        assert!(snippet.contains("&lt;img"), "{snippet}");
+        assert!(
+            !snippet
+                .replace("<mark>", "")
+                .replace("</mark>", "")
+                .contains('<'),
+            "{snippet}"
+        );
+    }
+}
+
 /// update: title 변경
 #[tokio::test]
 async fn update_todo_title_updates_correctly() {
     let pool = setup_test_db().await;
     let module = build_test_module(pool.clone());
     let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
     let mut tx = pool.begin().await.unwrap();
 
-    let inserted = repo.insert_tx(&mut tx, fixture_new_todo()).await.unwrap();
-    let update = UpdateTodo::new(inserted.id, Some("Updated Title".to_string()), None, None);
-    let updated = repo.update_tx(&mut tx, update).await.unwrap();
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let inserted = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    let update = UpdateTodo::new(
+        inserted.id,
+        owner_id,
+        Some("Updated Title".to_string()),
+        None,
+        None,
+    );
+    let updated = repo.update_tx(&mut tx, update).await.unwrap().unwrap();
 
     assert_eq!(updated.title, "Updated Title");
     assert_eq!(updated.description, "Test Todo Description");
     tx.rollback().await.unwrap();
 }
 
+/// update: 마감일과 우선순위를 바꾸고, 지정하지 않은 필드는 유지
+#[tokio::test]
+async fn update_todo_due_at_and_priority_keeps_other_fields() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let inserted = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    assert_eq!(inserted.priority, TodoPriority::Medium);
+    assert!(inserted.due_at.is_none());
+
+    let due_at = chrono::DateTime::from_timestamp(1_900_000_000, 0).unwrap();
+    let mut update = UpdateTodo::new(
+        Id::new(inserted.id.value),
+        owner_id.clone(),
+        None,
+        None,
+        None,
+    );
+    update.due_at = FieldUpdate::Set(due_at);
+    update.priority = Some(TodoPriority::Urgent);
+    let updated = repo.update_tx(&mut tx, update).await.unwrap().unwrap();
+    assert_eq!(updated.due_at, Some(due_at));
+    assert_eq!(updated.priority, TodoPriority::Urgent);
+    assert_eq!(updated.title, inserted.title);
+
+    let update = UpdateTodo::new(
+        Id::new(inserted.id.value),
+        owner_id.clone(),
+        Some("Renamed".to_string()),
+        None,
+        None,
+    );
+    let updated = repo.update_tx(&mut tx, update).await.unwrap().unwrap();
+    assert_eq!(updated.due_at, Some(due_at));
+    assert_eq!(updated.priority, TodoPriority::Urgent);
+
+    let mut update = UpdateTodo::new(inserted.id, owner_id, None, None, None);
+    update.due_at = FieldUpdate::Clear;
+    let updated = repo.update_tx(&mut tx, update).await.unwrap().unwrap();
+    assert!(updated.due_at.is_none());
+    assert_eq!(updated.priority, TodoPriority::Urgent);
+    tx.rollback().await.unwrap();
+}
+
+/// insert / update: 반복 규칙을 저장하고, 다른 필드만 바꾸면 유지하며, 지울 수 있다
+#[tokio::test]
+async fn update_todo_recurrence_keeps_or_clears_rule() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let mut new_todo = fixture_new_todo(owner_id.clone());
+    new_todo.recurrence = Some(RecurrenceRule::try_from("FREQ=DAILY;INTERVAL=2").unwrap());
+    let inserted = repo.insert_tx(&mut tx, new_todo).await.unwrap();
+    assert_eq!(
+        inserted
+            .recurrence
+            .as_ref()
+            .map(|r| r.to_string())
+            .as_deref(),
+        Some("FREQ=DAILY;INTERVAL=2")
+    );
+
+    let update = UpdateTodo::new(
+        Id::new(inserted.id.value),
+        owner_id.clone(),
+        Some("Renamed".to_string()),
+        None,
+        None,
+    );
+    let updated = repo.update_tx(&mut tx, update).await.unwrap().unwrap();
+    assert_eq!(updated.recurrence, inserted.recurrence);
+
+    let mut update = UpdateTodo::new(inserted.id, owner_id, None, None, None);
+    update.recurrence = FieldUpdate::Clear;
+    let updated = repo.update_tx(&mut tx, update).await.unwrap().unwrap();
+    assert!(updated.recurrence.is_none());
+    assert_eq!(updated.title, "Renamed");
+    tx.rollback().await.unwrap();
+}
+
+/// update: 다른 사용자의 todo → None 반환, 원본 유지
+#[tokio::test]
+async fn update_todo_owned_by_other_user_returns_none() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let other_id = insert_owner(&user_repo, &mut tx).await;
+    let inserted = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    let update = UpdateTodo::new(
+        Id::new(inserted.id.value),
+        other_id,
+        Some("Hijacked".to_string()),
+        None,
+        None,
+    );
+    let updated = repo.update_tx(&mut tx, update).await.unwrap();
+    assert!(updated.is_none());
+
+    let found = repo
+        .get_tx(&mut tx, &owner_id, &inserted.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.title, "Test Todo Title");
+    tx.rollback().await.unwrap();
+}
+
+/// update: 기대 버전이 같을 때만 바꾸고 버전을 올림, 다르면 None 반환
+#[tokio::test]
+async fn update_todo_with_expected_version_compares_and_sets() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let inserted = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    assert_eq!(inserted.version, 1);
+
+    let mut update = UpdateTodo::new(
+        Id::new(inserted.id.value),
+        owner_id.clone(),
+        Some("First".to_string()),
+        None,
+        None,
+    );
+    update.expected_version = Some(1);
+    let updated = repo.update_tx(&mut tx, update).await.unwrap().unwrap();
+    assert_eq!(updated.version, 2);
+
+    let mut stale = UpdateTodo::new(
+        Id::new(inserted.id.value),
+        owner_id.clone(),
+        Some("Second".to_string()),
+        None,
+        None,
+    );
+    stale.expected_version = Some(1);
+    assert!(repo.update_tx(&mut tx, stale).await.unwrap().is_none());
+
+    let found = repo
+        .get_tx(&mut tx, &owner_id, &inserted.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.title, "First");
+    assert_eq!(found.version, 2);
+    tx.rollback().await.unwrap();
+}
+
 /// upsert: 새 레코드 insert
 #[tokio::test]
 async fn upsert_todo_inserts_new_record() {
@@ -98,16 +726,19 @@ async fn upsert_todo_inserts_new_record() {
     let module = build_test_module(pool.clone());
     let repo: Arc<dyn ITodoRepository> = module.resolve();
     let status_repo: Arc<dyn ITodoStatusRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
     let mut tx = pool.begin().await.unwrap();
 
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
     let status = status_repo.get_by_code_tx(&mut tx, "new").await.unwrap();
     let upsert = UpsertTodo::new(
         Id::gen(),
+        owner_id,
         "Upserted Title".to_string(),
         "Upserted Desc".to_string(),
         status,
     );
-    let result = repo.upsert_tx(&mut tx, upsert).await.unwrap();
+    let result = repo.upsert_tx(&mut tx, upsert).await.unwrap().unwrap();
 
     assert_eq!(result.title, "Upserted Title");
     assert_eq!(result.status.code, TodoStatusCode::New);
@@ -121,19 +752,25 @@ async fn upsert_todo_updates_existing_record() {
     let module = build_test_module(pool.clone());
     let repo: Arc<dyn ITodoRepository> = module.resolve();
     let status_repo: Arc<dyn ITodoStatusRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
     let mut tx = pool.begin().await.unwrap();
 
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
     let status = status_repo.get_by_code_tx(&mut tx, "new").await.unwrap();
     let first_id: Id<Todo> = Id::gen();
     let first_id_value = first_id.value;
 
     let first = UpsertTodo::new(
         first_id,
+        owner_id.clone(),
         "Original Title".to_string(),
         "Original Desc".to_string(),
         status,
     );
-    repo.upsert_tx(&mut tx, first).await.unwrap();
+    let inserted = repo.upsert_tx(&mut tx, first).await.unwrap().unwrap();
+    repo.insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
 
     let status2 = status_repo
         .get_by_code_tx(&mut tx, "working")
@@ -141,15 +778,57 @@ async fn upsert_todo_updates_existing_record() {
         .unwrap();
     let second = UpsertTodo::new(
         Id::new(first_id_value),
+        owner_id,
         "Updated Title".to_string(),
         "Updated Desc".to_string(),
         status2,
     );
-    let result = repo.upsert_tx(&mut tx, second).await.unwrap();
+    let result = repo.upsert_tx(&mut tx, second).await.unwrap().unwrap();
 
     assert_eq!(result.id.value, first_id_value);
     assert_eq!(result.title, "Updated Title");
     assert_eq!(result.status.code, TodoStatusCode::Working);
+    assert_eq!(
+        result.position, inserted.position,
+        "upsert keeps the position of an existing todo"
+    );
+    tx.rollback().await.unwrap();
+}
+
+/// upsert: 다른 사용자의 todo id → None 반환, 원본 유지
+#[tokio::test]
+async fn upsert_todo_owned_by_other_user_returns_none() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let status_repo: Arc<dyn ITodoStatusRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let other_id = insert_owner(&user_repo, &mut tx).await;
+    let inserted = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    let status = status_repo.get_by_code_tx(&mut tx, "done").await.unwrap();
+    let upsert = UpsertTodo::new(
+        Id::new(inserted.id.value),
+        other_id,
+        "Hijacked".to_string(),
+        "Hijacked".to_string(),
+        status,
+    );
+    let result = repo.upsert_tx(&mut tx, upsert).await.unwrap();
+    assert!(result.is_none());
+
+    let found = repo
+        .get_tx(&mut tx, &owner_id, &inserted.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.title, "Test Todo Title");
+    assert_eq!(found.status.code, TodoStatusCode::New);
     tx.rollback().await.unwrap();
 }
 
@@ -159,16 +838,24 @@ async fn delete_todo_removes_and_returns_deleted_todo() {
     let pool = setup_test_db().await;
     let module = build_test_module(pool.clone());
     let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
     let mut tx = pool.begin().await.unwrap();
 
-    let inserted = repo.insert_tx(&mut tx, fixture_new_todo()).await.unwrap();
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let inserted = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
     let id_value = inserted.id.value;
 
-    let deleted = repo.delete_tx(&mut tx, &inserted.id).await.unwrap();
+    let deleted = repo
+        .delete_tx(&mut tx, &owner_id, &inserted.id, None)
+        .await
+        .unwrap();
     assert!(deleted.is_some(), "delete should return the removed todo");
     assert_eq!(deleted.unwrap().id.value, id_value);
 
-    let after = repo.get_tx(&mut tx, &inserted.id).await.unwrap();
+    let after = repo.get_tx(&mut tx, &owner_id, &inserted.id).await.unwrap();
     assert!(after.is_none(), "todo should not exist after deletion");
     tx.rollback().await.unwrap();
 }
@@ -181,9 +868,509 @@ async fn delete_nonexistent_todo_returns_none() {
     let repo: Arc<dyn ITodoRepository> = module.resolve();
     let mut tx = pool.begin().await.unwrap();
 
+    let owner_id: Id<User> = Id::gen();
     let id: Id<Todo> = Id::gen();
-    let result = repo.delete_tx(&mut tx, &id).await.unwrap();
+    let result = repo.delete_tx(&mut tx, &owner_id, &id, None).await.unwrap();
+
+    assert!(result.is_none());
+    tx.rollback().await.unwrap();
+}
+
+/// delete: 다른 사용자의 todo → None 반환, 원본 유지
+#[tokio::test]
+async fn delete_todo_owned_by_other_user_returns_none() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let other_id = insert_owner(&user_repo, &mut tx).await;
+    let inserted = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
 
+    let result = repo
+        .delete_tx(&mut tx, &other_id, &inserted.id, None)
+        .await
+        .unwrap();
     assert!(result.is_none());
+
+    let after = repo.get_tx(&mut tx, &owner_id, &inserted.id).await.unwrap();
+    assert!(
+        after.is_some(),
+        "owner's todo must survive other user's delete"
+    );
+    tx.rollback().await.unwrap();
+}
+
+/// soft delete → deleted 상태로 목록에서 빠지고, restore → 직전 상태로 복귀
+#[tokio::test]
+async fn soft_delete_then_restore_returns_to_previous_status() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let status_repo: Arc<dyn ITodoStatusRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let inserted = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    let working = status_repo
+        .get_by_code_tx(&mut tx, "working")
+        .await
+        .unwrap();
+    let deleted = status_repo
+        .get_by_code_tx(&mut tx, "deleted")
+        .await
+        .unwrap();
+    let new = status_repo.get_by_code_tx(&mut tx, "new").await.unwrap();
+    repo.update_tx(
+        &mut tx,
+        UpdateTodo::new(
+            Id::new(inserted.id.value),
+            owner_id.clone(),
+            None,
+            None,
+            Some(working),
+        ),
+    )
+    .await
+    .unwrap();
+
+    let trashed = repo
+        .soft_delete_tx(&mut tx, &owner_id, &inserted.id, &deleted, None)
+        .await
+        .unwrap()
+        .expect("soft delete should return the todo");
+    assert_eq!(trashed.status.code, TodoStatusCode::Deleted);
+    assert!(trashed.deleted_at.is_some());
+
+    let listed = repo
+        .find_tx(
+            &mut tx,
+            &owner_id,
+            &TodoFilter::default(),
+            TodoSort::default(),
+            None,
+            100,
+        )
+        .await
+        .unwrap();
+    assert!(
+        listed.is_empty(),
+        "trashed todo should be hidden by default"
+    );
+
+    let restored = repo
+        .restore_tx(&mut tx, &owner_id, &inserted.id, &new)
+        .await
+        .unwrap()
+        .expect("restore should return the todo");
+    assert_eq!(restored.status.code, TodoStatusCode::Working);
+    assert!(restored.deleted_at.is_none());
+    tx.rollback().await.unwrap();
+}
+
+/// purge: 기준 시각 이전에 휴지통으로 옮긴 todo 만 영구 삭제
+#[tokio::test]
+async fn purge_deleted_removes_only_trashed_todos_before_cutoff() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let status_repo: Arc<dyn ITodoStatusRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let trashed = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    let kept = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    let deleted = status_repo
+        .get_by_code_tx(&mut tx, "deleted")
+        .await
+        .unwrap();
+    repo.soft_delete_tx(&mut tx, &owner_id, &trashed.id, &deleted, None)
+        .await
+        .unwrap();
+
+    let before_delete = trashed.created_at - chrono::Duration::seconds(1);
+    let purged = repo
+        .purge_deleted_tx(&mut tx, &owner_id, before_delete)
+        .await
+        .unwrap();
+    assert_eq!(purged, 0);
+
+    let after_delete = chrono::Utc::now() + chrono::Duration::seconds(1);
+    let purged = repo
+        .purge_deleted_tx(&mut tx, &owner_id, after_delete)
+        .await
+        .unwrap();
+    assert_eq!(purged, 1);
+    assert!(repo
+        .get_tx(&mut tx, &owner_id, &trashed.id)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .get_tx(&mut tx, &owner_id, &kept.id)
+        .await
+        .unwrap()
+        .is_some());
+    tx.rollback().await.unwrap();
+}
+
+/// `parent` 아래에 하위 작업을 만든다.
+async fn insert_child(
+    repo: &Arc<dyn ITodoRepository>,
+    tx: &mut PgTx,
+    owner_id: &Id<User>,
+    parent: &Todo,
+) -> Todo {
+    let mut new_todo = fixture_new_todo(owner_id.clone());
+    new_todo.parent_id = Some(parent.id.clone());
+    repo.insert_tx(tx, new_todo).await.unwrap()
+}
+
+/// find_subtree: 뿌리부터 깊이 순으로 모든 하위 작업, parent_id 필터는 바로 아래만
+#[tokio::test]
+async fn find_subtree_returns_root_and_all_descendants() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let root = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    let child = insert_child(&repo, &mut tx, &owner_id, &root).await;
+    let grandchild = insert_child(&repo, &mut tx, &owner_id, &child).await;
+    let other_root = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    insert_child(&repo, &mut tx, &owner_id, &other_root).await;
+    assert_eq!(
+        grandchild.parent_id.as_ref().map(|p| p.value),
+        Some(child.id.value)
+    );
+
+    let subtree = repo
+        .find_subtree_tx(&mut tx, &owner_id, &root.id)
+        .await
+        .unwrap();
+    let ids: Vec<_> = subtree.iter().map(|t| t.id.value).collect();
+    assert_eq!(ids, [root.id.value, child.id.value, grandchild.id.value]);
+
+    let filter = TodoFilter {
+        parent_id: Some(root.id.clone()),
+        ..TodoFilter::default()
+    };
+    let children = repo
+        .find_tx(&mut tx, &owner_id, &filter, TodoSort::default(), None, 10)
+        .await
+        .unwrap();
+    let ids: Vec<_> = children.iter().map(|t| t.id.value).collect();
+    assert_eq!(ids, [child.id.value]);
+
+    // 다른 사용자에게는 보이지 않는다.
+    let stranger_id = insert_owner(&user_repo, &mut tx).await;
+    assert!(repo
+        .find_subtree_tx(&mut tx, &stranger_id, &root.id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // 영구 삭제하면 하위 작업도 함께 삭제된다.
+    repo.delete_tx(&mut tx, &owner_id, &root.id, None)
+        .await
+        .unwrap();
+    assert!(repo
+        .get_tx(&mut tx, &owner_id, &grandchild.id)
+        .await
+        .unwrap()
+        .is_none());
+    tx.rollback().await.unwrap();
+}
+
+/// set_parent: 다른 todo 아래로 옮기고, None 이면 최상위로
+#[tokio::test]
+async fn set_parent_moves_todo_and_clears_parent() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let parent = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    let todo = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+
+    let moved = repo
+        .set_parent_tx(&mut tx, &owner_id, &todo.id, Some(&parent.id), Some(1))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(moved.parent_id.map(|p| p.value), Some(parent.id.value));
+    assert_eq!(moved.version, 2, "moving bumps the version");
+
+    // 지난 버전으로는 옮기지 않는다.
+    let stale = repo
+        .set_parent_tx(&mut tx, &owner_id, &todo.id, None, Some(1))
+        .await
+        .unwrap();
+    assert!(stale.is_none());
+
+    let moved = repo
+        .set_parent_tx(&mut tx, &owner_id, &todo.id, None, None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(moved.parent_id.is_none());
+    assert_eq!(moved.version, 3);
+    tx.rollback().await.unwrap();
+}
+
+/// count_unfinished_children: 완료·중단·휴지통의 하위 작업은 세지 않는다
+#[tokio::test]
+async fn count_unfinished_children_skips_finished_and_trashed() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let status_repo: Arc<dyn ITodoStatusRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let parent = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    let done_child = insert_child(&repo, &mut tx, &owner_id, &parent).await;
+    let trashed_child = insert_child(&repo, &mut tx, &owner_id, &parent).await;
+    let open_child = insert_child(&repo, &mut tx, &owner_id, &parent).await;
+    assert_eq!(
+        repo.count_unfinished_children_tx(&mut tx, &owner_id, &parent.id)
+            .await
+            .unwrap(),
+        3
+    );
+
+    let done = status_repo.get_by_code_tx(&mut tx, "done").await.unwrap();
+    let update = UpdateTodo::new(done_child.id, owner_id.clone(), None, None, Some(done));
+    repo.update_tx(&mut tx, update).await.unwrap();
+    let deleted = status_repo
+        .get_by_code_tx(&mut tx, "deleted")
+        .await
+        .unwrap();
+    repo.soft_delete_tx(&mut tx, &owner_id, &trashed_child.id, &deleted, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        repo.count_unfinished_children_tx(&mut tx, &owner_id, &parent.id)
+            .await
+            .unwrap(),
+        1
+    );
+
+    repo.delete_tx(&mut tx, &owner_id, &open_child.id, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        repo.count_unfinished_children_tx(&mut tx, &owner_id, &parent.id)
+            .await
+            .unwrap(),
+        0
+    );
+    tx.rollback().await.unwrap();
+}
+
+/// stats: 상태별 건수는 모든 상태를 담고, 추이·평균 완료 시간·지연 건수를 SQL 로 집계
+#[tokio::test]
+async fn get_stats_aggregates_counts_trend_and_overdue() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let status_repo: Arc<dyn ITodoStatusRepository> = module.resolve();
+    let event_repo: Arc<dyn ITodoEventRepository> = module.resolve();
+
+    // get_stats 는 pool 로 조회하므로 데이터를 커밋한다.
+    let mut tx = pool.begin().await.unwrap();
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let now = Utc::now();
+    let mut overdue = fixture_new_todo(owner_id.clone());
+    overdue.due_at = Some(now - Duration::days(1));
+    repo.insert_tx(&mut tx, overdue).await.unwrap();
+    let mut trashed = fixture_new_todo(owner_id.clone());
+    trashed.due_at = Some(now - Duration::days(1));
+    let trashed = repo.insert_tx(&mut tx, trashed).await.unwrap();
+    let deleted = status_repo
+        .get_by_code_tx(&mut tx, "deleted")
+        .await
+        .unwrap();
+    repo.soft_delete_tx(&mut tx, &owner_id, &trashed.id, &deleted, None)
+        .await
+        .unwrap();
+    let finished = repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    let done = status_repo.get_by_code_tx(&mut tx, "done").await.unwrap();
+    repo.update_tx(
+        &mut tx,
+        UpdateTodo::new(
+            Id::new(finished.id.value),
+            owner_id.clone(),
+            None,
+            None,
+            Some(done),
+        ),
+    )
+    .await
+    .unwrap();
+    event_repo
+        .insert_tx(
+            &mut tx,
+            NewTodoEvent::new(
+                Id::gen(),
+                Id::new(finished.id.value),
+                owner_id.clone(),
+                vec![TodoFieldChange::new(
+                    "status",
+                    Some("new".to_string()),
+                    Some("done".to_string()),
+                )],
+            ),
+        )
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    let range = TodoStatsRange::new(
+        now - Duration::days(1),
+        now + Duration::days(1),
+        TodoStatsInterval::Day,
+    );
+    let stats = repo.get_stats(&owner_id, &range, Utc::now()).await.unwrap();
+
+    let counts: Vec<(TodoStatusCode, i64)> = stats
+        .status_counts
+        .into_iter()
+        .map(|c| (c.code, c.count))
+        .collect();
+    // 기본 상태가 먼저 나오고, 다른 테스트가 추가한 사용자 정의 상태가 뒤에 올 수 있다.
+    assert!(counts.len() >= TodoStatusCode::ALL.len());
+    assert_eq!(counts[0], (TodoStatusCode::New, 1));
+    assert_eq!(counts[3], (TodoStatusCode::Done, 1));
+    assert_eq!(counts[6], (TodoStatusCode::Deleted, 1));
+
+    assert_eq!(stats.buckets.len(), 3);
+    assert_eq!(stats.buckets.iter().map(|b| b.created).sum::<i64>(), 3);
+    assert_eq!(stats.buckets.iter().map(|b| b.completed).sum::<i64>(), 1);
+    assert!(stats.average_completion_seconds.unwrap() >= 0.0);
+    assert_eq!(stats.overdue, 1);
+
+    let later = TodoStatsRange::new(
+        now + Duration::days(7),
+        now + Duration::days(21),
+        TodoStatsInterval::Week,
+    );
+    let stats = repo.get_stats(&owner_id, &later, now).await.unwrap();
+    assert!(stats.buckets.iter().all(|b| b.created == 0));
+    assert!(stats.average_completion_seconds.is_none());
+}
+
+/// 새 todo 는 맨 뒤에 오고, 순위를 바꾸면 position 정렬과 cursor 가 그 순서를 따르며, 다시 배치해도 순서는 그대로이다.
+#[tokio::test]
+async fn positions_append_move_and_rebalance_keep_manual_order() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_owner(&user_repo, &mut tx).await;
+    let mut todos = vec![];
+    for title in ["a", "b", "c"] {
+        let todo = NewTodo::new(
+            Id::gen(),
+            owner_id.clone(),
+            title.to_string(),
+            "desc".to_string(),
+        );
+        todos.push(repo.insert_tx(&mut tx, todo).await.unwrap());
+    }
+    assert!(todos[0].position < todos[1].position && todos[1].position < todos[2].position);
+
+    // c 를 a 와 b 사이로 옮긴다.
+    let next = repo
+        .get_adjacent_position_tx(
+            &mut tx,
+            &owner_id,
+            &todos[0].position,
+            Adjacent::After,
+            &todos[2].id,
+        )
+        .await
+        .unwrap();
+    assert_eq!(next.as_deref(), Some(todos[1].position.as_str()));
+    let position = rank_between(Some(&todos[0].position), next.as_deref()).unwrap();
+    let moved = repo
+        .set_position_tx(&mut tx, &owner_id, &todos[2].id, &position)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(moved.position, position);
+    assert_eq!(moved.version, todos[2].version + 1);
+
+    let sort = TodoSort::try_from("position").unwrap();
+    let filter = TodoFilter::default();
+    let first = repo
+        .find_tx(&mut tx, &owner_id, &filter, sort, None, 2)
+        .await
+        .unwrap();
+    let titles: Vec<&str> = first.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["a", "c"]);
+    let last = first.last().unwrap();
+    let cursor = TodoCursor::new(sort.value_of(last), Id::new(last.id.value));
+    let second = repo
+        .find_tx(&mut tx, &owner_id, &filter, sort, Some(cursor), 2)
+        .await
+        .unwrap();
+    assert_eq!(second[0].title, "b");
+
+    assert_eq!(
+        repo.rebalance_positions_tx(&mut tx, &owner_id)
+            .await
+            .unwrap(),
+        3
+    );
+    let sort = TodoSort::try_from("-position").unwrap();
+    let found = repo
+        .find_tx(&mut tx, &owner_id, &filter, sort, None, 10)
+        .await
+        .unwrap();
+    let titles: Vec<&str> = found.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["b", "c", "a"]);
     tx.rollback().await.unwrap();
 }
@@ -0,0 +1,128 @@
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::{fixture_new_todo, fixture_new_user};
+use common::module::build_test_module;
+use domain::model::todo::share::{NewTodoShare, TodoSharePermission};
+use domain::model::user::User;
+use domain::model::Id;
+use infra::repository::todo::share::ITodoShareRepository;
+use infra::repository::todo::ITodoRepository;
+use infra::repository::user::IUserRepository;
+use infra::repository::PgTx;
+use shaku::HasComponent;
+use std::sync::Arc;
+
+/// 테스트 사용자를 같은 트랜잭션에 생성한다.
+/// fullname 길이 제한(32자)에 맞도록 ULID 의 뒤 12자만 suffix 로 사용한다.
+async fn insert_user(repo: &Arc<dyn IUserRepository>, tx: &mut PgTx) -> Id<User> {
+    let ulid = Id::<User>::gen().value.to_string().to_lowercase();
+    let suffix = &ulid[ulid.len() - 12..];
+    repo.insert_tx(tx, fixture_new_user(suffix))
+        .await
+        .unwrap()
+        .id
+}
+
+/// 공유하면 받은 사용자 입장에서 소유자와 권한이 보이고, 다시 공유하면 권한만 바뀐다
+#[tokio::test]
+async fn upsert_share_grants_then_changes_permission() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoShareRepository> = module.resolve();
+    let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_user(&user_repo, &mut tx).await;
+    let guest_id = insert_user(&user_repo, &mut tx).await;
+    let todo = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+
+    let shared = repo
+        .upsert_tx(
+            &mut tx,
+            NewTodoShare::new(todo.id.clone(), guest_id.clone(), TodoSharePermission::Read),
+        )
+        .await
+        .unwrap();
+    assert_eq!(shared.permission, TodoSharePermission::Read);
+    assert!(shared.username.starts_with("testuser_"));
+
+    let grant = repo
+        .get_grant_tx(&mut tx, &guest_id, &todo.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(grant.owner_id.value, owner_id.value);
+    assert_eq!(grant.permission, TodoSharePermission::Read);
+    // 소유자 자신에게는 공유가 없다.
+    assert!(repo
+        .get_grant_tx(&mut tx, &owner_id, &todo.id)
+        .await
+        .unwrap()
+        .is_none());
+
+    let changed = repo
+        .upsert_tx(
+            &mut tx,
+            NewTodoShare::new(
+                todo.id.clone(),
+                guest_id.clone(),
+                TodoSharePermission::Write,
+            ),
+        )
+        .await
+        .unwrap();
+    assert_eq!(changed.permission, TodoSharePermission::Write);
+    assert_eq!(changed.created_at, shared.created_at);
+    tx.rollback().await.unwrap();
+}
+
+/// 공유를 삭제하면 삭제한 공유를 반환하고, 없는 공유는 `None` 이다
+#[tokio::test]
+async fn delete_share_returns_removed_share_once() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoShareRepository> = module.resolve();
+    let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_repo: Arc<dyn IUserRepository> = module.resolve();
+    let mut tx = pool.begin().await.unwrap();
+
+    let owner_id = insert_user(&user_repo, &mut tx).await;
+    let guest_id = insert_user(&user_repo, &mut tx).await;
+    let todo = todo_repo
+        .insert_tx(&mut tx, fixture_new_todo(owner_id.clone()))
+        .await
+        .unwrap();
+    repo.upsert_tx(
+        &mut tx,
+        NewTodoShare::new(
+            todo.id.clone(),
+            guest_id.clone(),
+            TodoSharePermission::Write,
+        ),
+    )
+    .await
+    .unwrap();
+
+    let removed = repo
+        .delete_tx(&mut tx, &todo.id, &guest_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(removed.user_id.value, guest_id.value);
+    assert!(repo
+        .delete_tx(&mut tx, &todo.id, &guest_id)
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .get_grant_tx(&mut tx, &guest_id, &todo.id)
+        .await
+        .unwrap()
+        .is_none());
+    tx.rollback().await.unwrap();
+}
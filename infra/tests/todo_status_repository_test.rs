@@ -1,8 +1,10 @@
 mod common;
 
+use chrono::Utc;
 use common::db::setup_test_db;
 use common::module::build_test_module;
-use domain::model::todo::status::TodoStatusCode;
+use domain::model::todo::status::{NewTodoStatus, TodoStatusCode};
+use domain::model::Id;
 use infra::repository::todo::status::ITodoStatusRepository;
 use shaku::HasComponent;
 use std::sync::Arc;
@@ -33,3 +35,88 @@ async fn get_by_code_with_invalid_code_returns_error() {
 
     assert!(result.is_err(), "invalid code should return an error");
 }
+
+/// 등록된 모든 상태 반환 (다른 테스트가 추가한 사용자 정의 상태가 섞일 수 있다)
+#[tokio::test]
+async fn find_all_returns_every_seeded_status() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool);
+    let repo: Arc<dyn ITodoStatusRepository> = module.resolve();
+
+    let statuses = repo.find_all().await.unwrap();
+
+    assert!(statuses.len() >= TodoStatusCode::ALL.len());
+    for code in TodoStatusCode::ALL {
+        assert!(
+            statuses.iter().any(|s| s.code == code),
+            "status {} should be seeded",
+            code.as_str()
+        );
+    }
+}
+
+/// 사용자 정의 상태를 추가·이름 변경·끄기 하면 목록과 선택 가능 여부에 반영된다
+#[tokio::test]
+async fn custom_status_can_be_added_renamed_and_disabled() {
+    let pool = setup_test_db().await;
+    let module = build_test_module(pool.clone());
+    let repo: Arc<dyn ITodoStatusRepository> = module.resolve();
+    let code = TodoStatusCode::custom("infra_review").unwrap();
+
+    let mut tx = pool.begin().await.unwrap();
+    let entry = repo
+        .insert_tx(
+            &mut tx,
+            NewTodoStatus::new(Id::gen(), code.clone(), "검토".to_string()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(entry.status.code, code);
+    assert!(entry.disabled_at.is_none());
+
+    let renamed = repo
+        .rename_tx(&mut tx, "infra_review", "리뷰")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(renamed.status.name, "리뷰");
+    assert!(repo
+        .rename_tx(&mut tx, "nonexistent_code_xyz", "없음")
+        .await
+        .unwrap()
+        .is_none());
+
+    let disabled_at = Utc::now();
+    let disabled = repo
+        .set_disabled_tx(&mut tx, "infra_review", Some(disabled_at))
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(disabled.disabled_at.is_some());
+    assert!(repo
+        .get_enabled_by_code_tx(&mut tx, "infra_review")
+        .await
+        .unwrap()
+        .is_none());
+    assert!(repo
+        .get_enabled_by_code_tx(&mut tx, "new")
+        .await
+        .unwrap()
+        .is_some());
+    tx.commit().await.unwrap();
+
+    let entries = repo.find_entries().await.unwrap();
+    let builtin: Vec<TodoStatusCode> = entries
+        .iter()
+        .take(TodoStatusCode::ALL.len())
+        .map(|e| e.status.code.clone())
+        .collect();
+    assert_eq!(
+        builtin,
+        TodoStatusCode::ALL,
+        "기본 상태가 먼저 정해진 순서로 나와야 한다"
+    );
+    assert!(entries
+        .iter()
+        .any(|e| e.status.code == code && e.disabled_at.is_some()));
+}
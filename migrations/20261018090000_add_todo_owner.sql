@@ -0,0 +1,9 @@
+-- Todo 소유자
+-- 기존 데이터는 소유자를 알 수 없으므로 NULL 을 허용한다.
+-- 소유자가 없는 todo 는 어떤 사용자의 조회 범위에도 들어가지 않는다.
+alter table todos add column if not exists owner_id varchar(26);
+
+alter table todos
+    add constraint fk_todos_owner_id_users_id foreign key (owner_id) references users (id) on delete cascade;
+
+create index if not exists idx_todos_owner_id_created_at on todos (owner_id, created_at);
@@ -0,0 +1,4 @@
+-- 목록 keyset 페이지네이션 (owner_id, created_at, id) 인덱스
+drop index if exists idx_todos_owner_id_created_at;
+
+create index if not exists idx_todos_owner_id_created_at_id on todos (owner_id, created_at, id);
@@ -0,0 +1,11 @@
+-- Todo 전문 검색
+-- 형태소 분석기가 없는 한국어도 검색되도록 'simple' 설정의 tsvector 와 trigram 인덱스를 함께 둔다.
+-- 검색 쿼리는 아래 인덱스와 같은 표현식을 사용해야 인덱스를 탄다.
+create extension if not exists pg_trgm;
+
+create index if not exists idx_todos_search_vector on todos using gin (
+    (setweight(to_tsvector('simple', title), 'A') || setweight(to_tsvector('simple', description), 'B'))
+);
+
+create index if not exists idx_todos_title_trgm on todos using gin (title gin_trgm_ops);
+create index if not exists idx_todos_description_trgm on todos using gin (description gin_trgm_ops);
@@ -0,0 +1,14 @@
+-- Todo 휴지통 (soft delete)
+-- deleted 상태의 todo 는 삭제 시각과 삭제 직전 상태를 기록하여 복원할 수 있게 한다.
+alter table todos add column if not exists deleted_at timestamp with time zone;
+alter table todos add column if not exists previous_status_id varchar(26);
+
+alter table todos
+    add constraint fk_todos_previous_status_id_todo_statuses_id foreign key (previous_status_id) references todo_statuses (id);
+
+-- 이미 deleted 상태인 todo 는 마지막 수정 시각을 삭제 시각으로 본다.
+update todos set deleted_at = updated_at
+where status_id = '01JESRMCSRT1N81P95JG46N0K0' and deleted_at is null;
+
+-- 보존 기간이 지난 휴지통 항목 정리용
+create index if not exists idx_todos_owner_id_deleted_at on todos (owner_id, deleted_at) where deleted_at is not null;
@@ -0,0 +1,10 @@
+-- Todo 마감일과 우선순위
+-- 우선순위는 0(low) ~ 3(urgent) 이며 클수록 급하다.
+alter table todos add column if not exists due_at timestamp with time zone;
+alter table todos add column if not exists priority smallint not null default 1;
+
+alter table todos
+    add constraint ck_todos_priority check (priority between 0 and 3);
+
+-- 마감 임박·지연 조회용
+create index if not exists idx_todos_owner_id_due_at on todos (owner_id, due_at) where due_at is not null;
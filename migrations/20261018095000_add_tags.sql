@@ -0,0 +1,24 @@
+-- 태그
+-- 사용자별로 관리하며 이름은 사용자 안에서 유일하다.
+create table if not exists tags (
+    id varchar(26) not null,
+    owner_id varchar(26) not null,
+    name varchar(32) not null,
+    created_at timestamp with time zone not null default current_timestamp,
+    constraint pk_tags_id primary key (id),
+    constraint fk_tags_owner_id_users_id foreign key (owner_id) references users (id) on delete cascade,
+    constraint uq_tags_owner_id_name unique (owner_id, name)
+);
+
+-- Todo 와 태그의 다대다 관계
+-- todo 나 태그가 삭제되면 연결도 함께 삭제한다.
+create table if not exists todo_tags (
+    todo_id varchar(26) not null,
+    tag_id varchar(26) not null,
+    constraint pk_todo_tags primary key (todo_id, tag_id),
+    constraint fk_todo_tags_todo_id_todos_id foreign key (todo_id) references todos (id) on delete cascade,
+    constraint fk_todo_tags_tag_id_tags_id foreign key (tag_id) references tags (id) on delete cascade
+);
+
+-- 태그로 todo 를 찾는 조회용
+create index if not exists idx_todo_tags_tag_id on todo_tags (tag_id);
@@ -0,0 +1,9 @@
+-- 하위 작업
+-- todo 는 같은 사용자의 다른 todo 를 부모로 가질 수 있다. 부모가 영구 삭제되면 하위 작업도 함께 삭제한다.
+alter table todos add column if not exists parent_id varchar(26);
+
+alter table todos
+    add constraint fk_todos_parent_id_todos_id foreign key (parent_id) references todos (id) on delete cascade;
+
+-- 하위 작업 조회용
+create index if not exists idx_todos_parent_id on todos (parent_id) where parent_id is not null;
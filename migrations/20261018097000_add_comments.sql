@@ -0,0 +1,16 @@
+-- Todo 댓글
+-- todo 나 작성자가 삭제되면 댓글도 함께 삭제한다.
+create table if not exists comments (
+    id varchar(26) not null,
+    todo_id varchar(26) not null,
+    author_id varchar(26) not null,
+    body text not null,
+    created_at timestamp with time zone not null default current_timestamp,
+    updated_at timestamp with time zone not null default current_timestamp,
+    constraint pk_comments_id primary key (id),
+    constraint fk_comments_todo_id_todos_id foreign key (todo_id) references todos (id) on delete cascade,
+    constraint fk_comments_author_id_users_id foreign key (author_id) references users (id) on delete cascade
+);
+
+-- todo 별 댓글 목록 (created_at, id) keyset 페이지 조회용
+create index if not exists idx_comments_todo_id_created_at_id on comments (todo_id, created_at, id);
@@ -0,0 +1,19 @@
+-- Todo 첨부 파일 메타데이터
+-- 파일 본문은 blob 저장소에 두고 storage_key 로 찾는다.
+-- todo 나 업로드한 사용자가 삭제되면 메타데이터도 함께 삭제한다.
+create table if not exists attachments (
+    id varchar(26) not null,
+    todo_id varchar(26) not null,
+    uploader_id varchar(26) not null,
+    file_name varchar(255) not null,
+    content_type varchar(255) not null,
+    size_bytes bigint not null,
+    storage_key varchar(255) not null,
+    created_at timestamp with time zone not null default current_timestamp,
+    constraint pk_attachments_id primary key (id),
+    constraint fk_attachments_todo_id_todos_id foreign key (todo_id) references todos (id) on delete cascade,
+    constraint fk_attachments_uploader_id_users_id foreign key (uploader_id) references users (id) on delete cascade
+);
+
+-- todo 별 첨부 파일 목록 조회용
+create index if not exists idx_attachments_todo_id_created_at on attachments (todo_id, created_at);
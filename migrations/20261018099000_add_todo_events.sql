@@ -0,0 +1,16 @@
+-- Todo 변경 이력
+-- 수정 한 번마다 한 행을 남기며, changes 에는 바뀐 필드별 이전·이후 값을 담는다.
+-- 예: [{"field": "title", "before": "old", "after": "new"}]
+create table if not exists todo_events (
+    id varchar(26) not null,
+    todo_id varchar(26) not null,
+    actor_id varchar(26) not null,
+    changes jsonb not null,
+    created_at timestamp with time zone not null default current_timestamp,
+    constraint pk_todo_events_id primary key (id),
+    constraint fk_todo_events_todo_id_todos_id foreign key (todo_id) references todos (id) on delete cascade,
+    constraint fk_todo_events_actor_id_users_id foreign key (actor_id) references users (id) on delete cascade
+);
+
+-- todo 별 이력 최신순 keyset 페이지 조회용
+create index if not exists idx_todo_events_todo_id_created_at_id on todo_events (todo_id, created_at desc, id desc);
@@ -0,0 +1,3 @@
+-- Todo 낙관적 동시성 제어용 버전
+-- 내용이 바뀔 때마다 1 씩 올리며, ETag / If-Match 로 오래된 수정 요청을 거른다.
+alter table todos add column if not exists version bigint not null default 1;
@@ -0,0 +1,3 @@
+-- 반복 todo
+-- iCalendar RRULE 문자열을 저장한다. 완료하면 다음 회차 todo 를 만들고 규칙은 새 todo 로 옮긴다.
+alter table todos add column if not exists recurrence varchar(255);
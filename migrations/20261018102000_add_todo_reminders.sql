@@ -0,0 +1,9 @@
+-- Todo 알림
+-- remind_at 이 지나고 reminded_at 이 비어 있는 todo 를 백그라운드 작업이 보낸다.
+-- remind_at 을 다시 정하면 reminded_at 을 비워 새로 보낸다.
+alter table todos add column if not exists remind_at timestamp with time zone;
+alter table todos add column if not exists reminded_at timestamp with time zone;
+
+-- 보낼 알림 조회용
+create index if not exists idx_todos_pending_reminder on todos (remind_at)
+    where remind_at is not null and reminded_at is null;
@@ -0,0 +1,24 @@
+-- 프로젝트(목록)
+-- todo 를 묶는 단위로 사용자별로 관리하며 이름은 사용자 안에서 유일하다.
+create table if not exists projects (
+    id varchar(26) not null,
+    owner_id varchar(26) not null,
+    name varchar(64) not null,
+    color varchar(7) not null default '#9e9e9e',
+    archived boolean not null default false,
+    created_at timestamp with time zone not null default current_timestamp,
+    constraint pk_projects_id primary key (id),
+    constraint fk_projects_owner_id_users_id foreign key (owner_id) references users (id) on delete cascade,
+    constraint uq_projects_owner_id_name unique (owner_id, name)
+);
+
+-- todo 는 한 프로젝트에 속할 수 있다. 프로젝트를 삭제하면 todo 는 프로젝트 없이 남는다.
+-- archived_at 은 프로젝트와 함께 보관한 시각이다.
+alter table todos add column if not exists project_id varchar(26);
+alter table todos add column if not exists archived_at timestamp with time zone;
+
+alter table todos
+    add constraint fk_todos_project_id_projects_id foreign key (project_id) references projects (id) on delete set null;
+
+-- 프로젝트별 todo 조회용
+create index if not exists idx_todos_project_id on todos (project_id) where project_id is not null;
@@ -0,0 +1,16 @@
+-- Todo 공유
+-- 소유자가 다른 사용자에게 todo 하나를 읽기(read) 또는 쓰기(write) 권한으로 공유한다.
+-- todo 나 공유받은 사용자가 삭제되면 공유도 함께 삭제한다.
+create table if not exists todo_shares (
+    todo_id varchar(26) not null,
+    user_id varchar(26) not null,
+    permission varchar(8) not null,
+    created_at timestamp with time zone not null default current_timestamp,
+    constraint pk_todo_shares primary key (todo_id, user_id),
+    constraint ck_todo_shares_permission check (permission in ('read', 'write')),
+    constraint fk_todo_shares_todo_id_todos_id foreign key (todo_id) references todos (id) on delete cascade,
+    constraint fk_todo_shares_user_id_users_id foreign key (user_id) references users (id) on delete cascade
+);
+
+-- 사용자가 공유받은 todo 목록 조회용
+create index if not exists idx_todo_shares_user_id on todo_shares (user_id);
@@ -0,0 +1,7 @@
+-- Todo 상태 목록 관리
+-- 관리자가 상태 이름을 바꾸고 사용자 정의 상태를 추가하거나 끌 수 있다.
+-- 끈 상태는 새로 고를 수 없지만 이미 그 상태인 todo 는 그대로 둔다. 기본 상태는 끌 수 없다.
+alter table todo_statuses add column if not exists disabled_at timestamp with time zone;
+
+-- 상태는 코드로 찾으므로 코드가 겹치면 안 된다.
+create unique index if not exists uq_todo_statuses_code on todo_statuses (code);
@@ -0,0 +1,3 @@
+-- 사용자별 응답 언어
+-- NULL 이면 요청의 Accept-Language 를 따른다.
+alter table users add column if not exists locale varchar(16);
@@ -0,0 +1,48 @@
+-- 사용자가 직접 정한 todo 순서
+-- position 은 0-9a-z 로 이루어진 순위 문자열로, 바이트 순으로 비교하도록 "C" collation 을 쓴다.
+-- 두 todo 사이로 옮기면 사이 값을 새로 만들어 옮긴 todo 만 바꾼다.
+alter table todos add column if not exists position varchar(64) collate "C";
+
+-- 기존 todo 는 사용자별로 만든 순서대로 domain 의 spread_ranks 와 같은 순위를 준다.
+-- 0-9a-z 36진수로 36^3 간격을 띄우고, 자릿수(6자리 이상)는 사용자의 todo 수에 맞춰 늘려 앞뒤로 붙일 자리를 남긴다.
+-- 끝의 0 은 순위에 쓰지 않는다.
+with ranked as (
+    select id, owner_id,
+           row_number() over (partition by owner_id order by created_at, id) - 1 as slot
+    from todos
+),
+counted as (
+    select owner_id, count(*) as total from todos group by owner_id
+),
+-- 자릿수는 사용자마다 한 번만 구한다.
+sized as materialized (
+    select owner_id, total,
+           (select min(w) from generate_series(6, 32) w
+            where (total + 1) * 36::numeric ^ 3 < 36::numeric ^ w) as width
+    from counted
+),
+valued as (
+    select r.id, s.width,
+           (div(36::numeric ^ (s.width - 3) - s.total, 2) + r.slot) * 36::numeric ^ 3 as value
+    from ranked r
+    join sized s on s.owner_id is not distinct from r.owner_id
+),
+powers as (
+    select p, 36::numeric ^ p as base from generate_series(0, 31) p
+)
+update todos t set position = r.position
+from (
+    select v.id,
+           rtrim(string_agg(
+               substr('0123456789abcdefghijklmnopqrstuvwxyz', mod(div(v.value, pw.base), 36)::int + 1, 1),
+               '' order by pw.p desc), '0') as position
+    from valued v
+    join powers pw on pw.p < v.width
+    group by v.id
+) r
+where t.id = r.id and t.position is null;
+
+alter table todos alter column position set not null;
+
+-- 직접 정한 순서 목록 keyset 페이지네이션 (owner_id, position, id) 인덱스
+create index if not exists idx_todos_owner_id_position_id on todos (owner_id, position, id);
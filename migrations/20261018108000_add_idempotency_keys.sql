@@ -0,0 +1,15 @@
+-- 멱등 키(Idempotency-Key)
+-- 같은 키로 다시 보낸 요청에는 처음 요청의 응답을 그대로 돌려준다. 키는 사용자별이며 expires_at 이 지나면 다시 쓸 수 있다.
+-- status 가 null 이면 처음 요청을 아직 처리하고 있다.
+create table if not exists idempotency_keys (
+    user_id varchar(26) not null,
+    idempotency_key varchar(255) not null,
+    request_hash varchar(64) not null,
+    status smallint,
+    headers jsonb,
+    body bytea,
+    created_at timestamp with time zone not null default current_timestamp,
+    expires_at timestamp with time zone not null,
+    constraint pk_idempotency_keys primary key (user_id, idempotency_key),
+    constraint fk_idempotency_keys_user_id_users_id foreign key (user_id) references users (id) on delete cascade
+);
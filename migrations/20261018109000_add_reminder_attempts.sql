@@ -0,0 +1,6 @@
+-- Todo 알림 재시도
+-- 알림을 보내려 할 때마다 reminder_attempts 를 올리고, reminder_next_attempt_at 전에는 다시 잡지 않는다.
+-- 잡은 알림은 보내는 동안 reminder_next_attempt_at 까지 다른 서버가 잡지 않고, 보내지 못하면 그 시각을 뒤로 미룬다.
+-- remind_at 을 다시 정하면 둘 다 처음 값으로 되돌린다.
+alter table todos add column if not exists reminder_attempts integer not null default 0;
+alter table todos add column if not exists reminder_next_attempt_at timestamp with time zone;
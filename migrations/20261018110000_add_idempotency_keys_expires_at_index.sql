@@ -0,0 +1,3 @@
+-- 멱등 키 만료 정리
+-- 만료된 키를 사용자와 관계없이 주기적으로 지우므로 expires_at 으로 찾는다.
+create index if not exists idx_idempotency_keys_expires_at on idempotency_keys (expires_at);
@@ -0,0 +1,101 @@
+use std::fmt;
+
+// ---------------------------------------------------------------------------
+// UseCaseError
+// ---------------------------------------------------------------------------
+
+/// 유스케이스에서 호출자에게 구분해서 알려야 하는 오류.
+/// `anyhow::Error` 로 감싸서 반환하며, 컨트롤러에서 downcast 하여 응답을 결정한다.
+#[derive(Debug)]
+pub enum UseCaseError {
+    NotFound(String),
+    InvalidParameter(String),
+    /// 현재 상태와 충돌하여 요청을 처리할 수 없음.
+    Conflict(ErrorReason),
+    /// 대상은 있지만 호출한 사용자에게 권한이 없음.
+    Forbidden(ErrorReason),
+    /// 요청이 기대한 버전과 현재 버전이 다름.
+    PreconditionFailed(ErrorReason),
+}
+
+impl fmt::Display for UseCaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UseCaseError::NotFound(target) => write!(f, "{target} 을(를) 찾을 수 없습니다"),
+            UseCaseError::InvalidParameter(name) => write!(f, "`{name}` 값이 올바르지 않습니다"),
+            UseCaseError::Conflict(reason) => write!(f, "conflict: {reason:?}"),
+            UseCaseError::Forbidden(reason) => write!(f, "forbidden: {reason:?}"),
+            UseCaseError::PreconditionFailed(reason) => {
+                write!(f, "precondition failed: {reason:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for UseCaseError {}
+
+// ---------------------------------------------------------------------------
+// ErrorReason
+// ---------------------------------------------------------------------------
+
+/// 충돌·권한·버전 오류의 구체적인 이유.
+/// 사용자에게 보여줄 문구는 컨트롤러의 메시지 카탈로그가 요청 언어에 맞게 쓴다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorReason {
+    /// 같은 이름의 프로젝트가 이미 있다. 값은 이름이다.
+    ProjectNameTaken(String),
+    /// 같은 이름의 태그가 이미 있다. 값은 이름이다.
+    TagNameTaken(String),
+    /// 같은 코드의 상태가 이미 있다. 값은 코드이다.
+    StatusCodeTaken(String),
+    /// 기본 상태는 끌 수 없다. 값은 코드이다.
+    BuiltInStatus(String),
+    /// 상태를 `from` 에서 `to` 로 바꿀 수 없다.
+    StatusTransition { from: String, to: String },
+    /// 휴지통에 있는 todo 가 아니다.
+    NotInTrash,
+    /// 자기 자신이나 하위 작업 아래로 옮길 수 없다.
+    MoveIntoSubtree,
+    /// 소유자만 할 수 있는 작업이다.
+    OwnerOnly,
+    /// 소유자만 공유를 관리할 수 있다.
+    ShareOwnerOnly,
+    /// 공유받은 사용자는 태그·상위 todo·프로젝트를 바꿀 수 없다.
+    SharedTodoFields,
+    /// 읽기 권한으로 공유받은 todo 는 고칠 수 없다.
+    ReadOnlyTodo,
+    /// 읽기 권한으로 공유받은 todo 에는 댓글을 달 수 없다.
+    ReadOnlyComment,
+    /// 읽기 권한으로 공유받은 todo 의 첨부 파일은 바꿀 수 없다.
+    ReadOnlyAttachment,
+    /// 작성자만 댓글을 고치거나 지울 수 있다.
+    CommentAuthorOnly,
+    /// 올린 사용자만 첨부 파일을 지울 수 있다.
+    AttachmentUploaderOnly,
+    /// todo 가 다른 요청으로 먼저 바뀌었다.
+    TodoChanged,
+}
+
+// ---------------------------------------------------------------------------
+// TodoBatchError
+// ---------------------------------------------------------------------------
+
+/// 일괄 처리 중 `index` 번째 작업이 실패하여 모든 작업을 되돌렸음.
+/// `source` 는 실패한 작업의 오류이며 `UseCaseError` 일 수 있다.
+#[derive(Debug)]
+pub struct TodoBatchError {
+    pub index: usize,
+    pub source: anyhow::Error,
+}
+
+impl fmt::Display for TodoBatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "operations[{}]: {}", self.index, self.source)
+    }
+}
+
+impl std::error::Error for TodoBatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
@@ -1,3 +1,6 @@
+#![allow(clippy::module_inception)]
+
+pub mod error;
 pub mod model;
 pub mod module;
 pub mod usecase;
@@ -0,0 +1,137 @@
+use crate::error::UseCaseError;
+use crate::model::DateTimeRfc3339;
+use domain::model::attachment::{Attachment, ATTACHMENT_FILE_NAME_MAX_LEN};
+use infra::storage::BlobReader;
+
+#[derive(Debug, Clone)]
+pub struct AttachmentView {
+    pub id: String,
+    pub todo_id: String,
+    pub uploader_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub created_at: DateTimeRfc3339,
+}
+
+impl From<Attachment> for AttachmentView {
+    fn from(attachment: Attachment) -> Self {
+        Self {
+            id: attachment.id.value.to_string(),
+            todo_id: attachment.todo_id.value.to_string(),
+            uploader_id: attachment.uploader_id.value.to_string(),
+            file_name: attachment.file_name,
+            content_type: attachment.content_type,
+            size_bytes: attachment.size_bytes,
+            created_at: attachment.created_at.into(),
+        }
+    }
+}
+
+/// 업로드하는 파일의 이름과 형식. 본문은 스트림으로 따로 넘긴다.
+pub struct UploadAttachment {
+    pub file_name: String,
+    pub content_type: String,
+}
+
+impl UploadAttachment {
+    pub fn new(file_name: String, content_type: String) -> Self {
+        Self {
+            file_name,
+            content_type,
+        }
+    }
+}
+
+/// 업로드 제한. `ApplicationConfig` 의 첨부 파일 설정으로 만든다.
+pub struct AttachmentLimits {
+    pub max_bytes: u64,
+    /// 허용하는 content type 목록. 소문자이다.
+    pub content_types: Vec<String>,
+}
+
+impl AttachmentLimits {
+    pub fn new(max_bytes: u64, content_types: Vec<String>) -> Self {
+        Self {
+            max_bytes,
+            content_types,
+        }
+    }
+}
+
+/// 내려받을 첨부 파일. `body` 는 저장소에서 읽는 스트림이다.
+pub struct AttachmentDownload {
+    pub attachment: AttachmentView,
+    pub body: BlobReader<'static>,
+}
+
+/// 경로를 떼어 낸 파일 이름만 남긴다. 비어 있거나 너무 길거나 제어 문자가 있으면 `fileName` 오류를 낸다.
+pub(crate) fn normalize_file_name(file_name: &str) -> Result<String, UseCaseError> {
+    let name = file_name
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .trim();
+    if name.is_empty()
+        || name == "."
+        || name == ".."
+        || name.chars().count() > ATTACHMENT_FILE_NAME_MAX_LEN
+        || name.chars().any(char::is_control)
+    {
+        return Err(UseCaseError::InvalidParameter("fileName".to_string()));
+    }
+    Ok(name.to_string())
+}
+
+/// content type 의 파라미터(`; charset=...`)를 떼고 소문자로 바꾼다.
+/// 허용 목록에 없으면 `contentType` 오류를 낸다.
+pub(crate) fn normalize_content_type(
+    content_type: &str,
+    allowed: &[String],
+) -> Result<String, UseCaseError> {
+    let content_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    if !allowed.contains(&content_type) {
+        return Err(UseCaseError::InvalidParameter("contentType".to_string()));
+    }
+    Ok(content_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_file_name_strips_directories() {
+        assert_eq!(
+            normalize_file_name("C:\\tmp\\보고서.pdf").unwrap(),
+            "보고서.pdf"
+        );
+        assert_eq!(normalize_file_name("../../etc/passwd").unwrap(), "passwd");
+        for name in [
+            "",
+            "dir/",
+            "..",
+            "a\nb",
+            &"a".repeat(ATTACHMENT_FILE_NAME_MAX_LEN + 1),
+        ] {
+            let result = normalize_file_name(name);
+            assert!(matches!(result, Err(UseCaseError::InvalidParameter(_))));
+        }
+    }
+
+    #[test]
+    fn normalize_content_type_drops_parameters_and_checks_allowed() {
+        let allowed = vec!["text/plain".to_string()];
+        assert_eq!(
+            normalize_content_type("Text/Plain; charset=utf-8", &allowed).unwrap(),
+            "text/plain"
+        );
+        let result = normalize_content_type("application/x-msdownload", &allowed);
+        assert!(matches!(result, Err(UseCaseError::InvalidParameter(_))));
+    }
+}
@@ -0,0 +1,155 @@
+use crate::error::UseCaseError;
+use crate::model::DateTimeRfc3339;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::DateTime;
+use domain::model::comment::{Comment, CommentCursor, COMMENT_BODY_MAX_LEN};
+
+#[derive(Debug, Clone)]
+pub struct CommentView {
+    pub id: String,
+    pub todo_id: String,
+    pub author_id: String,
+    pub body: String,
+    pub created_at: DateTimeRfc3339,
+    pub updated_at: DateTimeRfc3339,
+}
+
+impl From<Comment> for CommentView {
+    fn from(comment: Comment) -> Self {
+        Self {
+            id: comment.id.value.to_string(),
+            todo_id: comment.todo_id.value.to_string(),
+            author_id: comment.author_id.value.to_string(),
+            body: comment.body,
+            created_at: comment.created_at.into(),
+            updated_at: comment.updated_at.into(),
+        }
+    }
+}
+
+pub struct CreateComment {
+    pub body: String,
+}
+
+impl CreateComment {
+    pub fn new(body: String) -> Self {
+        Self { body }
+    }
+}
+
+pub struct UpdateCommentView {
+    pub id: String,
+    pub body: String,
+}
+
+impl UpdateCommentView {
+    pub fn new(id: String, body: String) -> Self {
+        Self { id, body }
+    }
+}
+
+/// 댓글 목록 조회 조건. 오래된 순으로 `limit` 건씩 나누어 조회한다.
+#[derive(Default)]
+pub struct SearchCommentCondition {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+/// 댓글 목록 한 페이지.
+/// `next_cursor` 는 다음 페이지가 있을 때만 채워진다.
+#[derive(Debug, Clone)]
+pub struct CommentPageView {
+    pub comments: Vec<CommentView>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+/// 댓글 본문의 앞뒤 공백을 없앤다. 비어 있거나 너무 길면 `body` 오류를 낸다.
+pub(crate) fn normalize_comment_body(body: &str) -> Result<String, UseCaseError> {
+    let body = body.trim();
+    if body.is_empty() || body.chars().count() > COMMENT_BODY_MAX_LEN {
+        return Err(UseCaseError::InvalidParameter("body".to_string()));
+    }
+    Ok(body.to_string())
+}
+
+/// 목록의 마지막 댓글로부터 다음 페이지 cursor 문자열을 만든다.
+/// todo 목록 cursor 와 섞이지 않도록 `"comment:{마이크로초}:{id}"` 를 base64url 로 인코딩한다.
+pub fn encode_comment_cursor(comment: &Comment) -> String {
+    let raw = format!(
+        "comment:{}:{}",
+        comment.created_at.timestamp_micros(),
+        comment.id.value
+    );
+    URL_SAFE_NO_PAD.encode(raw)
+}
+
+/// 댓글 cursor 문자열을 keyset 위치로 복원한다.
+pub fn decode_comment_cursor(cursor: &str) -> Result<CommentCursor, UseCaseError> {
+    let invalid = || UseCaseError::InvalidParameter("cursor".to_string());
+    let bytes = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+    let raw = String::from_utf8(bytes).map_err(|_| invalid())?;
+    let (micros, id) = raw
+        .strip_prefix("comment:")
+        .and_then(|rest| rest.split_once(':'))
+        .ok_or_else(invalid)?;
+    let micros: i64 = micros.parse().map_err(|_| invalid())?;
+    let created_at = DateTime::from_timestamp_micros(micros).ok_or_else(invalid)?;
+    let id = id.to_string().try_into().map_err(|_| invalid())?;
+    Ok(CommentCursor::new(created_at, id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use domain::model::Id;
+
+    fn make_comment() -> Comment {
+        Comment {
+            id: Id::gen(),
+            todo_id: Id::gen(),
+            author_id: Id::gen(),
+            body: "확인했습니다".to_string(),
+            created_at: Utc.timestamp_micros(1_700_000_000_123_456).unwrap(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn comment_view_from_comment_maps_all_fields() {
+        let comment = make_comment();
+        let id = comment.id.value.to_string();
+        let author_id = comment.author_id.value.to_string();
+        let view = CommentView::from(comment);
+        assert_eq!(view.id, id);
+        assert_eq!(view.author_id, author_id);
+        assert_eq!(view.body, "확인했습니다");
+    }
+
+    #[test]
+    fn normalize_comment_body_trims_and_rejects_blank_or_too_long() {
+        assert_eq!(normalize_comment_body("  좋아요 \n").unwrap(), "좋아요");
+        for body in ["", " \n ", &"가".repeat(COMMENT_BODY_MAX_LEN + 1)] {
+            let result = normalize_comment_body(body);
+            assert!(matches!(result, Err(UseCaseError::InvalidParameter(_))));
+        }
+    }
+
+    #[test]
+    fn encode_then_decode_comment_cursor_round_trips() {
+        let comment = make_comment();
+        let cursor = decode_comment_cursor(&encode_comment_cursor(&comment)).unwrap();
+        assert_eq!(cursor.created_at, comment.created_at);
+        assert_eq!(cursor.id.value, comment.id.value);
+    }
+
+    #[test]
+    fn decode_comment_cursor_with_todo_cursor_returns_invalid_parameter() {
+        let cursor =
+            URL_SAFE_NO_PAD.encode("createdAt:1700000000123456:01JESRMCSRT1N81P95JG46N0K0");
+        let result = decode_comment_cursor(&cursor);
+        assert!(matches!(result, Err(UseCaseError::InvalidParameter(_))));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::error::UseCaseError;
+use domain::model::idempotency::{IdempotentResponse, IDEMPOTENCY_KEY_MAX_LEN};
+
+/// 멱등 키로 요청을 시작한 결과.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdempotencyBegin {
+    /// 키를 차지했다. 요청을 처리한 뒤 `complete` 나 `release` 를 불러야 한다.
+    Proceed,
+    /// 같은 요청을 이미 처리했다. 처음 응답을 그대로 돌려준다.
+    Replay(IdempotentResponseView),
+    /// 같은 키로 보낸 처음 요청을 아직 처리하고 있다.
+    InProgress,
+    /// 같은 키로 다른 요청을 보냈다.
+    Mismatch,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdempotentResponseView {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl From<IdempotentResponse> for IdempotentResponseView {
+    fn from(r: IdempotentResponse) -> Self {
+        Self {
+            status: r.status,
+            headers: r.headers,
+            body: r.body,
+        }
+    }
+}
+
+impl From<IdempotentResponseView> for IdempotentResponse {
+    fn from(r: IdempotentResponseView) -> Self {
+        Self {
+            status: r.status,
+            headers: r.headers,
+            body: r.body,
+        }
+    }
+}
+
+/// 멱등 키는 공백이 없는 ASCII 문자 1~255자이다. 아니면 `Idempotency-Key` 오류를 낸다.
+pub(crate) fn parse_idempotency_key(key: &str) -> Result<String, UseCaseError> {
+    if key.is_empty()
+        || key.len() > IDEMPOTENCY_KEY_MAX_LEN
+        || !key.bytes().all(|b| b.is_ascii_graphic())
+    {
+        return Err(UseCaseError::InvalidParameter(
+            "Idempotency-Key".to_string(),
+        ));
+    }
+    Ok(key.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_idempotency_key_accepts_visible_ascii_up_to_max_len() {
+        assert_eq!(
+            parse_idempotency_key("8e0f-4c1a").unwrap(),
+            "8e0f-4c1a".to_string()
+        );
+        assert!(parse_idempotency_key(&"k".repeat(IDEMPOTENCY_KEY_MAX_LEN)).is_ok());
+    }
+
+    #[test]
+    fn parse_idempotency_key_rejects_empty_long_or_blank_keys() {
+        assert!(parse_idempotency_key("").is_err());
+        assert!(parse_idempotency_key(&"k".repeat(IDEMPOTENCY_KEY_MAX_LEN + 1)).is_err());
+        assert!(parse_idempotency_key("a key").is_err());
+        assert!(parse_idempotency_key("키").is_err());
+    }
+}
@@ -1,7 +1,13 @@
 use chrono::{DateTime, Utc};
+pub use domain::model::FieldUpdate;
 use std::fmt;
 use std::fmt::Formatter;
 
+pub mod attachment;
+pub mod comment;
+pub mod idempotency;
+pub mod project;
+pub mod tag;
 pub mod todo;
 pub mod user;
 
@@ -0,0 +1,101 @@
+use crate::error::UseCaseError;
+use domain::model::project::{is_valid_color, Project, PROJECT_NAME_MAX_LEN};
+
+#[derive(Debug, Clone)]
+pub struct ProjectView {
+    pub id: String,
+    pub name: String,
+    pub color: String,
+    pub archived: bool,
+}
+
+impl From<Project> for ProjectView {
+    fn from(project: Project) -> Self {
+        Self {
+            id: project.id.value.to_string(),
+            name: project.name,
+            color: project.color,
+            archived: project.archived,
+        }
+    }
+}
+
+/// 프로젝트 생성 요청. `color` 는 `#rrggbb` 이며 없으면 기본 색으로 만든다.
+pub struct CreateProject {
+    pub name: String,
+    pub color: Option<String>,
+}
+
+impl CreateProject {
+    pub fn new(name: String, color: Option<String>) -> Self {
+        Self { name, color }
+    }
+}
+
+/// 프로젝트 이름·색 변경 요청. `None` 인 필드는 바꾸지 않는다.
+pub struct UpdateProjectView {
+    pub id: String,
+    pub name: Option<String>,
+    pub color: Option<String>,
+}
+
+impl UpdateProjectView {
+    pub fn new(id: String, name: Option<String>, color: Option<String>) -> Self {
+        Self { id, name, color }
+    }
+}
+
+/// 프로젝트 이름의 앞뒤 공백을 없앤다. 비어 있거나 너무 길면 `name` 오류를 낸다.
+pub(crate) fn normalize_project_name(name: &str) -> Result<String, UseCaseError> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > PROJECT_NAME_MAX_LEN {
+        return Err(UseCaseError::InvalidParameter("name".to_string()));
+    }
+    Ok(name.to_string())
+}
+
+/// 색을 소문자 `#rrggbb` 로 맞춘다. 형식이 틀리면 `color` 오류를 낸다.
+pub(crate) fn normalize_color(color: &str) -> Result<String, UseCaseError> {
+    if !is_valid_color(color) {
+        return Err(UseCaseError::InvalidParameter("color".to_string()));
+    }
+    Ok(color.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::model::Id;
+
+    #[test]
+    fn project_view_from_project_maps_all_fields() {
+        let id: Id<Project> = Id::gen();
+        let ulid_str = id.value.to_string();
+        let view = ProjectView::from(Project::new(
+            id,
+            Id::gen(),
+            "work".to_string(),
+            "#ff8800".to_string(),
+        ));
+        assert_eq!(view.id, ulid_str);
+        assert_eq!(view.name, "work");
+        assert_eq!(view.color, "#ff8800");
+        assert!(!view.archived);
+    }
+
+    #[test]
+    fn normalize_project_name_with_blank_or_too_long_returns_invalid_parameter() {
+        assert_eq!(normalize_project_name(" 집안일 ").unwrap(), "집안일");
+        for name in ["", "   ", &"가".repeat(PROJECT_NAME_MAX_LEN + 1)] {
+            let result = normalize_project_name(name);
+            assert!(matches!(result, Err(UseCaseError::InvalidParameter(_))));
+        }
+    }
+
+    #[test]
+    fn normalize_color_lowercases_or_returns_invalid_parameter() {
+        assert_eq!(normalize_color("#FF8800").unwrap(), "#ff8800");
+        let result = normalize_color("red");
+        assert!(matches!(result, Err(UseCaseError::InvalidParameter(name)) if name == "color"));
+    }
+}
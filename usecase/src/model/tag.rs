@@ -0,0 +1,76 @@
+use crate::error::UseCaseError;
+use domain::model::tag::{Tag, TAG_NAME_MAX_LEN};
+
+#[derive(Debug, Clone)]
+pub struct TagView {
+    pub id: String,
+    pub name: String,
+}
+
+impl From<Tag> for TagView {
+    fn from(tag: Tag) -> Self {
+        Self {
+            id: tag.id.value.to_string(),
+            name: tag.name,
+        }
+    }
+}
+
+pub struct CreateTag {
+    pub name: String,
+}
+
+impl CreateTag {
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+pub struct UpdateTagView {
+    pub id: String,
+    pub name: String,
+}
+
+impl UpdateTagView {
+    pub fn new(id: String, name: String) -> Self {
+        Self { id, name }
+    }
+}
+
+/// 태그 이름의 앞뒤 공백을 없앤다. 비어 있거나 너무 길면 `name` 오류를 낸다.
+pub(crate) fn normalize_tag_name(name: &str) -> Result<String, UseCaseError> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > TAG_NAME_MAX_LEN {
+        return Err(UseCaseError::InvalidParameter("name".to_string()));
+    }
+    Ok(name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::model::Id;
+
+    #[test]
+    fn tag_view_from_tag_maps_all_fields() {
+        let id: Id<Tag> = Id::gen();
+        let ulid_str = id.value.to_string();
+        let view = TagView::from(Tag::new(id, Id::gen(), "work".to_string()));
+        assert_eq!(view.id, ulid_str);
+        assert_eq!(view.name, "work");
+    }
+
+    #[test]
+    fn normalize_tag_name_trims_whitespace() {
+        assert_eq!(normalize_tag_name("  집안일 ").unwrap(), "집안일");
+    }
+
+    #[test]
+    fn normalize_tag_name_with_blank_or_too_long_returns_invalid_parameter() {
+        for name in ["", "   ", &"가".repeat(TAG_NAME_MAX_LEN + 1)] {
+            let result = normalize_tag_name(name);
+            assert!(matches!(result, Err(UseCaseError::InvalidParameter(_))));
+        }
+        assert!(normalize_tag_name(&"가".repeat(TAG_NAME_MAX_LEN)).is_ok());
+    }
+}
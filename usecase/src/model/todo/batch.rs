@@ -0,0 +1,13 @@
+use crate::model::todo::{CreateTodo, UpdateTodoView, UpsertTodoView};
+
+/// 일괄 처리 작업 하나. 같은 트랜잭션에서 요청한 순서대로 실행한다.
+pub enum TodoBatchOperation {
+    Create(CreateTodo),
+    Update(UpdateTodoView),
+    Upsert(UpsertTodoView),
+    /// todo 를 휴지통으로 옮긴다. `expected_version` 이 있으면 현재 버전이 같을 때만 옮긴다.
+    Delete {
+        id: String,
+        expected_version: Option<i64>,
+    },
+}
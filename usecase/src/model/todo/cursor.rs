@@ -0,0 +1,205 @@
+use crate::error::UseCaseError;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::DateTime;
+use domain::model::todo::filter::{TodoSort, TodoSortKey, TodoSortValue};
+use domain::model::todo::{Todo, TodoCursor, TodoSearchCursor, TodoSearchHit};
+
+/// 목록의 마지막 todo 로부터 다음 페이지 cursor 문자열을 만든다.
+/// 클라이언트에는 불투명한 값이며 `"{sort}:{정렬 키 값}:{id}"` 를 base64url 로 인코딩한다.
+/// 시각은 마이크로초 정수로 기록하고, 우선순위 정렬은 `"{rank}/{마감일}"` 로 기록한다.
+pub fn encode_cursor(todo: &Todo, sort: TodoSort) -> String {
+    let value = match sort.value_of(todo) {
+        TodoSortValue::Timestamp(v) => v.timestamp_micros().to_string(),
+        TodoSortValue::Text(v) => v,
+        TodoSortValue::Priority { rank, due_at } => format!(
+            "{}/{}",
+            rank,
+            due_at.map_or(String::new(), |v| v.timestamp_micros().to_string())
+        ),
+    };
+    let raw = format!("{}:{}:{}", sort.as_str(), value, todo.id.value);
+    URL_SAFE_NO_PAD.encode(raw)
+}
+
+/// cursor 문자열을 keyset 위치로 복원한다.
+/// 다른 정렬로 만들어진 cursor 는 잘못된 값으로 처리한다.
+pub fn decode_cursor(cursor: &str, sort: TodoSort) -> Result<TodoCursor, UseCaseError> {
+    let invalid = || UseCaseError::InvalidParameter("cursor".to_string());
+    let bytes = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+    let raw = String::from_utf8(bytes).map_err(|_| invalid())?;
+    let rest = raw
+        .strip_prefix(sort.as_str())
+        .and_then(|rest| rest.strip_prefix(':'))
+        .ok_or_else(invalid)?;
+    // title 에 ':' 가 있을 수 있으므로 id 는 뒤에서 자른다.
+    let (value, id) = rest.rsplit_once(':').ok_or_else(invalid)?;
+    let value = match sort.key {
+        TodoSortKey::CreatedAt | TodoSortKey::UpdatedAt => {
+            let micros: i64 = value.parse().map_err(|_| invalid())?;
+            TodoSortValue::Timestamp(DateTime::from_timestamp_micros(micros).ok_or_else(invalid)?)
+        }
+        TodoSortKey::Title | TodoSortKey::Position => TodoSortValue::Text(value.to_string()),
+        TodoSortKey::Priority => {
+            let (rank, due_at) = value.split_once('/').ok_or_else(invalid)?;
+            let due_at = match due_at {
+                "" => None,
+                micros => {
+                    let micros: i64 = micros.parse().map_err(|_| invalid())?;
+                    Some(DateTime::from_timestamp_micros(micros).ok_or_else(invalid)?)
+                }
+            };
+            TodoSortValue::Priority {
+                rank: rank.parse().map_err(|_| invalid())?,
+                due_at,
+            }
+        }
+    };
+    let id = id.to_string().try_into().map_err(|_| invalid())?;
+    Ok(TodoCursor::new(value, id))
+}
+
+/// 검색 결과의 마지막 항목으로부터 다음 페이지 cursor 문자열을 만든다.
+/// 목록 cursor 와 섞이지 않도록 `"rank:{rank}:{id}"` 형식을 쓴다.
+pub fn encode_search_cursor(hit: &TodoSearchHit) -> String {
+    let raw = format!("rank:{}:{}", hit.rank, hit.todo.id.value);
+    URL_SAFE_NO_PAD.encode(raw)
+}
+
+/// 검색 cursor 문자열을 keyset 위치로 복원한다.
+pub fn decode_search_cursor(cursor: &str) -> Result<TodoSearchCursor, UseCaseError> {
+    let invalid = || UseCaseError::InvalidParameter("cursor".to_string());
+    let bytes = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+    let raw = String::from_utf8(bytes).map_err(|_| invalid())?;
+    let (rank, id) = raw
+        .strip_prefix("rank:")
+        .and_then(|rest| rest.split_once(':'))
+        .ok_or_else(invalid)?;
+    let rank: f32 = rank.parse().map_err(|_| invalid())?;
+    let id = id.to_string().try_into().map_err(|_| invalid())?;
+    Ok(TodoSearchCursor::new(rank, id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use domain::model::todo::priority::TodoPriority;
+    use domain::model::todo::status::{TodoStatus, TodoStatusCode};
+    use domain::model::Id;
+
+    fn make_todo() -> Todo {
+        Todo {
+            id: Id::gen(),
+            owner_id: Id::gen(),
+            parent_id: None,
+            title: "Cursor".to_string(),
+            description: "desc".to_string(),
+            status: TodoStatus::new(Id::gen(), TodoStatusCode::New, "신규".to_string()),
+            due_at: None,
+            priority: TodoPriority::default(),
+            tags: vec![],
+            created_at: Utc.timestamp_micros(1_700_000_000_123_456).unwrap(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+            version: 1,
+            recurrence: None,
+            remind_at: None,
+            project_id: None,
+            archived_at: None,
+            position: "i".to_string(),
+        }
+    }
+
+    #[test]
+    fn encode_then_decode_cursor_round_trips() {
+        let todo = make_todo();
+        let sort = TodoSort::default();
+        let cursor = decode_cursor(&encode_cursor(&todo, sort), sort).unwrap();
+        assert_eq!(cursor.value, TodoSortValue::Timestamp(todo.created_at));
+        assert_eq!(cursor.id.value, todo.id.value);
+    }
+
+    #[test]
+    fn encode_then_decode_title_cursor_keeps_colons() {
+        let mut todo = make_todo();
+        todo.title = "회의: 10:30".to_string();
+        let sort = TodoSort::try_from("-title").unwrap();
+        let cursor = decode_cursor(&encode_cursor(&todo, sort), sort).unwrap();
+        assert_eq!(cursor.value, TodoSortValue::Text("회의: 10:30".to_string()));
+        assert_eq!(cursor.id.value, todo.id.value);
+    }
+
+    #[test]
+    fn encode_then_decode_priority_cursor_round_trips() {
+        let sort = TodoSort::try_from("priority").unwrap();
+        let mut todo = make_todo();
+        todo.priority = TodoPriority::Urgent;
+        let cursor = decode_cursor(&encode_cursor(&todo, sort), sort).unwrap();
+        assert_eq!(
+            cursor.value,
+            TodoSortValue::Priority {
+                rank: 3,
+                due_at: None
+            }
+        );
+
+        todo.due_at = Some(Utc.timestamp_micros(1_800_000_000_000_001).unwrap());
+        let cursor = decode_cursor(&encode_cursor(&todo, sort), sort).unwrap();
+        assert_eq!(
+            cursor.value,
+            TodoSortValue::Priority {
+                rank: 3,
+                due_at: todo.due_at
+            }
+        );
+        assert_eq!(cursor.id.value, todo.id.value);
+    }
+
+    #[test]
+    fn decode_cursor_with_other_sort_returns_invalid_parameter() {
+        let todo = make_todo();
+        let cursor = encode_cursor(&todo, TodoSort::default());
+        let result = decode_cursor(&cursor, TodoSort::try_from("-createdAt").unwrap());
+        assert!(matches!(result, Err(UseCaseError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn encode_then_decode_search_cursor_round_trips() {
+        let hit = TodoSearchHit {
+            todo: make_todo(),
+            rank: 0.607_927_1,
+            snippet: "<mark>Cursor</mark>".to_string(),
+        };
+        let cursor = decode_search_cursor(&encode_search_cursor(&hit)).unwrap();
+        assert_eq!(cursor.rank, hit.rank);
+        assert_eq!(cursor.id.value, hit.todo.id.value);
+    }
+
+    #[test]
+    fn list_and_search_cursors_are_not_interchangeable() {
+        let todo = make_todo();
+        let list_cursor = encode_cursor(&todo, TodoSort::default());
+        let hit = TodoSearchHit {
+            todo,
+            rank: 1.0,
+            snippet: String::new(),
+        };
+        let search_cursor = encode_search_cursor(&hit);
+        assert!(decode_search_cursor(&list_cursor).is_err());
+        assert!(decode_cursor(&search_cursor, TodoSort::default()).is_err());
+    }
+
+    #[test]
+    fn decode_cursor_with_garbage_returns_invalid_parameter() {
+        let result = decode_cursor("not a cursor!", TodoSort::default());
+        assert!(matches!(result, Err(UseCaseError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn decode_cursor_with_invalid_id_returns_invalid_parameter() {
+        let cursor = URL_SAFE_NO_PAD.encode("createdAt:1700000000123456:not-a-ulid");
+        let result = decode_cursor(&cursor, TodoSort::default());
+        assert!(matches!(result, Err(UseCaseError::InvalidParameter(_))));
+    }
+}
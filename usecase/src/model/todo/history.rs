@@ -0,0 +1,118 @@
+use crate::error::UseCaseError;
+use crate::model::DateTimeRfc3339;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::DateTime;
+use domain::model::todo::event::{TodoEvent, TodoEventCursor, TodoFieldChange};
+
+#[derive(Debug, Clone)]
+pub struct TodoFieldChangeView {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl From<TodoFieldChange> for TodoFieldChangeView {
+    fn from(change: TodoFieldChange) -> Self {
+        Self {
+            field: change.field,
+            before: change.before,
+            after: change.after,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TodoEventView {
+    pub id: String,
+    pub todo_id: String,
+    pub actor_id: String,
+    pub changes: Vec<TodoFieldChangeView>,
+    pub created_at: DateTimeRfc3339,
+}
+
+impl From<TodoEvent> for TodoEventView {
+    fn from(event: TodoEvent) -> Self {
+        Self {
+            id: event.id.value.to_string(),
+            todo_id: event.todo_id.value.to_string(),
+            actor_id: event.actor_id.value.to_string(),
+            changes: event.changes.into_iter().map(Into::into).collect(),
+            created_at: event.created_at.into(),
+        }
+    }
+}
+
+/// 변경 이력 조회 조건. 최신순으로 `limit` 건씩 나누어 조회한다.
+#[derive(Default)]
+pub struct SearchTodoHistoryCondition {
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+/// 변경 이력 한 페이지.
+/// `next_cursor` 는 다음 페이지가 있을 때만 채워진다.
+#[derive(Debug, Clone)]
+pub struct TodoHistoryPageView {
+    pub events: Vec<TodoEventView>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+/// 목록의 마지막 이력으로부터 다음 페이지 cursor 문자열을 만든다.
+/// 다른 목록 cursor 와 섞이지 않도록 `"event:{마이크로초}:{id}"` 를 base64url 로 인코딩한다.
+pub fn encode_history_cursor(event: &TodoEvent) -> String {
+    let raw = format!(
+        "event:{}:{}",
+        event.created_at.timestamp_micros(),
+        event.id.value
+    );
+    URL_SAFE_NO_PAD.encode(raw)
+}
+
+/// 이력 cursor 문자열을 keyset 위치로 복원한다.
+pub fn decode_history_cursor(cursor: &str) -> Result<TodoEventCursor, UseCaseError> {
+    let invalid = || UseCaseError::InvalidParameter("cursor".to_string());
+    let bytes = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+    let raw = String::from_utf8(bytes).map_err(|_| invalid())?;
+    let (micros, id) = raw
+        .strip_prefix("event:")
+        .and_then(|rest| rest.split_once(':'))
+        .ok_or_else(invalid)?;
+    let micros: i64 = micros.parse().map_err(|_| invalid())?;
+    let created_at = DateTime::from_timestamp_micros(micros).ok_or_else(invalid)?;
+    let id = id.to_string().try_into().map_err(|_| invalid())?;
+    Ok(TodoEventCursor::new(created_at, id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use domain::model::Id;
+
+    #[test]
+    fn history_cursor_round_trips() {
+        let event = TodoEvent {
+            id: Id::gen(),
+            todo_id: Id::gen(),
+            actor_id: Id::gen(),
+            changes: vec![],
+            created_at: Utc.timestamp_micros(1_700_000_000_123_456).unwrap(),
+        };
+        let cursor = decode_history_cursor(&encode_history_cursor(&event)).unwrap();
+        assert_eq!(cursor.created_at, event.created_at);
+        assert_eq!(cursor.id.value, event.id.value);
+    }
+
+    #[test]
+    fn decode_history_cursor_rejects_other_cursor_kinds() {
+        let comment_cursor = URL_SAFE_NO_PAD.encode("comment:1:01JRWBKE4KE4P9MQNHCX4F0000");
+        for cursor in ["not-base64!", comment_cursor.as_str()] {
+            assert!(matches!(
+                decode_history_cursor(cursor),
+                Err(UseCaseError::InvalidParameter(_))
+            ));
+        }
+    }
+}
@@ -1,57 +1,162 @@
+pub mod batch;
+pub mod cursor;
+pub mod history;
+pub mod recurrence;
+pub mod share;
+pub mod stats;
 pub mod status;
+pub mod transfer;
+pub mod tree;
 
+use crate::error::UseCaseError;
+use crate::model::tag::TagView;
 use crate::model::todo::status::TodoStatusView;
-use crate::model::DateTimeRfc3339;
-use domain::model::todo::{NewTodo, Todo};
+use crate::model::{DateTimeRfc3339, FieldUpdate};
+use chrono::{DateTime, Utc};
+use domain::model::project::Project;
+use domain::model::todo::priority::TodoPriority;
+use domain::model::todo::recurrence::RecurrenceRule;
+use domain::model::todo::{NewTodo, Todo, TodoSearchHit};
+use domain::model::user::User;
 use domain::model::Id;
 
 #[derive(Debug, Clone)]
 pub struct TodoView {
     pub id: String,
+    pub owner_id: String,
+    pub parent_id: Option<String>,
     pub title: String,
     pub description: String,
     pub status: TodoStatusView,
+    pub due_at: Option<DateTimeRfc3339>,
+    pub priority: String,
+    pub tags: Vec<TagView>,
     pub created_at: DateTimeRfc3339,
     pub updated_at: DateTimeRfc3339,
+    pub deleted_at: Option<DateTimeRfc3339>,
+    pub version: i64,
+    /// 반복 규칙 (RRULE). 반복 todo 에만 채워진다.
+    pub recurrence: Option<String>,
+    pub remind_at: Option<DateTimeRfc3339>,
+    pub project_id: Option<String>,
+    /// 프로젝트와 함께 보관한 시각. 보관한 todo 에만 채워진다.
+    pub archived_at: Option<DateTimeRfc3339>,
+    /// 직접 정한 순서의 순위. 문자열로 비교한다.
+    pub position: String,
+    /// 검색 결과일 때만 채워진다.
+    pub rank: Option<f32>,
+    pub snippet: Option<String>,
 }
 
 impl From<Todo> for TodoView {
     fn from(todo: Todo) -> Self {
         Self {
             id: todo.id.value.to_string(),
+            owner_id: todo.owner_id.value.to_string(),
+            parent_id: todo.parent_id.map(|p| p.value.to_string()),
             title: todo.title,
             description: todo.description,
             status: todo.status.into(),
+            due_at: todo.due_at.map(Into::into),
+            priority: todo.priority.as_str().to_string(),
+            tags: todo.tags.into_iter().map(Into::into).collect(),
             created_at: todo.created_at.into(),
             updated_at: todo.updated_at.into(),
+            deleted_at: todo.deleted_at.map(Into::into),
+            version: todo.version,
+            recurrence: todo.recurrence.map(|r| r.to_string()),
+            remind_at: todo.remind_at.map(Into::into),
+            project_id: todo.project_id.map(|p| p.value.to_string()),
+            archived_at: todo.archived_at.map(Into::into),
+            position: todo.position,
+            rank: None,
+            snippet: None,
         }
     }
 }
 
+impl From<TodoSearchHit> for TodoView {
+    fn from(hit: TodoSearchHit) -> Self {
+        Self {
+            rank: Some(hit.rank),
+            snippet: Some(hit.snippet),
+            ..hit.todo.into()
+        }
+    }
+}
+
+/// Todo 생성 요청. `due_at` 은 RFC 3339 문자열, `priority` 는 `low` ~ `urgent` 이며
+/// 값이 없으면 마감일 없음, `medium` 으로 만든다. `tag_ids` 의 태그를 붙여서 만든다.
+/// `parent_id` 가 있으면 그 todo 의 하위 작업으로 만든다.
+/// `recurrence` 는 RRULE 문자열이며, 있으면 완료할 때마다 다음 회차를 만든다.
+/// `remind_at` 은 알림을 보낼 RFC 3339 시각이다. `project_id` 가 있으면 그 프로젝트에 만든다.
 pub struct CreateTodo {
     pub title: String,
     pub description: String,
+    pub due_at: Option<String>,
+    pub priority: Option<String>,
+    pub tag_ids: Vec<String>,
+    pub parent_id: Option<String>,
+    pub recurrence: Option<String>,
+    pub remind_at: Option<String>,
+    pub project_id: Option<String>,
 }
 
 impl CreateTodo {
     pub fn new(title: String, description: String) -> Self {
-        Self { title, description }
+        Self {
+            title,
+            description,
+            due_at: None,
+            priority: None,
+            tag_ids: vec![],
+            parent_id: None,
+            recurrence: None,
+            remind_at: None,
+            project_id: None,
+        }
     }
-}
-
-impl TryFrom<CreateTodo> for NewTodo {
-    type Error = anyhow::Error;
 
-    fn try_from(ct: CreateTodo) -> Result<Self, Self::Error> {
-        Ok(NewTodo::new(Id::gen(), ct.title, ct.description))
+    /// 소유자를 지정하여 새 Todo 를 만든다.
+    pub fn into_new_todo(self, owner_id: Id<User>) -> Result<NewTodo, UseCaseError> {
+        let mut new_todo = NewTodo::new(Id::gen(), owner_id, self.title, self.description);
+        new_todo.due_at = parse_due_at(self.due_at.as_deref())?;
+        new_todo.parent_id = self.parent_id.map(parse_parent_id).transpose()?;
+        new_todo.recurrence = self
+            .recurrence
+            .as_deref()
+            .map(parse_recurrence)
+            .transpose()?;
+        new_todo.remind_at = parse_remind_at(self.remind_at.as_deref())?;
+        new_todo.project_id = self.project_id.map(parse_project_id).transpose()?;
+        if let Some(priority) = parse_priority(self.priority.as_deref())? {
+            new_todo.priority = priority;
+        }
+        Ok(new_todo)
     }
 }
 
+/// Todo 부분 수정 요청. `add_tag_ids` 의 태그를 붙이고 `remove_tag_ids` 의 태그를 뗀다.
+/// `due_at`, `remind_at` 은 RFC 3339 문자열, `recurrence` 는 RRULE 문자열로 바꾸거나 `Clear` 로 지운다.
+/// `parent_id` 는 옮길 상위 todo 이며 빈 문자열이면 최상위로 옮긴다.
+/// `project_id` 는 옮길 프로젝트이며 빈 문자열이면 프로젝트에서 뺀다.
+/// `roll_up_completion` 이면 완료한 todo 의 형제가 모두 끝났을 때 상위 todo 도 완료한다.
+/// `expected_version` 이 있으면 현재 버전이 같을 때만 바꾼다.
 pub struct UpdateTodoView {
     pub id: String,
     pub title: Option<String>,
     pub description: Option<String>,
     pub status_code: Option<String>,
+    pub due_at: FieldUpdate<String>,
+    pub priority: Option<String>,
+    pub recurrence: FieldUpdate<String>,
+    pub remind_at: FieldUpdate<String>,
+    pub add_tag_ids: Vec<String>,
+    pub remove_tag_ids: Vec<String>,
+    pub parent_id: Option<String>,
+    pub project_id: Option<String>,
+    pub roll_up_completion: bool,
+    pub expected_version: Option<i64>,
 }
 
 impl UpdateTodoView {
@@ -66,15 +171,30 @@ impl UpdateTodoView {
             title,
             description,
             status_code,
+            due_at: FieldUpdate::Keep,
+            priority: None,
+            recurrence: FieldUpdate::Keep,
+            remind_at: FieldUpdate::Keep,
+            add_tag_ids: vec![],
+            remove_tag_ids: vec![],
+            parent_id: None,
+            project_id: None,
+            roll_up_completion: false,
+            expected_version: None,
         }
     }
 }
 
+/// Todo 전체 교체 요청. 마감일이 없으면 지우고 우선순위가 없으면 `medium` 으로 둔다.
+/// `expected_version` 이 있으면 이미 있는 todo 의 버전이 같을 때만 덮어쓴다.
 pub struct UpsertTodoView {
     pub id: String,
     pub title: String,
     pub description: String,
     pub status_code: String,
+    pub due_at: Option<String>,
+    pub priority: Option<String>,
+    pub expected_version: Option<i64>,
 }
 
 impl UpsertTodoView {
@@ -84,18 +204,109 @@ impl UpsertTodoView {
             title,
             description,
             status_code,
+            due_at: None,
+            priority: None,
+            expected_version: None,
         }
     }
 }
 
+/// Todo 목록 조회 조건. 값이 없는 조건은 적용하지 않는다.
+/// 시각은 RFC 3339 문자열, `sort` 는 `createdAt`, `-updatedAt`, `title`, `priority` 형식이다.
+#[derive(Default)]
 pub struct SearchTodoCondition {
-    pub status_code: Option<String>,
+    pub q: Option<String>,
+    pub status_codes: Vec<String>,
+    pub created_after: Option<String>,
+    pub created_before: Option<String>,
+    pub updated_since: Option<String>,
+    pub due_before: Option<String>,
+    /// 마감일이 지났는데 끝나지 않은 todo 만 조회한다.
+    pub overdue: bool,
+    /// 태그 이름. 모두 붙어 있는 todo 만 조회한다.
+    pub tag_names: Vec<String>,
+    /// 이 todo 의 바로 아래 하위 작업만 조회한다.
+    pub parent_id: Option<String>,
+    /// 이 프로젝트의 todo 만 보관한 것까지 조회한다. 없으면 보관한 todo 는 제외한다.
+    pub project_id: Option<String>,
+    pub sort: Option<String>,
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+/// Todo 목록 한 페이지.
+/// `next_cursor` 는 다음 페이지가 있을 때만 채워진다.
+#[derive(Debug, Clone)]
+pub struct TodoPageView {
+    pub todos: Vec<TodoView>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+/// 마감일을 해석한다. RFC 3339 형식이 아니면 `dueAt` 오류를 낸다.
+pub(crate) fn parse_due_at(value: Option<&str>) -> Result<Option<DateTime<Utc>>, UseCaseError> {
+    value
+        .map(|v| {
+            DateTime::parse_from_rfc3339(v)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|_| UseCaseError::InvalidParameter("dueAt".to_string()))
+        })
+        .transpose()
+}
+
+/// 알림 시각을 해석한다. RFC 3339 형식이 아니면 `remindAt` 오류를 낸다.
+pub(crate) fn parse_remind_at(value: Option<&str>) -> Result<Option<DateTime<Utc>>, UseCaseError> {
+    value
+        .map(|v| {
+            DateTime::parse_from_rfc3339(v)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|_| UseCaseError::InvalidParameter("remindAt".to_string()))
+        })
+        .transpose()
+}
+
+/// 상위 todo id 를 해석한다. 형식이 틀리면 `parentId` 오류를 낸다.
+pub(crate) fn parse_parent_id(value: String) -> Result<Id<Todo>, UseCaseError> {
+    value
+        .try_into()
+        .map_err(|_| UseCaseError::InvalidParameter("parentId".to_string()))
+}
+
+/// 순서를 옮길 때 이웃으로 준 todo id 를 해석한다. 형식이 틀리면 `field` 오류를 낸다.
+pub(crate) fn parse_neighbour_id(value: String, field: &str) -> Result<Id<Todo>, UseCaseError> {
+    value
+        .try_into()
+        .map_err(|_| UseCaseError::InvalidParameter(field.to_string()))
+}
+
+/// 프로젝트 id 를 해석한다. 형식이 틀리면 `projectId` 오류를 낸다.
+pub(crate) fn parse_project_id(value: String) -> Result<Id<Project>, UseCaseError> {
+    value
+        .try_into()
+        .map_err(|_| UseCaseError::InvalidParameter("projectId".to_string()))
+}
+
+/// 우선순위를 해석한다. 알 수 없는 값이면 `priority` 오류를 낸다.
+pub(crate) fn parse_priority(value: Option<&str>) -> Result<Option<TodoPriority>, UseCaseError> {
+    value
+        .map(|v| {
+            TodoPriority::try_from(v)
+                .map_err(|_| UseCaseError::InvalidParameter("priority".to_string()))
+        })
+        .transpose()
+}
+
+/// 반복 규칙을 해석한다. 지원하지 않는 RRULE 이면 `recurrence` 오류를 낸다.
+pub(crate) fn parse_recurrence(value: &str) -> Result<RecurrenceRule, UseCaseError> {
+    RecurrenceRule::try_from(value)
+        .map_err(|_| UseCaseError::InvalidParameter("recurrence".to_string()))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::Utc;
+    use domain::model::todo::priority::TodoPriority;
     use domain::model::todo::status::{TodoStatus, TodoStatusCode};
     use domain::model::todo::Todo;
     use domain::model::Id;
@@ -105,11 +316,23 @@ mod tests {
         let id_str = id.value.to_string();
         let todo = Todo {
             id,
+            owner_id: Id::gen(),
+            parent_id: None,
             title: "Test Todo".to_string(),
             description: "Some desc".to_string(),
             status: TodoStatus::new(Id::gen(), TodoStatusCode::New, "신규".to_string()),
+            due_at: None,
+            priority: TodoPriority::default(),
+            tags: vec![],
             created_at: Utc::now(),
             updated_at: Utc::now(),
+            deleted_at: None,
+            version: 1,
+            recurrence: None,
+            remind_at: None,
+            project_id: None,
+            archived_at: None,
+            position: "i".to_string(),
         };
         (todo, id_str)
     }
@@ -125,10 +348,92 @@ mod tests {
     }
 
     #[test]
-    fn create_todo_try_into_new_todo_generates_id() {
+    fn todo_view_from_search_hit_keeps_rank_and_snippet() {
+        let (todo, id_str) = make_todo();
+        let hit = TodoSearchHit {
+            todo,
+            rank: 0.5,
+            snippet: "<mark>Test</mark> Todo".to_string(),
+        };
+        let view = TodoView::from(hit);
+        assert_eq!(view.id, id_str);
+        assert_eq!(view.rank, Some(0.5));
+        assert_eq!(view.snippet.as_deref(), Some("<mark>Test</mark> Todo"));
+    }
+
+    #[test]
+    fn create_todo_into_new_todo_generates_id() {
+        let owner_id: Id<User> = Id::gen();
+        let owner_ulid = owner_id.value;
         let ct = CreateTodo::new("My Task".to_string(), "Details".to_string());
-        let nt: NewTodo = ct.try_into().unwrap();
+        let nt: NewTodo = ct.into_new_todo(owner_id).unwrap();
+        assert_eq!(nt.owner_id.value, owner_ulid);
         assert_eq!(nt.title, "My Task");
         assert_eq!(nt.description, "Details");
+        assert_eq!(nt.due_at, None);
+        assert_eq!(nt.priority, TodoPriority::Medium);
+    }
+
+    #[test]
+    fn create_todo_into_new_todo_parses_due_at_and_priority() {
+        let mut ct = CreateTodo::new("My Task".to_string(), "Details".to_string());
+        ct.due_at = Some("2026-10-20T09:00:00+09:00".to_string());
+        ct.priority = Some("urgent".to_string());
+        let nt = ct.into_new_todo(Id::gen()).unwrap();
+        assert_eq!(
+            nt.due_at.map(|v| v.to_rfc3339()).as_deref(),
+            Some("2026-10-20T00:00:00+00:00")
+        );
+        assert_eq!(nt.priority, TodoPriority::Urgent);
+    }
+
+    #[test]
+    fn create_todo_into_new_todo_with_invalid_priority_returns_invalid_parameter() {
+        let mut ct = CreateTodo::new("My Task".to_string(), "Details".to_string());
+        ct.priority = Some("someday".to_string());
+        let result = ct.into_new_todo(Id::gen());
+        assert!(matches!(result, Err(UseCaseError::InvalidParameter(name)) if name == "priority"));
+    }
+
+    #[test]
+    fn create_todo_into_new_todo_with_invalid_parent_id_returns_invalid_parameter() {
+        let mut ct = CreateTodo::new("My Task".to_string(), "Details".to_string());
+        ct.parent_id = Some("not-a-ulid".to_string());
+        let result = ct.into_new_todo(Id::gen());
+        assert!(matches!(result, Err(UseCaseError::InvalidParameter(name)) if name == "parentId"));
+    }
+
+    #[test]
+    fn create_todo_into_new_todo_parses_recurrence() {
+        let mut ct = CreateTodo::new("My Task".to_string(), "Details".to_string());
+        ct.recurrence = Some("FREQ=WEEKLY;BYDAY=MO".to_string());
+        let nt = ct.into_new_todo(Id::gen()).unwrap();
+        assert_eq!(
+            nt.recurrence.map(|r| r.to_string()).as_deref(),
+            Some("FREQ=WEEKLY;BYDAY=MO")
+        );
+
+        let mut ct = CreateTodo::new("My Task".to_string(), "Details".to_string());
+        ct.recurrence = Some("FREQ=HOURLY".to_string());
+        let result = ct.into_new_todo(Id::gen());
+        assert!(
+            matches!(result, Err(UseCaseError::InvalidParameter(name)) if name == "recurrence")
+        );
+    }
+
+    #[test]
+    fn create_todo_into_new_todo_with_invalid_remind_at_returns_invalid_parameter() {
+        let mut ct = CreateTodo::new("My Task".to_string(), "Details".to_string());
+        ct.remind_at = Some("tomorrow".to_string());
+        let result = ct.into_new_todo(Id::gen());
+        assert!(matches!(result, Err(UseCaseError::InvalidParameter(name)) if name == "remindAt"));
+    }
+
+    #[test]
+    fn create_todo_into_new_todo_with_invalid_project_id_returns_invalid_parameter() {
+        let mut ct = CreateTodo::new("My Task".to_string(), "Details".to_string());
+        ct.project_id = Some("not-a-ulid".to_string());
+        let result = ct.into_new_todo(Id::gen());
+        assert!(matches!(result, Err(UseCaseError::InvalidParameter(name)) if name == "projectId"));
     }
 }
@@ -0,0 +1,9 @@
+use crate::model::DateTimeRfc3339;
+
+/// 반복 todo 의 다음 회차 미리보기.
+/// 반복하지 않는 todo 면 `recurrence` 가 없고 `occurrences` 는 비어 있다.
+#[derive(Debug, Clone)]
+pub struct TodoOccurrencesView {
+    pub recurrence: Option<String>,
+    pub occurrences: Vec<DateTimeRfc3339>,
+}
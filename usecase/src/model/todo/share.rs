@@ -0,0 +1,60 @@
+use crate::error::UseCaseError;
+use crate::model::DateTimeRfc3339;
+use domain::model::todo::share::{TodoShare, TodoSharePermission};
+
+#[derive(Debug, Clone)]
+pub struct TodoShareView {
+    pub todo_id: String,
+    pub user_id: String,
+    pub username: String,
+    pub permission: String,
+    pub created_at: DateTimeRfc3339,
+}
+
+impl From<TodoShare> for TodoShareView {
+    fn from(share: TodoShare) -> Self {
+        Self {
+            todo_id: share.todo_id.value.to_string(),
+            user_id: share.user_id.value.to_string(),
+            username: share.username,
+            permission: share.permission.as_str().to_string(),
+            created_at: share.created_at.into(),
+        }
+    }
+}
+
+/// 사용자 이름으로 todo 를 공유한다. `permission` 은 `read` 또는 `write` 이다.
+pub struct ShareTodo {
+    pub username: String,
+    pub permission: String,
+}
+
+impl ShareTodo {
+    pub fn new(username: String, permission: String) -> Self {
+        Self {
+            username,
+            permission,
+        }
+    }
+}
+
+/// 공유 권한 문자열을 바꾼다. 알 수 없는 값이면 `permission` 파라미터 오류를 낸다.
+pub(crate) fn parse_share_permission(value: &str) -> Result<TodoSharePermission, UseCaseError> {
+    TodoSharePermission::try_from(value)
+        .map_err(|_| UseCaseError::InvalidParameter("permission".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_share_permission_rejects_unknown_value() {
+        assert_eq!(
+            parse_share_permission("write").unwrap(),
+            TodoSharePermission::Write
+        );
+        let err = parse_share_permission("owner").unwrap_err();
+        assert!(matches!(err, UseCaseError::InvalidParameter(name) if name == "permission"));
+    }
+}
@@ -0,0 +1,176 @@
+use crate::error::UseCaseError;
+use chrono::{Duration, NaiveDate};
+use domain::model::todo::stats::{
+    TodoStats, TodoStatsBucket, TodoStatsInterval, TodoStatsRange, TodoStatusCount,
+};
+
+/// 기간을 주지 않았을 때 오늘까지 거슬러 올라가 셀 일 수.
+pub const DEFAULT_STATS_DAYS: i64 = 30;
+/// 한 번에 셀 수 있는 최대 일 수.
+pub const MAX_STATS_DAYS: i64 = 366;
+
+/// 통계 조회 조건. `from`·`to` 는 UTC 기준 `YYYY-MM-DD` 날짜이며 둘 다 기간에 들어간다.
+/// `interval` 은 `day`(기본) 또는 `week` 이다.
+#[derive(Debug, Default)]
+pub struct TodoStatsCondition {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub interval: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TodoStatusCountView {
+    pub code: String,
+    pub count: i64,
+}
+
+impl From<TodoStatusCount> for TodoStatusCountView {
+    fn from(count: TodoStatusCount) -> Self {
+        Self {
+            code: count.code.as_str().to_string(),
+            count: count.count,
+        }
+    }
+}
+
+/// 기간 한 칸. `start` 는 칸이 시작하는 날짜(`YYYY-MM-DD`)이다.
+#[derive(Debug, Clone)]
+pub struct TodoStatsBucketView {
+    pub start: String,
+    pub created: i64,
+    pub completed: i64,
+}
+
+impl From<TodoStatsBucket> for TodoStatsBucketView {
+    fn from(bucket: TodoStatsBucket) -> Self {
+        Self {
+            start: bucket.start.date_naive().to_string(),
+            created: bucket.created,
+            completed: bucket.completed,
+        }
+    }
+}
+
+/// todo 통계와 그 기간.
+#[derive(Debug, Clone)]
+pub struct TodoStatsView {
+    pub from: String,
+    pub to: String,
+    pub interval: String,
+    pub status_counts: Vec<TodoStatusCountView>,
+    pub buckets: Vec<TodoStatsBucketView>,
+    pub average_completion_seconds: Option<f64>,
+    pub overdue: i64,
+}
+
+impl TodoStatsView {
+    pub fn new(from: NaiveDate, to: NaiveDate, range: &TodoStatsRange, stats: TodoStats) -> Self {
+        Self {
+            from: from.to_string(),
+            to: to.to_string(),
+            interval: range.interval.as_str().to_string(),
+            status_counts: stats.status_counts.into_iter().map(Into::into).collect(),
+            buckets: stats.buckets.into_iter().map(Into::into).collect(),
+            average_completion_seconds: stats.average_completion_seconds,
+            overdue: stats.overdue,
+        }
+    }
+}
+
+/// 조회 조건을 기간으로 바꾼다. 기간은 `from` 00:00 부터 `to` 다음 날 00:00 전까지이다.
+///
+/// `to` 가 없으면 `today`, `from` 이 없으면 `to` 까지 [`DEFAULT_STATS_DAYS`] 일이다.
+/// 날짜 형식이 틀리거나 `from` 이 `to` 보다 늦으면 해당 이름의 오류를,
+/// 기간이 [`MAX_STATS_DAYS`] 일을 넘으면 `from` 오류를 낸다.
+pub(crate) fn parse_stats_range(
+    condition: &TodoStatsCondition,
+    today: NaiveDate,
+) -> Result<(NaiveDate, NaiveDate, TodoStatsRange), UseCaseError> {
+    let parse_date = |value: &str, name: &str| {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map_err(|_| UseCaseError::InvalidParameter(name.to_string()))
+    };
+    let to = match condition.to.as_deref() {
+        Some(to) => parse_date(to, "to")?,
+        None => today,
+    };
+    let from = match condition.from.as_deref() {
+        Some(from) => parse_date(from, "from")?,
+        None => to - Duration::days(DEFAULT_STATS_DAYS - 1),
+    };
+    let days = (to - from).num_days() + 1;
+    if !(1..=MAX_STATS_DAYS).contains(&days) {
+        return Err(UseCaseError::InvalidParameter("from".to_string()));
+    }
+    let interval = match condition.interval.as_deref() {
+        Some(interval) => TodoStatsInterval::try_from(interval)
+            .map_err(|_| UseCaseError::InvalidParameter("interval".to_string()))?,
+        None => TodoStatsInterval::Day,
+    };
+    let start = from.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc();
+    let end = start + Duration::days(days);
+    Ok((from, to, TodoStatsRange::new(start, end, interval)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn condition(
+        from: Option<&str>,
+        to: Option<&str>,
+        interval: Option<&str>,
+    ) -> TodoStatsCondition {
+        TodoStatsCondition {
+            from: from.map(str::to_string),
+            to: to.map(str::to_string),
+            interval: interval.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn parse_stats_range_defaults_to_last_thirty_days_by_day() {
+        let (from, to, range) =
+            parse_stats_range(&TodoStatsCondition::default(), date("2026-10-18")).unwrap();
+        assert_eq!(from, date("2026-09-19"));
+        assert_eq!(to, date("2026-10-18"));
+        assert_eq!(range.interval, TodoStatsInterval::Day);
+        assert_eq!(range.from.to_rfc3339(), "2026-09-19T00:00:00+00:00");
+        assert_eq!(range.to.to_rfc3339(), "2026-10-19T00:00:00+00:00");
+    }
+
+    #[test]
+    fn parse_stats_range_rejects_bad_dates_and_ranges() {
+        let today = date("2026-10-18");
+        let cases = [
+            (condition(Some("2026/10/01"), None, None), "from"),
+            (condition(None, Some("tomorrow"), None), "to"),
+            (
+                condition(Some("2026-10-02"), Some("2026-10-01"), None),
+                "from",
+            ),
+            (
+                condition(Some("2025-01-01"), Some("2026-10-01"), None),
+                "from",
+            ),
+            (condition(None, None, Some("month")), "interval"),
+        ];
+        for (condition, expected) in cases {
+            let err = parse_stats_range(&condition, today).unwrap_err();
+            assert!(
+                matches!(&err, UseCaseError::InvalidParameter(name) if name == expected),
+                "{condition:?}"
+            );
+        }
+        let (_, _, range) = parse_stats_range(
+            &condition(Some("2026-10-01"), Some("2026-10-01"), Some("week")),
+            today,
+        )
+        .unwrap();
+        assert_eq!(range.interval, TodoStatsInterval::Week);
+    }
+}
@@ -1,4 +1,6 @@
-use domain::model::todo::status::TodoStatus;
+use crate::error::UseCaseError;
+use domain::model::todo::status::{TodoStatus, TodoStatusCode, TodoStatusEntry};
+
 #[derive(Debug, Clone)]
 pub struct TodoStatusView {
     pub id: String,
@@ -16,12 +18,92 @@ impl From<TodoStatus> for TodoStatusView {
     }
 }
 
+/// 상태와 그 상태에서 바꿀 수 있는 다음 상태 목록.
+#[derive(Debug, Clone)]
+pub struct TodoStatusTransitionView {
+    pub status: TodoStatusView,
+    pub next: Vec<TodoStatusView>,
+}
+
+/// 상태 목록의 한 항목. `builtin` 은 기본 상태 여부, `enabled` 는 새로 고를 수 있는지 여부이다.
+#[derive(Debug, Clone)]
+pub struct TodoStatusEntryView {
+    pub status: TodoStatusView,
+    pub builtin: bool,
+    pub enabled: bool,
+}
+
+impl From<TodoStatusEntry> for TodoStatusEntryView {
+    fn from(entry: TodoStatusEntry) -> Self {
+        Self {
+            builtin: !entry.status.code.is_custom(),
+            enabled: entry.disabled_at.is_none(),
+            status: entry.status.into(),
+        }
+    }
+}
+
+/// 사용자 정의 상태 추가 요청.
+pub struct CreateTodoStatus {
+    pub code: String,
+    pub name: String,
+}
+
+impl CreateTodoStatus {
+    pub fn new(code: String, name: String) -> Self {
+        Self { code, name }
+    }
+}
+
+/// 상태 이름의 최대 길이. DB `todo_statuses.name` 컬럼 길이와 같다.
+pub const MAX_STATUS_NAME_LEN: usize = 32;
+
+/// 새 사용자 정의 상태 코드를 해석한다. 형식이 틀리거나 기본 상태 코드이면 `code` 오류를 낸다.
+pub(crate) fn parse_custom_status_code(code: &str) -> Result<TodoStatusCode, UseCaseError> {
+    TodoStatusCode::custom(code).map_err(|_| UseCaseError::InvalidParameter("code".to_string()))
+}
+
+/// 상태 이름을 다듬는다. 비었거나 너무 길면 `name` 오류를 낸다.
+pub(crate) fn parse_status_name(name: &str) -> Result<String, UseCaseError> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > MAX_STATUS_NAME_LEN {
+        return Err(UseCaseError::InvalidParameter("name".to_string()));
+    }
+    Ok(name.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use domain::model::todo::status::{TodoStatus, TodoStatusCode};
     use domain::model::Id;
 
+    #[test]
+    fn todo_status_entry_view_marks_builtin_and_enabled() {
+        let entry = TodoStatusEntry {
+            status: TodoStatus::new(
+                Id::gen(),
+                TodoStatusCode::Custom("review".to_string()),
+                "검토".to_string(),
+            ),
+            disabled_at: Some(chrono::Utc::now()),
+        };
+        let view = TodoStatusEntryView::from(entry);
+        assert_eq!(view.status.code, "review");
+        assert!(!view.builtin);
+        assert!(!view.enabled);
+    }
+
+    #[test]
+    fn parse_status_name_trims_and_checks_length() {
+        assert_eq!(parse_status_name(" 검토 ").unwrap(), "검토");
+        for name in ["  ", &"가".repeat(33)] {
+            let err = parse_status_name(name).unwrap_err();
+            assert!(matches!(err, UseCaseError::InvalidParameter(field) if field == "name"));
+        }
+        assert!(parse_custom_status_code("done").is_err());
+    }
+
     #[test]
     fn todo_status_view_from_todo_status_maps_all_fields() {
         let id: Id<TodoStatus> = Id::gen();
@@ -0,0 +1,70 @@
+use crate::error::UseCaseError;
+use crate::model::todo::{CreateTodo, TodoView};
+use domain::model::todo::status::TodoStatusCode;
+
+/// 한 번에 가져올 수 있는 최대 행 수.
+pub const MAX_IMPORT_ROWS: usize = 1000;
+
+/// 가져올 todo 한 건. `row` 는 파일 안에서의 위치(1부터)로, 오류를 알릴 때 쓴다.
+/// `status_code` 가 없으면 신규 상태로 만든다.
+pub struct ImportTodoRow {
+    pub row: usize,
+    pub source: CreateTodo,
+    pub status_code: Option<String>,
+}
+
+impl ImportTodoRow {
+    pub fn new(row: usize, source: CreateTodo, status_code: Option<String>) -> Self {
+        Self {
+            row,
+            source,
+            status_code,
+        }
+    }
+}
+
+/// 가져오지 못한 행과 그 이유. 응답 문구는 호출한 쪽이 언어에 맞게 정한다.
+#[derive(Debug)]
+pub struct TodoImportErrorView {
+    pub row: usize,
+    pub error: UseCaseError,
+}
+
+/// 가져오기 결과. 가져온 todo 는 요청한 행 순서이다.
+#[derive(Debug)]
+pub struct TodoImportView {
+    pub imported: Vec<TodoView>,
+    pub errors: Vec<TodoImportErrorView>,
+}
+
+/// 가져올 todo 의 상태 코드를 해석한다. 휴지통(`deleted`)이면 `statusCode` 오류를 낸다.
+/// 사용자 정의 상태가 있는지는 가져올 때 확인한다.
+pub(crate) fn parse_import_status(code: Option<&str>) -> Result<TodoStatusCode, UseCaseError> {
+    match code.map(TodoStatusCode::from) {
+        None => Ok(TodoStatusCode::New),
+        Some(TodoStatusCode::Deleted) => {
+            Err(UseCaseError::InvalidParameter("statusCode".to_string()))
+        }
+        Some(code) => Ok(code),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_import_status_defaults_to_new_and_rejects_trash() {
+        assert_eq!(parse_import_status(None).unwrap(), TodoStatusCode::New);
+        assert_eq!(
+            parse_import_status(Some("done")).unwrap(),
+            TodoStatusCode::Done
+        );
+        assert_eq!(
+            parse_import_status(Some("review")).unwrap(),
+            TodoStatusCode::Custom("review".to_string())
+        );
+        let err = parse_import_status(Some("deleted")).unwrap_err();
+        assert!(matches!(err, UseCaseError::InvalidParameter(name) if name == "statusCode"));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::model::todo::TodoView;
+use domain::model::todo::Todo;
+use std::collections::HashMap;
+
+/// 하위 작업을 포함한 todo 트리.
+#[derive(Debug, Clone)]
+pub struct TodoTreeView {
+    pub todo: TodoView,
+    /// 바로 아래 하위 작업. 생성 순이다.
+    pub children: Vec<TodoTreeView>,
+}
+
+impl TodoTreeView {
+    /// 깊이 순으로 정렬된 하위 트리 목록으로 트리를 만든다. 첫 번째 todo 가 뿌리이다.
+    /// 휴지통에 있는 하위 작업과 그 아래는 제외하며, 목록이 비어 있으면 `None` 이다.
+    pub fn from_subtree(todos: Vec<Todo>) -> Option<Self> {
+        let mut todos = todos.into_iter();
+        let root = todos.next()?;
+        let mut children: HashMap<String, Vec<Todo>> = HashMap::new();
+        for todo in todos.filter(|t| t.deleted_at.is_none()) {
+            if let Some(parent_id) = &todo.parent_id {
+                children
+                    .entry(parent_id.value.to_string())
+                    .or_default()
+                    .push(todo);
+            }
+        }
+        Some(Self::build(root, &mut children))
+    }
+
+    fn build(todo: Todo, children: &mut HashMap<String, Vec<Todo>>) -> Self {
+        let own = children
+            .remove(&todo.id.value.to_string())
+            .unwrap_or_default();
+        Self {
+            children: own
+                .into_iter()
+                .map(|child| Self::build(child, children))
+                .collect(),
+            todo: todo.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use domain::model::todo::priority::TodoPriority;
+    use domain::model::todo::status::{TodoStatus, TodoStatusCode};
+    use domain::model::Id;
+
+    fn make_todo(title: &str, parent: Option<&Todo>) -> Todo {
+        Todo {
+            id: Id::gen(),
+            owner_id: Id::gen(),
+            parent_id: parent.map(|p| p.id.clone()),
+            title: title.to_string(),
+            description: String::new(),
+            status: TodoStatus::new(Id::gen(), TodoStatusCode::New, "신규".to_string()),
+            due_at: None,
+            priority: TodoPriority::default(),
+            tags: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+            version: 1,
+            recurrence: None,
+            remind_at: None,
+            project_id: None,
+            archived_at: None,
+            position: "i".to_string(),
+        }
+    }
+
+    #[test]
+    fn from_subtree_nests_children_in_order() {
+        let root = make_todo("root", None);
+        let first = make_todo("first", Some(&root));
+        let second = make_todo("second", Some(&root));
+        let grandchild = make_todo("grandchild", Some(&first));
+
+        let tree = TodoTreeView::from_subtree(vec![root, first, second, grandchild]).unwrap();
+
+        assert_eq!(tree.todo.title, "root");
+        let titles: Vec<&str> = tree
+            .children
+            .iter()
+            .map(|c| c.todo.title.as_str())
+            .collect();
+        assert_eq!(titles, ["first", "second"]);
+        assert_eq!(tree.children[0].children[0].todo.title, "grandchild");
+        assert!(tree.children[1].children.is_empty());
+    }
+
+    #[test]
+    fn from_subtree_skips_deleted_children_and_their_descendants() {
+        let root = make_todo("root", None);
+        let mut deleted = make_todo("deleted", Some(&root));
+        deleted.deleted_at = Some(Utc::now());
+        let orphan = make_todo("orphan", Some(&deleted));
+
+        let tree = TodoTreeView::from_subtree(vec![root, deleted, orphan]).unwrap();
+
+        assert!(tree.children.is_empty());
+    }
+
+    #[test]
+    fn from_subtree_with_empty_list_returns_none() {
+        assert!(TodoTreeView::from_subtree(vec![]).is_none());
+    }
+}
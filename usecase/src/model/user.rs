@@ -7,6 +7,7 @@ pub struct UserView {
     pub username: String,
     pub email: String,
     pub fullname: String,
+    pub locale: Option<String>,
 }
 
 impl From<User> for UserView {
@@ -16,6 +17,7 @@ impl From<User> for UserView {
             username: user.username,
             email: user.email,
             fullname: user.fullname,
+            locale: user.locale,
         }
     }
 }
@@ -89,6 +91,7 @@ mod tests {
         assert_eq!(view.username, "alice");
         assert_eq!(view.email, "alice@example.com");
         assert_eq!(view.fullname, "Alice");
+        assert_eq!(view.locale, None);
     }
 
     #[test]
@@ -0,0 +1,192 @@
+use super::interface::IAttachmentUseCase;
+use crate::error::{ErrorReason, UseCaseError};
+use crate::model::attachment::{
+    normalize_content_type, normalize_file_name, AttachmentDownload, AttachmentLimits,
+    AttachmentView, UploadAttachment,
+};
+use async_trait::async_trait;
+use domain::model::attachment::{Attachment, NewAttachment};
+use domain::model::todo::Todo;
+use domain::model::user::User;
+use domain::model::Id;
+use infra::db::IDatabasePool;
+use infra::repository::attachment::IAttachmentRepository;
+use infra::repository::todo::share::ITodoShareRepository;
+use infra::repository::todo::ITodoRepository;
+use infra::storage::{BlobReader, IBlobStorage};
+use shaku::Component;
+use std::sync::Arc;
+use tokio::io::AsyncReadExt;
+use tracing::warn;
+
+/// Attachment 유스케이스 구현체.
+///
+/// 메타데이터는 DB 에, 본문은 `IBlobStorage` 에 저장한다.
+/// 저장소와 DB 는 한 트랜잭션으로 묶을 수 없으므로 본문을 먼저 저장하고 메타데이터 저장에 실패하면
+/// 본문을 지운다. 삭제는 반대로 메타데이터를 먼저 지운다.
+#[derive(Component)]
+#[shaku(interface = IAttachmentUseCase)]
+pub struct AttachmentUseCase {
+    #[shaku(inject)]
+    db: Arc<dyn IDatabasePool>,
+    #[shaku(inject)]
+    todo_repo: Arc<dyn ITodoRepository>,
+    #[shaku(inject)]
+    attachment_repo: Arc<dyn IAttachmentRepository>,
+    #[shaku(inject)]
+    storage: Arc<dyn IBlobStorage>,
+    #[shaku(inject)]
+    todo_share_repo: Arc<dyn ITodoShareRepository>,
+}
+
+#[async_trait]
+impl IAttachmentUseCase for AttachmentUseCase {
+    async fn find_attachments(
+        &self,
+        user_id: String,
+        todo_id: String,
+    ) -> anyhow::Result<Vec<AttachmentView>> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let todo_id: Id<Todo> = todo_id.try_into()?;
+        self.ensure_todo(&user_id, &todo_id, false).await?;
+        let attachments = self.attachment_repo.find(&todo_id).await?;
+        Ok(attachments.into_iter().map(Into::into).collect())
+    }
+
+    async fn upload_attachment(
+        &self,
+        user_id: String,
+        todo_id: String,
+        source: UploadAttachment,
+        limits: AttachmentLimits,
+        body: BlobReader<'_>,
+    ) -> anyhow::Result<AttachmentView> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let todo_id: Id<Todo> = todo_id.try_into()?;
+        let file_name = normalize_file_name(&source.file_name)?;
+        let content_type = normalize_content_type(&source.content_type, &limits.content_types)?;
+        self.ensure_todo(&user_id, &todo_id, true).await?;
+
+        let id: Id<Attachment> = Id::gen();
+        let storage_key = Attachment::storage_key_of(&todo_id, &id);
+        // 한도보다 1 바이트 더 읽어 보고 넘치면 한도 초과로 본다.
+        let written = self
+            .storage
+            .put(&storage_key, Box::pin(body.take(limits.max_bytes + 1)))
+            .await?;
+        if written > limits.max_bytes {
+            self.storage.delete(&storage_key).await?;
+            return Err(UseCaseError::InvalidParameter("file".to_string()).into());
+        }
+
+        let new_attachment = NewAttachment::new(
+            id,
+            todo_id,
+            user_id,
+            file_name,
+            content_type,
+            written as i64,
+        );
+        let mut tx = self.db.pool().begin().await?;
+        let attachment = match self
+            .attachment_repo
+            .insert_tx(&mut tx, new_attachment)
+            .await
+        {
+            Ok(attachment) => attachment,
+            Err(e) => {
+                drop(tx);
+                self.discard_blob(&storage_key).await;
+                return Err(e);
+            }
+        };
+        tx.commit().await?;
+        Ok(attachment.into())
+    }
+
+    async fn download_attachment(
+        &self,
+        user_id: String,
+        todo_id: String,
+        id: String,
+    ) -> anyhow::Result<AttachmentDownload> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let todo_id: Id<Todo> = todo_id.try_into()?;
+        let id: Id<Attachment> = id.try_into()?;
+        self.ensure_todo(&user_id, &todo_id, false).await?;
+        let attachment = self
+            .attachment_repo
+            .get(&todo_id, &id)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("attachment".to_string()))?;
+        let Some(body) = self.storage.get(&attachment.storage_key).await? else {
+            warn!(storage_key = %attachment.storage_key, "download_attachment: blob is missing");
+            return Err(UseCaseError::NotFound("attachment".to_string()).into());
+        };
+        Ok(AttachmentDownload {
+            attachment: attachment.into(),
+            body,
+        })
+    }
+
+    async fn delete_attachment(
+        &self,
+        user_id: String,
+        todo_id: String,
+        id: String,
+    ) -> anyhow::Result<AttachmentView> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let todo_id: Id<Todo> = todo_id.try_into()?;
+        let id: Id<Attachment> = id.try_into()?;
+        self.ensure_todo(&user_id, &todo_id, true).await?;
+        let attachment = self
+            .attachment_repo
+            .get(&todo_id, &id)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("attachment".to_string()))?;
+        if attachment.uploader_id.value != user_id.value {
+            return Err(UseCaseError::Forbidden(ErrorReason::AttachmentUploaderOnly).into());
+        }
+
+        let mut tx = self.db.pool().begin().await?;
+        let attachment = self
+            .attachment_repo
+            .delete_tx(&mut tx, &todo_id, &id)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("attachment".to_string()))?;
+        tx.commit().await?;
+        self.discard_blob(&attachment.storage_key).await;
+        Ok(attachment.into())
+    }
+}
+
+impl AttachmentUseCase {
+    /// 사용자가 접근할 수 있는 todo 인지 확인한다. 공유받은 todo 는 소유자의 todo 에서 찾으며,
+    /// `write` 인데 읽기 권한만 받았으면 `Forbidden` 이다.
+    async fn ensure_todo(
+        &self,
+        user_id: &Id<User>,
+        todo_id: &Id<Todo>,
+        write: bool,
+    ) -> anyhow::Result<()> {
+        let owner_id = match self.todo_share_repo.get_grant(user_id, todo_id).await? {
+            Some(grant) if write && !grant.permission.can_write() => {
+                return Err(UseCaseError::Forbidden(ErrorReason::ReadOnlyAttachment).into())
+            }
+            Some(grant) => grant.owner_id,
+            None => user_id.clone(),
+        };
+        match self.todo_repo.get(&owner_id, todo_id).await? {
+            Some(_) => Ok(()),
+            None => Err(UseCaseError::NotFound("todo".to_string()).into()),
+        }
+    }
+
+    /// 메타데이터와 짝이 맞지 않게 된 본문을 지운다.
+    /// 요청 자체는 이미 성공·실패가 정해졌으므로 지우지 못하면 로그만 남긴다.
+    async fn discard_blob(&self, storage_key: &str) {
+        if let Err(e) = self.storage.delete(storage_key).await {
+            warn!(storage_key, error = %e, "failed to delete attachment blob");
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::model::attachment::{
+    AttachmentDownload, AttachmentLimits, AttachmentView, UploadAttachment,
+};
+use async_trait::async_trait;
+use infra::storage::BlobReader;
+
+/// Attachment 유스케이스 인터페이스.
+/// 첫 번째 인자로 호출한 사용자의 id, 두 번째 인자로 todo id 를 받는다.
+/// todo 에 접근할 수 없으면 `UseCaseError::NotFound` 이며, 첨부 파일은 올린 사용자만 지울 수 있고
+/// 아니면 `UseCaseError::Forbidden` 을 반환한다.
+/// 공유받은 todo 의 첨부 파일은 누구나 보고 받을 수 있고, 올리거나 지우려면 쓰기(`write`) 권한이 있어야 한다.
+#[async_trait]
+pub trait IAttachmentUseCase: shaku::Interface {
+    async fn find_attachments(
+        &self,
+        user_id: String,
+        todo_id: String,
+    ) -> anyhow::Result<Vec<AttachmentView>>;
+    /// `body` 를 저장소로 흘려보내며 저장한다. `limits.max_bytes` 를 넘으면 저장한 내용을 지우고
+    /// `UseCaseError::InvalidParameter("file")` 을 반환한다.
+    async fn upload_attachment(
+        &self,
+        user_id: String,
+        todo_id: String,
+        source: UploadAttachment,
+        limits: AttachmentLimits,
+        body: BlobReader<'_>,
+    ) -> anyhow::Result<AttachmentView>;
+    async fn download_attachment(
+        &self,
+        user_id: String,
+        todo_id: String,
+        id: String,
+    ) -> anyhow::Result<AttachmentDownload>;
+    async fn delete_attachment(
+        &self,
+        user_id: String,
+        todo_id: String,
+        id: String,
+    ) -> anyhow::Result<AttachmentView>;
+}
@@ -0,0 +1,188 @@
+use super::interface::ICommentUseCase;
+use crate::error::{ErrorReason, UseCaseError};
+use crate::model::comment::{
+    decode_comment_cursor, encode_comment_cursor, normalize_comment_body, CommentPageView,
+    CommentView, CreateComment, SearchCommentCondition, UpdateCommentView,
+};
+use async_trait::async_trait;
+use domain::model::comment::{Comment, NewComment, UpdateComment};
+use domain::model::todo::Todo;
+use domain::model::user::User;
+use domain::model::Id;
+use infra::db::IDatabasePool;
+use infra::repository::comment::ICommentRepository;
+use infra::repository::todo::share::ITodoShareRepository;
+use infra::repository::todo::ITodoRepository;
+use infra::repository::PgTx;
+use shaku::Component;
+use std::sync::Arc;
+
+/// 댓글 목록 기본 페이지 크기.
+const DEFAULT_PAGE_LIMIT: i64 = 20;
+/// 댓글 목록 최대 페이지 크기.
+const MAX_PAGE_LIMIT: i64 = 100;
+
+/// Comment 유스케이스 구현체.
+#[derive(Component)]
+#[shaku(interface = ICommentUseCase)]
+pub struct CommentUseCase {
+    #[shaku(inject)]
+    db: Arc<dyn IDatabasePool>,
+    #[shaku(inject)]
+    todo_repo: Arc<dyn ITodoRepository>,
+    #[shaku(inject)]
+    comment_repo: Arc<dyn ICommentRepository>,
+    #[shaku(inject)]
+    todo_share_repo: Arc<dyn ITodoShareRepository>,
+}
+
+#[async_trait]
+impl ICommentUseCase for CommentUseCase {
+    async fn find_comments(
+        &self,
+        user_id: String,
+        todo_id: String,
+        condition: SearchCommentCondition,
+    ) -> anyhow::Result<CommentPageView> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let todo_id: Id<Todo> = todo_id.try_into()?;
+        let limit = condition.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if !(1..=MAX_PAGE_LIMIT).contains(&limit) {
+            return Err(UseCaseError::InvalidParameter("limit".to_string()).into());
+        }
+        let cursor = condition
+            .cursor
+            .as_deref()
+            .map(decode_comment_cursor)
+            .transpose()?;
+        let owner_id = match self.todo_share_repo.get_grant(&user_id, &todo_id).await? {
+            Some(grant) => grant.owner_id,
+            None => user_id,
+        };
+        if self.todo_repo.get(&owner_id, &todo_id).await?.is_none() {
+            return Err(UseCaseError::NotFound("todo".to_string()).into());
+        }
+
+        // 다음 페이지 존재 여부를 알기 위해 한 건 더 조회한다.
+        let mut comments = self.comment_repo.find(&todo_id, cursor, limit + 1).await?;
+        let has_more = comments.len() as i64 > limit;
+        comments.truncate(limit as usize);
+        let next_cursor = if has_more {
+            comments.last().map(encode_comment_cursor)
+        } else {
+            None
+        };
+        Ok(CommentPageView {
+            comments: comments.into_iter().map(Into::into).collect(),
+            next_cursor,
+            has_more,
+        })
+    }
+
+    async fn create_comment(
+        &self,
+        user_id: String,
+        todo_id: String,
+        source: CreateComment,
+    ) -> anyhow::Result<CommentView> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let todo_id: Id<Todo> = todo_id.try_into()?;
+        let body = normalize_comment_body(&source.body)?;
+        let mut tx = self.db.pool().begin().await?;
+        self.ensure_todo(&mut tx, &user_id, &todo_id).await?;
+        let comment = self
+            .comment_repo
+            .insert_tx(&mut tx, NewComment::new(Id::gen(), todo_id, user_id, body))
+            .await?;
+        tx.commit().await?;
+        Ok(comment.into())
+    }
+
+    async fn update_comment(
+        &self,
+        user_id: String,
+        todo_id: String,
+        source: UpdateCommentView,
+    ) -> anyhow::Result<CommentView> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let todo_id: Id<Todo> = todo_id.try_into()?;
+        let id: Id<Comment> = source.id.try_into()?;
+        let body = normalize_comment_body(&source.body)?;
+        let mut tx = self.db.pool().begin().await?;
+        self.get_own_comment(&mut tx, &user_id, &todo_id, &id)
+            .await?;
+        let comment = self
+            .comment_repo
+            .update_tx(&mut tx, UpdateComment::new(id, todo_id, body))
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("comment".to_string()))?;
+        tx.commit().await?;
+        Ok(comment.into())
+    }
+
+    async fn delete_comment(
+        &self,
+        user_id: String,
+        todo_id: String,
+        id: String,
+    ) -> anyhow::Result<CommentView> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let todo_id: Id<Todo> = todo_id.try_into()?;
+        let id: Id<Comment> = id.try_into()?;
+        let mut tx = self.db.pool().begin().await?;
+        self.get_own_comment(&mut tx, &user_id, &todo_id, &id)
+            .await?;
+        let comment = self
+            .comment_repo
+            .delete_tx(&mut tx, &todo_id, &id)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("comment".to_string()))?;
+        tx.commit().await?;
+        Ok(comment.into())
+    }
+}
+
+impl CommentUseCase {
+    /// 사용자가 댓글을 달거나 고칠 수 있는 todo 인지 확인한다. 공유받은 todo 는 소유자의 todo 에서 찾으며,
+    /// 읽기 권한만 받았으면 `Forbidden` 이다.
+    async fn ensure_todo(
+        &self,
+        tx: &mut PgTx,
+        user_id: &Id<User>,
+        todo_id: &Id<Todo>,
+    ) -> anyhow::Result<()> {
+        let owner_id = match self
+            .todo_share_repo
+            .get_grant_tx(tx, user_id, todo_id)
+            .await?
+        {
+            Some(grant) if grant.permission.can_write() => grant.owner_id,
+            Some(_) => return Err(UseCaseError::Forbidden(ErrorReason::ReadOnlyComment).into()),
+            None => user_id.clone(),
+        };
+        match self.todo_repo.get_tx(tx, &owner_id, todo_id).await? {
+            Some(_) => Ok(()),
+            None => Err(UseCaseError::NotFound("todo".to_string()).into()),
+        }
+    }
+
+    /// 사용자가 작성한 댓글을 조회한다. 다른 사용자의 댓글이면 `UseCaseError::Forbidden` 이다.
+    async fn get_own_comment(
+        &self,
+        tx: &mut PgTx,
+        user_id: &Id<User>,
+        todo_id: &Id<Todo>,
+        id: &Id<Comment>,
+    ) -> anyhow::Result<Comment> {
+        self.ensure_todo(tx, user_id, todo_id).await?;
+        let comment = self
+            .comment_repo
+            .get_tx(tx, todo_id, id)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("comment".to_string()))?;
+        if comment.author_id.value != user_id.value {
+            return Err(UseCaseError::Forbidden(ErrorReason::CommentAuthorOnly).into());
+        }
+        Ok(comment)
+    }
+}
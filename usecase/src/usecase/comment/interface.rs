@@ -0,0 +1,37 @@
+use crate::model::comment::{
+    CommentPageView, CommentView, CreateComment, SearchCommentCondition, UpdateCommentView,
+};
+use async_trait::async_trait;
+
+/// Comment 유스케이스 인터페이스.
+/// 첫 번째 인자로 호출한 사용자의 id, 두 번째 인자로 todo id 를 받는다.
+/// todo 에 접근할 수 없으면 `UseCaseError::NotFound` 이며, 댓글은 작성자만 고치거나 지울 수 있고
+/// 작성자가 아니면 `UseCaseError::Forbidden` 을 반환한다.
+/// 공유받은 todo 의 댓글은 누구나 볼 수 있고, 댓글을 달거나 고치거나 지우려면 쓰기(`write`) 권한이 있어야 한다.
+#[async_trait]
+pub trait ICommentUseCase: shaku::Interface {
+    async fn find_comments(
+        &self,
+        user_id: String,
+        todo_id: String,
+        condition: SearchCommentCondition,
+    ) -> anyhow::Result<CommentPageView>;
+    async fn create_comment(
+        &self,
+        user_id: String,
+        todo_id: String,
+        source: CreateComment,
+    ) -> anyhow::Result<CommentView>;
+    async fn update_comment(
+        &self,
+        user_id: String,
+        todo_id: String,
+        source: UpdateCommentView,
+    ) -> anyhow::Result<CommentView>;
+    async fn delete_comment(
+        &self,
+        user_id: String,
+        todo_id: String,
+        id: String,
+    ) -> anyhow::Result<CommentView>;
+}
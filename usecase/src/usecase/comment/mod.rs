@@ -0,0 +1,5 @@
+mod comment;
+mod interface;
+
+pub use comment::CommentUseCase;
+pub use interface::ICommentUseCase;
@@ -0,0 +1,99 @@
+use super::interface::IIdempotencyUseCase;
+use crate::model::idempotency::{parse_idempotency_key, IdempotencyBegin, IdempotentResponseView};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use domain::model::idempotency::NewIdempotencyKey;
+use domain::model::user::User;
+use domain::model::Id;
+use infra::db::IDatabasePool;
+use infra::repository::idempotency::IIdempotencyRepository;
+use shaku::Component;
+use std::sync::Arc;
+
+/// 처리 중으로 남은 키를 다시 차지할 수 있게 되는 시간(초).
+/// 요청 시간 제한(10초)으로 끊긴 요청은 응답을 남기지 못하므로, 그보다 넉넉히 기다린 뒤 풀어 준다.
+const IN_PROGRESS_TIMEOUT_SECONDS: i64 = 60;
+
+/// 멱등 키 유스케이스 구현체.
+#[derive(Component)]
+#[shaku(interface = IIdempotencyUseCase)]
+pub struct IdempotencyUseCase {
+    #[shaku(inject)]
+    db: Arc<dyn IDatabasePool>,
+    #[shaku(inject)]
+    idempotency_repo: Arc<dyn IIdempotencyRepository>,
+}
+
+#[async_trait]
+impl IIdempotencyUseCase for IdempotencyUseCase {
+    async fn begin(
+        &self,
+        user_id: String,
+        key: String,
+        request_hash: String,
+        ttl_seconds: i64,
+    ) -> anyhow::Result<IdempotencyBegin> {
+        let key = parse_idempotency_key(&key)?;
+        let user_id: Id<User> = user_id.try_into()?;
+        let now = Utc::now();
+        let mut tx = self.db.pool().begin().await?;
+        self.idempotency_repo
+            .delete_expired_tx(&mut tx, &user_id)
+            .await?;
+        let claimed = self
+            .idempotency_repo
+            .claim_tx(
+                &mut tx,
+                NewIdempotencyKey::new(
+                    user_id.clone(),
+                    key.clone(),
+                    request_hash.clone(),
+                    now + Duration::seconds(ttl_seconds),
+                ),
+                now - Duration::seconds(IN_PROGRESS_TIMEOUT_SECONDS),
+            )
+            .await?;
+        tx.commit().await?;
+        if claimed {
+            return Ok(IdempotencyBegin::Proceed);
+        }
+
+        // 차지하지 못한 사이에 키가 풀렸으면 처리 중과 같이 다루어 다시 보내게 한다.
+        Ok(match self.idempotency_repo.get(&user_id, &key).await? {
+            Some(stored) if stored.request_hash != request_hash => IdempotencyBegin::Mismatch,
+            Some(stored) => stored.response.map_or(IdempotencyBegin::InProgress, |r| {
+                IdempotencyBegin::Replay(r.into())
+            }),
+            None => IdempotencyBegin::InProgress,
+        })
+    }
+
+    async fn complete(
+        &self,
+        user_id: String,
+        key: String,
+        response: IdempotentResponseView,
+    ) -> anyhow::Result<()> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let mut tx = self.db.pool().begin().await?;
+        self.idempotency_repo
+            .complete_tx(&mut tx, &user_id, &key, &response.into())
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn release(&self, user_id: String, key: String) -> anyhow::Result<()> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let mut tx = self.db.pool().begin().await?;
+        self.idempotency_repo
+            .release_tx(&mut tx, &user_id, &key)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn purge_expired(&self, limit: i64) -> anyhow::Result<u64> {
+        self.idempotency_repo.delete_all_expired(limit).await
+    }
+}
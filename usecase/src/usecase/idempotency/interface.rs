@@ -0,0 +1,29 @@
+use crate::model::idempotency::{IdempotencyBegin, IdempotentResponseView};
+use async_trait::async_trait;
+
+/// 멱등 키 유스케이스 인터페이스.
+///
+/// 같은 사용자가 같은 키로 다시 보낸 요청에는 처음 응답을 돌려주도록 키와 응답을 `ttl_seconds` 동안 남긴다.
+/// 요청이 같은지는 호출하는 쪽이 만든 `request_hash` 로 가린다.
+#[async_trait]
+pub trait IIdempotencyUseCase: shaku::Interface {
+    /// 키로 요청을 시작한다. 키 형식이 틀리면 `UseCaseError::InvalidParameter` 이다.
+    async fn begin(
+        &self,
+        user_id: String,
+        key: String,
+        request_hash: String,
+        ttl_seconds: i64,
+    ) -> anyhow::Result<IdempotencyBegin>;
+    /// `begin` 이 `Proceed` 였던 요청의 응답을 남긴다.
+    async fn complete(
+        &self,
+        user_id: String,
+        key: String,
+        response: IdempotentResponseView,
+    ) -> anyhow::Result<()>;
+    /// `begin` 이 `Proceed` 였던 요청을 응답을 남기지 않고 끝낸다. 같은 키로 다시 시도할 수 있다.
+    async fn release(&self, user_id: String, key: String) -> anyhow::Result<()>;
+    /// 모든 사용자의 만료된 키를 최대 `limit` 건 지우고 지운 건수를 반환한다.
+    async fn purge_expired(&self, limit: i64) -> anyhow::Result<u64>;
+}
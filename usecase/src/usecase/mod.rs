@@ -1,3 +1,11 @@
+pub mod attachment;
+pub mod comment;
 pub mod health_check;
+pub mod idempotency;
+pub mod project;
+pub mod reminder;
+pub mod share;
+pub mod status;
+pub mod tag;
 pub mod todo;
 pub mod user;
@@ -0,0 +1,38 @@
+use crate::model::project::{CreateProject, ProjectView, UpdateProjectView};
+use async_trait::async_trait;
+
+/// Project 유스케이스 인터페이스.
+/// 첫 번째 인자로 호출한 사용자의 id 를 받으며, 해당 사용자가 소유한 프로젝트만 다룬다.
+/// 이름은 앞뒤 공백을 없애고 저장하며, 사용자 안에서 이미 쓰는 이름이면 `UseCaseError::Conflict` 를 반환한다.
+#[async_trait]
+pub trait IProjectUseCase: shaku::Interface {
+    async fn get_project(&self, user_id: String, id: String)
+        -> anyhow::Result<Option<ProjectView>>;
+    /// 프로젝트 목록. `include_archived` 가 아니면 보관한 프로젝트는 제외한다.
+    async fn find_projects(
+        &self,
+        user_id: String,
+        include_archived: bool,
+    ) -> anyhow::Result<Vec<ProjectView>>;
+    async fn create_project(
+        &self,
+        user_id: String,
+        source: CreateProject,
+    ) -> anyhow::Result<ProjectView>;
+    async fn update_project(
+        &self,
+        user_id: String,
+        source: UpdateProjectView,
+    ) -> anyhow::Result<ProjectView>;
+    /// 프로젝트를 삭제한다. 속한 todo 는 프로젝트 없이 보관이 풀린 채 남는다.
+    async fn delete_project(
+        &self,
+        user_id: String,
+        id: String,
+    ) -> anyhow::Result<Option<ProjectView>>;
+    /// 프로젝트와 속한 todo 를 한 트랜잭션에서 보관한다. 이미 보관했으면 그대로 둔다.
+    /// 프로젝트가 없으면 `UseCaseError::NotFound` 이다.
+    async fn archive_project(&self, user_id: String, id: String) -> anyhow::Result<ProjectView>;
+    /// 프로젝트와 속한 todo 의 보관을 한 트랜잭션에서 푼다.
+    async fn unarchive_project(&self, user_id: String, id: String) -> anyhow::Result<ProjectView>;
+}
@@ -0,0 +1,5 @@
+mod interface;
+mod project;
+
+pub use interface::IProjectUseCase;
+pub use project::ProjectUseCase;
@@ -0,0 +1,179 @@
+use super::interface::IProjectUseCase;
+use crate::error::{ErrorReason, UseCaseError};
+use crate::model::project::{
+    normalize_color, normalize_project_name, CreateProject, ProjectView, UpdateProjectView,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use domain::model::project::{NewProject, Project, UpdateProject, DEFAULT_PROJECT_COLOR};
+use domain::model::user::User;
+use domain::model::Id;
+use infra::db::IDatabasePool;
+use infra::repository::project::IProjectRepository;
+use infra::repository::todo::ITodoRepository;
+use infra::repository::PgTx;
+use shaku::Component;
+use std::sync::Arc;
+
+/// Project 유스케이스 구현체.
+#[derive(Component)]
+#[shaku(interface = IProjectUseCase)]
+pub struct ProjectUseCase {
+    #[shaku(inject)]
+    db: Arc<dyn IDatabasePool>,
+    #[shaku(inject)]
+    project_repo: Arc<dyn IProjectRepository>,
+    #[shaku(inject)]
+    todo_repo: Arc<dyn ITodoRepository>,
+}
+
+#[async_trait]
+impl IProjectUseCase for ProjectUseCase {
+    async fn get_project(
+        &self,
+        user_id: String,
+        id: String,
+    ) -> anyhow::Result<Option<ProjectView>> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let resp = self.project_repo.get(&owner_id, &id.try_into()?).await?;
+        Ok(resp.map(Into::into))
+    }
+
+    async fn find_projects(
+        &self,
+        user_id: String,
+        include_archived: bool,
+    ) -> anyhow::Result<Vec<ProjectView>> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let projects = self
+            .project_repo
+            .find_all(&owner_id, include_archived)
+            .await?;
+        Ok(projects.into_iter().map(Into::into).collect())
+    }
+
+    async fn create_project(
+        &self,
+        user_id: String,
+        source: CreateProject,
+    ) -> anyhow::Result<ProjectView> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let name = normalize_project_name(&source.name)?;
+        let color = match source.color.as_deref() {
+            Some(color) => normalize_color(color)?,
+            None => DEFAULT_PROJECT_COLOR.to_string(),
+        };
+        let mut tx = self.db.pool().begin().await?;
+        self.ensure_name_available(&mut tx, &owner_id, &name, None)
+            .await?;
+        let project = self
+            .project_repo
+            .insert_tx(&mut tx, NewProject::new(Id::gen(), owner_id, name, color))
+            .await?;
+        tx.commit().await?;
+        Ok(project.into())
+    }
+
+    async fn update_project(
+        &self,
+        user_id: String,
+        source: UpdateProjectView,
+    ) -> anyhow::Result<ProjectView> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let id: Id<Project> = source.id.try_into()?;
+        let name = source
+            .name
+            .as_deref()
+            .map(normalize_project_name)
+            .transpose()?;
+        let color = source.color.as_deref().map(normalize_color).transpose()?;
+        let mut tx = self.db.pool().begin().await?;
+        if let Some(name) = &name {
+            self.ensure_name_available(&mut tx, &owner_id, name, Some(&id))
+                .await?;
+        }
+        let project = self
+            .project_repo
+            .update_tx(&mut tx, UpdateProject::new(id, owner_id, name, color))
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("project".to_string()))?;
+        tx.commit().await?;
+        Ok(project.into())
+    }
+
+    async fn delete_project(
+        &self,
+        user_id: String,
+        id: String,
+    ) -> anyhow::Result<Option<ProjectView>> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let id: Id<Project> = id.try_into()?;
+        let mut tx = self.db.pool().begin().await?;
+        // 외래 키는 project_id 만 비우므로 보관 표시는 먼저 지운다.
+        self.todo_repo
+            .set_archived_by_project_tx(&mut tx, &owner_id, &id, None)
+            .await?;
+        let resp = self.project_repo.delete_tx(&mut tx, &owner_id, &id).await?;
+        tx.commit().await?;
+        Ok(resp.map(Into::into))
+    }
+
+    async fn archive_project(&self, user_id: String, id: String) -> anyhow::Result<ProjectView> {
+        self.set_archived(user_id, id, true).await
+    }
+
+    async fn unarchive_project(&self, user_id: String, id: String) -> anyhow::Result<ProjectView> {
+        self.set_archived(user_id, id, false).await
+    }
+}
+
+impl ProjectUseCase {
+    /// 같은 이름의 다른 프로젝트가 있으면 `UseCaseError::Conflict` 를 낸다.
+    /// 이름을 바꾸는 경우 `id` 는 자기 자신이므로 제외한다.
+    async fn ensure_name_available(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        name: &str,
+        id: Option<&Id<Project>>,
+    ) -> anyhow::Result<()> {
+        let existing = self.project_repo.get_by_name_tx(tx, owner_id, name).await?;
+        match existing {
+            Some(project) if id.is_none_or(|id| id.value != project.id.value) => {
+                Err(UseCaseError::Conflict(ErrorReason::ProjectNameTaken(name.to_string())).into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// 프로젝트의 보관 여부를 바꾸고 속한 todo 도 같은 트랜잭션에서 함께 바꾼다.
+    async fn set_archived(
+        &self,
+        user_id: String,
+        id: String,
+        archived: bool,
+    ) -> anyhow::Result<ProjectView> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let id: Id<Project> = id.try_into()?;
+        let mut tx = self.db.pool().begin().await?;
+        let current = self
+            .project_repo
+            .get_tx(&mut tx, &owner_id, &id)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("project".to_string()))?;
+        if current.archived == archived {
+            return Ok(current.into());
+        }
+        let project = self
+            .project_repo
+            .set_archived_tx(&mut tx, &owner_id, &id, archived)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("project".to_string()))?;
+        let archived_at = archived.then(Utc::now);
+        self.todo_repo
+            .set_archived_by_project_tx(&mut tx, &owner_id, &id, archived_at)
+            .await?;
+        tx.commit().await?;
+        Ok(project.into())
+    }
+}
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+/// todo 알림 유스케이스 인터페이스. 백그라운드 작업이 주기적으로 호출한다.
+#[async_trait]
+pub trait IReminderUseCase: shaku::Interface {
+    /// 보낼 때가 된 알림을 최대 `limit` 건 보내고 보낸 건수를 반환한다.
+    /// 보내지 못한 알림은 점점 길게 기다렸다가 다시 보내고, 정해진 횟수만큼 실패하면 더는 보내지 않는다.
+    /// 여러 서버에서 동시에 호출해도 같은 알림을 함께 보내지 않는다.
+    async fn deliver_due_reminders(&self, limit: i64) -> anyhow::Result<usize>;
+}
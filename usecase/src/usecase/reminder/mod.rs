@@ -0,0 +1,5 @@
+mod interface;
+mod reminder;
+
+pub use interface::IReminderUseCase;
+pub use reminder::ReminderUseCase;
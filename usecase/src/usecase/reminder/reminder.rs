@@ -0,0 +1,96 @@
+use super::interface::IReminderUseCase;
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use infra::db::IDatabasePool;
+use infra::notifier::INotifier;
+use infra::repository::todo::reminder::ITodoReminderRepository;
+use shaku::Component;
+use std::sync::Arc;
+use tracing::warn;
+
+/// 잡은 알림을 보내고 결과를 표시할 때까지 다른 서버가 다시 잡지 않는 시간.
+const CLAIM_LEASE: Duration = Duration::minutes(15);
+/// 알림 하나를 보내려 하는 최대 횟수. 이만큼 실패하면 더는 보내지 않는다.
+const MAX_ATTEMPTS: i32 = 5;
+/// 첫 실패 뒤 다시 보내기까지 기다리는 시간. 실패할 때마다 두 배로 늘린다.
+const RETRY_BASE: Duration = Duration::minutes(1);
+/// 다시 보내기까지 기다리는 최대 시간.
+const RETRY_MAX: Duration = Duration::hours(1);
+
+/// Reminder 유스케이스 구현체.
+///
+/// 보낼 알림을 짧은 트랜잭션에서 잡아 커밋한 뒤 트랜잭션 밖에서 보내고, 한 건씩 결과를 표시한다.
+/// 보내지 못한 알림은 점점 길게 기다렸다가 다시 보내므로 앞쪽의 실패한 알림이 뒤의 알림을 막지 않는다.
+/// 보낸 뒤 표시하기 전에 서버가 멈추면 `CLAIM_LEASE` 가 지난 뒤 그 알림을 한 번 더 보낼 수 있다.
+#[derive(Component)]
+#[shaku(interface = IReminderUseCase)]
+pub struct ReminderUseCase {
+    #[shaku(inject)]
+    db: Arc<dyn IDatabasePool>,
+    #[shaku(inject)]
+    reminder_repo: Arc<dyn ITodoReminderRepository>,
+    #[shaku(inject)]
+    notifier: Arc<dyn INotifier>,
+}
+
+/// `attempts` 번째 실패 뒤 다시 보내기까지 기다리는 시간.
+fn retry_delay(attempts: i32) -> Duration {
+    let exponent = attempts.clamp(1, 16) - 1;
+    std::cmp::min(RETRY_BASE * 2i32.pow(exponent as u32), RETRY_MAX)
+}
+
+#[async_trait]
+impl IReminderUseCase for ReminderUseCase {
+    async fn deliver_due_reminders(&self, limit: i64) -> anyhow::Result<usize> {
+        let now = Utc::now();
+        let mut tx = self.db.pool().begin().await?;
+        let reminders = self
+            .reminder_repo
+            .claim_due_tx(&mut tx, now, now + CLAIM_LEASE, MAX_ATTEMPTS, limit)
+            .await?;
+        tx.commit().await?;
+
+        let mut delivered = 0;
+        for reminder in reminders {
+            let result = self.notifier.notify(&reminder).await;
+            let mut tx = self.db.pool().begin().await?;
+            match result {
+                Ok(()) => {
+                    self.reminder_repo
+                        .mark_delivered_tx(&mut tx, &reminder, Utc::now())
+                        .await?;
+                    delivered += 1;
+                }
+                Err(e) => {
+                    warn!(
+                        todo_id = %reminder.todo_id.value,
+                        attempts = reminder.attempts,
+                        max_attempts = MAX_ATTEMPTS,
+                        error = %e,
+                        "failed to deliver reminder"
+                    );
+                    let retry_at = Utc::now() + retry_delay(reminder.attempts);
+                    self.reminder_repo
+                        .mark_failed_tx(&mut tx, &reminder, retry_at)
+                        .await?;
+                }
+            }
+            tx.commit().await?;
+        }
+        Ok(delivered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay_doubles_up_to_max() {
+        assert_eq!(retry_delay(1), Duration::minutes(1));
+        assert_eq!(retry_delay(2), Duration::minutes(2));
+        assert_eq!(retry_delay(4), Duration::minutes(8));
+        assert_eq!(retry_delay(7), Duration::hours(1));
+        assert_eq!(retry_delay(i32::MAX), Duration::hours(1));
+    }
+}
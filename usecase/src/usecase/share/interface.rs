@@ -0,0 +1,31 @@
+use crate::model::todo::share::{ShareTodo, TodoShareView};
+use async_trait::async_trait;
+
+/// Todo 공유 유스케이스 인터페이스.
+/// 첫 번째 인자로 호출한 사용자의 id, 두 번째 인자로 todo id 를 받는다.
+/// 공유 목록 조회와 공유 추가·삭제는 소유자만 할 수 있으며, 공유받은 사용자가 호출하면
+/// `UseCaseError::Forbidden`, todo 에 접근할 수 없으면 `UseCaseError::NotFound` 이다.
+/// 공유할 사용자는 이름으로 찾으며, 없는 사용자이거나 자기 자신이면 `UseCaseError::InvalidParameter` 이다.
+/// todo 를 공유하면 그 아래의 하위 작업도 같은 권한으로 공유된다.
+#[async_trait]
+pub trait ITodoShareUseCase: shaku::Interface {
+    async fn find_shares(
+        &self,
+        user_id: String,
+        todo_id: String,
+    ) -> anyhow::Result<Vec<TodoShareView>>;
+    /// 공유를 추가한다. 이미 공유한 사용자이면 권한을 바꾼다.
+    async fn share_todo(
+        &self,
+        user_id: String,
+        todo_id: String,
+        source: ShareTodo,
+    ) -> anyhow::Result<TodoShareView>;
+    /// 공유를 취소한다. 공유하지 않은 사용자이면 `UseCaseError::NotFound` 이다.
+    async fn unshare_todo(
+        &self,
+        user_id: String,
+        todo_id: String,
+        username: String,
+    ) -> anyhow::Result<TodoShareView>;
+}
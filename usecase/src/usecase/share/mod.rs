@@ -0,0 +1,5 @@
+mod interface;
+mod share;
+
+pub use interface::ITodoShareUseCase;
+pub use share::TodoShareUseCase;
@@ -0,0 +1,121 @@
+use super::interface::ITodoShareUseCase;
+use crate::error::{ErrorReason, UseCaseError};
+use crate::model::todo::share::{parse_share_permission, ShareTodo, TodoShareView};
+use async_trait::async_trait;
+use domain::model::todo::share::NewTodoShare;
+use domain::model::todo::Todo;
+use domain::model::user::User;
+use domain::model::Id;
+use infra::db::IDatabasePool;
+use infra::repository::todo::share::ITodoShareRepository;
+use infra::repository::todo::ITodoRepository;
+use infra::repository::user::IUserRepository;
+use infra::repository::PgTx;
+use shaku::Component;
+use std::sync::Arc;
+
+/// Todo 공유 유스케이스 구현체.
+#[derive(Component)]
+#[shaku(interface = ITodoShareUseCase)]
+pub struct TodoShareUseCase {
+    #[shaku(inject)]
+    db: Arc<dyn IDatabasePool>,
+    #[shaku(inject)]
+    todo_repo: Arc<dyn ITodoRepository>,
+    #[shaku(inject)]
+    todo_share_repo: Arc<dyn ITodoShareRepository>,
+    #[shaku(inject)]
+    user_repo: Arc<dyn IUserRepository>,
+}
+
+#[async_trait]
+impl ITodoShareUseCase for TodoShareUseCase {
+    async fn find_shares(
+        &self,
+        user_id: String,
+        todo_id: String,
+    ) -> anyhow::Result<Vec<TodoShareView>> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let todo_id: Id<Todo> = todo_id.try_into()?;
+        let mut tx = self.db.pool().begin().await?;
+        self.ensure_owner(&mut tx, &owner_id, &todo_id).await?;
+        tx.commit().await?;
+        let shares = self.todo_share_repo.find(&todo_id).await?;
+        Ok(shares.into_iter().map(Into::into).collect())
+    }
+
+    async fn share_todo(
+        &self,
+        user_id: String,
+        todo_id: String,
+        source: ShareTodo,
+    ) -> anyhow::Result<TodoShareView> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let todo_id: Id<Todo> = todo_id.try_into()?;
+        let permission = parse_share_permission(&source.permission)?;
+        let mut tx = self.db.pool().begin().await?;
+        self.ensure_owner(&mut tx, &owner_id, &todo_id).await?;
+        let target = self.find_target(&mut tx, &source.username).await?;
+        if target.id.value == owner_id.value {
+            return Err(UseCaseError::InvalidParameter("username".to_string()).into());
+        }
+        let share = self
+            .todo_share_repo
+            .upsert_tx(&mut tx, NewTodoShare::new(todo_id, target.id, permission))
+            .await?;
+        tx.commit().await?;
+        Ok(share.into())
+    }
+
+    async fn unshare_todo(
+        &self,
+        user_id: String,
+        todo_id: String,
+        username: String,
+    ) -> anyhow::Result<TodoShareView> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let todo_id: Id<Todo> = todo_id.try_into()?;
+        let mut tx = self.db.pool().begin().await?;
+        self.ensure_owner(&mut tx, &owner_id, &todo_id).await?;
+        let target = self.find_target(&mut tx, &username).await?;
+        let share = self
+            .todo_share_repo
+            .delete_tx(&mut tx, &todo_id, &target.id)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("share".to_string()))?;
+        tx.commit().await?;
+        Ok(share.into())
+    }
+}
+
+impl TodoShareUseCase {
+    /// 호출한 사용자가 todo 의 소유자인지 확인한다.
+    /// 공유받은 사용자이면 `Forbidden`, 접근할 수 없는 todo 이면 `NotFound` 이다.
+    async fn ensure_owner(
+        &self,
+        tx: &mut PgTx,
+        user_id: &Id<User>,
+        todo_id: &Id<Todo>,
+    ) -> anyhow::Result<()> {
+        if self.todo_repo.get_tx(tx, user_id, todo_id).await?.is_some() {
+            return Ok(());
+        }
+        if self
+            .todo_share_repo
+            .get_grant_tx(tx, user_id, todo_id)
+            .await?
+            .is_some()
+        {
+            return Err(UseCaseError::Forbidden(ErrorReason::ShareOwnerOnly).into());
+        }
+        Err(UseCaseError::NotFound("todo".to_string()).into())
+    }
+
+    /// 공유할 사용자를 이름으로 찾는다. 없으면 `username` 파라미터 오류를 낸다.
+    async fn find_target(&self, tx: &mut PgTx, username: &str) -> anyhow::Result<User> {
+        self.user_repo
+            .get_user_by_username_tx(tx, username.trim())
+            .await?
+            .ok_or_else(|| UseCaseError::InvalidParameter("username".to_string()).into())
+    }
+}
@@ -0,0 +1,24 @@
+use crate::model::todo::status::{CreateTodoStatus, TodoStatusEntryView};
+use async_trait::async_trait;
+
+/// Todo 상태 목록 유스케이스 인터페이스.
+/// 상태는 코드로 찾으며, 없는 코드이면 `UseCaseError::NotFound` 이다.
+/// 관리자만 부를 수 있도록 하는 것은 호출하는 쪽의 책임이다.
+#[async_trait]
+pub trait ITodoStatusUseCase: shaku::Interface {
+    /// 끈 상태까지 모든 상태를 기본 상태, 사용자 정의 상태 순으로 조회한다.
+    async fn find_statuses(&self) -> anyhow::Result<Vec<TodoStatusEntryView>>;
+    /// 사용자 정의 상태를 추가한다. 코드 형식이 틀리면 `UseCaseError::InvalidParameter`,
+    /// 이미 있는 코드이면 `UseCaseError::Conflict` 이다.
+    async fn create_status(&self, source: CreateTodoStatus) -> anyhow::Result<TodoStatusEntryView>;
+    /// 상태 이름을 바꾼다. 기본 상태도 바꿀 수 있다.
+    async fn rename_status(
+        &self,
+        code: String,
+        name: String,
+    ) -> anyhow::Result<TodoStatusEntryView>;
+    /// 상태를 꺼서 더는 고를 수 없게 한다. 기본 상태는 끌 수 없으며 `UseCaseError::Conflict` 이다.
+    async fn disable_status(&self, code: String) -> anyhow::Result<TodoStatusEntryView>;
+    /// 끈 상태를 다시 켠다.
+    async fn enable_status(&self, code: String) -> anyhow::Result<TodoStatusEntryView>;
+}
@@ -0,0 +1,5 @@
+mod interface;
+mod status;
+
+pub use interface::ITodoStatusUseCase;
+pub use status::TodoStatusUseCase;
@@ -0,0 +1,95 @@
+use super::interface::ITodoStatusUseCase;
+use crate::error::{ErrorReason, UseCaseError};
+use crate::model::todo::status::{
+    parse_custom_status_code, parse_status_name, CreateTodoStatus, TodoStatusEntryView,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use domain::model::todo::status::{NewTodoStatus, TodoStatusCode};
+use domain::model::Id;
+use infra::db::IDatabasePool;
+use infra::repository::todo::status::ITodoStatusRepository;
+use shaku::Component;
+use std::sync::Arc;
+
+/// Todo 상태 목록 유스케이스 구현체.
+#[derive(Component)]
+#[shaku(interface = ITodoStatusUseCase)]
+pub struct TodoStatusUseCase {
+    #[shaku(inject)]
+    db: Arc<dyn IDatabasePool>,
+    #[shaku(inject)]
+    todo_status_repo: Arc<dyn ITodoStatusRepository>,
+}
+
+#[async_trait]
+impl ITodoStatusUseCase for TodoStatusUseCase {
+    async fn find_statuses(&self) -> anyhow::Result<Vec<TodoStatusEntryView>> {
+        let entries = self.todo_status_repo.find_entries().await?;
+        Ok(entries.into_iter().map(Into::into).collect())
+    }
+
+    async fn create_status(&self, source: CreateTodoStatus) -> anyhow::Result<TodoStatusEntryView> {
+        let code = parse_custom_status_code(&source.code)?;
+        let name = parse_status_name(&source.name)?;
+        let mut tx = self.db.pool().begin().await?;
+        if self
+            .todo_status_repo
+            .get_entry_by_code_tx(&mut tx, code.as_str())
+            .await?
+            .is_some()
+        {
+            return Err(UseCaseError::Conflict(ErrorReason::StatusCodeTaken(
+                code.as_str().to_string(),
+            ))
+            .into());
+        }
+        let entry = self
+            .todo_status_repo
+            .insert_tx(&mut tx, NewTodoStatus::new(Id::gen(), code, name))
+            .await?;
+        tx.commit().await?;
+        Ok(entry.into())
+    }
+
+    async fn rename_status(
+        &self,
+        code: String,
+        name: String,
+    ) -> anyhow::Result<TodoStatusEntryView> {
+        let name = parse_status_name(&name)?;
+        let mut tx = self.db.pool().begin().await?;
+        let entry = self
+            .todo_status_repo
+            .rename_tx(&mut tx, &code, &name)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("status".to_string()))?;
+        tx.commit().await?;
+        Ok(entry.into())
+    }
+
+    async fn disable_status(&self, code: String) -> anyhow::Result<TodoStatusEntryView> {
+        if !TodoStatusCode::from(code.as_str()).is_custom() {
+            return Err(UseCaseError::Conflict(ErrorReason::BuiltInStatus(code)).into());
+        }
+        let mut tx = self.db.pool().begin().await?;
+        let entry = self
+            .todo_status_repo
+            .set_disabled_tx(&mut tx, &code, Some(Utc::now()))
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("status".to_string()))?;
+        tx.commit().await?;
+        Ok(entry.into())
+    }
+
+    async fn enable_status(&self, code: String) -> anyhow::Result<TodoStatusEntryView> {
+        let mut tx = self.db.pool().begin().await?;
+        let entry = self
+            .todo_status_repo
+            .set_disabled_tx(&mut tx, &code, None)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("status".to_string()))?;
+        tx.commit().await?;
+        Ok(entry.into())
+    }
+}
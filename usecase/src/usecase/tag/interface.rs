@@ -0,0 +1,15 @@
+use crate::model::tag::{CreateTag, TagView, UpdateTagView};
+use async_trait::async_trait;
+
+/// Tag 유스케이스 인터페이스.
+/// 첫 번째 인자로 호출한 사용자의 id 를 받으며, 해당 사용자가 소유한 태그만 다룬다.
+/// 이름은 앞뒤 공백을 없애고 저장하며, 사용자 안에서 이미 쓰는 이름이면 `UseCaseError::Conflict` 를 반환한다.
+#[async_trait]
+pub trait ITagUseCase: shaku::Interface {
+    async fn get_tag(&self, user_id: String, id: String) -> anyhow::Result<Option<TagView>>;
+    async fn find_tags(&self, user_id: String) -> anyhow::Result<Vec<TagView>>;
+    async fn create_tag(&self, user_id: String, source: CreateTag) -> anyhow::Result<TagView>;
+    async fn update_tag(&self, user_id: String, source: UpdateTagView) -> anyhow::Result<TagView>;
+    /// 태그를 삭제한다. 붙어 있던 todo 에서도 떨어진다.
+    async fn delete_tag(&self, user_id: String, id: String) -> anyhow::Result<Option<TagView>>;
+}
@@ -0,0 +1,98 @@
+use super::interface::ITagUseCase;
+use crate::error::{ErrorReason, UseCaseError};
+use crate::model::tag::{normalize_tag_name, CreateTag, TagView, UpdateTagView};
+use async_trait::async_trait;
+use domain::model::tag::{NewTag, Tag, UpdateTag};
+use domain::model::user::User;
+use domain::model::Id;
+use infra::db::IDatabasePool;
+use infra::repository::tag::ITagRepository;
+use infra::repository::PgTx;
+use shaku::Component;
+use std::sync::Arc;
+
+/// Tag 유스케이스 구현체.
+#[derive(Component)]
+#[shaku(interface = ITagUseCase)]
+pub struct TagUseCase {
+    #[shaku(inject)]
+    db: Arc<dyn IDatabasePool>,
+    #[shaku(inject)]
+    tag_repo: Arc<dyn ITagRepository>,
+}
+
+#[async_trait]
+impl ITagUseCase for TagUseCase {
+    async fn get_tag(&self, user_id: String, id: String) -> anyhow::Result<Option<TagView>> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let resp = self.tag_repo.get(&owner_id, &id.try_into()?).await?;
+        Ok(resp.map(Into::into))
+    }
+
+    async fn find_tags(&self, user_id: String) -> anyhow::Result<Vec<TagView>> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let tags = self.tag_repo.find_all(&owner_id).await?;
+        Ok(tags.into_iter().map(Into::into).collect())
+    }
+
+    async fn create_tag(&self, user_id: String, source: CreateTag) -> anyhow::Result<TagView> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let name = normalize_tag_name(&source.name)?;
+        let mut tx = self.db.pool().begin().await?;
+        self.ensure_name_available(&mut tx, &owner_id, &name, None)
+            .await?;
+        let tag = self
+            .tag_repo
+            .insert_tx(&mut tx, NewTag::new(Id::gen(), owner_id, name))
+            .await?;
+        tx.commit().await?;
+        Ok(tag.into())
+    }
+
+    async fn update_tag(&self, user_id: String, source: UpdateTagView) -> anyhow::Result<TagView> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let id: Id<Tag> = source.id.try_into()?;
+        let name = normalize_tag_name(&source.name)?;
+        let mut tx = self.db.pool().begin().await?;
+        self.ensure_name_available(&mut tx, &owner_id, &name, Some(&id))
+            .await?;
+        let tag = self
+            .tag_repo
+            .update_tx(&mut tx, UpdateTag::new(id, owner_id, name))
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("tag".to_string()))?;
+        tx.commit().await?;
+        Ok(tag.into())
+    }
+
+    async fn delete_tag(&self, user_id: String, id: String) -> anyhow::Result<Option<TagView>> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let mut tx = self.db.pool().begin().await?;
+        let resp = self
+            .tag_repo
+            .delete_tx(&mut tx, &owner_id, &id.try_into()?)
+            .await?;
+        tx.commit().await?;
+        Ok(resp.map(Into::into))
+    }
+}
+
+impl TagUseCase {
+    /// 같은 이름의 다른 태그가 있으면 `UseCaseError::Conflict` 를 낸다.
+    /// 이름을 바꾸는 경우 `id` 는 자기 자신이므로 제외한다.
+    async fn ensure_name_available(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        name: &str,
+        id: Option<&Id<Tag>>,
+    ) -> anyhow::Result<()> {
+        let existing = self.tag_repo.get_by_name_tx(tx, owner_id, name).await?;
+        match existing {
+            Some(tag) if id.is_none_or(|id| id.value != tag.id.value) => {
+                Err(UseCaseError::Conflict(ErrorReason::TagNameTaken(name.to_string())).into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
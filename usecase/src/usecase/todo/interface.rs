@@ -1,20 +1,157 @@
+use crate::model::todo::batch::TodoBatchOperation;
+use crate::model::todo::history::{SearchTodoHistoryCondition, TodoHistoryPageView};
+use crate::model::todo::recurrence::TodoOccurrencesView;
+use crate::model::todo::stats::{TodoStatsCondition, TodoStatsView};
+use crate::model::todo::status::TodoStatusTransitionView;
+use crate::model::todo::transfer::{ImportTodoRow, TodoImportView};
+use crate::model::todo::tree::TodoTreeView;
 use crate::model::todo::{
-    CreateTodo, SearchTodoCondition, TodoView, UpdateTodoView, UpsertTodoView,
+    CreateTodo, SearchTodoCondition, TodoPageView, TodoView, UpdateTodoView, UpsertTodoView,
 };
 use async_trait::async_trait;
 
 /// Todo 유스케이스 인터페이스.
+/// todo 를 다루는 메서드는 첫 번째 인자로 호출한 사용자의 id 를 받으며, 해당 사용자가 소유한 todo 만 다룬다.
+/// 단, 공유받은 todo 는 조회·하위 작업 조회·변경 이력·반복 회차 미리보기를 할 수 있고, 쓰기(`write`) 권한이면 수정·upsert·알림 미루기도 할 수 있다.
+/// 읽기 권한으로 고치려 하거나 공유받은 사용자가 태그·상위 todo·프로젝트를 바꾸거나 삭제·영구 삭제·복원을 요청하면
+/// `UseCaseError::Forbidden` 이다. 공유받은 사용자가 고친 이력에는 그 사용자가 남는다.
+/// 상태 변경은 `TodoStatusCode::can_transition_to` 를 따르며, 허용되지 않으면 `UseCaseError::Conflict` 를 반환한다.
+/// 상위 todo 는 같은 사용자의 휴지통에 없는 todo 여야 하며, 자기 자신이나 하위 작업 아래로 옮기면
+/// `UseCaseError::Conflict` 를 반환한다.
+/// todo 를 만들거나 옮길 프로젝트는 같은 사용자의 보관하지 않은 프로젝트여야 하며, 아니면 `UseCaseError::InvalidParameter` 이다.
+/// 수정·upsert·휴지통 이동·복원으로 필드가 바뀌면 같은 트랜잭션에서 변경 이력을 남긴다.
+/// 수정·upsert·삭제에 기대 버전을 주면 현재 버전과 다를 때 `UseCaseError::PreconditionFailed` 를 반환한다.
+/// 반복 todo 를 수정으로 완료하면 같은 트랜잭션에서 다음 회차 todo 를 만들고 반복 규칙을 그쪽으로 옮긴다.
 #[async_trait]
 pub trait ITodoUseCase: shaku::Interface {
-    async fn get_todo(&self, id: String) -> anyhow::Result<Option<TodoView>>;
-    async fn find_todo(&self, condition: SearchTodoCondition) -> anyhow::Result<Vec<TodoView>>;
-    async fn create_todo(&self, source: CreateTodo) -> anyhow::Result<TodoView>;
-    async fn update_todo(&self, source: UpdateTodoView) -> anyhow::Result<TodoView>;
-    async fn upsert_todo(&self, source: UpsertTodoView) -> anyhow::Result<TodoView>;
+    async fn get_todo(&self, user_id: String, id: String) -> anyhow::Result<Option<TodoView>>;
+    /// todo 와 그 아래 모든 하위 작업을 트리로 조회한다. 휴지통에 있는 하위 작업은 제외한다.
+    async fn get_todo_tree(
+        &self,
+        user_id: String,
+        id: String,
+    ) -> anyhow::Result<Option<TodoTreeView>>;
+    async fn find_todo(
+        &self,
+        user_id: String,
+        condition: SearchTodoCondition,
+    ) -> anyhow::Result<TodoPageView>;
+    /// todo 의 바로 아래 하위 작업을 `condition` 으로 조회한다. todo 가 없으면 `UseCaseError::NotFound` 이다.
+    async fn find_children(
+        &self,
+        user_id: String,
+        id: String,
+        condition: SearchTodoCondition,
+    ) -> anyhow::Result<TodoPageView>;
+    /// 프로젝트의 todo 를 보관한 것까지 `condition` 으로 조회한다. 프로젝트가 없으면 `UseCaseError::NotFound` 이다.
+    async fn find_project_todos(
+        &self,
+        user_id: String,
+        project_id: String,
+        condition: SearchTodoCondition,
+    ) -> anyhow::Result<TodoPageView>;
+    async fn create_todo(&self, user_id: String, source: CreateTodo) -> anyhow::Result<TodoView>;
+    async fn update_todo(
+        &self,
+        user_id: String,
+        source: UpdateTodoView,
+    ) -> anyhow::Result<TodoView>;
+    async fn upsert_todo(
+        &self,
+        user_id: String,
+        source: UpsertTodoView,
+    ) -> anyhow::Result<TodoView>;
     async fn create_and_update_todo(
         &self,
+        user_id: String,
         create_source: CreateTodo,
         update_source: UpdateTodoView,
     ) -> anyhow::Result<(TodoView, TodoView)>;
-    async fn delete_todo(&self, id: String) -> anyhow::Result<Option<TodoView>>;
+    /// 작업을 요청한 순서대로 한 트랜잭션에서 실행하고 작업마다 결과 todo 를 같은 순서로 반환한다.
+    /// 하나라도 실패하면 모두 되돌리고 실패한 작업 위치를 담은 `TodoBatchError` 를 반환한다.
+    /// 휴지통으로 옮길 todo 가 없으면 실패로 본다.
+    async fn run_batch(
+        &self,
+        user_id: String,
+        operations: Vec<TodoBatchOperation>,
+    ) -> anyhow::Result<Vec<TodoView>>;
+    /// todo 를 휴지통(`deleted` 상태)으로 옮긴다. 이미 휴지통에 있으면 그대로 반환한다.
+    async fn delete_todo(
+        &self,
+        user_id: String,
+        id: String,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<Option<TodoView>>;
+    /// 휴지통의 todo 를 영구 삭제한다. 휴지통에 없으면 `UseCaseError::Conflict` 이다.
+    async fn purge_todo(
+        &self,
+        user_id: String,
+        id: String,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<Option<TodoView>>;
+    /// 휴지통의 todo 를 삭제 직전 상태로 되돌린다. 휴지통에 없으면 `UseCaseError::Conflict` 이다.
+    async fn restore_todo(&self, user_id: String, id: String) -> anyhow::Result<TodoView>;
+    /// 휴지통에서 `retention_days` 일이 지난 todo 를 영구 삭제하고 삭제한 건수를 반환한다.
+    async fn purge_deleted_todos(
+        &self,
+        user_id: String,
+        retention_days: i64,
+    ) -> anyhow::Result<u64>;
+    /// todo 의 변경 이력을 최신순으로 조회한다. todo 가 없으면 `UseCaseError::NotFound` 이다.
+    async fn find_history(
+        &self,
+        user_id: String,
+        id: String,
+        condition: SearchTodoHistoryCondition,
+    ) -> anyhow::Result<TodoHistoryPageView>;
+    /// 반복 todo 의 다음 회차를 마감일(없으면 지금) 이후부터 최대 `limit` 개 미리 본다.
+    /// todo 가 없으면 `UseCaseError::NotFound` 이다.
+    async fn get_occurrences(
+        &self,
+        user_id: String,
+        id: String,
+        limit: Option<i64>,
+    ) -> anyhow::Result<TodoOccurrencesView>;
+    /// 알림을 지금부터 `minutes` 분 뒤로 미루고 다시 보내도록 한다. 알림이 없던 todo 에도 새로 건다.
+    /// `minutes` 가 1 ~ 10080(7일) 밖이면 `UseCaseError::InvalidParameter` 이다.
+    async fn snooze_todo(
+        &self,
+        user_id: String,
+        id: String,
+        minutes: i64,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<TodoView>;
+    /// todo 를 직접 정한 순서에서 `after` 바로 뒤나 `before` 바로 앞으로 옮긴다. 둘 다 주면 그 사이로 옮긴다.
+    /// 옮긴 todo 의 순위만 바꾸고 그 버전을 올리며, 사이에 자리가 없으면 사용자의 todo 를 다시 배치한 뒤 옮긴다.
+    /// 이웃을 하나도 주지 않았거나, 이웃이 없는 todo 이거나, `after` 가 `before` 보다 뒤이면
+    /// `UseCaseError::InvalidParameter` 이다.
+    async fn move_todo(
+        &self,
+        user_id: String,
+        id: String,
+        before: Option<String>,
+        after: Option<String>,
+    ) -> anyhow::Result<TodoView>;
+    async fn get_status_transitions(&self) -> anyhow::Result<Vec<TodoStatusTransitionView>>;
+    /// 내보낼 todo 를 만든 순서로 한 페이지씩 조회한다. 보관한 todo 는 포함하고 휴지통의 todo 는 제외한다.
+    /// 다음 페이지는 `next_cursor` 로 이어서 조회한다.
+    async fn export_todos(
+        &self,
+        user_id: String,
+        cursor: Option<String>,
+    ) -> anyhow::Result<TodoPageView>;
+    /// 행마다 todo 를 만들고, 만들 수 없는 행은 건너뛰며 이유를 모은다. 가져온 행은 한 트랜잭션에서 만든다.
+    /// 한 번에 1000 행까지 가져올 수 있으며 넘으면 `UseCaseError::InvalidParameter` 이다.
+    async fn import_todos(
+        &self,
+        user_id: String,
+        rows: Vec<ImportTodoRow>,
+    ) -> anyhow::Result<TodoImportView>;
+    /// 자신의 todo 의 상태별 건수, 기간 안의 생성·완료 추이, 평균 완료 시간, 지연 건수를 조회한다.
+    /// 조건이 틀리면 `UseCaseError::InvalidParameter` 이다.
+    async fn get_stats(
+        &self,
+        user_id: String,
+        condition: TodoStatsCondition,
+    ) -> anyhow::Result<TodoStatsView>;
 }
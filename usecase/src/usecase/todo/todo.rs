@@ -1,14 +1,64 @@
 use super::interface::ITodoUseCase;
+use crate::error::{ErrorReason, TodoBatchError, UseCaseError};
+use crate::model::todo::batch::TodoBatchOperation;
+use crate::model::todo::cursor::{
+    decode_cursor, decode_search_cursor, encode_cursor, encode_search_cursor,
+};
+use crate::model::todo::history::{
+    decode_history_cursor, encode_history_cursor, SearchTodoHistoryCondition, TodoHistoryPageView,
+};
+use crate::model::todo::recurrence::TodoOccurrencesView;
+use crate::model::todo::stats::{parse_stats_range, TodoStatsCondition, TodoStatsView};
+use crate::model::todo::status::TodoStatusTransitionView;
+use crate::model::todo::transfer::{
+    parse_import_status, ImportTodoRow, TodoImportErrorView, TodoImportView, MAX_IMPORT_ROWS,
+};
+use crate::model::todo::tree::TodoTreeView;
 use crate::model::todo::{
-    CreateTodo, SearchTodoCondition, TodoView, UpdateTodoView, UpsertTodoView,
+    parse_due_at, parse_neighbour_id, parse_parent_id, parse_priority, parse_project_id,
+    parse_recurrence, parse_remind_at, CreateTodo, SearchTodoCondition, TodoPageView, TodoView,
+    UpdateTodoView, UpsertTodoView,
 };
 use async_trait::async_trait;
-use domain::model::todo::{UpdateTodo, UpsertTodo};
+use chrono::{DateTime, Duration, Utc};
+use domain::model::project::Project;
+use domain::model::tag::Tag;
+use domain::model::todo::event::{NewTodoEvent, TodoFieldChange};
+use domain::model::todo::filter::{TodoFilter, TodoSort};
+use domain::model::todo::rank::rank_between;
+use domain::model::todo::recurrence::RecurrenceRule;
+use domain::model::todo::status::{TodoStatus, TodoStatusCode};
+use domain::model::todo::{NewTodo, Todo, UpdateTodo, UpsertTodo};
+use domain::model::user::User;
+use domain::model::{FieldUpdate, Id};
 use infra::db::IDatabasePool;
+use infra::repository::attachment::IAttachmentRepository;
+use infra::repository::project::IProjectRepository;
+use infra::repository::tag::ITagRepository;
+use infra::repository::todo::event::ITodoEventRepository;
+use infra::repository::todo::share::ITodoShareRepository;
 use infra::repository::todo::status::ITodoStatusRepository;
-use infra::repository::todo::ITodoRepository;
+use infra::repository::todo::{Adjacent, ITodoRepository};
+use infra::repository::PgTx;
+use infra::storage::IBlobStorage;
 use shaku::Component;
 use std::sync::Arc;
+use tracing::warn;
+
+/// 목록 조회 기본 페이지 크기.
+const DEFAULT_PAGE_LIMIT: i64 = 20;
+/// 목록 조회 최대 페이지 크기.
+const MAX_PAGE_LIMIT: i64 = 100;
+/// 한 번에 일괄 처리할 수 있는 최대 작업 수.
+const MAX_BATCH_OPERATIONS: usize = 100;
+/// 반복 회차 미리보기 기본 개수.
+const DEFAULT_OCCURRENCE_LIMIT: i64 = 5;
+/// 반복 회차 미리보기 최대 개수.
+const MAX_OCCURRENCE_LIMIT: i64 = 50;
+/// 알림을 한 번에 미룰 수 있는 최대 시간(분). 7일이다.
+const MAX_SNOOZE_MINUTES: i64 = 7 * 24 * 60;
+/// 내보내기 한 페이지 크기.
+const EXPORT_PAGE_LIMIT: i64 = 100;
 
 /// Todo 유스케이스 구현체.
 #[derive(Component)]
@@ -20,107 +70,1280 @@ pub struct TodoUseCase {
     todo_repo: Arc<dyn ITodoRepository>,
     #[shaku(inject)]
     todo_status_repo: Arc<dyn ITodoStatusRepository>,
+    #[shaku(inject)]
+    tag_repo: Arc<dyn ITagRepository>,
+    #[shaku(inject)]
+    todo_event_repo: Arc<dyn ITodoEventRepository>,
+    #[shaku(inject)]
+    project_repo: Arc<dyn IProjectRepository>,
+    #[shaku(inject)]
+    todo_share_repo: Arc<dyn ITodoShareRepository>,
+    #[shaku(inject)]
+    attachment_repo: Arc<dyn IAttachmentRepository>,
+    #[shaku(inject)]
+    storage: Arc<dyn IBlobStorage>,
 }
 
 #[async_trait]
 impl ITodoUseCase for TodoUseCase {
-    async fn get_todo(&self, id: String) -> anyhow::Result<Option<TodoView>> {
-        let resp = self.todo_repo.get(&id.try_into()?).await?;
+    async fn get_todo(&self, user_id: String, id: String) -> anyhow::Result<Option<TodoView>> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let id: Id<Todo> = id.try_into()?;
+        let owner_id = self.resolve_owner(&user_id, &id).await?;
+        let resp = self.todo_repo.get(&owner_id, &id).await?;
         Ok(resp.map(Into::into))
     }
 
-    async fn find_todo(&self, condition: SearchTodoCondition) -> anyhow::Result<Vec<TodoView>> {
-        let status = match &condition.status_code {
-            Some(code) => Some(self.todo_status_repo.get_by_code(code.as_str()).await?),
-            None => None,
+    async fn get_todo_tree(
+        &self,
+        user_id: String,
+        id: String,
+    ) -> anyhow::Result<Option<TodoTreeView>> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let id: Id<Todo> = id.try_into()?;
+        let owner_id = self.resolve_owner(&user_id, &id).await?;
+        let todos = self.todo_repo.find_subtree(&owner_id, &id).await?;
+        Ok(TodoTreeView::from_subtree(todos))
+    }
+
+    async fn find_todo(
+        &self,
+        user_id: String,
+        condition: SearchTodoCondition,
+    ) -> anyhow::Result<TodoPageView> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let limit = condition.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if !(1..=MAX_PAGE_LIMIT).contains(&limit) {
+            return Err(UseCaseError::InvalidParameter("limit".to_string()).into());
+        }
+        let sort = match condition.sort.as_deref() {
+            Some(s) => TodoSort::try_from(s)
+                .map_err(|_| UseCaseError::InvalidParameter("sort".to_string()))?,
+            None => TodoSort::default(),
+        };
+        let mut statuses = Vec::with_capacity(condition.status_codes.len());
+        for code in &condition.status_codes {
+            statuses.push(self.todo_status_repo.get_by_code(code.as_str()).await?);
+        }
+        let filter = TodoFilter {
+            statuses,
+            created_after: parse_timestamp("createdAfter", condition.created_after.as_deref())?,
+            created_before: parse_timestamp("createdBefore", condition.created_before.as_deref())?,
+            updated_since: parse_timestamp("updatedSince", condition.updated_since.as_deref())?,
+            due_before: parse_timestamp("dueBefore", condition.due_before.as_deref())?,
+            overdue: condition.overdue,
+            tag_names: condition.tag_names,
+            parent_id: condition.parent_id.map(parse_parent_id).transpose()?,
+            project_id: condition.project_id.map(parse_project_id).transpose()?,
+            include_archived: false,
+        };
+
+        // 다음 페이지 존재 여부를 알기 위해 한 건 더 조회한다.
+        // 검색어가 있으면 관련도 순으로 정렬하므로 sort 는 무시한다.
+        if let Some(q) = condition
+            .q
+            .as_deref()
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+        {
+            let cursor = condition
+                .cursor
+                .as_deref()
+                .map(decode_search_cursor)
+                .transpose()?;
+            let mut hits = self
+                .todo_repo
+                .search(&owner_id, q, &filter, cursor, limit + 1)
+                .await?;
+            let has_more = hits.len() as i64 > limit;
+            hits.truncate(limit as usize);
+            let next_cursor = if has_more {
+                hits.last().map(encode_search_cursor)
+            } else {
+                None
+            };
+            return Ok(TodoPageView {
+                todos: hits.into_iter().map(Into::into).collect(),
+                next_cursor,
+                has_more,
+            });
+        }
+        let cursor = condition
+            .cursor
+            .as_deref()
+            .map(|c| decode_cursor(c, sort))
+            .transpose()?;
+        let mut todos = self
+            .todo_repo
+            .find(&owner_id, &filter, sort, cursor, limit + 1)
+            .await?;
+        let has_more = todos.len() as i64 > limit;
+        todos.truncate(limit as usize);
+        let next_cursor = if has_more {
+            todos.last().map(|t| encode_cursor(t, sort))
+        } else {
+            None
+        };
+        Ok(TodoPageView {
+            todos: todos.into_iter().map(Into::into).collect(),
+            next_cursor,
+            has_more,
+        })
+    }
+
+    async fn find_children(
+        &self,
+        user_id: String,
+        id: String,
+        condition: SearchTodoCondition,
+    ) -> anyhow::Result<TodoPageView> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let parent_id: Id<Todo> = id.clone().try_into()?;
+        let owner_id = self.resolve_owner(&user_id, &parent_id).await?;
+        if self.todo_repo.get(&owner_id, &parent_id).await?.is_none() {
+            return Err(UseCaseError::NotFound("todo".to_string()).into());
+        }
+        let condition = SearchTodoCondition {
+            parent_id: Some(id),
+            ..condition
+        };
+        self.find_todo(owner_id.value.to_string(), condition).await
+    }
+
+    async fn find_project_todos(
+        &self,
+        user_id: String,
+        project_id: String,
+        condition: SearchTodoCondition,
+    ) -> anyhow::Result<TodoPageView> {
+        let owner_id: Id<User> = user_id.clone().try_into()?;
+        let id: Id<Project> = project_id.clone().try_into()?;
+        if self.project_repo.get(&owner_id, &id).await?.is_none() {
+            return Err(UseCaseError::NotFound("project".to_string()).into());
+        }
+        let condition = SearchTodoCondition {
+            project_id: Some(project_id),
+            ..condition
+        };
+        self.find_todo(user_id, condition).await
+    }
+
+    async fn create_todo(&self, user_id: String, source: CreateTodo) -> anyhow::Result<TodoView> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let mut tx = self.db.pool().begin().await?;
+        let todo = self.insert_todo(&mut tx, &owner_id, source).await?;
+        tx.commit().await?;
+        Ok(todo.into())
+    }
+
+    async fn update_todo(
+        &self,
+        user_id: String,
+        source: UpdateTodoView,
+    ) -> anyhow::Result<TodoView> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let id: Id<Todo> = source.id.clone().try_into()?;
+        let mut tx = self.db.pool().begin().await?;
+        let owner_id = self.resolve_writer_tx(&mut tx, &user_id, &id).await?;
+        let todo = self
+            .update_todo_tx(&mut tx, &owner_id, &user_id, source)
+            .await?;
+        tx.commit().await?;
+        Ok(todo.into())
+    }
+
+    async fn upsert_todo(
+        &self,
+        user_id: String,
+        source: UpsertTodoView,
+    ) -> anyhow::Result<TodoView> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let id: Id<Todo> = source.id.clone().try_into()?;
+        let mut tx = self.db.pool().begin().await?;
+        let owner_id = self.resolve_writer_tx(&mut tx, &user_id, &id).await?;
+        let todo = self
+            .upsert_todo_tx(&mut tx, &owner_id, &user_id, source)
+            .await?;
+        tx.commit().await?;
+        Ok(todo.into())
+    }
+
+    async fn create_and_update_todo(
+        &self,
+        user_id: String,
+        create_source: CreateTodo,
+        update_source: UpdateTodoView,
+    ) -> anyhow::Result<(TodoView, TodoView)> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let mut tx = self.db.pool().begin().await?;
+        let created = self.insert_todo(&mut tx, &owner_id, create_source).await?;
+        let updated = self
+            .update_todo_tx(&mut tx, &owner_id, &owner_id, update_source)
+            .await?;
+        tx.commit().await?;
+        Ok((created.into(), updated.into()))
+    }
+
+    async fn run_batch(
+        &self,
+        user_id: String,
+        operations: Vec<TodoBatchOperation>,
+    ) -> anyhow::Result<Vec<TodoView>> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        if !(1..=MAX_BATCH_OPERATIONS).contains(&operations.len()) {
+            return Err(UseCaseError::InvalidParameter("operations".to_string()).into());
+        }
+        let mut tx = self.db.pool().begin().await?;
+        let mut results = Vec::with_capacity(operations.len());
+        for (index, operation) in operations.into_iter().enumerate() {
+            // 실패하면 tx 를 commit 하지 않고 버리므로 앞선 작업도 모두 되돌아간다.
+            let todo = self
+                .run_batch_operation(&mut tx, &owner_id, operation)
+                .await
+                .map_err(|source| TodoBatchError { index, source })?;
+            results.push(todo.into());
+        }
+        tx.commit().await?;
+        Ok(results)
+    }
+
+    async fn delete_todo(
+        &self,
+        user_id: String,
+        id: String,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<Option<TodoView>> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let id: Id<Todo> = id.try_into()?;
+        let mut tx = self.db.pool().begin().await?;
+        self.ensure_not_grantee_tx(&mut tx, &owner_id, &id).await?;
+        let resp = self
+            .delete_todo_tx(&mut tx, &owner_id, id, expected_version)
+            .await?;
+        tx.commit().await?;
+        Ok(resp.map(Into::into))
+    }
+
+    async fn purge_todo(
+        &self,
+        user_id: String,
+        id: String,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<Option<TodoView>> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let id: Id<Todo> = id.try_into()?;
+        let mut tx = self.db.pool().begin().await?;
+        self.ensure_not_grantee_tx(&mut tx, &owner_id, &id).await?;
+        let Some(current) = self.todo_repo.get_tx(&mut tx, &owner_id, &id).await? else {
+            return Ok(None);
         };
-        let todos = self.todo_repo.find(status).await?;
-        Ok(todos.into_iter().map(Into::into).collect())
+        // 보존 기간을 건너뛰지 않도록 휴지통에 있는 todo 만 영구 삭제한다.
+        if current.deleted_at.is_none() {
+            return Err(UseCaseError::Conflict(ErrorReason::NotInTrash).into());
+        }
+        ensure_version(&current, expected_version)?;
+        let storage_keys = self
+            .attachment_repo
+            .find_storage_keys_in_subtrees_tx(&mut tx, std::slice::from_ref(&id))
+            .await?;
+        let resp = self
+            .todo_repo
+            .delete_tx(&mut tx, &owner_id, &id, expected_version)
+            .await?
+            .ok_or_else(|| stale_or_not_found(expected_version))?;
+        tx.commit().await?;
+        self.discard_blobs(&storage_keys).await;
+        Ok(Some(resp.into()))
     }
 
-    async fn create_todo(&self, source: CreateTodo) -> anyhow::Result<TodoView> {
+    async fn restore_todo(&self, user_id: String, id: String) -> anyhow::Result<TodoView> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let id: Id<Todo> = id.try_into()?;
         let mut tx = self.db.pool().begin().await?;
+        self.ensure_not_grantee_tx(&mut tx, &owner_id, &id).await?;
+        let current = self
+            .todo_repo
+            .get_tx(&mut tx, &owner_id, &id)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("todo".to_string()))?;
+        if current.deleted_at.is_none() {
+            return Err(UseCaseError::Conflict(ErrorReason::NotInTrash).into());
+        }
+        // 직전 상태가 기록되지 않은 todo 는 신규 상태로 되돌린다.
+        let fallback = self
+            .todo_status_repo
+            .get_by_code_tx(&mut tx, TodoStatusCode::New.as_str())
+            .await?;
         let todo = self
             .todo_repo
-            .insert_tx(&mut tx, source.try_into()?)
+            .restore_tx(&mut tx, &owner_id, &id, &fallback)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("todo".to_string()))?;
+        self.record_changes(&mut tx, &owner_id, &current, &todo)
             .await?;
         tx.commit().await?;
         Ok(todo.into())
     }
 
-    async fn update_todo(&self, source: UpdateTodoView) -> anyhow::Result<TodoView> {
+    async fn purge_deleted_todos(
+        &self,
+        user_id: String,
+        retention_days: i64,
+    ) -> anyhow::Result<u64> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let deleted_before = Utc::now() - Duration::days(retention_days);
         let mut tx = self.db.pool().begin().await?;
+        let ids = self
+            .todo_repo
+            .find_purgeable_ids_tx(&mut tx, &owner_id, deleted_before)
+            .await?;
+        let storage_keys = self
+            .attachment_repo
+            .find_storage_keys_in_subtrees_tx(&mut tx, &ids)
+            .await?;
+        let purged = self
+            .todo_repo
+            .purge_deleted_tx(&mut tx, &owner_id, deleted_before)
+            .await?;
+        tx.commit().await?;
+        self.discard_blobs(&storage_keys).await;
+        Ok(purged)
+    }
+
+    async fn find_history(
+        &self,
+        user_id: String,
+        id: String,
+        condition: SearchTodoHistoryCondition,
+    ) -> anyhow::Result<TodoHistoryPageView> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let id: Id<Todo> = id.try_into()?;
+        let limit = condition.limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+        if !(1..=MAX_PAGE_LIMIT).contains(&limit) {
+            return Err(UseCaseError::InvalidParameter("limit".to_string()).into());
+        }
+        let cursor = condition
+            .cursor
+            .as_deref()
+            .map(decode_history_cursor)
+            .transpose()?;
+        let owner_id = self.resolve_owner(&user_id, &id).await?;
+        if self.todo_repo.get(&owner_id, &id).await?.is_none() {
+            return Err(UseCaseError::NotFound("todo".to_string()).into());
+        }
+
+        // 다음 페이지 존재 여부를 알기 위해 한 건 더 조회한다.
+        let mut events = self.todo_event_repo.find(&id, cursor, limit + 1).await?;
+        let has_more = events.len() as i64 > limit;
+        events.truncate(limit as usize);
+        let next_cursor = if has_more {
+            events.last().map(encode_history_cursor)
+        } else {
+            None
+        };
+        Ok(TodoHistoryPageView {
+            events: events.into_iter().map(Into::into).collect(),
+            next_cursor,
+            has_more,
+        })
+    }
+
+    async fn get_occurrences(
+        &self,
+        user_id: String,
+        id: String,
+        limit: Option<i64>,
+    ) -> anyhow::Result<TodoOccurrencesView> {
+        let user_id: Id<User> = user_id.try_into()?;
+        let id: Id<Todo> = id.try_into()?;
+        let limit = limit.unwrap_or(DEFAULT_OCCURRENCE_LIMIT);
+        if !(1..=MAX_OCCURRENCE_LIMIT).contains(&limit) {
+            return Err(UseCaseError::InvalidParameter("limit".to_string()).into());
+        }
+        let owner_id = self.resolve_owner(&user_id, &id).await?;
+        let todo = self
+            .todo_repo
+            .get(&owner_id, &id)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("todo".to_string()))?;
+        let occurrences = match &todo.recurrence {
+            Some(rule) => rule
+                .upcoming(recurrence_anchor(&todo), limit as usize)
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            None => vec![],
+        };
+        Ok(TodoOccurrencesView {
+            recurrence: todo.recurrence.map(|r| r.to_string()),
+            occurrences,
+        })
+    }
+
+    async fn snooze_todo(
+        &self,
+        user_id: String,
+        id: String,
+        minutes: i64,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<TodoView> {
+        if !(1..=MAX_SNOOZE_MINUTES).contains(&minutes) {
+            return Err(UseCaseError::InvalidParameter("minutes".to_string()).into());
+        }
+        let remind_at = Utc::now() + Duration::minutes(minutes);
+        let mut source = UpdateTodoView::new(id, None, None, None);
+        source.remind_at = FieldUpdate::Set(remind_at.to_rfc3339());
+        source.expected_version = expected_version;
+        self.update_todo(user_id, source).await
+    }
+
+    async fn move_todo(
+        &self,
+        user_id: String,
+        id: String,
+        before: Option<String>,
+        after: Option<String>,
+    ) -> anyhow::Result<TodoView> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let id: Id<Todo> = id.try_into()?;
+        if before.is_none() && after.is_none() {
+            return Err(UseCaseError::InvalidParameter("before".to_string()).into());
+        }
+        let before = before
+            .map(|v| parse_neighbour_id(v, "before"))
+            .transpose()?;
+        let after = after.map(|v| parse_neighbour_id(v, "after")).transpose()?;
+        // 자기 자신의 앞뒤로는 옮길 수 없다.
+        for (neighbour, field) in [(&before, "before"), (&after, "after")] {
+            if neighbour.as_ref().is_some_and(|n| n.value == id.value) {
+                return Err(UseCaseError::InvalidParameter(field.to_string()).into());
+            }
+        }
+        let mut tx = self.db.pool().begin().await?;
+        self.ensure_not_grantee_tx(&mut tx, &owner_id, &id).await?;
+        if self
+            .todo_repo
+            .get_tx(&mut tx, &owner_id, &id)
+            .await?
+            .is_none()
+        {
+            return Err(UseCaseError::NotFound("todo".to_string()).into());
+        }
+        self.todo_repo.lock_positions_tx(&mut tx, &owner_id).await?;
+        let position = match self
+            .position_between(&mut tx, &owner_id, &id, before.as_ref(), after.as_ref())
+            .await?
+        {
+            Some(position) => position,
+            None => {
+                // 사이에 자리가 없으면 한 번 다시 배치한다. 그래도 없으면 이웃의 순서가 거꾸로 된 것이다.
+                self.todo_repo
+                    .rebalance_positions_tx(&mut tx, &owner_id)
+                    .await?;
+                self.position_between(&mut tx, &owner_id, &id, before.as_ref(), after.as_ref())
+                    .await?
+                    .ok_or_else(|| UseCaseError::InvalidParameter("before".to_string()))?
+            }
+        };
+        let todo = self
+            .todo_repo
+            .set_position_tx(&mut tx, &owner_id, &id, &position)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("todo".to_string()))?;
+        tx.commit().await?;
+        Ok(todo.into())
+    }
+
+    async fn get_status_transitions(&self) -> anyhow::Result<Vec<TodoStatusTransitionView>> {
+        let statuses: Vec<TodoStatus> = self
+            .todo_status_repo
+            .find_entries()
+            .await?
+            .into_iter()
+            .filter(|entry| entry.disabled_at.is_none())
+            .map(|entry| entry.status)
+            .collect();
+        let transitions = statuses
+            .iter()
+            .map(|status| TodoStatusTransitionView {
+                status: status.clone().into(),
+                next: statuses
+                    .iter()
+                    .filter(|next| {
+                        next.code != status.code && status.code.can_transition_to(&next.code)
+                    })
+                    .cloned()
+                    .map(Into::into)
+                    .collect(),
+            })
+            .collect();
+        Ok(transitions)
+    }
+
+    async fn export_todos(
+        &self,
+        user_id: String,
+        cursor: Option<String>,
+    ) -> anyhow::Result<TodoPageView> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let sort = TodoSort::default();
+        let cursor = cursor
+            .as_deref()
+            .map(|c| decode_cursor(c, sort))
+            .transpose()?;
+        let filter = TodoFilter {
+            include_archived: true,
+            ..Default::default()
+        };
+        let mut todos = self
+            .todo_repo
+            .find(&owner_id, &filter, sort, cursor, EXPORT_PAGE_LIMIT + 1)
+            .await?;
+        let has_more = todos.len() as i64 > EXPORT_PAGE_LIMIT;
+        todos.truncate(EXPORT_PAGE_LIMIT as usize);
+        let next_cursor = if has_more {
+            todos.last().map(|t| encode_cursor(t, sort))
+        } else {
+            None
+        };
+        Ok(TodoPageView {
+            todos: todos.into_iter().map(Into::into).collect(),
+            next_cursor,
+            has_more,
+        })
+    }
+
+    async fn import_todos(
+        &self,
+        user_id: String,
+        rows: Vec<ImportTodoRow>,
+    ) -> anyhow::Result<TodoImportView> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        if rows.len() > MAX_IMPORT_ROWS {
+            return Err(UseCaseError::InvalidParameter("rows".to_string()).into());
+        }
+        let mut tx = self.db.pool().begin().await?;
+        let mut imported = Vec::with_capacity(rows.len());
+        let mut errors = vec![];
+        for row in rows {
+            let index = row.row;
+            match self.import_todo(&mut tx, &owner_id, row).await {
+                Ok(todo) => imported.push(todo.into()),
+                Err(err) => match err.downcast::<UseCaseError>() {
+                    Ok(error) => errors.push(TodoImportErrorView { row: index, error }),
+                    Err(err) => return Err(err),
+                },
+            }
+        }
+        tx.commit().await?;
+        Ok(TodoImportView { imported, errors })
+    }
+
+    async fn get_stats(
+        &self,
+        user_id: String,
+        condition: TodoStatsCondition,
+    ) -> anyhow::Result<TodoStatsView> {
+        let owner_id: Id<User> = user_id.try_into()?;
+        let now = Utc::now();
+        let (from, to, range) = parse_stats_range(&condition, now.date_naive())?;
+        let stats = self.todo_repo.get_stats(&owner_id, &range, now).await?;
+        Ok(TodoStatsView::new(from, to, &range, stats))
+    }
+}
+
+impl TodoUseCase {
+    /// 영구 삭제한 todo 에 붙어 있던 첨부 파일 본문을 지운다.
+    /// 메타데이터는 이미 지워졌으므로 지우지 못한 본문은 로그만 남긴다.
+    async fn discard_blobs(&self, storage_keys: &[String]) {
+        for storage_key in storage_keys {
+            if let Err(e) = self.storage.delete(storage_key).await {
+                warn!(storage_key, error = %e, "failed to delete attachment blob");
+            }
+        }
+    }
+
+    /// 새로 고를 상태를 찾는다. 없거나 끈 상태이면 `statusCode` 오류이지만,
+    /// 지금 상태(`current`)를 그대로 두는 것은 끈 상태라도 허용한다.
+    async fn assignable_status(
+        &self,
+        tx: &mut PgTx,
+        code: &str,
+        current: Option<&TodoStatus>,
+    ) -> anyhow::Result<TodoStatus> {
+        if let Some(current) = current.filter(|status| status.code.as_str() == code) {
+            return Ok(current.clone());
+        }
+        let status = self
+            .todo_status_repo
+            .get_enabled_by_code_tx(tx, code)
+            .await?
+            .ok_or_else(|| UseCaseError::InvalidParameter("statusCode".to_string()))?;
+        Ok(status)
+    }
+
+    /// todo 를 만들고 요청한 태그를 붙인다.
+    async fn insert_todo(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        source: CreateTodo,
+    ) -> anyhow::Result<Todo> {
+        let tags = self
+            .find_tags(tx, owner_id, "tagIds", &source.tag_ids)
+            .await?;
+        let new_todo = source.into_new_todo(owner_id.clone())?;
+        if let Some(parent_id) = &new_todo.parent_id {
+            self.ensure_parent(tx, owner_id, parent_id).await?;
+        }
+        if let Some(project_id) = &new_todo.project_id {
+            self.ensure_project(tx, owner_id, project_id).await?;
+        }
+        let todo = self.todo_repo.insert_tx(tx, new_todo).await?;
+        if tags.is_empty() {
+            return Ok(todo);
+        }
+        self.tag_repo
+            .attach_tx(tx, owner_id, &todo.id, &tags)
+            .await?;
+        self.todo_repo
+            .get_tx(tx, owner_id, &todo.id)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("todo".to_string()).into())
+    }
+
+    /// 가져온 행 하나로 todo 를 만든다. 신규가 아닌 상태는 만든 뒤 바로 바꾸며 이력은 남기지 않는다.
+    /// 실패한 행이 만든 todo 가 남지 않도록 상태는 만들기 전에 확인한다.
+    async fn import_todo(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        row: ImportTodoRow,
+    ) -> anyhow::Result<Todo> {
+        let code = parse_import_status(row.status_code.as_deref())?;
+        let status = match code {
+            TodoStatusCode::New => None,
+            code => Some(self.assignable_status(tx, code.as_str(), None).await?),
+        };
+        let todo = self.insert_todo(tx, owner_id, row.source).await?;
+        let Some(status) = status else {
+            return Ok(todo);
+        };
+        let update_todo = UpdateTodo::new(todo.id, owner_id.clone(), None, None, Some(status));
+        let todo = self
+            .todo_repo
+            .update_tx(tx, update_todo)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("todo".to_string()))?;
+        Ok(todo)
+    }
+
+    /// todo 를 부분 수정한다. 요청에 따라 태그와 상위 todo, 프로젝트도 바꾸고 완료를 상위로 올린다.
+    /// `actor_id` 는 실제로 고친 사용자로, 소유자가 아니면 공유받은 사용자이다.
+    /// 공유받은 사용자는 소유자가 정리한 태그·상위 todo·프로젝트를 바꿀 수 없다.
+    async fn update_todo_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        actor_id: &Id<User>,
+        source: UpdateTodoView,
+    ) -> anyhow::Result<Todo> {
+        let id: Id<Todo> = source.id.try_into()?;
+        if actor_id.value != owner_id.value
+            && (!source.add_tag_ids.is_empty()
+                || !source.remove_tag_ids.is_empty()
+                || source.parent_id.is_some()
+                || source.project_id.is_some())
+        {
+            return Err(UseCaseError::Forbidden(ErrorReason::SharedTodoFields).into());
+        }
+        let roll_up = source.roll_up_completion && source.status_code.is_some();
+        let current = self
+            .todo_repo
+            .get_tx(tx, owner_id, &id)
+            .await?
+            .ok_or_else(|| UseCaseError::NotFound("todo".to_string()))?;
+        ensure_version(&current, source.expected_version)?;
         let status = match &source.status_code {
-            Some(code) => Some(
-                self.todo_status_repo
-                    .get_by_code_tx(&mut tx, code.as_str())
-                    .await?,
-            ),
+            Some(code) => {
+                let status = self
+                    .assignable_status(tx, code, Some(&current.status))
+                    .await?;
+                ensure_transition(&current.status, &status)?;
+                Some(status)
+            }
             None => None,
         };
-        let update_todo = UpdateTodo::new(
-            source.id.try_into()?,
+        let completes = status
+            .as_ref()
+            .is_some_and(|s| s.code == TodoStatusCode::Done && current.status.code != s.code);
+        self.change_tags(
+            tx,
+            owner_id,
+            &id,
+            &source.add_tag_ids,
+            &source.remove_tag_ids,
+        )
+        .await?;
+        // 옮길 때마다 버전이 오르고 행은 이 트랜잭션이 잡으므로, 다음 단계는 옮긴 뒤의 버전과 비교한다.
+        let mut expected_version = source.expected_version;
+        if let Some(moved) = self
+            .reparent_todo(
+                tx,
+                owner_id,
+                &id,
+                source.parent_id.as_deref(),
+                expected_version,
+            )
+            .await?
+        {
+            expected_version = Some(moved.version);
+        }
+        if let Some(moved) = self
+            .move_to_project(
+                tx,
+                owner_id,
+                &id,
+                source.project_id.as_deref(),
+                expected_version,
+            )
+            .await?
+        {
+            expected_version = Some(moved.version);
+        }
+        let mut update_todo = UpdateTodo::new(
+            id,
+            owner_id.clone(),
             source.title,
             source.description,
             status,
         );
-        let todo = self.todo_repo.update_tx(&mut tx, update_todo).await?;
-        tx.commit().await?;
-        Ok(todo.into())
+        update_todo.due_at = match source.due_at {
+            FieldUpdate::Keep => FieldUpdate::Keep,
+            FieldUpdate::Clear => FieldUpdate::Clear,
+            FieldUpdate::Set(value) => parse_due_at(Some(&value))?.into(),
+        };
+        update_todo.priority = parse_priority(source.priority.as_deref())?;
+        update_todo.recurrence = match source.recurrence {
+            FieldUpdate::Keep => FieldUpdate::Keep,
+            FieldUpdate::Clear => FieldUpdate::Clear,
+            FieldUpdate::Set(value) => FieldUpdate::Set(parse_recurrence(&value)?),
+        };
+        update_todo.remind_at = match source.remind_at {
+            FieldUpdate::Keep => FieldUpdate::Keep,
+            FieldUpdate::Clear => FieldUpdate::Clear,
+            FieldUpdate::Set(value) => parse_remind_at(Some(&value))?.into(),
+        };
+        update_todo.expected_version = expected_version;
+        // 반복 todo 를 완료하면 규칙은 다음 회차로 넘어가고 이 todo 는 더 반복하지 않는다.
+        let series = match &update_todo.recurrence {
+            _ if !completes => None,
+            FieldUpdate::Keep => current.recurrence.clone(),
+            FieldUpdate::Clear => None,
+            FieldUpdate::Set(rule) => Some(rule.clone()),
+        };
+        if series.is_some() {
+            update_todo.recurrence = FieldUpdate::Clear;
+        }
+        let todo = self
+            .todo_repo
+            .update_tx(tx, update_todo)
+            .await?
+            .ok_or_else(|| stale_or_not_found(source.expected_version))?;
+        self.record_changes(tx, actor_id, &current, &todo).await?;
+        if let Some(series) = series {
+            self.create_next_occurrence(tx, owner_id, &todo, series)
+                .await?;
+        }
+        if roll_up && todo.status.code == TodoStatusCode::Done {
+            self.roll_up_completion(tx, owner_id, actor_id, &todo)
+                .await?;
+        }
+        Ok(todo)
     }
 
-    async fn upsert_todo(&self, source: UpsertTodoView) -> anyhow::Result<TodoView> {
-        let mut tx = self.db.pool().begin().await?;
+    /// todo 를 통째로 바꾸거나 없으면 만든다.
+    async fn upsert_todo_tx(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        actor_id: &Id<User>,
+        source: UpsertTodoView,
+    ) -> anyhow::Result<Todo> {
+        let id: Id<Todo> = source.id.try_into()?;
+        // 새로 만드는 경우에는 처음 상태를 자유롭게 정할 수 있지만 휴지통에서 시작할 수는 없다.
+        // 기대 버전이 있는데 todo 가 없으면 새로 만들지 않는다.
+        let current = self.todo_repo.get_tx(tx, owner_id, &id).await?;
         let status = self
-            .todo_status_repo
-            .get_by_code_tx(&mut tx, &source.status_code)
+            .assignable_status(
+                tx,
+                &source.status_code,
+                current.as_ref().map(|todo| &todo.status),
+            )
             .await?;
-        let upsert_todo = UpsertTodo::new(
-            source.id.try_into()?,
+        match &current {
+            Some(current) => {
+                ensure_version(current, source.expected_version)?;
+                ensure_transition(&current.status, &status)?;
+            }
+            None if source.expected_version.is_some() => {
+                return Err(stale_or_not_found(source.expected_version).into());
+            }
+            None if status.code == TodoStatusCode::Deleted => {
+                return Err(UseCaseError::InvalidParameter("statusCode".to_string()).into());
+            }
+            None => {}
+        }
+        let mut upsert_todo = UpsertTodo::new(
+            id,
+            owner_id.clone(),
             source.title,
             source.description,
             status,
         );
-        let todo = self.todo_repo.upsert_tx(&mut tx, upsert_todo).await?;
-        tx.commit().await?;
-        Ok(todo.into())
+        upsert_todo.due_at = parse_due_at(source.due_at.as_deref())?;
+        upsert_todo.priority = parse_priority(source.priority.as_deref())?.unwrap_or_default();
+        upsert_todo.expected_version = source.expected_version;
+        let todo = self
+            .todo_repo
+            .upsert_tx(tx, upsert_todo)
+            .await?
+            .ok_or_else(|| stale_or_not_found(source.expected_version))?;
+        // 새로 만든 경우는 바뀐 것이 아니므로 이력을 남기지 않는다.
+        if let Some(current) = &current {
+            self.record_changes(tx, actor_id, current, &todo).await?;
+        }
+        Ok(todo)
     }
 
-    async fn create_and_update_todo(
+    /// todo 를 휴지통으로 옮긴다. todo 가 없으면 `None` 이다.
+    async fn delete_todo_tx(
         &self,
-        create_source: CreateTodo,
-        update_source: UpdateTodoView,
-    ) -> anyhow::Result<(TodoView, TodoView)> {
-        let mut tx = self.db.pool().begin().await?;
-
-        let created = self
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: Id<Todo>,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<Option<Todo>> {
+        let deleted = self
+            .todo_status_repo
+            .get_by_code_tx(tx, TodoStatusCode::Deleted.as_str())
+            .await?;
+        let current = self.todo_repo.get_tx(tx, owner_id, &id).await?;
+        if let Some(current) = &current {
+            ensure_version(current, expected_version)?;
+        }
+        let resp = self
             .todo_repo
-            .insert_tx(&mut tx, create_source.try_into()?)
+            .soft_delete_tx(tx, owner_id, &id, &deleted, expected_version)
+            .await?;
+        if let (Some(current), Some(todo)) = (&current, &resp) {
+            // 확인한 뒤 다른 요청이 먼저 바꿨으면 휴지통으로 옮기지 못한다.
+            if expected_version.is_some() && todo.deleted_at.is_none() {
+                return Err(stale_or_not_found(expected_version).into());
+            }
+            self.record_changes(tx, owner_id, current, todo).await?;
+        }
+        Ok(resp)
+    }
+
+    /// 일괄 처리 작업 하나를 실행한다.
+    async fn run_batch_operation(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        operation: TodoBatchOperation,
+    ) -> anyhow::Result<Todo> {
+        match operation {
+            TodoBatchOperation::Create(source) => self.insert_todo(tx, owner_id, source).await,
+            TodoBatchOperation::Update(source) => {
+                self.update_todo_tx(tx, owner_id, owner_id, source).await
+            }
+            TodoBatchOperation::Upsert(source) => {
+                self.upsert_todo_tx(tx, owner_id, owner_id, source).await
+            }
+            TodoBatchOperation::Delete {
+                id,
+                expected_version,
+            } => self
+                .delete_todo_tx(tx, owner_id, id.try_into()?, expected_version)
+                .await?
+                .ok_or_else(|| UseCaseError::NotFound("todo".to_string()).into()),
+        }
+    }
+
+    /// todo 에 태그를 붙이고 뗀다. todo 가 없으면 아무것도 바꾸지 않는다.
+    async fn change_tags(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        add_tag_ids: &[String],
+        remove_tag_ids: &[String],
+    ) -> anyhow::Result<()> {
+        let add = self
+            .find_tags(tx, owner_id, "addTagIds", add_tag_ids)
+            .await?;
+        let remove = self
+            .find_tags(tx, owner_id, "removeTagIds", remove_tag_ids)
             .await?;
+        if !add.is_empty() {
+            self.tag_repo.attach_tx(tx, owner_id, id, &add).await?;
+        }
+        if !remove.is_empty() {
+            self.tag_repo.detach_tx(tx, owner_id, id, &remove).await?;
+        }
+        Ok(())
+    }
 
-        let status = match &update_source.status_code {
-            Some(code) => Some(
-                self.todo_status_repo
-                    .get_by_code_tx(&mut tx, code.as_str())
+    /// todo 를 `parent_id` 아래로 옮기고 옮긴 todo 를 반환한다. 빈 문자열이면 최상위로, `None` 이면 그대로 둔다.
+    /// 자기 자신이나 하위 작업 아래로 옮기면 순환이 생기므로 `Conflict` 이다.
+    async fn reparent_todo(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        parent_id: Option<&str>,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<Option<Todo>> {
+        let parent_id = match parent_id {
+            None => return Ok(None),
+            Some("") => None,
+            Some(parent_id) => {
+                let parent_id = parse_parent_id(parent_id.to_string())?;
+                self.ensure_parent(tx, owner_id, &parent_id).await?;
+                let subtree = self.todo_repo.find_subtree_tx(tx, owner_id, id).await?;
+                if subtree.iter().any(|t| t.id.value == parent_id.value) {
+                    return Err(UseCaseError::Conflict(ErrorReason::MoveIntoSubtree).into());
+                }
+                Some(parent_id)
+            }
+        };
+        let todo = self
+            .todo_repo
+            .set_parent_tx(tx, owner_id, id, parent_id.as_ref(), expected_version)
+            .await?
+            .ok_or_else(|| stale_or_not_found(expected_version))?;
+        Ok(Some(todo))
+    }
+
+    /// todo 를 `project_id` 프로젝트로 옮기고 옮긴 todo 를 반환한다. 빈 문자열이면 프로젝트에서 빼고,
+    /// `None` 이면 그대로 둔다.
+    async fn move_to_project(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        project_id: Option<&str>,
+        expected_version: Option<i64>,
+    ) -> anyhow::Result<Option<Todo>> {
+        let project_id = match project_id {
+            None => return Ok(None),
+            Some("") => None,
+            Some(project_id) => {
+                let project_id = parse_project_id(project_id.to_string())?;
+                self.ensure_project(tx, owner_id, &project_id).await?;
+                Some(project_id)
+            }
+        };
+        let todo = self
+            .todo_repo
+            .set_project_tx(tx, owner_id, id, project_id.as_ref(), expected_version)
+            .await?
+            .ok_or_else(|| stale_or_not_found(expected_version))?;
+        Ok(Some(todo))
+    }
+
+    /// todo 를 담을 수 있는 프로젝트인지 확인한다. 소유자의 보관하지 않은 프로젝트가 아니면 `projectId` 오류를 낸다.
+    async fn ensure_project(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        project_id: &Id<Project>,
+    ) -> anyhow::Result<()> {
+        match self.project_repo.get_tx(tx, owner_id, project_id).await? {
+            Some(project) if !project.archived => Ok(()),
+            _ => Err(UseCaseError::InvalidParameter("projectId".to_string()).into()),
+        }
+    }
+
+    /// 상위 todo 로 쓸 수 있는지 확인한다. 소유자의 휴지통에 없는 todo 가 아니면 `parentId` 오류를 낸다.
+    async fn ensure_parent(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        parent_id: &Id<Todo>,
+    ) -> anyhow::Result<()> {
+        match self.todo_repo.get_tx(tx, owner_id, parent_id).await? {
+            Some(parent) if parent.deleted_at.is_none() => Ok(()),
+            _ => Err(UseCaseError::InvalidParameter("parentId".to_string()).into()),
+        }
+    }
+
+    /// 사용자가 todo 를 조회할 때 쓸 소유자 id. 공유받은 todo 이면 소유자, 아니면 사용자 자신이다.
+    async fn resolve_owner(&self, user_id: &Id<User>, id: &Id<Todo>) -> anyhow::Result<Id<User>> {
+        let grant = self.todo_share_repo.get_grant(user_id, id).await?;
+        Ok(grant.map_or_else(|| user_id.clone(), |g| g.owner_id))
+    }
+
+    /// 사용자가 todo 를 고칠 때 쓸 소유자 id. 공유받은 todo 이면 소유자, 아니면 사용자 자신이다.
+    /// 읽기 권한만 받았으면 `Forbidden` 이다.
+    async fn resolve_writer_tx(
+        &self,
+        tx: &mut PgTx,
+        user_id: &Id<User>,
+        id: &Id<Todo>,
+    ) -> anyhow::Result<Id<User>> {
+        match self.todo_share_repo.get_grant_tx(tx, user_id, id).await? {
+            Some(grant) if grant.permission.can_write() => Ok(grant.owner_id),
+            Some(_) => Err(UseCaseError::Forbidden(ErrorReason::ReadOnlyTodo).into()),
+            None => Ok(user_id.clone()),
+        }
+    }
+
+    /// `after` 와 `before` 사이의 순위를 만든다. 한쪽만 주면 다른 쪽은 그 todo 의 바로 옆 이웃이며,
+    /// 옮길 todo 자신은 이웃으로 보지 않는다. 사이에 자리가 없으면 `None` 이다.
+    async fn position_between(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        before: Option<&Id<Todo>>,
+        after: Option<&Id<Todo>>,
+    ) -> anyhow::Result<Option<String>> {
+        let mut lower = match after {
+            Some(after) => Some(
+                self.neighbour_position(tx, owner_id, after, "after")
                     .await?,
             ),
             None => None,
         };
-        let update_todo = UpdateTodo::new(
-            update_source.id.try_into()?,
-            update_source.title,
-            update_source.description,
-            status,
+        let mut upper = match before {
+            Some(before) => Some(
+                self.neighbour_position(tx, owner_id, before, "before")
+                    .await?,
+            ),
+            None => None,
+        };
+        match (&lower, &upper) {
+            (Some(position), None) => {
+                upper = self
+                    .todo_repo
+                    .get_adjacent_position_tx(tx, owner_id, position, Adjacent::After, id)
+                    .await?;
+            }
+            (None, Some(position)) => {
+                lower = self
+                    .todo_repo
+                    .get_adjacent_position_tx(tx, owner_id, position, Adjacent::Before, id)
+                    .await?;
+            }
+            _ => {}
+        }
+        Ok(rank_between(lower.as_deref(), upper.as_deref()))
+    }
+
+    /// 이웃으로 준 todo 의 순위. 사용자의 todo 가 아니면 `field` 오류를 낸다.
+    async fn neighbour_position(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        id: &Id<Todo>,
+        field: &str,
+    ) -> anyhow::Result<String> {
+        let todo = self
+            .todo_repo
+            .get_tx(tx, owner_id, id)
+            .await?
+            .ok_or_else(|| UseCaseError::InvalidParameter(field.to_string()))?;
+        Ok(todo.position)
+    }
+
+    /// 공유받은 사용자가 소유자만 할 수 있는 작업(삭제·복원)을 요청하면 `Forbidden` 이다.
+    async fn ensure_not_grantee_tx(
+        &self,
+        tx: &mut PgTx,
+        user_id: &Id<User>,
+        id: &Id<Todo>,
+    ) -> anyhow::Result<()> {
+        if self
+            .todo_share_repo
+            .get_grant_tx(tx, user_id, id)
+            .await?
+            .is_some()
+        {
+            return Err(UseCaseError::Forbidden(ErrorReason::OwnerOnly).into());
+        }
+        Ok(())
+    }
+
+    /// 수정 전후를 비교해 바뀐 필드가 있으면 같은 트랜잭션에 변경 이력을 남긴다.
+    async fn record_changes(
+        &self,
+        tx: &mut PgTx,
+        actor_id: &Id<User>,
+        before: &Todo,
+        after: &Todo,
+    ) -> anyhow::Result<()> {
+        let changes = TodoFieldChange::diff(before, after);
+        if changes.is_empty() {
+            return Ok(());
+        }
+        let event = NewTodoEvent::new(Id::gen(), after.id.clone(), actor_id.clone(), changes);
+        self.todo_event_repo.insert_tx(tx, event).await?;
+        Ok(())
+    }
+
+    /// 완료한 반복 todo 의 다음 회차를 같은 내용으로 만든다. 남은 회차가 없으면 만들지 않는다.
+    /// 알림은 마감일과의 간격을 그대로 두고 옮긴다.
+    async fn create_next_occurrence(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        done: &Todo,
+        series: RecurrenceRule,
+    ) -> anyhow::Result<()> {
+        let anchor = recurrence_anchor(done);
+        let Some((due_at, rest)) = series.next_occurrence(anchor) else {
+            return Ok(());
+        };
+        let mut new_todo = NewTodo::new(
+            Id::gen(),
+            owner_id.clone(),
+            done.title.clone(),
+            done.description.clone(),
         );
-        let updated = self.todo_repo.update_tx(&mut tx, update_todo).await?;
-        tx.commit().await?;
-        Ok((created.into(), updated.into()))
+        new_todo.parent_id = done.parent_id.clone();
+        new_todo.project_id = done.project_id.clone();
+        new_todo.due_at = Some(due_at);
+        new_todo.priority = done.priority;
+        new_todo.recurrence = Some(rest);
+        new_todo.remind_at = done
+            .remind_at
+            .map(|remind_at| due_at - (anchor - remind_at));
+        let todo = self.todo_repo.insert_tx(tx, new_todo).await?;
+        if !done.tags.is_empty() {
+            self.tag_repo
+                .attach_tx(tx, owner_id, &todo.id, &done.tags)
+                .await?;
+        }
+        Ok(())
     }
 
-    async fn delete_todo(&self, id: String) -> anyhow::Result<Option<TodoView>> {
-        let mut tx = self.db.pool().begin().await?;
-        let resp = self.todo_repo.delete_tx(&mut tx, &id.try_into()?).await?;
-        tx.commit().await?;
-        Ok(resp.map(Into::into))
+    /// 완료한 todo 의 상위 todo 를 차례로 올라가며, 끝나지 않은 하위 작업이 없으면 완료로 바꾼다.
+    /// 이미 완료했거나 완료로 바꿀 수 없는 상태의 상위 todo 를 만나면 멈춘다.
+    async fn roll_up_completion(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        actor_id: &Id<User>,
+        todo: &Todo,
+    ) -> anyhow::Result<()> {
+        let mut parent_id = match &todo.parent_id {
+            Some(parent_id) => parent_id.clone(),
+            None => return Ok(()),
+        };
+        let done = self
+            .todo_status_repo
+            .get_by_code_tx(tx, TodoStatusCode::Done.as_str())
+            .await?;
+        loop {
+            let Some(parent) = self.todo_repo.get_tx(tx, owner_id, &parent_id).await? else {
+                return Ok(());
+            };
+            if parent.status.code == TodoStatusCode::Done
+                || !parent.status.code.can_transition_to(&TodoStatusCode::Done)
+                || self
+                    .todo_repo
+                    .count_unfinished_children_tx(tx, owner_id, &parent_id)
+                    .await?
+                    > 0
+            {
+                return Ok(());
+            }
+            let update_todo =
+                UpdateTodo::new(parent_id, owner_id.clone(), None, None, Some(done.clone()));
+            let updated = self
+                .todo_repo
+                .update_tx(tx, update_todo)
+                .await?
+                .ok_or_else(|| UseCaseError::NotFound("todo".to_string()))?;
+            self.record_changes(tx, actor_id, &parent, &updated).await?;
+            let parent = updated;
+            match parent.parent_id {
+                Some(next) => parent_id = next,
+                None => return Ok(()),
+            }
+        }
     }
+
+    /// 태그 id 목록을 소유자의 태그로 바꾼다.
+    /// 형식이 틀렸거나 소유자의 태그가 아닌 id 가 있으면 `name` 파라미터 오류를 낸다.
+    async fn find_tags(
+        &self,
+        tx: &mut PgTx,
+        owner_id: &Id<User>,
+        name: &str,
+        ids: &[String],
+    ) -> anyhow::Result<Vec<Tag>> {
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+        let invalid = || UseCaseError::InvalidParameter(name.to_string());
+        let mut tag_ids: Vec<Id<Tag>> = Vec::with_capacity(ids.len());
+        for id in ids {
+            let tag_id: Id<Tag> = id.clone().try_into().map_err(|_| invalid())?;
+            if !tag_ids.iter().any(|t| t.value == tag_id.value) {
+                tag_ids.push(tag_id);
+            }
+        }
+        let tags = self.tag_repo.find_by_ids_tx(tx, owner_id, &tag_ids).await?;
+        if tags.len() != tag_ids.len() {
+            return Err(invalid().into());
+        }
+        Ok(tags)
+    }
+}
+
+/// `current` 에서 `next` 로 상태를 바꿀 수 있는지 확인한다.
+fn ensure_transition(current: &TodoStatus, next: &TodoStatus) -> Result<(), UseCaseError> {
+    if current.code.can_transition_to(&next.code) {
+        Ok(())
+    } else {
+        Err(UseCaseError::Conflict(ErrorReason::StatusTransition {
+            from: current.code.as_str().to_string(),
+            to: next.code.as_str().to_string(),
+        }))
+    }
+}
+
+/// 기대한 버전이 있으면 `current` 의 버전과 같은지 확인한다.
+fn ensure_version(current: &Todo, expected_version: Option<i64>) -> Result<(), UseCaseError> {
+    match expected_version {
+        Some(expected) if expected != current.version => Err(stale_or_not_found(Some(expected))),
+        _ => Ok(()),
+    }
+}
+
+/// 저장소에서 바꾸지 못한 이유를 고른다. 기대 버전이 있었다면 그 사이 다른 요청이 먼저 바꾼 것으로 본다.
+fn stale_or_not_found(expected_version: Option<i64>) -> UseCaseError {
+    match expected_version {
+        Some(_) => UseCaseError::PreconditionFailed(ErrorReason::TodoChanged),
+        None => UseCaseError::NotFound("todo".to_string()),
+    }
+}
+
+/// RFC 3339 시각 조건을 해석한다. 형식이 틀리면 해당 파라미터 이름으로 오류를 낸다.
+fn parse_timestamp(name: &str, value: Option<&str>) -> Result<Option<DateTime<Utc>>, UseCaseError> {
+    value
+        .map(|v| {
+            DateTime::parse_from_rfc3339(v)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|_| UseCaseError::InvalidParameter(name.to_string()))
+        })
+        .transpose()
+}
+
+/// 반복 회차를 셀 기준 시각. 마감일이 없으면 지금부터 센다.
+fn recurrence_anchor(todo: &Todo) -> DateTime<Utc> {
+    todo.due_at.unwrap_or_else(Utc::now)
 }
@@ -11,4 +11,10 @@ pub trait IUserUseCase: shaku::Interface {
     ) -> anyhow::Result<Option<UserView>>;
     async fn create_user(&self, source: CreateUser) -> anyhow::Result<UserView>;
     async fn login_user(&self, source: LoginUser) -> anyhow::Result<UserView>;
+    /// 응답 언어를 바꾼다. `None` 이면 지워서 `Accept-Language` 를 따르게 한다.
+    async fn update_locale(
+        &self,
+        id: String,
+        locale: Option<String>,
+    ) -> anyhow::Result<Option<UserView>>;
 }
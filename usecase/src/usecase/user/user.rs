@@ -94,4 +94,18 @@ impl IUserUseCase for UserUseCase {
             Err(anyhow!("잘못된 사용자명 또는 비밀번호입니다"))
         }
     }
+
+    async fn update_locale(
+        &self,
+        id: String,
+        locale: Option<String>,
+    ) -> anyhow::Result<Option<UserView>> {
+        let mut tx = self.db.pool().begin().await?;
+        let user = self
+            .user_repo
+            .update_locale_tx(&mut tx, &id.try_into()?, locale.as_deref())
+            .await?;
+        tx.commit().await?;
+        Ok(user.map(Into::into))
+    }
 }
@@ -0,0 +1,247 @@
+//! AttachmentUseCase 통합 테스트
+//!
+//! 실행 방법:
+//! ```
+//! cargo test -p usecase --test attachment_usecase_happy_path_test
+//! ```
+//!
+//! Docker가 실행 중이면 PostgreSQL 컨테이너를 자동으로 기동한다.
+//! 파일 본문은 임시 디렉터리의 로컬 저장소에 저장한다.
+
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::create_test_user;
+use common::module::build_usecase_test_module;
+use domain::model::attachment::Attachment;
+use domain::model::Id;
+use infra::storage::IBlobStorage;
+use shaku::HasComponent;
+use std::sync::Arc;
+use tokio::io::AsyncReadExt;
+use usecase::error::UseCaseError;
+use usecase::model::attachment::{AttachmentLimits, UploadAttachment};
+use usecase::model::todo::CreateTodo;
+use usecase::usecase::attachment::IAttachmentUseCase;
+use usecase::usecase::todo::ITodoUseCase;
+
+fn limits(max_bytes: u64) -> AttachmentLimits {
+    AttachmentLimits::new(max_bytes, vec!["text/plain".to_string()])
+}
+
+fn text_file(file_name: &str) -> UploadAttachment {
+    UploadAttachment::new(
+        file_name.to_string(),
+        "text/plain; charset=utf-8".to_string(),
+    )
+}
+
+async fn create_todo(module: &Arc<common::module::UsecaseTestModule>, user_id: &str) -> String {
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    uc.create_todo(
+        user_id.to_string(),
+        CreateTodo::new("With files".to_string(), "desc".to_string()),
+    )
+    .await
+    .expect("create_todo must succeed")
+    .id
+}
+
+#[tokio::test]
+async fn upload_then_download_and_delete_attachment() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn IAttachmentUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let todo_id = create_todo(&module, &user_id).await;
+
+    let uploaded = uc
+        .upload_attachment(
+            user_id.clone(),
+            todo_id.clone(),
+            text_file("notes/memo.txt"),
+            limits(1024),
+            Box::pin(&b"hello attachment"[..]),
+        )
+        .await
+        .expect("upload_attachment must succeed");
+    assert_eq!(uploaded.file_name, "memo.txt");
+    assert_eq!(uploaded.content_type, "text/plain");
+    assert_eq!(uploaded.size_bytes, 16);
+
+    let list = uc
+        .find_attachments(user_id.clone(), todo_id.clone())
+        .await
+        .unwrap();
+    assert_eq!(list.len(), 1);
+
+    let mut download = uc
+        .download_attachment(user_id.clone(), todo_id.clone(), uploaded.id.clone())
+        .await
+        .expect("download_attachment must succeed");
+    let mut body = String::new();
+    download.body.read_to_string(&mut body).await.unwrap();
+    assert_eq!(body, "hello attachment");
+    assert_eq!(download.attachment.id, uploaded.id);
+
+    uc.delete_attachment(user_id.clone(), todo_id.clone(), uploaded.id.clone())
+        .await
+        .expect("delete_attachment must succeed");
+    let err = uc
+        .download_attachment(user_id, todo_id, uploaded.id)
+        .await
+        .err()
+        .expect("deleted attachment must not be downloadable");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn upload_attachment_over_limit_is_rejected_and_not_stored() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn IAttachmentUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let todo_id = create_todo(&module, &user_id).await;
+
+    let err = uc
+        .upload_attachment(
+            user_id.clone(),
+            todo_id.clone(),
+            text_file("big.txt"),
+            limits(4),
+            Box::pin(&b"12345"[..]),
+        )
+        .await
+        .expect_err("file over the limit must be rejected");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(p)) if p == "file"
+    ));
+
+    uc.upload_attachment(
+        user_id.clone(),
+        todo_id.clone(),
+        text_file("fits.txt"),
+        limits(4),
+        Box::pin(&b"1234"[..]),
+    )
+    .await
+    .expect("file exactly at the limit must be accepted");
+    let list = uc.find_attachments(user_id, todo_id).await.unwrap();
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0].file_name, "fits.txt");
+}
+
+#[tokio::test]
+async fn upload_attachment_with_disallowed_type_or_other_users_todo_fails() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn IAttachmentUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let other_id = create_test_user(&module).await;
+    let todo_id = create_todo(&module, &user_id).await;
+
+    let err = uc
+        .upload_attachment(
+            user_id,
+            todo_id.clone(),
+            UploadAttachment::new(
+                "run.exe".to_string(),
+                "application/x-msdownload".to_string(),
+            ),
+            limits(1024),
+            Box::pin(&b"MZ"[..]),
+        )
+        .await
+        .expect_err("disallowed content type must be rejected");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(p)) if p == "contentType"
+    ));
+
+    let err = uc
+        .upload_attachment(
+            other_id,
+            todo_id,
+            text_file("memo.txt"),
+            limits(1024),
+            Box::pin(&b"hi"[..]),
+        )
+        .await
+        .expect_err("other user's todo must not be visible");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::NotFound(_))
+    ));
+}
+
+/// 업로드한 첨부 파일의 본문이 저장소에 남아 있는지 본다.
+async fn blob_exists(
+    module: &Arc<common::module::UsecaseTestModule>,
+    todo_id: &str,
+    attachment_id: &str,
+) -> bool {
+    let storage: Arc<dyn IBlobStorage> = module.resolve();
+    let key = Attachment::storage_key_of(
+        &Id::try_from(todo_id.to_string()).unwrap(),
+        &Id::try_from(attachment_id.to_string()).unwrap(),
+    );
+    storage.get(&key).await.unwrap().is_some()
+}
+
+#[tokio::test]
+async fn purging_todos_deletes_attachment_blobs_of_subtree() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn IAttachmentUseCase> = module.resolve();
+    let todo_uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let upload = |todo_id: String| {
+        uc.upload_attachment(
+            user_id.clone(),
+            todo_id,
+            text_file("memo.txt"),
+            limits(1024),
+            Box::pin(&b"bye"[..]),
+        )
+    };
+
+    // 상위 todo 를 영구 삭제하면 하위 작업의 첨부 파일 본문도 지운다.
+    let root_id = create_todo(&module, &user_id).await;
+    let mut source = CreateTodo::new("Child".to_string(), "desc".to_string());
+    source.parent_id = Some(root_id.clone());
+    let child_id = todo_uc
+        .create_todo(user_id.clone(), source)
+        .await
+        .expect("setup: create child must succeed")
+        .id;
+    let on_root = upload(root_id.clone()).await.unwrap();
+    let on_child = upload(child_id.clone()).await.unwrap();
+    assert!(blob_exists(&module, &child_id, &on_child.id).await);
+    todo_uc
+        .delete_todo(user_id.clone(), root_id.clone(), None)
+        .await
+        .expect("setup: delete_todo must succeed");
+    todo_uc
+        .purge_todo(user_id.clone(), root_id.clone(), None)
+        .await
+        .expect("purge_todo must succeed");
+    assert!(!blob_exists(&module, &root_id, &on_root.id).await);
+    assert!(!blob_exists(&module, &child_id, &on_child.id).await);
+
+    // 휴지통 비우기도 같다.
+    let trashed_id = create_todo(&module, &user_id).await;
+    let on_trashed = upload(trashed_id.clone()).await.unwrap();
+    todo_uc
+        .delete_todo(user_id.clone(), trashed_id.clone(), None)
+        .await
+        .expect("setup: delete_todo must succeed");
+    todo_uc
+        .purge_deleted_todos(user_id.clone(), 0)
+        .await
+        .expect("purge_deleted_todos must succeed");
+    assert!(!blob_exists(&module, &trashed_id, &on_trashed.id).await);
+}
@@ -0,0 +1,162 @@
+//! CommentUseCase 통합 테스트
+//!
+//! 실행 방법:
+//! ```
+//! cargo test -p usecase --test comment_usecase_happy_path_test
+//! ```
+//!
+//! Docker가 실행 중이면 PostgreSQL 컨테이너를 자동으로 기동한다.
+
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::create_test_user;
+use common::module::build_usecase_test_module;
+use shaku::HasComponent;
+use std::sync::Arc;
+use usecase::error::UseCaseError;
+use usecase::model::comment::{CreateComment, SearchCommentCondition, UpdateCommentView};
+use usecase::model::todo::{CreateTodo, TodoView};
+use usecase::usecase::comment::ICommentUseCase;
+use usecase::usecase::todo::ITodoUseCase;
+
+async fn create_todo(uc: &Arc<dyn ITodoUseCase>, user_id: &str) -> TodoView {
+    uc.create_todo(
+        user_id.to_string(),
+        CreateTodo::new("Commented".to_string(), "desc".to_string()),
+    )
+    .await
+    .expect("create_todo must succeed")
+}
+
+#[tokio::test]
+async fn create_update_delete_comment() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let todo_uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let uc: Arc<dyn ICommentUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let todo = create_todo(&todo_uc, &user_id).await;
+
+    let created = uc
+        .create_comment(
+            user_id.clone(),
+            todo.id.clone(),
+            CreateComment::new("  looks good  ".to_string()),
+        )
+        .await
+        .expect("create_comment must succeed");
+    assert_eq!(created.body, "looks good");
+    assert_eq!(created.author_id, user_id);
+    assert_eq!(created.todo_id, todo.id);
+
+    let updated = uc
+        .update_comment(
+            user_id.clone(),
+            todo.id.clone(),
+            UpdateCommentView::new(created.id.clone(), "edited".to_string()),
+        )
+        .await
+        .expect("update_comment must succeed");
+    assert_eq!(updated.body, "edited");
+
+    uc.delete_comment(user_id.clone(), todo.id.clone(), created.id.clone())
+        .await
+        .expect("delete_comment must succeed");
+    let page = uc
+        .find_comments(user_id, todo.id, SearchCommentCondition::default())
+        .await
+        .unwrap();
+    assert!(page.comments.is_empty());
+    assert!(!page.has_more);
+}
+
+#[tokio::test]
+async fn find_comments_pages_with_cursor() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let todo_uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let uc: Arc<dyn ICommentUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let todo = create_todo(&todo_uc, &user_id).await;
+
+    for body in ["one", "two", "three"] {
+        uc.create_comment(
+            user_id.clone(),
+            todo.id.clone(),
+            CreateComment::new(body.to_string()),
+        )
+        .await
+        .unwrap();
+    }
+
+    let first = uc
+        .find_comments(
+            user_id.clone(),
+            todo.id.clone(),
+            SearchCommentCondition {
+                cursor: None,
+                limit: Some(2),
+            },
+        )
+        .await
+        .unwrap();
+    let bodies: Vec<&str> = first.comments.iter().map(|c| c.body.as_str()).collect();
+    assert_eq!(bodies, ["one", "two"]);
+    assert!(first.has_more);
+
+    let second = uc
+        .find_comments(
+            user_id,
+            todo.id,
+            SearchCommentCondition {
+                cursor: first.next_cursor,
+                limit: Some(2),
+            },
+        )
+        .await
+        .unwrap();
+    let bodies: Vec<&str> = second.comments.iter().map(|c| c.body.as_str()).collect();
+    assert_eq!(bodies, ["three"]);
+    assert!(!second.has_more);
+    assert!(second.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn comment_on_other_users_todo_is_not_found() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let todo_uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let uc: Arc<dyn ICommentUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let other_id = create_test_user(&module).await;
+    let todo = create_todo(&todo_uc, &user_id).await;
+
+    let err = uc
+        .create_comment(other_id, todo.id, CreateComment::new("hi".to_string()))
+        .await
+        .expect_err("other user's todo must not be visible");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn create_comment_with_blank_body_is_invalid() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let todo_uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let uc: Arc<dyn ICommentUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let todo = create_todo(&todo_uc, &user_id).await;
+
+    let err = uc
+        .create_comment(user_id, todo.id, CreateComment::new("   ".to_string()))
+        .await
+        .expect_err("blank body must be rejected");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(p)) if p == "body"
+    ));
+}
@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+
+use crate::common::module::UsecaseTestModule;
+use domain::model::user::User;
+use domain::model::Id;
+use shaku::HasComponent;
+use std::sync::Arc;
+use usecase::model::user::CreateUser;
+use usecase::usecase::user::IUserUseCase;
+
+/// todo 소유자로 사용할 사용자를 커밋하여 생성하고 id 를 반환한다.
+/// username 은 ULID 로 고유하게 만들고, fullname 길이 제한(32자)에 맞게 뒤 12자만 쓴다.
+pub async fn create_test_user(module: &Arc<UsecaseTestModule>) -> String {
+    let uc: Arc<dyn IUserUseCase> = module.resolve();
+    let ulid = Id::<User>::gen().value.to_string().to_lowercase();
+    let suffix = &ulid[ulid.len() - 12..];
+    uc.create_user(CreateUser::new(
+        format!("todo_owner_{suffix}"),
+        "password1234".to_string(),
+        format!("Todo Owner {suffix}"),
+    ))
+    .await
+    .expect("setup: create_user must succeed")
+    .id
+}
@@ -1,3 +1,4 @@
 pub mod container;
 pub mod db;
+pub mod fixtures;
 pub mod module;
@@ -1,10 +1,28 @@
-#![allow(unused_imports)]
+#![allow(unused_imports, dead_code)]
 use infra::db::{Db, DbParameters};
+use infra::notifier::{INotifier, LogNotifier};
+use infra::repository::attachment::AttachmentRepository;
+use infra::repository::comment::CommentRepository;
+use infra::repository::idempotency::IdempotencyRepository;
+use infra::repository::project::ProjectRepository;
+use infra::repository::tag::TagRepository;
+use infra::repository::todo::event::TodoEventRepository;
+use infra::repository::todo::reminder::TodoReminderRepository;
+use infra::repository::todo::share::TodoShareRepository;
 use infra::repository::todo::status::TodoStatusRepository;
 use infra::repository::todo::TodoRepository;
 use infra::repository::user::UserRepository;
+use infra::storage::{LocalBlobStorage, LocalBlobStorageParameters};
 use shaku::module;
 use std::sync::Arc;
+use usecase::usecase::attachment::AttachmentUseCase;
+use usecase::usecase::comment::CommentUseCase;
+use usecase::usecase::idempotency::IdempotencyUseCase;
+use usecase::usecase::project::ProjectUseCase;
+use usecase::usecase::reminder::ReminderUseCase;
+use usecase::usecase::share::TodoShareUseCase;
+use usecase::usecase::status::TodoStatusUseCase;
+use usecase::usecase::tag::TagUseCase;
 use usecase::usecase::todo::TodoUseCase;
 use usecase::usecase::user::UserUseCase;
 
@@ -14,9 +32,27 @@ module! {
             Db,
             TodoRepository,
             TodoStatusRepository,
+            TodoEventRepository,
+            TodoReminderRepository,
+            TodoShareRepository,
             TodoUseCase,
+            TagRepository,
+            TagUseCase,
+            ProjectRepository,
+            ProjectUseCase,
+            CommentRepository,
+            CommentUseCase,
+            AttachmentRepository,
+            LocalBlobStorage,
+            AttachmentUseCase,
+            LogNotifier,
+            ReminderUseCase,
+            TodoShareUseCase,
+            TodoStatusUseCase,
             UserRepository,
             UserUseCase,
+            IdempotencyRepository,
+            IdempotencyUseCase,
         ],
         providers = []
     }
@@ -26,6 +62,25 @@ pub fn build_usecase_test_module(pool: sqlx::PgPool) -> Arc<UsecaseTestModule> {
     Arc::new(
         UsecaseTestModule::builder()
             .with_component_parameters::<Db>(DbParameters { pool })
+            .with_component_parameters::<LocalBlobStorage>(LocalBlobStorageParameters {
+                root: std::env::temp_dir().join("usecase-test-attachments"),
+            })
+            .build(),
+    )
+}
+
+/// 알림 채널을 `notifier` 로 바꿔 끼운 모듈. 보낸 알림을 확인하는 테스트에서 쓴다.
+pub fn build_usecase_test_module_with_notifier(
+    pool: sqlx::PgPool,
+    notifier: Box<dyn INotifier>,
+) -> Arc<UsecaseTestModule> {
+    Arc::new(
+        UsecaseTestModule::builder()
+            .with_component_parameters::<Db>(DbParameters { pool })
+            .with_component_parameters::<LocalBlobStorage>(LocalBlobStorageParameters {
+                root: std::env::temp_dir().join("usecase-test-attachments"),
+            })
+            .with_component_override::<dyn INotifier>(notifier)
             .build(),
     )
 }
@@ -0,0 +1,111 @@
+//! IdempotencyUseCase 통합 테스트
+//!
+//! 실행 방법:
+//! ```
+//! cargo test -p usecase --test idempotency_usecase_happy_path_test
+//! ```
+//!
+//! Docker가 실행 중이면 PostgreSQL 컨테이너를 자동으로 기동한다.
+
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::create_test_user;
+use common::module::build_usecase_test_module;
+use shaku::HasComponent;
+use std::sync::Arc;
+use usecase::error::UseCaseError;
+use usecase::model::idempotency::{IdempotencyBegin, IdempotentResponseView};
+use usecase::usecase::idempotency::IIdempotencyUseCase;
+
+const TTL_SECONDS: i64 = 60;
+
+#[tokio::test]
+async fn begin_replays_completed_response_and_detects_mismatch() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn IIdempotencyUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let other_id = create_test_user(&module).await;
+    let begin = |user_id: &String, hash: &str| {
+        uc.begin(
+            user_id.clone(),
+            "order-1".to_string(),
+            hash.to_string(),
+            TTL_SECONDS,
+        )
+    };
+
+    assert_eq!(
+        begin(&user_id, "h1").await.unwrap(),
+        IdempotencyBegin::Proceed
+    );
+    assert_eq!(
+        begin(&user_id, "h1").await.unwrap(),
+        IdempotencyBegin::InProgress
+    );
+    // 키는 사용자별이다.
+    assert_eq!(
+        begin(&other_id, "h2").await.unwrap(),
+        IdempotencyBegin::Proceed
+    );
+
+    let response = IdempotentResponseView {
+        status: 200,
+        headers: vec![("content-type".to_string(), "application/json".to_string())],
+        body: b"{\"result\":true}".to_vec(),
+    };
+    uc.complete(user_id.clone(), "order-1".to_string(), response.clone())
+        .await
+        .expect("complete must succeed");
+
+    assert_eq!(
+        begin(&user_id, "h1").await.unwrap(),
+        IdempotencyBegin::Replay(response)
+    );
+    assert_eq!(
+        begin(&user_id, "h2").await.unwrap(),
+        IdempotencyBegin::Mismatch
+    );
+}
+
+#[tokio::test]
+async fn released_key_can_be_used_again() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn IIdempotencyUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let begin = || {
+        uc.begin(
+            user_id.clone(),
+            "retry-me".to_string(),
+            "h1".to_string(),
+            TTL_SECONDS,
+        )
+    };
+    assert_eq!(begin().await.unwrap(), IdempotencyBegin::Proceed);
+    uc.release(user_id.clone(), "retry-me".to_string())
+        .await
+        .expect("release must succeed");
+    assert_eq!(begin().await.unwrap(), IdempotencyBegin::Proceed);
+}
+
+#[tokio::test]
+async fn begin_with_invalid_key_returns_invalid_parameter() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn IIdempotencyUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    for key in ["".to_string(), "k".repeat(256), "with space".to_string()] {
+        let err = uc
+            .begin(user_id.clone(), key, "h1".to_string(), TTL_SECONDS)
+            .await
+            .expect_err("invalid key must be rejected");
+        assert!(matches!(
+            err.downcast_ref::<UseCaseError>(),
+            Some(UseCaseError::InvalidParameter(name)) if name == "Idempotency-Key"
+        ));
+    }
+}
@@ -0,0 +1,223 @@
+//! ProjectUseCase 와 프로젝트별 todo 통합 테스트
+//!
+//! 실행 방법:
+//! ```
+//! cargo test -p usecase --test project_usecase_happy_path_test
+//! ```
+//!
+//! Docker가 실행 중이면 PostgreSQL 컨테이너를 자동으로 기동한다.
+
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::create_test_user;
+use common::module::build_usecase_test_module;
+use shaku::HasComponent;
+use std::sync::Arc;
+use usecase::error::UseCaseError;
+use usecase::model::project::{CreateProject, UpdateProjectView};
+use usecase::model::todo::{CreateTodo, SearchTodoCondition, UpdateTodoView};
+use usecase::usecase::project::IProjectUseCase;
+use usecase::usecase::todo::ITodoUseCase;
+
+// ─── project CRUD ────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn create_project_normalizes_and_rejects_duplicate_or_bad_color() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn IProjectUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let created = uc
+        .create_project(
+            user_id.clone(),
+            CreateProject::new(" 집안일 ".to_string(), Some("#FF8800".to_string())),
+        )
+        .await
+        .expect("create_project must succeed");
+    assert_eq!(created.name, "집안일");
+    assert_eq!(created.color, "#ff8800");
+
+    let err = uc
+        .create_project(
+            user_id.clone(),
+            CreateProject::new("집안일".to_string(), None),
+        )
+        .await
+        .expect_err("duplicate name must fail");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::Conflict(_))
+    ));
+
+    let err = uc
+        .create_project(
+            user_id.clone(),
+            CreateProject::new("work".to_string(), Some("red".to_string())),
+        )
+        .await
+        .expect_err("invalid color must fail");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(name)) if name == "color"
+    ));
+
+    let recolored = uc
+        .update_project(
+            user_id.clone(),
+            UpdateProjectView::new(created.id, None, Some("#00AA00".to_string())),
+        )
+        .await
+        .unwrap();
+    assert_eq!(recolored.name, "집안일");
+    assert_eq!(recolored.color, "#00aa00");
+}
+
+// ─── todos in projects ───────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn move_todo_between_projects_and_list_per_project() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn IProjectUseCase> = module.resolve();
+    let todo_uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let work = uc
+        .create_project(
+            user_id.clone(),
+            CreateProject::new("work".to_string(), None),
+        )
+        .await
+        .unwrap();
+    let home = uc
+        .create_project(
+            user_id.clone(),
+            CreateProject::new("home".to_string(), None),
+        )
+        .await
+        .unwrap();
+    let mut create = CreateTodo::new("Report".to_string(), "".to_string());
+    create.project_id = Some(work.id.clone());
+    let todo = todo_uc.create_todo(user_id.clone(), create).await.unwrap();
+    assert_eq!(todo.project_id.as_deref(), Some(work.id.as_str()));
+
+    let mut update = UpdateTodoView::new(todo.id.clone(), None, None, None);
+    update.project_id = Some(home.id.clone());
+    let moved = todo_uc.update_todo(user_id.clone(), update).await.unwrap();
+    assert_eq!(moved.project_id.as_deref(), Some(home.id.as_str()));
+
+    let page = todo_uc
+        .find_project_todos(
+            user_id.clone(),
+            work.id.clone(),
+            SearchTodoCondition::default(),
+        )
+        .await
+        .unwrap();
+    assert!(page.todos.is_empty());
+    let page = todo_uc
+        .find_project_todos(
+            user_id.clone(),
+            home.id.clone(),
+            SearchTodoCondition::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(page.todos.len(), 1);
+
+    // 빈 문자열이면 프로젝트에서 뺀다.
+    let mut update = UpdateTodoView::new(todo.id.clone(), None, None, None);
+    update.project_id = Some(String::new());
+    let moved = todo_uc.update_todo(user_id.clone(), update).await.unwrap();
+    assert!(moved.project_id.is_none());
+
+    let other_id = create_test_user(&module).await;
+    let err = todo_uc
+        .find_project_todos(other_id, home.id, SearchTodoCondition::default())
+        .await
+        .expect_err("other user's project must not be found");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn archive_project_archives_its_todos_until_unarchived() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn IProjectUseCase> = module.resolve();
+    let todo_uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let project = uc
+        .create_project(
+            user_id.clone(),
+            CreateProject::new("trip".to_string(), None),
+        )
+        .await
+        .unwrap();
+    for title in ["Pack", "Book hotel"] {
+        let mut create = CreateTodo::new(title.to_string(), "".to_string());
+        create.project_id = Some(project.id.clone());
+        todo_uc.create_todo(user_id.clone(), create).await.unwrap();
+    }
+    todo_uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Loose".to_string(), "".to_string()),
+        )
+        .await
+        .unwrap();
+
+    let archived = uc
+        .archive_project(user_id.clone(), project.id.clone())
+        .await
+        .unwrap();
+    assert!(archived.archived);
+    let page = todo_uc
+        .find_todo(user_id.clone(), SearchTodoCondition::default())
+        .await
+        .unwrap();
+    let titles: Vec<&str> = page.todos.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(titles, vec!["Loose"]);
+    let page = todo_uc
+        .find_project_todos(
+            user_id.clone(),
+            project.id.clone(),
+            SearchTodoCondition::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(page.todos.len(), 2);
+    assert!(page.todos.iter().all(|t| t.archived_at.is_some()));
+    assert!(uc
+        .find_projects(user_id.clone(), false)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // 보관한 프로젝트에는 todo 를 만들 수 없다.
+    let mut create = CreateTodo::new("Late".to_string(), "".to_string());
+    create.project_id = Some(project.id.clone());
+    let err = todo_uc
+        .create_todo(user_id.clone(), create)
+        .await
+        .expect_err("archived project must not accept todos");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(name)) if name == "projectId"
+    ));
+
+    uc.unarchive_project(user_id.clone(), project.id.clone())
+        .await
+        .unwrap();
+    let page = todo_uc
+        .find_todo(user_id.clone(), SearchTodoCondition::default())
+        .await
+        .unwrap();
+    assert_eq!(page.todos.len(), 3);
+    assert!(page.todos.iter().all(|t| t.archived_at.is_none()));
+}
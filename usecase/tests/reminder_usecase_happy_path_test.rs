@@ -0,0 +1,204 @@
+//! todo 알림 유스케이스 통합 테스트
+//!
+//! 실행 방법:
+//! ```
+//! cargo test -p usecase --test reminder_usecase_happy_path_test
+//! ```
+//!
+//! Docker가 실행 중이면 PostgreSQL 컨테이너를 자동으로 기동한다.
+
+mod common;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use common::db::setup_test_db;
+use common::fixtures::create_test_user;
+use common::module::build_usecase_test_module_with_notifier;
+use domain::model::todo::reminder::TodoReminder;
+use infra::notifier::INotifier;
+use shaku::HasComponent;
+use std::sync::{Arc, Mutex};
+use usecase::error::UseCaseError;
+use usecase::model::todo::{CreateTodo, TodoView, UpdateTodoView};
+use usecase::model::FieldUpdate;
+use usecase::usecase::reminder::IReminderUseCase;
+use usecase::usecase::todo::ITodoUseCase;
+
+/// 보내려 한 알림의 todo id 를 기록하고, 제목이 `Broken` 인 알림은 실패시킨다.
+#[derive(Clone, Default)]
+struct RecordingNotifier {
+    attempts: Arc<Mutex<Vec<String>>>,
+}
+
+impl RecordingNotifier {
+    fn take(&self) -> Vec<String> {
+        std::mem::take(&mut *self.attempts.lock().unwrap())
+    }
+}
+
+#[async_trait]
+impl INotifier for RecordingNotifier {
+    async fn notify(&self, reminder: &TodoReminder) -> anyhow::Result<()> {
+        self.attempts
+            .lock()
+            .unwrap()
+            .push(reminder.todo_id.value.to_string());
+        if reminder.title == "Broken" {
+            anyhow::bail!("channel is down");
+        }
+        Ok(())
+    }
+}
+
+async fn create_with_reminder(
+    uc: &Arc<dyn ITodoUseCase>,
+    user_id: &str,
+    title: &str,
+    remind_at: DateTime<Utc>,
+) -> TodoView {
+    let mut create = CreateTodo::new(title.to_string(), "desc".to_string());
+    create.remind_at = Some(remind_at.to_rfc3339());
+    uc.create_todo(user_id.to_string(), create)
+        .await
+        .expect("setup: create_todo must succeed")
+}
+
+/// 알림을 잠그면 같은 DB 를 쓰는 다른 테스트가 그 알림을 건너뛰므로 알림을 보내는 테스트는 이것 하나만 둔다.
+#[tokio::test]
+async fn deliver_due_reminders_sends_once_backs_off_failures_and_resends_when_rescheduled() {
+    let pool = setup_test_db().await;
+    let notifier = RecordingNotifier::default();
+    let module = build_usecase_test_module_with_notifier(pool.clone(), Box::new(notifier.clone()));
+    let todo_uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let reminder_uc: Arc<dyn IReminderUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let past = Utc::now() - Duration::minutes(5);
+
+    let due = create_with_reminder(&todo_uc, &user_id, "Due", past).await;
+    let later =
+        create_with_reminder(&todo_uc, &user_id, "Later", Utc::now() + Duration::hours(1)).await;
+    let broken = create_with_reminder(&todo_uc, &user_id, "Broken", past).await;
+    let done = create_with_reminder(&todo_uc, &user_id, "Done", past).await;
+    todo_uc
+        .update_todo(
+            user_id.clone(),
+            UpdateTodoView::new(done.id.clone(), None, None, Some("done".to_string())),
+        )
+        .await
+        .expect("completing todo must succeed");
+
+    reminder_uc
+        .deliver_due_reminders(1000)
+        .await
+        .expect("deliver_due_reminders must succeed");
+    let attempts = notifier.take();
+    assert!(attempts.contains(&due.id));
+    assert!(attempts.contains(&broken.id));
+    assert!(!attempts.contains(&later.id), "future reminder is not due");
+    assert!(
+        !attempts.contains(&done.id),
+        "finished todo is not reminded"
+    );
+
+    reminder_uc
+        .deliver_due_reminders(1000)
+        .await
+        .expect("deliver_due_reminders must succeed");
+    let attempts = notifier.take();
+    assert!(
+        !attempts.contains(&due.id),
+        "delivered reminder is not sent again"
+    );
+    assert!(
+        !attempts.contains(&broken.id),
+        "failed reminder waits before it is retried"
+    );
+
+    sqlx::query("UPDATE todos SET reminder_next_attempt_at = now() WHERE id = $1")
+        .bind(&broken.id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    reminder_uc.deliver_due_reminders(1000).await.unwrap();
+    assert!(
+        notifier.take().contains(&broken.id),
+        "failed reminder is retried once its retry time has come"
+    );
+
+    let mut reschedule = UpdateTodoView::new(due.id.clone(), None, None, None);
+    reschedule.remind_at = FieldUpdate::Set((Utc::now() - Duration::minutes(1)).to_rfc3339());
+    todo_uc
+        .update_todo(user_id.clone(), reschedule)
+        .await
+        .expect("rescheduling reminder must succeed");
+    reminder_uc.deliver_due_reminders(1000).await.unwrap();
+    assert!(
+        notifier.take().contains(&due.id),
+        "rescheduled reminder is sent again"
+    );
+}
+
+#[tokio::test]
+async fn snooze_postpones_reminder_from_now() {
+    let pool = setup_test_db().await;
+    let module =
+        build_usecase_test_module_with_notifier(pool, Box::new(RecordingNotifier::default()));
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let todo =
+        create_with_reminder(&uc, &user_id, "Snoozed", Utc::now() + Duration::days(30)).await;
+
+    let before = Utc::now();
+    let snoozed = uc
+        .snooze_todo(user_id.clone(), todo.id.clone(), 10, Some(todo.version))
+        .await
+        .expect("snooze_todo must succeed");
+    let remind_at = DateTime::parse_from_rfc3339(&snoozed.remind_at.unwrap().to_string()).unwrap();
+    assert!(remind_at >= before + Duration::minutes(10));
+    assert!(remind_at <= Utc::now() + Duration::minutes(10));
+    assert_eq!(snoozed.version, todo.version + 1);
+
+    let err = uc
+        .snooze_todo(user_id.clone(), todo.id.clone(), 0, None)
+        .await
+        .expect_err("snoozing 0 minutes must fail");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(name)) if name == "minutes"
+    ));
+}
+
+#[tokio::test]
+async fn next_occurrence_keeps_reminder_offset() {
+    let pool = setup_test_db().await;
+    let module =
+        build_usecase_test_module_with_notifier(pool, Box::new(RecordingNotifier::default()));
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let mut create = CreateTodo::new("Daily report".to_string(), "desc".to_string());
+    create.due_at = Some("2030-01-07T09:00:00Z".to_string());
+    create.remind_at = Some("2030-01-07T08:30:00Z".to_string());
+    create.recurrence = Some("FREQ=DAILY".to_string());
+    let first = uc.create_todo(user_id.clone(), create).await.unwrap();
+
+    uc.update_todo(
+        user_id.clone(),
+        UpdateTodoView::new(first.id.clone(), None, None, Some("done".to_string())),
+    )
+    .await
+    .unwrap();
+
+    let page = uc
+        .find_todo(user_id.clone(), Default::default())
+        .await
+        .unwrap();
+    let next = page
+        .todos
+        .iter()
+        .find(|t| t.id != first.id)
+        .expect("next occurrence must be created");
+    assert_eq!(
+        next.remind_at.as_ref().map(|d| d.to_string()).as_deref(),
+        Some("2030-01-08T08:30:00+00:00")
+    );
+}
@@ -0,0 +1,227 @@
+//! 하위 작업(todo 계층) 유스케이스 통합 테스트
+//!
+//! 실행 방법:
+//! ```
+//! cargo test -p usecase --test subtask_usecase_happy_path_test
+//! ```
+//!
+//! Docker가 실행 중이면 PostgreSQL 컨테이너를 자동으로 기동한다.
+
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::create_test_user;
+use common::module::build_usecase_test_module;
+use shaku::HasComponent;
+use std::sync::Arc;
+use usecase::error::UseCaseError;
+use usecase::model::todo::{CreateTodo, SearchTodoCondition, TodoView, UpdateTodoView};
+use usecase::usecase::todo::ITodoUseCase;
+
+/// `parent_id` 아래에 하위 작업을 만든다.
+async fn create_child(
+    uc: &Arc<dyn ITodoUseCase>,
+    user_id: &str,
+    title: &str,
+    parent_id: Option<&str>,
+) -> TodoView {
+    let mut source = CreateTodo::new(title.to_string(), "desc".to_string());
+    source.parent_id = parent_id.map(str::to_string);
+    uc.create_todo(user_id.to_string(), source)
+        .await
+        .expect("create_todo must succeed")
+}
+
+/// 상태만 바꾸는 수정 요청
+fn status_update(id: &str, code: &str, roll_up: bool) -> UpdateTodoView {
+    let mut view = UpdateTodoView::new(id.to_string(), None, None, Some(code.to_string()));
+    view.roll_up_completion = roll_up;
+    view
+}
+
+#[tokio::test]
+async fn create_subtasks_then_get_tree_and_children() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let root = create_child(&uc, &user_id, "Root", None).await;
+    let child = create_child(&uc, &user_id, "Child", Some(&root.id)).await;
+    let grandchild = create_child(&uc, &user_id, "Grandchild", Some(&child.id)).await;
+    assert_eq!(child.parent_id.as_deref(), Some(root.id.as_str()));
+
+    let tree = uc
+        .get_todo_tree(user_id.clone(), root.id.clone())
+        .await
+        .unwrap()
+        .expect("tree must exist");
+    assert_eq!(tree.todo.id, root.id);
+    assert_eq!(tree.children.len(), 1);
+    assert_eq!(tree.children[0].todo.id, child.id);
+    assert_eq!(tree.children[0].children[0].todo.id, grandchild.id);
+
+    let page = uc
+        .find_children(
+            user_id.clone(),
+            root.id.clone(),
+            SearchTodoCondition::default(),
+        )
+        .await
+        .unwrap();
+    let ids: Vec<&str> = page.todos.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, [child.id.as_str()]);
+
+    // 하위 작업은 휴지통으로 옮기면 트리에서 빠진다.
+    uc.delete_todo(user_id.clone(), child.id.clone(), None)
+        .await
+        .unwrap();
+    let tree = uc.get_todo_tree(user_id, root.id).await.unwrap().unwrap();
+    assert!(tree.children.is_empty());
+}
+
+#[tokio::test]
+async fn find_children_of_other_users_todo_returns_not_found() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let owner_id = create_test_user(&module).await;
+    let other_id = create_test_user(&module).await;
+    let root = create_child(&uc, &owner_id, "Root", None).await;
+
+    let err = uc
+        .find_children(
+            other_id.clone(),
+            root.id.clone(),
+            SearchTodoCondition::default(),
+        )
+        .await
+        .expect_err("other user's todo must not be found");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::NotFound(_))
+    ));
+
+    let mut source = CreateTodo::new("Intruder".to_string(), "desc".to_string());
+    source.parent_id = Some(root.id);
+    let err = uc
+        .create_todo(other_id, source)
+        .await
+        .expect_err("other user's todo must not be a parent");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(name)) if name == "parentId"
+    ));
+}
+
+#[tokio::test]
+async fn update_todo_parent_rejects_cycles_and_moves_to_top_level() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let root = create_child(&uc, &user_id, "Root", None).await;
+    let child = create_child(&uc, &user_id, "Child", Some(&root.id)).await;
+    let grandchild = create_child(&uc, &user_id, "Grandchild", Some(&child.id)).await;
+
+    for parent_id in [&root.id, &grandchild.id] {
+        let mut view = UpdateTodoView::new(root.id.clone(), None, None, None);
+        view.parent_id = Some(parent_id.clone());
+        let err = uc
+            .update_todo(user_id.clone(), view)
+            .await
+            .expect_err("moving under itself or a descendant must fail");
+        assert!(matches!(
+            err.downcast_ref::<UseCaseError>(),
+            Some(UseCaseError::Conflict(_))
+        ));
+    }
+
+    let mut view = UpdateTodoView::new(grandchild.id.clone(), None, None, None);
+    view.parent_id = Some(root.id.clone());
+    let moved = uc.update_todo(user_id.clone(), view).await.unwrap();
+    assert_eq!(moved.parent_id.as_deref(), Some(root.id.as_str()));
+
+    let mut view = UpdateTodoView::new(grandchild.id.clone(), None, None, None);
+    view.parent_id = Some(String::new());
+    let moved = uc.update_todo(user_id.clone(), view).await.unwrap();
+    assert!(moved.parent_id.is_none());
+
+    let mut view = UpdateTodoView::new(grandchild.id, None, None, None);
+    view.parent_id = Some("not-a-ulid".to_string());
+    let err = uc.update_todo(user_id, view).await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(name)) if name == "parentId"
+    ));
+}
+
+#[tokio::test]
+async fn completing_last_subtask_rolls_up_to_ancestors() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let root = create_child(&uc, &user_id, "Root", None).await;
+    let parent = create_child(&uc, &user_id, "Parent", Some(&root.id)).await;
+    let first = create_child(&uc, &user_id, "First", Some(&parent.id)).await;
+    let second = create_child(&uc, &user_id, "Second", Some(&parent.id)).await;
+    let status_of = |id: String| {
+        let uc = uc.clone();
+        let user_id = user_id.clone();
+        async move { uc.get_todo(user_id, id).await.unwrap().unwrap().status.code }
+    };
+
+    uc.update_todo(user_id.clone(), status_update(&first.id, "done", true))
+        .await
+        .unwrap();
+    assert_eq!(status_of(parent.id.clone()).await, "new");
+
+    // 롤업을 끄면 상위 todo 는 그대로이다.
+    uc.update_todo(user_id.clone(), status_update(&second.id, "done", false))
+        .await
+        .unwrap();
+    assert_eq!(status_of(parent.id.clone()).await, "new");
+
+    uc.update_todo(user_id.clone(), status_update(&second.id, "working", false))
+        .await
+        .unwrap();
+    uc.update_todo(user_id.clone(), status_update(&second.id, "done", true))
+        .await
+        .unwrap();
+    assert_eq!(status_of(parent.id).await, "done");
+    assert_eq!(status_of(root.id).await, "done");
+}
+
+#[tokio::test]
+async fn update_todo_parent_with_expected_version_bumps_version() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let root = create_child(&uc, &user_id, "Root", None).await;
+    let todo = create_child(&uc, &user_id, "Todo", None).await;
+
+    let mut view = UpdateTodoView::new(todo.id.clone(), Some("Moved".to_string()), None, None);
+    view.parent_id = Some(root.id.clone());
+    view.expected_version = Some(todo.version);
+    let moved = uc
+        .update_todo(user_id.clone(), view)
+        .await
+        .expect("update with current version must succeed");
+    assert_eq!(moved.parent_id.as_deref(), Some(root.id.as_str()));
+    assert!(moved.version > todo.version);
+
+    // 옮기기 전 버전으로는 다시 옮길 수 없다.
+    let mut view = UpdateTodoView::new(todo.id.clone(), None, None, None);
+    view.parent_id = Some(String::new());
+    view.expected_version = Some(todo.version);
+    let err = uc
+        .update_todo(user_id, view)
+        .await
+        .expect_err("stale version must be rejected");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::PreconditionFailed(_))
+    ));
+}
@@ -0,0 +1,172 @@
+//! TagUseCase 와 todo 태그 연동 통합 테스트
+//!
+//! 실행 방법:
+//! ```
+//! cargo test -p usecase --test tag_usecase_happy_path_test
+//! ```
+//!
+//! Docker가 실행 중이면 PostgreSQL 컨테이너를 자동으로 기동한다.
+
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::create_test_user;
+use common::module::build_usecase_test_module;
+use shaku::HasComponent;
+use std::sync::Arc;
+use usecase::error::UseCaseError;
+use usecase::model::tag::{CreateTag, UpdateTagView};
+use usecase::model::todo::{CreateTodo, SearchTodoCondition, UpdateTodoView};
+use usecase::usecase::tag::ITagUseCase;
+use usecase::usecase::todo::ITodoUseCase;
+
+// ─── tag CRUD ────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn create_tag_trims_name_and_rejects_duplicate() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITagUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let other_id = create_test_user(&module).await;
+
+    let created = uc
+        .create_tag(user_id.clone(), CreateTag::new("  work ".to_string()))
+        .await
+        .expect("create_tag must succeed");
+    assert_eq!(created.name, "work");
+
+    let err = uc
+        .create_tag(user_id.clone(), CreateTag::new("work".to_string()))
+        .await
+        .expect_err("duplicate name must fail");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::Conflict(_))
+    ));
+
+    uc.create_tag(other_id, CreateTag::new("work".to_string()))
+        .await
+        .expect("other user may use the same name");
+    let tags = uc.find_tags(user_id).await.unwrap();
+    assert_eq!(tags.len(), 1);
+}
+
+#[tokio::test]
+async fn update_tag_renames_and_rejects_name_in_use() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITagUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let work = uc
+        .create_tag(user_id.clone(), CreateTag::new("work".to_string()))
+        .await
+        .unwrap();
+    uc.create_tag(user_id.clone(), CreateTag::new("home".to_string()))
+        .await
+        .unwrap();
+
+    // 자기 이름으로 바꾸는 것은 충돌이 아니다.
+    uc.update_tag(
+        user_id.clone(),
+        UpdateTagView::new(work.id.clone(), "work".to_string()),
+    )
+    .await
+    .expect("renaming to the same name must succeed");
+
+    let err = uc
+        .update_tag(
+            user_id.clone(),
+            UpdateTagView::new(work.id.clone(), "home".to_string()),
+        )
+        .await
+        .expect_err("name in use must fail");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::Conflict(_))
+    ));
+
+    let renamed = uc
+        .update_tag(
+            user_id.clone(),
+            UpdateTagView::new(work.id.clone(), "office".to_string()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(renamed.name, "office");
+}
+
+// ─── todo 태그 ───────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn create_and_update_todo_attach_and_detach_tags() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let tag_uc: Arc<dyn ITagUseCase> = module.resolve();
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let work = tag_uc
+        .create_tag(user_id.clone(), CreateTag::new("work".to_string()))
+        .await
+        .unwrap();
+    let home = tag_uc
+        .create_tag(user_id.clone(), CreateTag::new("home".to_string()))
+        .await
+        .unwrap();
+
+    let mut source = CreateTodo::new("Tagged".to_string(), "desc".to_string());
+    source.tag_ids = vec![work.id.clone()];
+    let created = uc.create_todo(user_id.clone(), source).await.unwrap();
+    let names: Vec<&str> = created.tags.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, vec!["work"]);
+
+    let mut update = UpdateTodoView::new(created.id.clone(), None, None, None);
+    update.add_tag_ids = vec![home.id.clone()];
+    update.remove_tag_ids = vec![work.id.clone()];
+    let updated = uc.update_todo(user_id.clone(), update).await.unwrap();
+    let names: Vec<&str> = updated.tags.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, vec!["home"]);
+
+    let page = uc
+        .find_todo(
+            user_id.clone(),
+            SearchTodoCondition {
+                tag_names: vec!["home".to_string()],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(page.todos.len(), 1);
+    assert_eq!(page.todos[0].id, created.id);
+}
+
+#[tokio::test]
+async fn create_todo_with_other_user_tag_returns_invalid_parameter() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let tag_uc: Arc<dyn ITagUseCase> = module.resolve();
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let other_id = create_test_user(&module).await;
+
+    let theirs = tag_uc
+        .create_tag(other_id, CreateTag::new("theirs".to_string()))
+        .await
+        .unwrap();
+
+    for tag_id in [theirs.id, "not-a-ulid".to_string()] {
+        let mut source = CreateTodo::new("Tagged".to_string(), "desc".to_string());
+        source.tag_ids = vec![tag_id];
+        let err = uc
+            .create_todo(user_id.clone(), source)
+            .await
+            .expect_err("unknown tag must fail");
+        assert!(matches!(
+            err.downcast_ref::<UseCaseError>(),
+            Some(UseCaseError::InvalidParameter(name)) if name == "tagIds"
+        ));
+    }
+}
@@ -0,0 +1,176 @@
+//! Todo 일괄 처리 유스케이스 통합 테스트
+//!
+//! 실행 방법:
+//! ```
+//! cargo test -p usecase --test todo_batch_usecase_happy_path_test
+//! ```
+//!
+//! Docker가 실행 중이면 PostgreSQL 컨테이너를 자동으로 기동한다.
+
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::create_test_user;
+use common::module::build_usecase_test_module;
+use shaku::HasComponent;
+use std::sync::Arc;
+use usecase::error::{TodoBatchError, UseCaseError};
+use usecase::model::todo::batch::TodoBatchOperation;
+use usecase::model::todo::{CreateTodo, SearchTodoCondition, UpdateTodoView, UpsertTodoView};
+use usecase::usecase::todo::ITodoUseCase;
+
+#[tokio::test]
+async fn run_batch_applies_every_operation_in_order() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let existing = uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Existing".to_string(), "desc".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+
+    let results = uc
+        .run_batch(
+            user_id.clone(),
+            vec![
+                TodoBatchOperation::Create(CreateTodo::new(
+                    "Created".to_string(),
+                    "desc".to_string(),
+                )),
+                TodoBatchOperation::Update(UpdateTodoView::new(
+                    existing.id.clone(),
+                    Some("Renamed".to_string()),
+                    None,
+                    Some("working".to_string()),
+                )),
+                TodoBatchOperation::Upsert(UpsertTodoView::new(
+                    existing.id.clone(),
+                    "Replaced".to_string(),
+                    "new desc".to_string(),
+                    "done".to_string(),
+                )),
+                TodoBatchOperation::Delete {
+                    id: existing.id.clone(),
+                    expected_version: Some(existing.version + 2),
+                },
+            ],
+        )
+        .await
+        .expect("run_batch must succeed");
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].title, "Created");
+    assert_eq!(results[1].title, "Renamed");
+    assert_eq!(results[1].status.code, "working");
+    assert_eq!(results[2].title, "Replaced");
+    assert_eq!(results[3].status.code, "deleted");
+    let created = uc
+        .get_todo(user_id.clone(), results[0].id.clone())
+        .await
+        .unwrap();
+    assert!(created.is_some(), "created todo must be committed");
+}
+
+#[tokio::test]
+async fn run_batch_rolls_back_everything_and_reports_failing_index() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let existing = uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Existing".to_string(), "desc".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+
+    let err = uc
+        .run_batch(
+            user_id.clone(),
+            vec![
+                TodoBatchOperation::Create(CreateTodo::new(
+                    "Rolled back".to_string(),
+                    "desc".to_string(),
+                )),
+                TodoBatchOperation::Update(UpdateTodoView::new(
+                    existing.id.clone(),
+                    Some("Renamed".to_string()),
+                    None,
+                    Some("done".to_string()),
+                )),
+                // done → new 는 허용되지 않는 전이다.
+                TodoBatchOperation::Update(UpdateTodoView::new(
+                    existing.id.clone(),
+                    None,
+                    None,
+                    Some("new".to_string()),
+                )),
+            ],
+        )
+        .await
+        .expect_err("run_batch must fail on the illegal transition");
+
+    let failure = err
+        .downcast_ref::<TodoBatchError>()
+        .expect("error must carry the failing index");
+    assert_eq!(failure.index, 2);
+    assert!(matches!(
+        failure.source.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::Conflict(_))
+    ));
+
+    let view = uc
+        .get_todo(user_id.clone(), existing.id.clone())
+        .await
+        .unwrap()
+        .expect("todo must still exist");
+    assert_eq!(view.title, "Existing");
+    assert_eq!(view.status.code, "new");
+    let page = uc
+        .find_todo(user_id, SearchTodoCondition::default())
+        .await
+        .unwrap();
+    assert_eq!(page.todos.len(), 1, "created todo must be rolled back");
+}
+
+#[tokio::test]
+async fn run_batch_with_no_operations_or_missing_delete_target_fails() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let err = uc
+        .run_batch(user_id.clone(), vec![])
+        .await
+        .expect_err("empty batch must be rejected");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(_))
+    ));
+
+    let missing = domain::model::Id::<domain::model::todo::Todo>::gen()
+        .value
+        .to_string();
+    let err = uc
+        .run_batch(
+            user_id,
+            vec![TodoBatchOperation::Delete {
+                id: missing,
+                expected_version: None,
+            }],
+        )
+        .await
+        .expect_err("deleting a missing todo must fail");
+    let failure = err.downcast_ref::<TodoBatchError>().unwrap();
+    assert_eq!(failure.index, 0);
+    assert!(matches!(
+        failure.source.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::NotFound(_))
+    ));
+}
@@ -0,0 +1,185 @@
+//! Todo 변경 이력 유스케이스 통합 테스트
+//!
+//! 실행 방법:
+//! ```
+//! cargo test -p usecase --test todo_history_usecase_happy_path_test
+//! ```
+//!
+//! Docker가 실행 중이면 PostgreSQL 컨테이너를 자동으로 기동한다.
+
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::create_test_user;
+use common::module::build_usecase_test_module;
+use shaku::HasComponent;
+use std::sync::Arc;
+use usecase::error::UseCaseError;
+use usecase::model::todo::history::SearchTodoHistoryCondition;
+use usecase::model::todo::{CreateTodo, UpdateTodoView, UpsertTodoView};
+use usecase::usecase::todo::ITodoUseCase;
+
+async fn create_todo(uc: &Arc<dyn ITodoUseCase>, user_id: &str, title: &str) -> String {
+    uc.create_todo(
+        user_id.to_string(),
+        CreateTodo::new(title.to_string(), "desc".to_string()),
+    )
+    .await
+    .expect("create_todo must succeed")
+    .id
+}
+
+#[tokio::test]
+async fn update_records_field_level_changes_newest_first() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let id = create_todo(&uc, &user_id, "Before").await;
+
+    uc.update_todo(
+        user_id.clone(),
+        UpdateTodoView::new(id.clone(), Some("After".to_string()), None, None),
+    )
+    .await
+    .unwrap();
+    uc.update_todo(
+        user_id.clone(),
+        UpdateTodoView::new(id.clone(), None, None, Some("working".to_string())),
+    )
+    .await
+    .unwrap();
+    // 값이 그대로인 수정은 이력을 남기지 않는다.
+    uc.update_todo(
+        user_id.clone(),
+        UpdateTodoView::new(id.clone(), Some("After".to_string()), None, None),
+    )
+    .await
+    .unwrap();
+
+    let page = uc
+        .find_history(
+            user_id.clone(),
+            id.clone(),
+            SearchTodoHistoryCondition::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(page.events.len(), 2);
+    assert!(!page.has_more);
+    let latest = &page.events[0];
+    assert_eq!(latest.actor_id, user_id);
+    assert_eq!(latest.changes.len(), 1);
+    assert_eq!(latest.changes[0].field, "status");
+    assert_eq!(latest.changes[0].before.as_deref(), Some("new"));
+    assert_eq!(latest.changes[0].after.as_deref(), Some("working"));
+    let first = &page.events[1];
+    assert_eq!(first.changes[0].field, "title");
+    assert_eq!(first.changes[0].before.as_deref(), Some("Before"));
+    assert_eq!(first.changes[0].after.as_deref(), Some("After"));
+
+    let first_page = uc
+        .find_history(
+            user_id.clone(),
+            id.clone(),
+            SearchTodoHistoryCondition {
+                cursor: None,
+                limit: Some(1),
+            },
+        )
+        .await
+        .unwrap();
+    assert!(first_page.has_more);
+    let second_page = uc
+        .find_history(
+            user_id,
+            id,
+            SearchTodoHistoryCondition {
+                cursor: first_page.next_cursor,
+                limit: Some(1),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(second_page.events[0].id, first.id);
+}
+
+#[tokio::test]
+async fn upsert_delete_and_restore_record_history() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let id = create_todo(&uc, &user_id, "Title").await;
+
+    uc.upsert_todo(
+        user_id.clone(),
+        UpsertTodoView::new(
+            id.clone(),
+            "Title".to_string(),
+            "new desc".to_string(),
+            "new".to_string(),
+        ),
+    )
+    .await
+    .unwrap();
+    uc.delete_todo(user_id.clone(), id.clone(), None)
+        .await
+        .unwrap();
+    uc.restore_todo(user_id.clone(), id.clone()).await.unwrap();
+
+    let page = uc
+        .find_history(user_id, id, SearchTodoHistoryCondition::default())
+        .await
+        .unwrap();
+    let fields: Vec<(&str, Option<&str>)> = page
+        .events
+        .iter()
+        .map(|e| (e.changes[0].field.as_str(), e.changes[0].after.as_deref()))
+        .collect();
+    assert_eq!(
+        fields,
+        [
+            ("status", Some("new")),
+            ("status", Some("deleted")),
+            ("description", Some("new desc")),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn rejected_update_records_nothing_and_other_user_cannot_read_history() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let other_id = create_test_user(&module).await;
+    let id = create_todo(&uc, &user_id, "Title").await;
+
+    // 수정으로 휴지통에 보낼 수는 없으므로 제목 변경도 함께 취소된다.
+    uc.update_todo(
+        user_id.clone(),
+        UpdateTodoView::new(
+            id.clone(),
+            Some("Changed".to_string()),
+            None,
+            Some("deleted".to_string()),
+        ),
+    )
+    .await
+    .expect_err("transition new -> deleted must be rejected");
+    let page = uc
+        .find_history(user_id, id.clone(), SearchTodoHistoryCondition::default())
+        .await
+        .unwrap();
+    assert!(page.events.is_empty());
+
+    let err = uc
+        .find_history(other_id, id, SearchTodoHistoryCondition::default())
+        .await
+        .expect_err("other user's todo must not be visible");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::NotFound(_))
+    ));
+}
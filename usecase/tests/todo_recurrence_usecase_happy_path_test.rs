@@ -0,0 +1,175 @@
+//! 반복 todo 유스케이스 통합 테스트
+//!
+//! 실행 방법:
+//! ```
+//! cargo test -p usecase --test todo_recurrence_usecase_happy_path_test
+//! ```
+//!
+//! Docker가 실행 중이면 PostgreSQL 컨테이너를 자동으로 기동한다.
+
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::create_test_user;
+use common::module::build_usecase_test_module;
+use shaku::HasComponent;
+use std::sync::Arc;
+use usecase::error::UseCaseError;
+use usecase::model::todo::{CreateTodo, SearchTodoCondition, TodoView, UpdateTodoView};
+use usecase::model::FieldUpdate;
+use usecase::usecase::todo::ITodoUseCase;
+
+async fn create_recurring(
+    uc: &Arc<dyn ITodoUseCase>,
+    user_id: &str,
+    title: &str,
+    due_at: &str,
+    recurrence: &str,
+) -> TodoView {
+    let mut create = CreateTodo::new(title.to_string(), "desc".to_string());
+    create.due_at = Some(due_at.to_string());
+    create.priority = Some("high".to_string());
+    create.recurrence = Some(recurrence.to_string());
+    uc.create_todo(user_id.to_string(), create)
+        .await
+        .expect("setup: create_todo must succeed")
+}
+
+async fn complete(uc: &Arc<dyn ITodoUseCase>, user_id: &str, id: &str) -> TodoView {
+    let update = UpdateTodoView::new(id.to_string(), None, None, Some("done".to_string()));
+    uc.update_todo(user_id.to_string(), update)
+        .await
+        .expect("completing todo must succeed")
+}
+
+async fn find_by_title(uc: &Arc<dyn ITodoUseCase>, user_id: &str, title: &str) -> Vec<TodoView> {
+    let page = uc
+        .find_todo(user_id.to_string(), SearchTodoCondition::default())
+        .await
+        .expect("find_todo must succeed");
+    page.todos
+        .into_iter()
+        .filter(|t| t.title == title)
+        .collect()
+}
+
+#[tokio::test]
+async fn completing_recurring_todo_creates_next_occurrence_until_count_runs_out() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    // 2030-01-07 은 월요일이다.
+    let first = create_recurring(
+        &uc,
+        &user_id,
+        "Standup",
+        "2030-01-07T09:00:00Z",
+        "FREQ=WEEKLY;BYDAY=MO,WE;COUNT=2",
+    )
+    .await;
+    assert_eq!(
+        first.recurrence.as_deref(),
+        Some("FREQ=WEEKLY;BYDAY=MO,WE;COUNT=2")
+    );
+
+    let done = complete(&uc, &user_id, &first.id).await;
+    assert_eq!(done.status.code, "done");
+    assert!(done.recurrence.is_none(), "series moves to the next todo");
+
+    let todos = find_by_title(&uc, &user_id, "Standup").await;
+    assert_eq!(todos.len(), 2);
+    let next = todos
+        .iter()
+        .find(|t| t.id != first.id)
+        .expect("next occurrence must be created");
+    assert_eq!(next.status.code, "new");
+    assert_eq!(next.priority, "high");
+    assert_eq!(
+        next.due_at.as_ref().map(|d| d.to_string()).as_deref(),
+        Some("2030-01-09T09:00:00+00:00")
+    );
+    assert_eq!(
+        next.recurrence.as_deref(),
+        Some("FREQ=WEEKLY;BYDAY=MO,WE;COUNT=1")
+    );
+
+    complete(&uc, &user_id, &next.id).await;
+    let todos = find_by_title(&uc, &user_id, "Standup").await;
+    assert_eq!(todos.len(), 2, "COUNT=1 is the last occurrence");
+}
+
+#[tokio::test]
+async fn get_occurrences_previews_series_and_stopping_it_prevents_next_todo() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let todo = create_recurring(
+        &uc,
+        &user_id,
+        "Rent",
+        "2030-01-15T00:00:00Z",
+        "FREQ=MONTHLY;BYMONTHDAY=1",
+    )
+    .await;
+
+    let view = uc
+        .get_occurrences(user_id.clone(), todo.id.clone(), Some(3))
+        .await
+        .expect("get_occurrences must succeed");
+    let occurrences: Vec<String> = view.occurrences.iter().map(|d| d.to_string()).collect();
+    assert_eq!(
+        occurrences,
+        vec![
+            "2030-02-01T00:00:00+00:00",
+            "2030-03-01T00:00:00+00:00",
+            "2030-04-01T00:00:00+00:00",
+        ]
+    );
+
+    let err = uc
+        .get_occurrences(user_id.clone(), todo.id.clone(), Some(0))
+        .await
+        .expect_err("limit 0 must fail");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(name)) if name == "limit"
+    ));
+
+    let mut stop = UpdateTodoView::new(todo.id.clone(), None, None, None);
+    stop.recurrence = FieldUpdate::Clear;
+    let stopped = uc
+        .update_todo(user_id.clone(), stop)
+        .await
+        .expect("stopping series must succeed");
+    assert!(stopped.recurrence.is_none());
+    let view = uc
+        .get_occurrences(user_id.clone(), todo.id.clone(), None)
+        .await
+        .expect("get_occurrences must succeed");
+    assert!(view.recurrence.is_none());
+    assert!(view.occurrences.is_empty());
+
+    complete(&uc, &user_id, &todo.id).await;
+    assert_eq!(find_by_title(&uc, &user_id, "Rent").await.len(), 1);
+}
+
+#[tokio::test]
+async fn create_todo_with_unsupported_recurrence_returns_invalid_parameter() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let mut create = CreateTodo::new("Hourly".to_string(), "desc".to_string());
+    create.recurrence = Some("FREQ=HOURLY".to_string());
+    let err = uc
+        .create_todo(user_id, create)
+        .await
+        .expect_err("unsupported RRULE must fail");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(name)) if name == "recurrence"
+    ));
+}
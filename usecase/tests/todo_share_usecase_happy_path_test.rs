@@ -0,0 +1,439 @@
+//! TodoShareUseCase 와 공유받은 todo 권한 통합 테스트
+//!
+//! 실행 방법:
+//! ```
+//! cargo test -p usecase --test todo_share_usecase_happy_path_test
+//! ```
+//!
+//! Docker가 실행 중이면 PostgreSQL 컨테이너를 자동으로 기동한다.
+
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::create_test_user;
+use common::module::{build_usecase_test_module, UsecaseTestModule};
+use shaku::HasComponent;
+use std::sync::Arc;
+use tokio::io::AsyncReadExt;
+use usecase::error::UseCaseError;
+use usecase::model::attachment::{AttachmentLimits, UploadAttachment};
+use usecase::model::comment::{CreateComment, SearchCommentCondition};
+use usecase::model::todo::history::SearchTodoHistoryCondition;
+use usecase::model::todo::share::ShareTodo;
+use usecase::model::todo::{CreateTodo, UpdateTodoView};
+use usecase::usecase::attachment::IAttachmentUseCase;
+use usecase::usecase::comment::ICommentUseCase;
+use usecase::usecase::share::ITodoShareUseCase;
+use usecase::usecase::todo::ITodoUseCase;
+use usecase::usecase::user::IUserUseCase;
+
+/// 사용자 id 로 사용자 이름을 찾는다.
+async fn username_of(module: &Arc<UsecaseTestModule>, user_id: &str) -> String {
+    let uc: Arc<dyn IUserUseCase> = module.resolve();
+    uc.get_user(user_id.to_string())
+        .await
+        .unwrap()
+        .expect("setup: user must exist")
+        .username
+}
+
+fn is_forbidden(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::Forbidden(_))
+    )
+}
+
+// ─── share management ────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn only_owner_can_share_and_unshare() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let todo_uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let uc: Arc<dyn ITodoShareUseCase> = module.resolve();
+    let owner_id = create_test_user(&module).await;
+    let guest_id = create_test_user(&module).await;
+    let other_id = create_test_user(&module).await;
+    let owner_name = username_of(&module, &owner_id).await;
+    let guest_name = username_of(&module, &guest_id).await;
+    let other_name = username_of(&module, &other_id).await;
+
+    let todo = todo_uc
+        .create_todo(
+            owner_id.clone(),
+            CreateTodo::new("공유 todo".to_string(), String::new()),
+        )
+        .await
+        .unwrap();
+
+    let shared = uc
+        .share_todo(
+            owner_id.clone(),
+            todo.id.clone(),
+            ShareTodo::new(guest_name.clone(), "read".to_string()),
+        )
+        .await
+        .expect("share_todo must succeed");
+    assert_eq!(shared.user_id, guest_id);
+    assert_eq!(shared.permission, "read");
+
+    // 자기 자신, 없는 사용자, 알 수 없는 권한은 받지 않는다.
+    for (username, permission, name) in [
+        (owner_name.as_str(), "read", "username"),
+        ("no_such_user_for_share", "read", "username"),
+        (other_name.as_str(), "admin", "permission"),
+    ] {
+        let err = uc
+            .share_todo(
+                owner_id.clone(),
+                todo.id.clone(),
+                ShareTodo::new(username.to_string(), permission.to_string()),
+            )
+            .await
+            .expect_err("invalid share must fail");
+        assert!(matches!(
+            err.downcast_ref::<UseCaseError>(),
+            Some(UseCaseError::InvalidParameter(n)) if n == name
+        ));
+    }
+
+    // 공유받은 사용자는 다시 공유하거나 목록을 볼 수 없고, 관계없는 사용자에게는 todo 가 없다.
+    let err = uc
+        .share_todo(
+            guest_id.clone(),
+            todo.id.clone(),
+            ShareTodo::new(other_name.clone(), "read".to_string()),
+        )
+        .await
+        .expect_err("guest must not reshare");
+    assert!(is_forbidden(&err));
+    let err = uc
+        .find_shares(other_id.clone(), todo.id.clone())
+        .await
+        .expect_err("stranger must not see shares");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::NotFound(_))
+    ));
+
+    let shares = uc
+        .find_shares(owner_id.clone(), todo.id.clone())
+        .await
+        .unwrap();
+    assert_eq!(shares.len(), 1);
+    assert_eq!(shares[0].username, guest_name);
+
+    uc.unshare_todo(owner_id.clone(), todo.id.clone(), guest_name.clone())
+        .await
+        .expect("unshare_todo must succeed");
+    assert!(todo_uc
+        .get_todo(guest_id.clone(), todo.id.clone())
+        .await
+        .unwrap()
+        .is_none());
+    let err = uc
+        .unshare_todo(owner_id.clone(), todo.id.clone(), guest_name)
+        .await
+        .expect_err("unsharing twice must fail");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::NotFound(_))
+    ));
+}
+
+// ─── shared todo access ──────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn shared_users_read_or_edit_but_never_delete() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let todo_uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let uc: Arc<dyn ITodoShareUseCase> = module.resolve();
+    let owner_id = create_test_user(&module).await;
+    let reader_id = create_test_user(&module).await;
+    let writer_id = create_test_user(&module).await;
+    let reader_name = username_of(&module, &reader_id).await;
+    let writer_name = username_of(&module, &writer_id).await;
+
+    let todo = todo_uc
+        .create_todo(
+            owner_id.clone(),
+            CreateTodo::new("함께 할 일".to_string(), String::new()),
+        )
+        .await
+        .unwrap();
+    for (username, permission) in [(&reader_name, "read"), (&writer_name, "write")] {
+        uc.share_todo(
+            owner_id.clone(),
+            todo.id.clone(),
+            ShareTodo::new(username.clone(), permission.to_string()),
+        )
+        .await
+        .unwrap();
+    }
+
+    let seen = todo_uc
+        .get_todo(reader_id.clone(), todo.id.clone())
+        .await
+        .unwrap()
+        .expect("reader must see the shared todo");
+    assert_eq!(seen.owner_id, owner_id);
+
+    let err = todo_uc
+        .update_todo(
+            reader_id.clone(),
+            UpdateTodoView::new(todo.id.clone(), Some("몰래".to_string()), None, None),
+        )
+        .await
+        .expect_err("reader must not edit");
+    assert!(is_forbidden(&err));
+
+    let updated = todo_uc
+        .update_todo(
+            writer_id.clone(),
+            UpdateTodoView::new(
+                todo.id.clone(),
+                Some("같이 고침".to_string()),
+                None,
+                Some("working".to_string()),
+            ),
+        )
+        .await
+        .expect("writer must edit");
+    assert_eq!(updated.title, "같이 고침");
+    assert_eq!(updated.owner_id, owner_id);
+
+    // 공유받은 사용자는 소유자가 정리한 구조를 바꿀 수 없다.
+    let mut source = UpdateTodoView::new(todo.id.clone(), None, None, None);
+    source.parent_id = Some(String::new());
+    let err = todo_uc
+        .update_todo(writer_id.clone(), source)
+        .await
+        .expect_err("writer must not move the todo");
+    assert!(is_forbidden(&err));
+
+    // 변경 이력에는 실제로 고친 사용자가 남는다.
+    let history = todo_uc
+        .find_history(
+            reader_id.clone(),
+            todo.id.clone(),
+            SearchTodoHistoryCondition::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(history.events[0].actor_id, writer_id);
+
+    let err = todo_uc
+        .delete_todo(writer_id.clone(), todo.id.clone(), None)
+        .await
+        .expect_err("writer must not delete");
+    assert!(is_forbidden(&err));
+    let deleted = todo_uc
+        .delete_todo(owner_id.clone(), todo.id.clone(), None)
+        .await
+        .unwrap();
+    assert!(deleted.is_some());
+}
+
+#[tokio::test]
+async fn shared_users_see_subtasks_comments_and_attachments() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let todo_uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let uc: Arc<dyn ITodoShareUseCase> = module.resolve();
+    let comment_uc: Arc<dyn ICommentUseCase> = module.resolve();
+    let attachment_uc: Arc<dyn IAttachmentUseCase> = module.resolve();
+    let owner_id = create_test_user(&module).await;
+    let reader_id = create_test_user(&module).await;
+    let writer_id = create_test_user(&module).await;
+    let reader_name = username_of(&module, &reader_id).await;
+    let writer_name = username_of(&module, &writer_id).await;
+
+    let todo = todo_uc
+        .create_todo(
+            owner_id.clone(),
+            CreateTodo::new("함께 할 일".to_string(), String::new()),
+        )
+        .await
+        .unwrap();
+    let mut source = CreateTodo::new("하위 작업".to_string(), String::new());
+    source.parent_id = Some(todo.id.clone());
+    let child = todo_uc.create_todo(owner_id.clone(), source).await.unwrap();
+    for (username, permission) in [(&reader_name, "read"), (&writer_name, "write")] {
+        uc.share_todo(
+            owner_id.clone(),
+            todo.id.clone(),
+            ShareTodo::new(username.clone(), permission.to_string()),
+        )
+        .await
+        .unwrap();
+    }
+
+    let tree = todo_uc
+        .get_todo_tree(reader_id.clone(), todo.id.clone())
+        .await
+        .unwrap()
+        .expect("reader must see the shared tree");
+    assert_eq!(tree.children.len(), 1);
+    assert_eq!(tree.children[0].todo.id, child.id);
+    let children = todo_uc
+        .find_children(reader_id.clone(), todo.id.clone(), Default::default())
+        .await
+        .expect("reader must see the subtasks");
+    assert_eq!(children.todos.len(), 1);
+    assert_eq!(children.todos[0].id, child.id);
+
+    // 댓글과 첨부 파일은 읽기 권한으로 볼 수 있고, 남기려면 쓰기 권한이 있어야 한다.
+    let err = comment_uc
+        .create_comment(
+            reader_id.clone(),
+            todo.id.clone(),
+            CreateComment::new("읽기만".to_string()),
+        )
+        .await
+        .expect_err("reader must not comment");
+    assert!(is_forbidden(&err));
+    comment_uc
+        .create_comment(
+            writer_id.clone(),
+            todo.id.clone(),
+            CreateComment::new("같이 봐요".to_string()),
+        )
+        .await
+        .expect("writer must comment");
+    let comments = comment_uc
+        .find_comments(
+            reader_id.clone(),
+            todo.id.clone(),
+            SearchCommentCondition::default(),
+        )
+        .await
+        .expect("reader must see comments");
+    assert_eq!(comments.comments.len(), 1);
+
+    let limits = || AttachmentLimits::new(1024, vec!["text/plain".to_string()]);
+    let file = || UploadAttachment::new("memo.txt".to_string(), "text/plain".to_string());
+    let err = attachment_uc
+        .upload_attachment(
+            reader_id.clone(),
+            todo.id.clone(),
+            file(),
+            limits(),
+            Box::pin(&b"reader"[..]),
+        )
+        .await
+        .expect_err("reader must not upload");
+    assert!(is_forbidden(&err));
+    let uploaded = attachment_uc
+        .upload_attachment(
+            writer_id.clone(),
+            todo.id.clone(),
+            file(),
+            limits(),
+            Box::pin(&b"shared file"[..]),
+        )
+        .await
+        .expect("writer must upload");
+    let mut download = attachment_uc
+        .download_attachment(reader_id.clone(), todo.id.clone(), uploaded.id.clone())
+        .await
+        .expect("reader must download");
+    let mut body = String::new();
+    download.body.read_to_string(&mut body).await.unwrap();
+    assert_eq!(body, "shared file");
+}
+
+/// 상위 todo 의 공유는 하위 작업에도 미친다. 하위 작업을 읽고, 쓰기 권한이면 댓글과 첨부 파일을 남길 수 있다.
+#[tokio::test]
+async fn parent_share_covers_subtasks_comments_and_attachments() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let todo_uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let uc: Arc<dyn ITodoShareUseCase> = module.resolve();
+    let comment_uc: Arc<dyn ICommentUseCase> = module.resolve();
+    let attachment_uc: Arc<dyn IAttachmentUseCase> = module.resolve();
+    let owner_id = create_test_user(&module).await;
+    let reader_id = create_test_user(&module).await;
+    let writer_id = create_test_user(&module).await;
+    let stranger_id = create_test_user(&module).await;
+    let reader_name = username_of(&module, &reader_id).await;
+    let writer_name = username_of(&module, &writer_id).await;
+
+    let todo = todo_uc
+        .create_todo(
+            owner_id.clone(),
+            CreateTodo::new("함께 할 일".to_string(), String::new()),
+        )
+        .await
+        .unwrap();
+    let mut source = CreateTodo::new("하위 작업".to_string(), String::new());
+    source.parent_id = Some(todo.id.clone());
+    let child = todo_uc.create_todo(owner_id.clone(), source).await.unwrap();
+    let mut source = CreateTodo::new("더 아래 작업".to_string(), String::new());
+    source.parent_id = Some(child.id.clone());
+    let grandchild = todo_uc.create_todo(owner_id.clone(), source).await.unwrap();
+    for (username, permission) in [(&reader_name, "read"), (&writer_name, "write")] {
+        uc.share_todo(
+            owner_id.clone(),
+            todo.id.clone(),
+            ShareTodo::new(username.clone(), permission.to_string()),
+        )
+        .await
+        .unwrap();
+    }
+
+    let found = todo_uc
+        .get_todo(reader_id.clone(), grandchild.id.clone())
+        .await
+        .expect("reader must see a subtask of the shared todo");
+    assert_eq!(found.map(|t| t.id), Some(grandchild.id.clone()));
+    assert!(todo_uc
+        .get_todo(stranger_id.clone(), grandchild.id.clone())
+        .await
+        .map_or(true, |t| t.is_none()));
+
+    let err = comment_uc
+        .create_comment(
+            reader_id.clone(),
+            child.id.clone(),
+            CreateComment::new("읽기만".to_string()),
+        )
+        .await
+        .expect_err("reader must not comment on a subtask");
+    assert!(is_forbidden(&err));
+    comment_uc
+        .create_comment(
+            writer_id.clone(),
+            child.id.clone(),
+            CreateComment::new("하위 작업도 같이 봐요".to_string()),
+        )
+        .await
+        .expect("writer must comment on a subtask");
+    let comments = comment_uc
+        .find_comments(
+            reader_id.clone(),
+            child.id.clone(),
+            SearchCommentCondition::default(),
+        )
+        .await
+        .expect("reader must see comments of a subtask");
+    assert_eq!(comments.comments.len(), 1);
+
+    let uploaded = attachment_uc
+        .upload_attachment(
+            writer_id.clone(),
+            grandchild.id.clone(),
+            UploadAttachment::new("memo.txt".to_string(), "text/plain".to_string()),
+            AttachmentLimits::new(1024, vec!["text/plain".to_string()]),
+            Box::pin(&b"subtask file"[..]),
+        )
+        .await
+        .expect("writer must upload to a subtask");
+    let attachments = attachment_uc
+        .find_attachments(reader_id.clone(), grandchild.id.clone())
+        .await
+        .expect("reader must see attachments of a subtask");
+    assert_eq!(attachments.len(), 1);
+    assert_eq!(attachments[0].id, uploaded.id);
+}
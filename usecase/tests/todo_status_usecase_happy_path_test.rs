@@ -0,0 +1,164 @@
+//! TodoStatusUseCase 와 사용자 정의 상태 할당 통합 테스트
+//!
+//! 실행 방법:
+//! ```
+//! cargo test -p usecase --test todo_status_usecase_happy_path_test
+//! ```
+//!
+//! Docker가 실행 중이면 PostgreSQL 컨테이너를 자동으로 기동한다.
+
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::create_test_user;
+use common::module::build_usecase_test_module;
+use shaku::HasComponent;
+use std::sync::Arc;
+use usecase::error::UseCaseError;
+use usecase::model::todo::status::CreateTodoStatus;
+use usecase::model::todo::{CreateTodo, UpdateTodoView};
+use usecase::usecase::status::ITodoStatusUseCase;
+use usecase::usecase::todo::ITodoUseCase;
+
+#[tokio::test]
+async fn custom_status_is_assignable_until_disabled() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let status_uc: Arc<dyn ITodoStatusUseCase> = module.resolve();
+    let todo_uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let created = status_uc
+        .create_status(CreateTodoStatus::new(
+            "uc_review".to_string(),
+            " 검토 ".to_string(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(created.status.name, "검토");
+    assert!(!created.builtin && created.enabled);
+
+    let err = status_uc
+        .create_status(CreateTodoStatus::new(
+            "uc_review".to_string(),
+            "중복".to_string(),
+        ))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::Conflict(_))
+    ));
+
+    let renamed = status_uc
+        .rename_status("uc_review".to_string(), "리뷰".to_string())
+        .await
+        .unwrap();
+    assert_eq!(renamed.status.name, "리뷰");
+
+    let todo = todo_uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Review me".to_string(), "desc".to_string()),
+        )
+        .await
+        .unwrap();
+    let reviewed = todo_uc
+        .update_todo(
+            user_id.clone(),
+            UpdateTodoView::new(todo.id.clone(), None, None, Some("uc_review".to_string())),
+        )
+        .await
+        .unwrap();
+    assert_eq!(reviewed.status.code, "uc_review");
+    assert_eq!(reviewed.status.name, "리뷰");
+
+    let disabled = status_uc
+        .disable_status("uc_review".to_string())
+        .await
+        .unwrap();
+    assert!(!disabled.enabled);
+
+    // 끈 상태는 새로 고를 수 없지만, 이미 그 상태인 todo 는 그대로 둘 수 있다.
+    let other = todo_uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Other".to_string(), "desc".to_string()),
+        )
+        .await
+        .unwrap();
+    let err = todo_uc
+        .update_todo(
+            user_id.clone(),
+            UpdateTodoView::new(other.id, None, None, Some("uc_review".to_string())),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(name)) if name == "statusCode"
+    ));
+    let kept = todo_uc
+        .update_todo(
+            user_id.clone(),
+            UpdateTodoView::new(
+                todo.id.clone(),
+                Some("Still reviewing".to_string()),
+                None,
+                Some("uc_review".to_string()),
+            ),
+        )
+        .await
+        .unwrap();
+    assert_eq!(kept.status.code, "uc_review");
+
+    let transitions = todo_uc.get_status_transitions().await.unwrap();
+    assert!(transitions.iter().all(|t| t.status.code != "uc_review"));
+
+    let statuses = status_uc.find_statuses().await.unwrap();
+    let entry = statuses
+        .iter()
+        .find(|s| s.status.code == "uc_review")
+        .unwrap();
+    assert!(!entry.enabled);
+
+    let enabled = status_uc
+        .enable_status("uc_review".to_string())
+        .await
+        .unwrap();
+    assert!(enabled.enabled);
+}
+
+#[tokio::test]
+async fn built_in_status_cannot_be_disabled() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoStatusUseCase> = module.resolve();
+
+    let err = uc.disable_status("done".to_string()).await.unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::Conflict(_))
+    ));
+
+    let err = uc
+        .rename_status("nonexistent_code_xyz".to_string(), "없음".to_string())
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::NotFound(_))
+    ));
+
+    let err = uc
+        .create_status(CreateTodoStatus::new(
+            "Bad-Code".to_string(),
+            "잘못".to_string(),
+        ))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(name)) if name == "code"
+    ));
+}
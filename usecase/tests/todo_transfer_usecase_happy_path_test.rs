@@ -0,0 +1,152 @@
+//! Todo 내보내기·가져오기 통합 테스트
+//!
+//! 실행 방법:
+//! ```
+//! cargo test -p usecase --test todo_transfer_usecase_happy_path_test
+//! ```
+//!
+//! Docker가 실행 중이면 PostgreSQL 컨테이너를 자동으로 기동한다.
+
+mod common;
+
+use common::db::setup_test_db;
+use common::fixtures::create_test_user;
+use common::module::build_usecase_test_module;
+use shaku::HasComponent;
+use std::sync::Arc;
+use usecase::error::UseCaseError;
+use usecase::model::project::CreateProject;
+use usecase::model::todo::transfer::{ImportTodoRow, MAX_IMPORT_ROWS};
+use usecase::model::todo::CreateTodo;
+use usecase::usecase::project::IProjectUseCase;
+use usecase::usecase::todo::ITodoUseCase;
+
+fn row(row: usize, title: &str, status_code: Option<&str>) -> ImportTodoRow {
+    ImportTodoRow::new(
+        row,
+        CreateTodo::new(title.to_string(), String::new()),
+        status_code.map(str::to_string),
+    )
+}
+
+// ─── import ──────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn import_todos_inserts_valid_rows_and_reports_the_rest() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let mut bad_due = row(3, "bad due", None);
+    bad_due.source.due_at = Some("tomorrow".to_string());
+    let rows = vec![
+        row(1, "first", None),
+        row(2, "trash", Some("deleted")),
+        bad_due,
+        row(4, "finished", Some("done")),
+    ];
+    let view = uc
+        .import_todos(user_id.clone(), rows)
+        .await
+        .expect("import_todos must succeed");
+
+    assert_eq!(view.imported.len(), 2);
+    assert_eq!(view.imported[0].status.code, "new");
+    assert_eq!(view.imported[1].title, "finished");
+    assert_eq!(view.imported[1].status.code, "done");
+    let failed: Vec<usize> = view.errors.iter().map(|e| e.row).collect();
+    assert_eq!(failed, vec![2, 3]);
+
+    let page = uc.export_todos(user_id.clone(), None).await.unwrap();
+    assert_eq!(page.todos.len(), 2);
+
+    let too_many = (1..=MAX_IMPORT_ROWS + 1)
+        .map(|i| row(i, "x", None))
+        .collect();
+    let err = uc
+        .import_todos(user_id, too_many)
+        .await
+        .expect_err("too many rows must fail");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(name)) if name == "rows"
+    ));
+}
+
+#[tokio::test]
+async fn import_todos_with_unknown_status_creates_nothing_for_that_row() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let rows = vec![
+        row(1, "kept", None),
+        row(2, "unknown status", Some("no_such_status")),
+    ];
+    let view = uc
+        .import_todos(user_id.clone(), rows)
+        .await
+        .expect("import_todos must succeed");
+    assert_eq!(view.imported.len(), 1);
+    let failed: Vec<usize> = view.errors.iter().map(|e| e.row).collect();
+    assert_eq!(failed, vec![2]);
+
+    let page = uc.export_todos(user_id, None).await.unwrap();
+    let titles: Vec<&str> = page.todos.iter().map(|t| t.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        vec!["kept"],
+        "a failed row must not leave a todo behind"
+    );
+}
+
+// ─── export ──────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn export_todos_pages_through_everything_including_archived() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let project_uc: Arc<dyn IProjectUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let project = project_uc
+        .create_project(user_id.clone(), CreateProject::new("old".to_string(), None))
+        .await
+        .unwrap();
+    let mut archived = CreateTodo::new("archived".to_string(), String::new());
+    archived.project_id = Some(project.id.clone());
+    uc.create_todo(user_id.clone(), archived).await.unwrap();
+    project_uc
+        .archive_project(user_id.clone(), project.id)
+        .await
+        .unwrap();
+    let rows = (1..=120).map(|i| row(i, &format!("t{i}"), None)).collect();
+    uc.import_todos(user_id.clone(), rows).await.unwrap();
+    let trashed = uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("trashed".to_string(), String::new()),
+        )
+        .await
+        .unwrap();
+    uc.delete_todo(user_id.clone(), trashed.id, None)
+        .await
+        .unwrap();
+
+    let mut titles = vec![];
+    let mut cursor = None;
+    loop {
+        let page = uc.export_todos(user_id.clone(), cursor).await.unwrap();
+        titles.extend(page.todos.into_iter().map(|t| t.title));
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    assert_eq!(titles.len(), 121);
+    assert_eq!(titles[0], "archived");
+    assert!(!titles.contains(&"trashed".to_string()));
+}
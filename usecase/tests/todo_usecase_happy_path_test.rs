@@ -10,10 +10,13 @@
 mod common;
 
 use common::db::setup_test_db;
+use common::fixtures::create_test_user;
 use common::module::build_usecase_test_module;
 use shaku::HasComponent;
 use std::sync::Arc;
+use usecase::error::UseCaseError;
 use usecase::model::todo::{CreateTodo, SearchTodoCondition, UpdateTodoView, UpsertTodoView};
+use usecase::model::FieldUpdate;
 use usecase::usecase::todo::ITodoUseCase;
 
 // ─── create_todo ─────────────────────────────────────────────────────────────
@@ -23,9 +26,10 @@ async fn create_todo_with_valid_input_stores_and_returns_todo() {
     let pool = setup_test_db().await;
     let module = build_usecase_test_module(pool);
     let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
 
     let source = CreateTodo::new("Buy milk".to_string(), "2 bottles".to_string());
-    let result = uc.create_todo(source).await;
+    let result = uc.create_todo(user_id.clone(), source).await;
 
     let view = result.expect("create_todo must succeed with valid input");
     assert_eq!(view.title, "Buy milk");
@@ -33,6 +37,33 @@ async fn create_todo_with_valid_input_stores_and_returns_todo() {
     assert!(!view.id.is_empty(), "id must be assigned");
 }
 
+#[tokio::test]
+async fn create_todo_concurrently_assigns_distinct_positions() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let uc = uc.clone();
+            let user_id = user_id.clone();
+            tokio::spawn(async move {
+                let source = CreateTodo::new(format!("Parallel {i}"), String::new());
+                uc.create_todo(user_id, source).await
+            })
+        })
+        .collect();
+    let mut positions = vec![];
+    for handle in handles {
+        let view = handle.await.unwrap().expect("create_todo must succeed");
+        positions.push(view.position);
+    }
+    positions.sort();
+    positions.dedup();
+    assert_eq!(positions.len(), 8, "each todo must get its own position");
+}
+
 // ─── get_todo ────────────────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -40,16 +71,17 @@ async fn get_todo_with_existing_id_returns_todo() {
     let pool = setup_test_db().await;
     let module = build_usecase_test_module(pool);
     let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
 
     let created = uc
-        .create_todo(CreateTodo::new(
-            "Read book".to_string(),
-            "Rust book".to_string(),
-        ))
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Read book".to_string(), "Rust book".to_string()),
+        )
         .await
         .expect("setup: create_todo must succeed");
 
-    let result = uc.get_todo(created.id.clone()).await;
+    let result = uc.get_todo(user_id.clone(), created.id.clone()).await;
     let view = result
         .expect("get_todo must succeed")
         .expect("todo must be found");
@@ -62,11 +94,12 @@ async fn get_todo_with_nonexistent_id_returns_none() {
     let pool = setup_test_db().await;
     let module = build_usecase_test_module(pool);
     let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
 
     let fake_id = domain::model::Id::<domain::model::todo::Todo>::gen()
         .value
         .to_string();
-    let result = uc.get_todo(fake_id).await;
+    let result = uc.get_todo(user_id.clone(), fake_id).await;
 
     assert!(
         result.expect("get_todo must not error").is_none(),
@@ -81,19 +114,21 @@ async fn find_todo_without_filter_returns_created_todo() {
     let pool = setup_test_db().await;
     let module = build_usecase_test_module(pool);
     let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
 
     let created = uc
-        .create_todo(CreateTodo::new(
-            "__FIND_TEST_TODO__".to_string(),
-            "find test".to_string(),
-        ))
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("__FIND_TEST_TODO__".to_string(), "find test".to_string()),
+        )
         .await
         .expect("setup: create_todo must succeed");
 
     let todos = uc
-        .find_todo(SearchTodoCondition { status_code: None })
+        .find_todo(user_id.clone(), SearchTodoCondition::default())
         .await
-        .expect("find_todo must succeed");
+        .expect("find_todo must succeed")
+        .todos;
 
     assert!(
         todos.iter().any(|t| t.id == created.id),
@@ -101,31 +136,381 @@ async fn find_todo_without_filter_returns_created_todo() {
     );
 }
 
+#[tokio::test]
+async fn find_todo_with_limit_pages_through_all_todos_with_cursor() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let mut created_ids = Vec::new();
+    for i in 0..3 {
+        let created = uc
+            .create_todo(
+                user_id.clone(),
+                CreateTodo::new(format!("Page {i}"), "page".to_string()),
+            )
+            .await
+            .expect("setup: create_todo must succeed");
+        created_ids.push(created.id);
+    }
+
+    let first = uc
+        .find_todo(
+            user_id.clone(),
+            SearchTodoCondition {
+                limit: Some(2),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("first page must succeed");
+    assert_eq!(first.todos.len(), 2);
+    assert!(first.has_more);
+    let next_cursor = first.next_cursor.expect("next_cursor must be set");
+
+    let second = uc
+        .find_todo(
+            user_id,
+            SearchTodoCondition {
+                cursor: Some(next_cursor),
+                limit: Some(2),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("second page must succeed");
+    assert_eq!(second.todos.len(), 1);
+    assert!(!second.has_more);
+    assert!(second.next_cursor.is_none());
+
+    let paged_ids: Vec<String> = first
+        .todos
+        .into_iter()
+        .chain(second.todos)
+        .map(|t| t.id)
+        .collect();
+    assert_eq!(paged_ids, created_ids, "pages must follow creation order");
+}
+
+#[tokio::test]
+async fn find_todo_with_invalid_cursor_returns_invalid_parameter() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let result = uc
+        .find_todo(
+            user_id,
+            SearchTodoCondition {
+                cursor: Some("broken".to_string()),
+                ..Default::default()
+            },
+        )
+        .await;
+    let err = result.expect_err("invalid cursor must return Err");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(_))
+    ));
+}
+
+// ─── find_todo (q 검색) ───────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn find_todo_with_q_returns_ranked_hits_with_snippet() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let titled = uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Buy oatmilk".to_string(), "groceries".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+    let described = uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Groceries".to_string(), "remember oatmilk".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+    uc.create_todo(
+        user_id.clone(),
+        CreateTodo::new("Unrelated".to_string(), "nothing here".to_string()),
+    )
+    .await
+    .expect("setup: create_todo must succeed");
+
+    let page = uc
+        .find_todo(
+            user_id,
+            SearchTodoCondition {
+                q: Some("oatmilk".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("search must succeed");
+
+    let ids: Vec<&str> = page.todos.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(ids, vec![titled.id.as_str(), described.id.as_str()]);
+    assert!(page.todos[0].rank.unwrap() > page.todos[1].rank.unwrap());
+    assert!(page.todos[0]
+        .snippet
+        .as_deref()
+        .unwrap()
+        .contains("<mark>oatmilk</mark>"));
+}
+
+#[tokio::test]
+async fn find_todo_with_korean_q_matches_partial_word() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let created = uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new(
+                "주간회의 준비".to_string(),
+                "발표 자료를 만든다".to_string(),
+            ),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+
+    let page = uc
+        .find_todo(
+            user_id,
+            SearchTodoCondition {
+                q: Some("회의".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("search must succeed");
+
+    assert_eq!(page.todos.len(), 1);
+    assert_eq!(page.todos[0].id, created.id);
+    assert!(page.todos[0]
+        .snippet
+        .as_deref()
+        .unwrap()
+        .contains("<mark>회의</mark>"));
+}
+
+#[tokio::test]
+async fn find_todo_with_q_pages_through_hits_with_cursor() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    for i in 0..3 {
+        uc.create_todo(
+            user_id.clone(),
+            CreateTodo::new(format!("report {i}"), "weekly".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+    }
+
+    let first = uc
+        .find_todo(
+            user_id.clone(),
+            SearchTodoCondition {
+                q: Some("report".to_string()),
+                limit: Some(2),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("first page must succeed");
+    assert_eq!(first.todos.len(), 2);
+    assert!(first.has_more);
+
+    let second = uc
+        .find_todo(
+            user_id,
+            SearchTodoCondition {
+                q: Some("report".to_string()),
+                limit: Some(2),
+                cursor: first.next_cursor,
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("second page must succeed");
+    assert_eq!(second.todos.len(), 1);
+    assert!(!second.has_more);
+    assert!(!first.todos.iter().any(|t| t.id == second.todos[0].id));
+}
+
 // ─── delete_todo ─────────────────────────────────────────────────────────────
 
 #[tokio::test]
-async fn delete_todo_removes_and_returns_deleted_todo() {
+async fn delete_todo_moves_todo_to_trash_and_hides_it_from_listing() {
     let pool = setup_test_db().await;
     let module = build_usecase_test_module(pool);
     let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
 
     let created = uc
-        .create_todo(CreateTodo::new("Delete me".to_string(), "temp".to_string()))
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Delete me".to_string(), "temp".to_string()),
+        )
         .await
         .expect("setup: create_todo must succeed");
 
     let deleted = uc
-        .delete_todo(created.id.clone())
+        .delete_todo(user_id.clone(), created.id.clone(), None)
         .await
         .expect("delete_todo must succeed")
         .expect("deleted todo must be returned");
     assert_eq!(deleted.id, created.id);
+    assert_eq!(deleted.status.code, "deleted");
+    assert!(deleted.deleted_at.is_some());
+
+    let page = uc
+        .find_todo(user_id.clone(), SearchTodoCondition::default())
+        .await
+        .unwrap();
+    assert!(!page.todos.iter().any(|t| t.id == created.id));
+
+    let trash = uc
+        .find_todo(
+            user_id.clone(),
+            SearchTodoCondition {
+                status_codes: vec!["deleted".to_string()],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert!(trash.todos.iter().any(|t| t.id == created.id));
+}
+
+#[tokio::test]
+async fn purge_todo_removes_todo_permanently() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let created = uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Purge me".to_string(), "temp".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+
+    // 휴지통에 없는 todo 는 영구 삭제하지 않는다.
+    let err = uc
+        .purge_todo(user_id.clone(), created.id.clone(), None)
+        .await
+        .expect_err("purge_todo outside the trash must fail");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::Conflict(_))
+    ));
+    uc.delete_todo(user_id.clone(), created.id.clone(), None)
+        .await
+        .expect("setup: delete_todo must succeed");
+
+    let purged = uc
+        .purge_todo(user_id.clone(), created.id.clone(), None)
+        .await
+        .expect("purge_todo must succeed")
+        .expect("purged todo must be returned");
+    assert_eq!(purged.id, created.id);
 
     let after = uc
-        .get_todo(created.id)
+        .get_todo(user_id.clone(), created.id)
         .await
-        .expect("get_todo after delete must not error");
-    assert!(after.is_none(), "todo must not exist after deletion");
+        .expect("get_todo after purge must not error");
+    assert!(after.is_none(), "todo must not exist after purge");
+}
+
+#[tokio::test]
+async fn restore_todo_returns_todo_to_previous_status() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let created = uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Restore me".to_string(), "temp".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+    uc.update_todo(
+        user_id.clone(),
+        UpdateTodoView::new(created.id.clone(), None, None, Some("working".to_string())),
+    )
+    .await
+    .expect("setup: new -> working must succeed");
+    uc.delete_todo(user_id.clone(), created.id.clone(), None)
+        .await
+        .expect("setup: delete_todo must succeed");
+
+    let restored = uc
+        .restore_todo(user_id.clone(), created.id.clone())
+        .await
+        .expect("restore_todo must succeed");
+    assert_eq!(restored.status.code, "working");
+    assert!(restored.deleted_at.is_none());
+
+    let err = uc
+        .restore_todo(user_id, created.id)
+        .await
+        .expect_err("restoring a todo not in trash must fail");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::Conflict(_))
+    ));
+}
+
+#[tokio::test]
+async fn purge_deleted_todos_removes_only_todos_past_retention() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let created = uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Trash".to_string(), "temp".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+    uc.delete_todo(user_id.clone(), created.id.clone(), None)
+        .await
+        .expect("setup: delete_todo must succeed");
+
+    let purged = uc
+        .purge_deleted_todos(user_id.clone(), 30)
+        .await
+        .expect("purge_deleted_todos must succeed");
+    assert_eq!(purged, 0, "todo deleted just now is within retention");
+
+    let purged = uc
+        .purge_deleted_todos(user_id.clone(), 0)
+        .await
+        .expect("purge_deleted_todos must succeed");
+    assert_eq!(purged, 1);
+    let after = uc.get_todo(user_id, created.id).await.unwrap();
+    assert!(after.is_none(), "purged todo must not exist");
 }
 
 // ─── update_todo ──────────────────────────────────────────────────────────────
@@ -135,12 +520,13 @@ async fn update_todo_with_title_change_returns_updated_view() {
     let pool = setup_test_db().await;
     let module = build_usecase_test_module(pool);
     let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
 
     let created = uc
-        .create_todo(CreateTodo::new(
-            "Original Title".to_string(),
-            "Original Desc".to_string(),
-        ))
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Original Title".to_string(), "Original Desc".to_string()),
+        )
         .await
         .expect("setup: create_todo must succeed");
 
@@ -150,7 +536,7 @@ async fn update_todo_with_title_change_returns_updated_view() {
         None,
         None,
     );
-    let result = uc.update_todo(update).await;
+    let result = uc.update_todo(user_id.clone(), update).await;
 
     let view = result.expect("update_todo must succeed with valid input");
     assert_eq!(view.id, created.id);
@@ -166,23 +552,74 @@ async fn update_todo_with_status_code_changes_status() {
     let pool = setup_test_db().await;
     let module = build_usecase_test_module(pool);
     let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
 
     let created = uc
-        .create_todo(CreateTodo::new(
-            "Status Test".to_string(),
-            "desc".to_string(),
-        ))
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Status Test".to_string(), "desc".to_string()),
+        )
         .await
         .expect("setup: create_todo must succeed");
 
     let update = UpdateTodoView::new(created.id.clone(), None, None, Some("done".to_string()));
     let view = uc
-        .update_todo(update)
+        .update_todo(user_id.clone(), update)
         .await
         .expect("update_todo with valid status must succeed");
     assert_eq!(view.status.code, "done");
 }
 
+#[tokio::test]
+async fn update_todo_with_due_at_and_priority_changes_them() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let created = uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Plan".to_string(), "desc".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+    assert_eq!(created.priority, "medium");
+
+    let mut update = UpdateTodoView::new(created.id.clone(), None, None, None);
+    update.due_at = FieldUpdate::Set("2030-05-01T09:00:00Z".to_string());
+    update.priority = Some("high".to_string());
+    let view = uc
+        .update_todo(user_id.clone(), update)
+        .await
+        .expect("update_todo with due date and priority must succeed");
+    assert_eq!(view.priority, "high");
+    assert_eq!(
+        view.due_at.map(|d| d.to_string()).as_deref(),
+        Some("2030-05-01T09:00:00+00:00")
+    );
+
+    let mut update = UpdateTodoView::new(created.id.clone(), None, None, None);
+    update.due_at = FieldUpdate::Set("next week".to_string());
+    let err = uc
+        .update_todo(user_id.clone(), update)
+        .await
+        .expect_err("invalid dueAt must fail");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(name)) if name == "dueAt"
+    ));
+
+    let mut update = UpdateTodoView::new(created.id.clone(), None, None, None);
+    update.due_at = FieldUpdate::Clear;
+    let view = uc
+        .update_todo(user_id.clone(), update)
+        .await
+        .expect("clearing dueAt must succeed");
+    assert!(view.due_at.is_none());
+    assert_eq!(view.priority, "high");
+}
+
 // ─── upsert_todo ─────────────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -190,6 +627,7 @@ async fn upsert_todo_inserts_when_id_not_exists() {
     let pool = setup_test_db().await;
     let module = build_usecase_test_module(pool);
     let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
 
     let new_id = domain::model::Id::<domain::model::todo::Todo>::gen()
         .value
@@ -201,7 +639,7 @@ async fn upsert_todo_inserts_when_id_not_exists() {
         "new".to_string(),
     );
     let view = uc
-        .upsert_todo(upsert)
+        .upsert_todo(user_id.clone(), upsert)
         .await
         .expect("upsert_todo must succeed on insert");
     assert_eq!(view.id, new_id);
@@ -214,12 +652,13 @@ async fn upsert_todo_updates_existing_todo() {
     let pool = setup_test_db().await;
     let module = build_usecase_test_module(pool);
     let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
 
     let created = uc
-        .create_todo(CreateTodo::new(
-            "Before Upsert".to_string(),
-            "desc".to_string(),
-        ))
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Before Upsert".to_string(), "desc".to_string()),
+        )
         .await
         .expect("setup: create_todo must succeed");
 
@@ -230,7 +669,7 @@ async fn upsert_todo_updates_existing_todo() {
         "working".to_string(),
     );
     let view = uc
-        .upsert_todo(upsert)
+        .upsert_todo(user_id.clone(), upsert)
         .await
         .expect("upsert_todo must succeed on update");
     assert_eq!(view.id, created.id);
@@ -238,6 +677,237 @@ async fn upsert_todo_updates_existing_todo() {
     assert_eq!(view.status.code, "working");
 }
 
+// ─── 상태 전이 ───────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn update_todo_with_illegal_status_transition_returns_conflict() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let created = uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Workflow".to_string(), "desc".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+    uc.update_todo(
+        user_id.clone(),
+        UpdateTodoView::new(created.id.clone(), None, None, Some("done".to_string())),
+    )
+    .await
+    .expect("setup: new -> done must succeed");
+
+    let result = uc
+        .update_todo(
+            user_id.clone(),
+            UpdateTodoView::new(
+                created.id.clone(),
+                Some("Renamed".to_string()),
+                None,
+                Some("new".to_string()),
+            ),
+        )
+        .await;
+
+    let err = result.expect_err("done -> new must be rejected");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::Conflict(_))
+    ));
+    let view = uc
+        .get_todo(user_id, created.id)
+        .await
+        .unwrap()
+        .expect("todo must still exist");
+    assert_eq!(view.title, "Workflow");
+    assert_eq!(view.status.code, "done");
+}
+
+#[tokio::test]
+async fn upsert_todo_on_deleted_todo_returns_conflict() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let created = uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Workflow".to_string(), "desc".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+    uc.delete_todo(user_id.clone(), created.id.clone(), None)
+        .await
+        .expect("setup: delete_todo must succeed");
+
+    let upsert = UpsertTodoView::new(
+        created.id,
+        "Resurrected".to_string(),
+        "desc".to_string(),
+        "working".to_string(),
+    );
+    let err = uc
+        .upsert_todo(user_id, upsert)
+        .await
+        .expect_err("deleted -> working must be rejected");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::Conflict(_))
+    ));
+}
+
+#[tokio::test]
+async fn update_todo_keeping_same_status_succeeds() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let created = uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Workflow".to_string(), "desc".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+
+    let view = uc
+        .update_todo(
+            user_id,
+            UpdateTodoView::new(
+                created.id,
+                Some("Renamed".to_string()),
+                None,
+                Some("new".to_string()),
+            ),
+        )
+        .await
+        .expect("keeping the same status must succeed");
+    assert_eq!(view.title, "Renamed");
+    assert_eq!(view.status.code, "new");
+}
+
+// ─── 버전 ────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn update_todo_with_stale_version_returns_precondition_failed() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let created = uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Versioned".to_string(), "desc".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+    assert_eq!(created.version, 1);
+
+    let mut first = UpdateTodoView::new(created.id.clone(), Some("First".to_string()), None, None);
+    first.expected_version = Some(created.version);
+    let updated = uc
+        .update_todo(user_id.clone(), first)
+        .await
+        .expect("update with current version must succeed");
+    assert_eq!(updated.version, 2);
+
+    let mut stale = UpdateTodoView::new(created.id.clone(), Some("Second".to_string()), None, None);
+    stale.expected_version = Some(created.version);
+    let err = uc
+        .update_todo(user_id.clone(), stale)
+        .await
+        .expect_err("update with stale version must be rejected");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::PreconditionFailed(_))
+    ));
+
+    let err = uc
+        .delete_todo(user_id.clone(), created.id.clone(), Some(created.version))
+        .await
+        .expect_err("delete with stale version must be rejected");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::PreconditionFailed(_))
+    ));
+
+    let view = uc
+        .get_todo(user_id, created.id)
+        .await
+        .unwrap()
+        .expect("todo must still exist");
+    assert_eq!(view.title, "First");
+    assert_eq!(view.status.code, "new");
+    assert_eq!(view.version, 2);
+}
+
+#[tokio::test]
+async fn upsert_todo_with_expected_version_does_not_create_missing_todo() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let id = domain::model::Id::<domain::model::todo::Todo>::gen()
+        .value
+        .to_string();
+    let mut upsert = UpsertTodoView::new(
+        id.clone(),
+        "Ghost".to_string(),
+        "desc".to_string(),
+        "new".to_string(),
+    );
+    upsert.expected_version = Some(1);
+    let err = uc
+        .upsert_todo(user_id.clone(), upsert)
+        .await
+        .expect_err("upsert with version on missing todo must be rejected");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::PreconditionFailed(_))
+    ));
+    assert!(uc.get_todo(user_id, id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn get_status_transitions_lists_every_status_with_names() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+
+    let transitions = uc.get_status_transitions().await.unwrap();
+
+    // 다른 테스트가 추가한 사용자 정의 상태는 기본 상태 뒤에 온다.
+    let codes: Vec<&str> = transitions
+        .iter()
+        .take(7)
+        .map(|t| t.status.code.as_str())
+        .collect();
+    assert_eq!(
+        codes,
+        [
+            "new",
+            "working",
+            "waiting",
+            "done",
+            "discontinued",
+            "pending",
+            "deleted"
+        ]
+    );
+    let done = &transitions[3];
+    assert_eq!(done.status.name, "완료");
+    let next: Vec<&str> = done.next.iter().map(|s| s.code.as_str()).collect();
+    assert_eq!(next[0], "working");
+    assert!(next[1..].iter().all(|code| !codes.contains(code)));
+}
+
 // ─── find_todo (status filter) ────────────────────────────────────────────────
 
 #[tokio::test]
@@ -245,22 +915,31 @@ async fn find_todo_with_status_code_filter_returns_only_matching_todos() {
     let pool = setup_test_db().await;
     let module = build_usecase_test_module(pool);
     let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
 
     // 기본 상태 "new"로 todo 생성
     let created = uc
-        .create_todo(CreateTodo::new(
-            "__FILTER_TEST_TODO__".to_string(),
-            "filter test".to_string(),
-        ))
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new(
+                "__FILTER_TEST_TODO__".to_string(),
+                "filter test".to_string(),
+            ),
+        )
         .await
         .expect("setup: create_todo must succeed");
 
     let todos = uc
-        .find_todo(SearchTodoCondition {
-            status_code: Some("new".to_string()),
-        })
+        .find_todo(
+            user_id.clone(),
+            SearchTodoCondition {
+                status_codes: vec!["new".to_string()],
+                ..Default::default()
+            },
+        )
         .await
-        .expect("find_todo with status filter must succeed");
+        .expect("find_todo with status filter must succeed")
+        .todos;
 
     assert!(
         todos.iter().any(|t| t.id == created.id),
@@ -272,6 +951,158 @@ async fn find_todo_with_status_code_filter_returns_only_matching_todos() {
     );
 }
 
+#[tokio::test]
+async fn find_todo_with_multiple_statuses_and_sort_pages_in_sort_order() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    for (title, status) in [("b", "working"), ("a", "done"), ("c", "done"), ("d", "new")] {
+        let created = uc
+            .create_todo(
+                user_id.clone(),
+                CreateTodo::new(title.to_string(), "desc".to_string()),
+            )
+            .await
+            .expect("setup: create_todo must succeed");
+        if status != "new" {
+            uc.update_todo(
+                user_id.clone(),
+                UpdateTodoView::new(created.id, None, None, Some(status.to_string())),
+            )
+            .await
+            .expect("setup: update_todo must succeed");
+        }
+    }
+
+    let condition = |cursor| SearchTodoCondition {
+        status_codes: vec!["working".to_string(), "done".to_string()],
+        sort: Some("title".to_string()),
+        limit: Some(2),
+        cursor,
+        ..Default::default()
+    };
+    let first = uc
+        .find_todo(user_id.clone(), condition(None))
+        .await
+        .expect("first page must succeed");
+    let titles: Vec<String> = first.todos.iter().map(|t| t.title.clone()).collect();
+    assert_eq!(titles, vec!["a", "b"]);
+    assert!(first.has_more);
+
+    let second = uc
+        .find_todo(user_id.clone(), condition(first.next_cursor))
+        .await
+        .expect("second page must succeed");
+    let titles: Vec<String> = second.todos.iter().map(|t| t.title.clone()).collect();
+    assert_eq!(titles, vec!["c"]);
+    assert!(!second.has_more);
+}
+
+#[tokio::test]
+async fn find_todo_with_cursor_from_other_sort_returns_invalid_parameter() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    for title in ["a", "b"] {
+        uc.create_todo(
+            user_id.clone(),
+            CreateTodo::new(title.to_string(), "desc".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+    }
+    let first = uc
+        .find_todo(
+            user_id.clone(),
+            SearchTodoCondition {
+                sort: Some("-updatedAt".to_string()),
+                limit: Some(1),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("first page must succeed");
+
+    let result = uc
+        .find_todo(
+            user_id,
+            SearchTodoCondition {
+                sort: Some("title".to_string()),
+                cursor: first.next_cursor,
+                ..Default::default()
+            },
+        )
+        .await;
+    let err = result.expect_err("cursor from another sort must return Err");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::InvalidParameter(_))
+    ));
+}
+
+#[tokio::test]
+async fn find_todo_with_invalid_sort_or_timestamp_returns_invalid_parameter() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    for condition in [
+        SearchTodoCondition {
+            sort: Some("ownerId".to_string()),
+            ..Default::default()
+        },
+        SearchTodoCondition {
+            created_after: Some("yesterday".to_string()),
+            ..Default::default()
+        },
+        SearchTodoCondition {
+            updated_since: Some("2024-13-01T00:00:00Z".to_string()),
+            ..Default::default()
+        },
+    ] {
+        let err = uc
+            .find_todo(user_id.clone(), condition)
+            .await
+            .expect_err("invalid condition must return Err");
+        assert!(matches!(
+            err.downcast_ref::<UseCaseError>(),
+            Some(UseCaseError::InvalidParameter(_))
+        ));
+    }
+}
+
+#[tokio::test]
+async fn find_todo_with_created_after_in_future_returns_empty() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    uc.create_todo(
+        user_id.clone(),
+        CreateTodo::new("past".to_string(), "desc".to_string()),
+    )
+    .await
+    .expect("setup: create_todo must succeed");
+
+    let page = uc
+        .find_todo(
+            user_id,
+            SearchTodoCondition {
+                created_after: Some("2999-01-01T00:00:00+09:00".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect("find_todo must succeed");
+    assert!(page.todos.is_empty());
+}
+
 // ─── create_and_update_todo (happy path) ─────────────────────────────────────
 
 #[tokio::test]
@@ -279,13 +1110,14 @@ async fn create_and_update_todo_with_valid_input_returns_both_views() {
     let pool = setup_test_db().await;
     let module = build_usecase_test_module(pool);
     let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
 
     // Arrange: update 대상 todo를 미리 생성
     let target = uc
-        .create_todo(CreateTodo::new(
-            "Update Target".to_string(),
-            "target desc".to_string(),
-        ))
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Update Target".to_string(), "target desc".to_string()),
+        )
         .await
         .expect("setup: create target todo must succeed");
 
@@ -301,7 +1133,7 @@ async fn create_and_update_todo_with_valid_input_returns_both_views() {
         None,
     );
     let result = uc
-        .create_and_update_todo(create_source, update_source)
+        .create_and_update_todo(user_id.clone(), create_source, update_source)
         .await;
 
     // Assert: 두 TodoView 모두 반환 + 내용 검증
@@ -323,6 +1155,60 @@ async fn create_and_update_todo_with_valid_input_returns_both_views() {
     );
 }
 
+// ─── 소유자 격리 ──────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn todo_owned_by_other_user_is_not_visible_or_mutable() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let other_id = create_test_user(&module).await;
+
+    let created = uc
+        .create_todo(
+            user_id.clone(),
+            CreateTodo::new("Private".to_string(), "mine".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+    assert_eq!(created.owner_id, user_id);
+
+    let found = uc
+        .get_todo(other_id.clone(), created.id.clone())
+        .await
+        .expect("get_todo must not error");
+    assert!(found.is_none(), "other user must not see the todo");
+
+    let listed = uc
+        .find_todo(other_id.clone(), SearchTodoCondition::default())
+        .await
+        .expect("find_todo must succeed")
+        .todos;
+    assert!(!listed.iter().any(|t| t.id == created.id));
+
+    let update = UpdateTodoView::new(created.id.clone(), Some("Hijacked".to_string()), None, None);
+    let updated = uc.update_todo(other_id.clone(), update).await;
+    let err = updated.expect_err("update by other user must fail");
+    assert!(matches!(
+        err.downcast_ref::<UseCaseError>(),
+        Some(UseCaseError::NotFound(_))
+    ));
+
+    let deleted = uc
+        .delete_todo(other_id, created.id.clone(), None)
+        .await
+        .expect("delete_todo must not error");
+    assert!(deleted.is_none(), "other user must not delete the todo");
+
+    let still = uc
+        .get_todo(user_id, created.id)
+        .await
+        .expect("get_todo must not error")
+        .expect("owner's todo must still exist");
+    assert_eq!(still.title, "Private");
+}
+
 // ─── 에러 케이스 ──────────────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -330,8 +1216,11 @@ async fn get_todo_with_invalid_ulid_format_returns_error() {
     let pool = setup_test_db().await;
     let module = build_usecase_test_module(pool);
     let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
 
-    let result = uc.get_todo("not-a-valid-ulid".to_string()).await;
+    let result = uc
+        .get_todo(user_id.clone(), "not-a-valid-ulid".to_string())
+        .await;
     assert!(
         result.is_err(),
         "invalid ULID format must return Err before DB call"
@@ -343,12 +1232,13 @@ async fn delete_todo_with_nonexistent_valid_id_returns_none() {
     let pool = setup_test_db().await;
     let module = build_usecase_test_module(pool);
     let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
 
     let nonexistent_id = domain::model::Id::<domain::model::todo::Todo>::gen()
         .value
         .to_string();
     let result = uc
-        .delete_todo(nonexistent_id)
+        .delete_todo(user_id.clone(), nonexistent_id, None)
         .await
         .expect("delete of nonexistent id must not return Err");
     assert!(
@@ -356,3 +1246,133 @@ async fn delete_todo_with_nonexistent_valid_id_returns_none() {
         "deleting nonexistent todo must return None"
     );
 }
+
+// ─── move_todo ───────────────────────────────────────────────────────────────
+
+/// 직접 정한 순서대로 제목을 조회한다.
+async fn titles_in_position_order(uc: &Arc<dyn ITodoUseCase>, user_id: &str) -> Vec<String> {
+    uc.find_todo(
+        user_id.to_string(),
+        SearchTodoCondition {
+            sort: Some("position".to_string()),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("find_todo must succeed")
+    .todos
+    .into_iter()
+    .map(|t| t.title)
+    .collect()
+}
+
+#[tokio::test]
+async fn move_todo_places_todo_between_neighbours_in_position_order() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+
+    let mut created = vec![];
+    for title in ["a", "b", "c", "d"] {
+        let todo = uc
+            .create_todo(
+                user_id.clone(),
+                CreateTodo::new(title.to_string(), "desc".to_string()),
+            )
+            .await
+            .expect("setup: create_todo must succeed");
+        created.push(todo);
+    }
+    let ids: Vec<String> = created.iter().map(|t| t.id.clone()).collect();
+
+    // d 를 a 바로 뒤로, a 를 c 바로 앞으로, c 를 d 와 b 사이로 옮긴다.
+    uc.move_todo(user_id.clone(), ids[3].clone(), None, Some(ids[0].clone()))
+        .await
+        .expect("move after a must succeed");
+    assert_eq!(
+        titles_in_position_order(&uc, &user_id).await,
+        vec!["a", "d", "b", "c"]
+    );
+    uc.move_todo(user_id.clone(), ids[0].clone(), Some(ids[2].clone()), None)
+        .await
+        .expect("move before c must succeed");
+    assert_eq!(
+        titles_in_position_order(&uc, &user_id).await,
+        vec!["d", "b", "a", "c"]
+    );
+    let moved = uc
+        .move_todo(
+            user_id.clone(),
+            ids[2].clone(),
+            Some(ids[1].clone()),
+            Some(ids[3].clone()),
+        )
+        .await
+        .expect("move between d and b must succeed");
+    assert_eq!(
+        titles_in_position_order(&uc, &user_id).await,
+        vec!["d", "c", "b", "a"]
+    );
+    assert_eq!(moved.version, created[2].version + 1);
+
+    // 같은 자리에 계속 끼워 넣어 자리가 없어지면 다시 배치하고 옮긴다.
+    for _ in 0..200 {
+        uc.move_todo(user_id.clone(), ids[2].clone(), Some(ids[1].clone()), None)
+            .await
+            .expect("move before b must succeed");
+        uc.move_todo(user_id.clone(), ids[1].clone(), None, Some(ids[3].clone()))
+            .await
+            .expect("move after d must succeed");
+    }
+    assert_eq!(
+        titles_in_position_order(&uc, &user_id).await,
+        vec!["d", "b", "c", "a"]
+    );
+}
+
+#[tokio::test]
+async fn move_todo_with_invalid_neighbours_returns_invalid_parameter() {
+    let pool = setup_test_db().await;
+    let module = build_usecase_test_module(pool);
+    let uc: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let other_id = create_test_user(&module).await;
+
+    let mut ids = vec![];
+    for title in ["a", "b", "c"] {
+        let created = uc
+            .create_todo(
+                user_id.clone(),
+                CreateTodo::new(title.to_string(), "desc".to_string()),
+            )
+            .await
+            .expect("setup: create_todo must succeed");
+        ids.push(created.id);
+    }
+    let others = uc
+        .create_todo(
+            other_id,
+            CreateTodo::new("x".to_string(), "desc".to_string()),
+        )
+        .await
+        .expect("setup: create_todo must succeed");
+
+    for (before, after) in [
+        (None, None),
+        (Some(ids[2].clone()), None),
+        (None, Some(others.id.clone())),
+        (Some("not-an-id".to_string()), None),
+        // b 뒤이면서 a 앞인 자리는 없다.
+        (Some(ids[0].clone()), Some(ids[1].clone())),
+    ] {
+        let err = uc
+            .move_todo(user_id.clone(), ids[2].clone(), before, after)
+            .await
+            .expect_err("invalid neighbours must return Err");
+        assert!(matches!(
+            err.downcast_ref::<UseCaseError>(),
+            Some(UseCaseError::InvalidParameter(_))
+        ));
+    }
+}
@@ -10,8 +10,11 @@
 mod common;
 
 use common::db::setup_test_db;
+use common::fixtures::create_test_user;
 use common::module::build_usecase_test_module;
+use domain::model::todo::filter::{TodoFilter, TodoSort};
 use domain::model::todo::NewTodo;
+use domain::model::user::User;
 use domain::model::Id;
 use infra::repository::todo::ITodoRepository;
 use shaku::HasComponent;
@@ -28,10 +31,13 @@ async fn update_todo_with_invalid_status_rolls_back_transaction() {
     let module = build_usecase_test_module(pool.clone());
     let usecase: Arc<dyn ITodoUseCase> = module.resolve();
     let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let owner_id: Id<User> = user_id.clone().try_into().unwrap();
 
     // Setup: 테스트용 todo를 커밋하여 DB에 영구 저장
     let new_todo = NewTodo::new(
         Id::gen(),
+        owner_id.clone(),
         "Original Title".to_string(),
         "Original Description".to_string(),
     );
@@ -47,14 +53,14 @@ async fn update_todo_with_invalid_status_rolls_back_transaction() {
         None,
         Some("INVALID_STATUS_THAT_DOES_NOT_EXIST".to_string()),
     );
-    let result = usecase.update_todo(update_view).await;
+    let result = usecase.update_todo(user_id.clone(), update_view).await;
     assert!(
         result.is_err(),
         "invalid status_code must return Err, got: {result:?}"
     );
 
     // Assert: DB에 변경 없음 (롤백 검증)
-    let found = todo_repo.get(&inserted.id).await.unwrap();
+    let found = todo_repo.get(&owner_id, &inserted.id).await.unwrap();
     let found = found.expect("todo must still exist after rollback");
     assert_eq!(
         found.title, "Original Title",
@@ -64,7 +70,7 @@ async fn update_todo_with_invalid_status_rolls_back_transaction() {
     // Cleanup
     let mut cleanup_tx = pool.begin().await.unwrap();
     todo_repo
-        .delete_tx(&mut cleanup_tx, &inserted.id)
+        .delete_tx(&mut cleanup_tx, &owner_id, &inserted.id, None)
         .await
         .unwrap();
     cleanup_tx.commit().await.unwrap();
@@ -78,6 +84,8 @@ async fn create_and_update_todo_when_update_fails_rolls_back_create() {
     let module = build_usecase_test_module(pool.clone());
     let usecase: Arc<dyn ITodoUseCase> = module.resolve();
     let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let owner_id: Id<User> = user_id.clone().try_into().unwrap();
 
     // Setup: update 대상 todo를 커밋하여 DB에 저장
     let mut setup_tx = pool.begin().await.unwrap();
@@ -86,6 +94,7 @@ async fn create_and_update_todo_when_update_fails_rolls_back_create() {
             &mut setup_tx,
             NewTodo::new(
                 Id::gen(),
+                owner_id.clone(),
                 "Update Target".to_string(),
                 "Target Desc".to_string(),
             ),
@@ -110,7 +119,7 @@ async fn create_and_update_todo_when_update_fails_rolls_back_create() {
         Some("INVALID_STATUS_THAT_DOES_NOT_EXIST".to_string()),
     );
     let result = usecase
-        .create_and_update_todo(create_source, update_source)
+        .create_and_update_todo(user_id.clone(), create_source, update_source)
         .await;
     assert!(
         result.is_err(),
@@ -118,7 +127,16 @@ async fn create_and_update_todo_when_update_fails_rolls_back_create() {
     );
 
     // Assert: create도 롤백됨
-    let all_todos = todo_repo.find(None).await.unwrap();
+    let all_todos = todo_repo
+        .find(
+            &owner_id,
+            &TodoFilter::default(),
+            TodoSort::default(),
+            None,
+            100,
+        )
+        .await
+        .unwrap();
     assert!(
         !all_todos.iter().any(|t| t.title == unique_title),
         "created todo must not exist in DB after transaction rollback"
@@ -126,7 +144,7 @@ async fn create_and_update_todo_when_update_fails_rolls_back_create() {
 
     // Assert: update 대상 todo는 변경 없음
     let target = todo_repo
-        .get(&target_todo.id)
+        .get(&owner_id, &target_todo.id)
         .await
         .unwrap()
         .expect("update target must still exist after rollback");
@@ -138,7 +156,7 @@ async fn create_and_update_todo_when_update_fails_rolls_back_create() {
     // Cleanup
     let mut cleanup_tx = pool.begin().await.unwrap();
     todo_repo
-        .delete_tx(&mut cleanup_tx, &target_todo.id)
+        .delete_tx(&mut cleanup_tx, &owner_id, &target_todo.id, None)
         .await
         .unwrap();
     cleanup_tx.commit().await.unwrap();
@@ -152,11 +170,12 @@ async fn update_todo_with_nonexistent_id_rolls_back_transaction() {
     let pool = setup_test_db().await;
     let module = build_usecase_test_module(pool);
     let usecase: Arc<dyn ITodoUseCase> = module.resolve();
+    let user_id = create_test_user(&module).await;
 
     let nonexistent_id = Id::<domain::model::todo::Todo>::gen().value.to_string();
     let update_view =
         UpdateTodoView::new(nonexistent_id, Some("Ghost Title".to_string()), None, None);
-    let result = usecase.update_todo(update_view).await;
+    let result = usecase.update_todo(user_id.clone(), update_view).await;
 
     assert!(
         result.is_err(),
@@ -172,6 +191,8 @@ async fn create_and_update_todo_with_invalid_id_format_rolls_back_create() {
     let module = build_usecase_test_module(pool.clone());
     let usecase: Arc<dyn ITodoUseCase> = module.resolve();
     let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let owner_id: Id<User> = user_id.clone().try_into().unwrap();
 
     let unique_title = format!(
         "__ROLLBACK_INVALID_ID_TEST__{}",
@@ -185,7 +206,7 @@ async fn create_and_update_todo_with_invalid_id_format_rolls_back_create() {
         None,
     );
     let result = usecase
-        .create_and_update_todo(create_source, update_source)
+        .create_and_update_todo(user_id.clone(), create_source, update_source)
         .await;
     assert!(
         result.is_err(),
@@ -193,7 +214,16 @@ async fn create_and_update_todo_with_invalid_id_format_rolls_back_create() {
     );
 
     // Assert: insert된 todo가 DB에 없음 (롤백 검증)
-    let all = todo_repo.find(None).await.unwrap();
+    let all = todo_repo
+        .find(
+            &owner_id,
+            &TodoFilter::default(),
+            TodoSort::default(),
+            None,
+            100,
+        )
+        .await
+        .unwrap();
     assert!(
         !all.iter().any(|t| t.title == unique_title),
         "created todo must not exist in DB after transaction rollback"
@@ -209,6 +239,8 @@ async fn upsert_todo_with_invalid_status_rolls_back_transaction() {
     let module = build_usecase_test_module(pool.clone());
     let usecase: Arc<dyn ITodoUseCase> = module.resolve();
     let todo_repo: Arc<dyn ITodoRepository> = module.resolve();
+    let user_id = create_test_user(&module).await;
+    let owner_id: Id<User> = user_id.clone().try_into().unwrap();
 
     let upsert_id = Id::<domain::model::todo::Todo>::gen().value.to_string();
     let upsert_source = UpsertTodoView::new(
@@ -217,7 +249,7 @@ async fn upsert_todo_with_invalid_status_rolls_back_transaction() {
         "Upsert Desc".to_string(),
         "INVALID_STATUS_CODE".to_string(),
     );
-    let result = usecase.upsert_todo(upsert_source).await;
+    let result = usecase.upsert_todo(user_id.clone(), upsert_source).await;
     assert!(
         result.is_err(),
         "invalid status_code must return Err, got: {result:?}"
@@ -225,7 +257,7 @@ async fn upsert_todo_with_invalid_status_rolls_back_transaction() {
 
     // Assert: upsert된 todo가 DB에 없음 (롤백 검증)
     let parsed_id: Id<domain::model::todo::Todo> = upsert_id.try_into().unwrap();
-    let found = todo_repo.get(&parsed_id).await.unwrap();
+    let found = todo_repo.get(&owner_id, &parsed_id).await.unwrap();
     assert!(
         found.is_none(),
         "upserted todo must not exist in DB after transaction rollback"